@@ -22,6 +22,8 @@ use frame_support::{
 	traits::tokens::Balance as BalanceTrait,
 };
 
+pub mod math;
+
 /// A minimal interface to test the functionality of the Voting pallet.
 pub trait VotingInterface {
 	/// The type which can be used to identify accounts.
@@ -0,0 +1,107 @@
+//! The quadratic voting arithmetic used by the runtime, exposed so that front-ends and other
+//! pallets compute the cost and the weight of a vote exactly like the voting pallet does.
+
+use frame_support::sp_runtime::{
+	traits::{AtLeast32BitUnsigned, IntegerSquareRoot, UniqueSaturatedInto},
+	Percent, Saturating,
+};
+
+/// The collateral, or the voice credits, a vote of `power` costs: `power` squared, saturated.
+pub fn quadratic_cost<Balance: AtLeast32BitUnsigned>(power: Balance) -> Balance {
+	power.clone().saturating_mul(power)
+}
+
+/// The highest power a `balance` can afford, the integer square root of the balance.
+pub fn max_power<Balance: AtLeast32BitUnsigned>(balance: Balance) -> Balance {
+	balance.integer_sqrt()
+}
+
+/// The amount a vote of `power` adds to the tally, the quadratic amount increased by `bonus`.
+/// Tallies are kept in `u128` whatever the balance type. Returns `None` on overflow.
+pub fn tallied_amount<Power: UniqueSaturatedInto<u128>>(
+	power: Power,
+	bonus: Percent,
+) -> Option<u128> {
+	let power: u128 = power.unique_saturated_into();
+	power
+		.checked_mul(power)
+		.and_then(|amount| amount.checked_add(bonus.mul_floor(amount)))
+}
+
+/// The tallied `amount` of a vote cast `hops` delegations away from the voter, reduced by
+/// `attenuation` at each hop.
+pub fn attenuate(amount: u128, attenuation: Percent, hops: u32) -> u128 {
+	Percent::from_percent(100)
+		.saturating_sub(attenuation)
+		.saturating_pow(hops as usize)
+		.mul_floor(amount)
+}
+
+#[cfg(test)]
+mod tests {
+	use super::*;
+	use frame_support::sp_runtime::traits::Zero;
+
+	#[test]
+	fn quadratic_cost_squares_the_power() {
+		for power in 0u64..=1_000 {
+			assert_eq!(quadratic_cost(power), power * power);
+		}
+		assert_eq!(quadratic_cost(u32::MAX), u32::MAX);
+		assert_eq!(quadratic_cost(u128::from(u64::MAX)), u128::from(u64::MAX).pow(2));
+		assert_eq!(quadratic_cost(u128::MAX), u128::MAX);
+	}
+
+	#[test]
+	fn max_power_is_the_integer_square_root() {
+		for balance in 0u64..=10_000 {
+			let power = max_power(balance);
+			assert!(quadratic_cost(power) <= balance);
+			assert!(quadratic_cost(power + 1) > balance);
+		}
+		assert_eq!(max_power(u32::MAX), u32::from(u16::MAX));
+		assert_eq!(max_power(u128::MAX), u128::from(u64::MAX));
+	}
+
+	#[test]
+	fn max_power_round_trips_the_cost() {
+		for power in (0u128..=1_000).chain([u128::from(u32::MAX), u128::from(u64::MAX)]) {
+			assert_eq!(max_power(quadratic_cost(power)), power);
+		}
+	}
+
+	#[test]
+	fn tallied_amount_adds_the_bonus() {
+		for power in 0u32..=1_000 {
+			let amount = u128::from(power).pow(2);
+			assert_eq!(tallied_amount(power, Percent::zero()), Some(amount));
+			assert_eq!(
+				tallied_amount(power, Percent::from_percent(10)),
+				Some(amount + amount / 10)
+			);
+			assert_eq!(tallied_amount(power, Percent::from_percent(100)), Some(amount * 2));
+		}
+	}
+
+	#[test]
+	fn tallied_amount_detects_overflows() {
+		assert_eq!(tallied_amount(u64::MAX, Percent::zero()), Some(u128::from(u64::MAX).pow(2)));
+		assert_eq!(tallied_amount(u64::MAX, Percent::from_percent(100)), None);
+		assert_eq!(tallied_amount(u128::from(u64::MAX) + 1, Percent::zero()), None);
+		assert_eq!(tallied_amount(u128::MAX, Percent::zero()), None);
+	}
+
+	#[test]
+	fn attenuate_reduces_the_amount_at_each_hop() {
+		let attenuation = Percent::from_percent(50);
+		assert_eq!(attenuate(1_000, attenuation, 0), 1_000);
+		assert_eq!(attenuate(1_000, attenuation, 1), 500);
+		assert_eq!(attenuate(1_000, attenuation, 2), 250);
+		for hops in 0..=10 {
+			assert_eq!(attenuate(1_000, Percent::zero(), hops), 1_000);
+		}
+		assert_eq!(attenuate(1_000, Percent::from_percent(100), 0), 1_000);
+		assert_eq!(attenuate(1_000, Percent::from_percent(100), 1), 0);
+		assert_eq!(attenuate(u128::MAX, attenuation, 1), u128::MAX / 2);
+	}
+}
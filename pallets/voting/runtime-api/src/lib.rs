@@ -0,0 +1,74 @@
+//! Runtime API to query the voting pallet without scanning its storage.
+
+#![cfg_attr(not(feature = "std"), no_std)]
+
+use codec::Codec;
+use pallet_voting::{
+	ArchiveProof, CreateError, HoldHealth, LockReport, ProposalId, ProposalKind, ProposalOutcome,
+	ProposalStatus, VoteProof, VoteRatio, VoteShortfall,
+};
+use sp_std::vec::Vec;
+
+sp_api::decl_runtime_apis! {
+	pub trait VotingApi<AccountId, Balance, BlockNumber>
+	where
+		AccountId: Codec,
+		Balance: Codec,
+		BlockNumber: Codec,
+	{
+		/// The private proposals whose account list allows `who` to vote.
+		fn eligible_proposals(who: AccountId) -> Vec<ProposalId>;
+
+		/// The published tally of a proposal, open or archived.
+		fn tally(proposal_id: ProposalId) -> Option<VoteRatio>;
+
+		/// How an archived proposal ended, withheld while it is disputed.
+		fn outcome(proposal_id: ProposalId) -> Option<ProposalOutcome>;
+
+		/// Where a proposal stands in its lifecycle, until its archive is pruned.
+		fn status(proposal_id: ProposalId) -> Option<ProposalStatus>;
+
+		/// The proposals ending at `block`, closed automatically in its `on_initialize`.
+		fn proposals_ending_at(block: BlockNumber) -> Vec<ProposalId>;
+
+		/// Whether `who` is a registered voter.
+		fn is_registered(who: AccountId) -> bool;
+
+		/// The balance of `who` held as collateral of its votes.
+		fn held_for_voting(who: AccountId) -> Balance;
+
+		/// The collateral held for the votes of `who` against the cost of its votes, to detect an
+		/// accounting drift.
+		fn hold_health(who: AccountId) -> HoldHealth<Balance>;
+
+		/// How the collateral held for the votes of `who` overlaps with its other holds, locks and
+		/// freezes, with its spendable balance and the power it can still afford.
+		fn lock_report(who: AccountId) -> LockReport<Balance>;
+
+		/// What `who` is short of to vote with `power` on an open proposal, `None` when the cost
+		/// is covered.
+		fn check_vote(
+			who: AccountId,
+			proposal_id: ProposalId,
+			power: Balance,
+		) -> Option<VoteShortfall<Balance>>;
+
+		/// The last vote of `who` on a proposal with the merkle proof of its inclusion in the
+		/// votes tree of the proposal, open or archived, `None` without a vote.
+		fn vote_proof(proposal_id: ProposalId, who: AccountId) -> Option<VoteProof<Balance>>;
+
+		/// The pruned archive of a proposal archived in the epoch `era`, with the merkle proof of
+		/// its inclusion in the archive tree of the epoch, `None` while it is not pruned.
+		fn archive_proof(era: u32, proposal_id: ProposalId) -> Option<ArchiveProof>;
+
+		/// Dry runs the checks of `create_proposal`, so a proposal form can be validated without
+		/// submitting it.
+		fn validate_proposal(
+			creator: AccountId,
+			kind: ProposalKind,
+			account_list_len: u32,
+			start: BlockNumber,
+			end: BlockNumber,
+		) -> Result<(), CreateError>;
+	}
+}
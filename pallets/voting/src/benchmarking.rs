@@ -2,33 +2,1257 @@
 #![cfg(feature = "runtime-benchmarks")]
 use super::*;
 
+use crate::types::{membership_leaf, merkle_parent, MAX_OPTION_LABEL_LEN, MAX_PROOF_DEPTH};
 #[allow(unused)]
 use crate::Pallet as Voting;
 use frame_benchmarking::v2::*;
+use frame_support::{sp_runtime::traits::Bounded, sp_std::vec};
 use frame_system::RawOrigin;
 
-#[benchmarks]
+const SEED: u32 = 0;
+
+/// Moves past `BalanceAge` so the balance snapshots taken at block zero back the votes, and
+/// returns the current block.
+fn setup_block<T: Config<I>, I: 'static>() -> BlockNumberFor<T> {
+	let now: BlockNumberFor<T> = T::BalanceAge::get().max(1).into();
+	frame_system::Pallet::<T>::set_block_number(now);
+	now
+}
+
+/// Funds `who` well above the cost of the benchmarked votes, bonds and deposits.
+fn fund<T: Config<I>, I: 'static>(who: &T::AccountId) -> BalanceOf<T, I> {
+	let balance = BalanceOf::<T, I>::max_value() / 1_000_000u32.into();
+	<T::NativeBalance as fungible::Mutate<T::AccountId>>::set_balance(who, balance);
+	balance
+}
+
+/// A funded registered voter whose balance snapshot is seasoned.
+fn funded_voter<T: Config<I>, I: 'static>(
+	name: &'static str,
+	index: u32,
+) -> Result<T::AccountId, BenchmarkError> {
+	let who: T::AccountId = account(name, index, SEED);
+	let balance = fund::<T, I>(&who);
+	Pallet::<T, I>::register(&who)?;
+	BalanceSnapshots::<T, I>::insert(&who, (BlockNumberFor::<T>::zero(), balance));
+	Ok(who)
+}
+
+/// An account list of `count` accounts.
+fn account_list<T: Config<I>, I: 'static>(
+	name: &'static str,
+	count: u32,
+) -> BoundedVec<T::AccountId, T::AccountSizeLimit> {
+	let accounts: Vec<T::AccountId> = (0..count).map(|index| account(name, index, SEED)).collect();
+	BoundedVec::truncate_from(accounts)
+}
+
+/// `MaxOptions` options with labels of the maximum length.
+fn options<T: Config<I>, I: 'static>() -> BoundedVec<OptionLabel, T::MaxOptions> {
+	let label = OptionLabel::truncate_from(vec![0; MAX_OPTION_LABEL_LEN as usize]);
+	BoundedVec::truncate_from(vec![label; T::MaxOptions::get() as usize])
+}
+
+/// An amount well above the existential deposit, to open the sub-accounts of the proposals.
+fn deposit<T: Config<I>, I: 'static>() -> BalanceOf<T, I> {
+	<T::NativeBalance as fungible::Inspect<T::AccountId>>::minimum_balance()
+		.saturating_mul(100u32.into())
+		.max(100u32.into())
+}
+
+/// Creates a proposal of `creator` starting at `start_block` and lasting the minimum duration,
+/// already seconded by `SecondsThreshold` voters. Returns its ID.
+fn open_proposal<T: Config<I>, I: 'static>(
+	creator: &T::AccountId,
+	kind: ProposalKind,
+	account_list: Option<BoundedVec<T::AccountId, T::AccountSizeLimit>>,
+	start_block: BlockNumberFor<T>,
+	settings: ProposalSettings,
+) -> Result<ProposalId, BenchmarkError> {
+	let end_block = start_block.saturating_add(T::ProposalMinimumDuration::get().max(1).into());
+	let proposal = ProposalData::<T, I>::new(
+		BoundedVec::default(),
+		kind,
+		creator.clone(),
+		account_list,
+		start_block,
+		end_block,
+		settings,
+	);
+	let proposal_id = NextProposalId::<T, I>::get();
+	Pallet::<T, I>::do_create_proposal(proposal)?;
+	Proposals::<T, I>::mutate(proposal_id, |maybe_proposal| {
+		if let Some(proposal) = maybe_proposal {
+			proposal.seconds = T::SecondsThreshold::get();
+		}
+	});
+	Ok(proposal_id)
+}
+
+/// Casts a vote of power one of `count` new voters on `proposal_id`.
+fn cast_votes<T: Config<I>, I: 'static>(
+	proposal_id: ProposalId,
+	count: u32,
+) -> Result<(), BenchmarkError> {
+	for index in 0..count {
+		let voter = funded_voter::<T, I>("voter", index)?;
+		Pallet::<T, I>::vote(
+			RawOrigin::Signed(voter).into(),
+			proposal_id,
+			true,
+			1u32.into(),
+			Conviction::None,
+		)?;
+	}
+	Ok(())
+}
+
+/// Moves to the end of `proposal_id` and closes it.
+fn close<T: Config<I>, I: 'static>(proposal_id: ProposalId) -> Result<(), BenchmarkError> {
+	let proposal = Proposals::<T, I>::get(proposal_id).ok_or(BenchmarkError::Weightless)?;
+	frame_system::Pallet::<T>::set_block_number(proposal.end_block);
+	Pallet::<T, I>::do_close_proposal(proposal_id, None)?;
+	Ok(())
+}
+
+/// A closed proposal of `creator` voted on by `voter`.
+fn closed_proposal<T: Config<I>, I: 'static>(
+	creator: &T::AccountId,
+	voter: &T::AccountId,
+) -> Result<ProposalId, BenchmarkError> {
+	let now = setup_block::<T, I>();
+	let proposal_id =
+		open_proposal::<T, I>(creator, ProposalKind::Public, None, now, Default::default())?;
+	Pallet::<T, I>::vote(
+		RawOrigin::Signed(voter.clone()).into(),
+		proposal_id,
+		true,
+		1u32.into(),
+		Conviction::None,
+	)?;
+	close::<T, I>(proposal_id)?;
+	Ok(proposal_id)
+}
+
+/// A chain of `hops` delegates starting from `delegator`. Returns the last delegate.
+fn delegation_chain<T: Config<I>, I: 'static>(delegator: &T::AccountId, hops: u32) -> T::AccountId {
+	let mut last = delegator.clone();
+	for index in 0..hops {
+		let delegate: T::AccountId = account("delegate", index, SEED);
+		Delegations::<T, I>::insert(&last, &delegate);
+		Delegators::<T, I>::insert(&delegate, &last, ());
+		last = delegate;
+	}
+	last
+}
+
+/// An organisation administered by `admin`.
+fn new_org<T: Config<I>, I: 'static>(admin: &T::AccountId) -> Result<OrgId, BenchmarkError> {
+	let org = NextOrgId::<T, I>::get();
+	Pallet::<T, I>::create_org(RawOrigin::Root.into(), admin.clone())?;
+	Ok(org)
+}
+
+#[instance_benchmarks]
 mod benchmarks {
 	use super::*;
 
+	/// Registers a voter by a deputy registrar, whose limit is counted.
+	#[benchmark]
+	fn register_voter() {
+		let who: T::AccountId = account("voter", 0, SEED);
+		fund::<T, I>(&who);
+		let deputy: T::AccountId = whitelisted_caller();
+		Deputies::<T, I>::insert(
+			&deputy,
+			DeputyInfo { limit: 1, period_start: Zero::zero(), used: 0 },
+		);
+
+		#[extrinsic_call]
+		register_voter(RawOrigin::Signed(deputy), who.clone());
+
+		assert!(RegisteredVoters::<T, I>::contains_key(&who));
+	}
+
+	/// Unregisters by a deputy registrar a voter with votes on open proposals if `v` is 1 and
+	/// delegators if `d` is 1. The votes and the delegations are dropped by the cleanup queue, one
+	/// per step charged with it, so the call only depends on whether it enqueues each task.
 	#[benchmark]
-	fn do_something() {
-		let value = 100u32.into();
+	fn unregister_voter(v: Linear<0, 1>, d: Linear<0, 1>) {
 		let caller: T::AccountId = whitelisted_caller();
+		let deputy: T::AccountId = account("deputy", 0, SEED);
+		Deputies::<T, I>::insert(
+			&deputy,
+			DeputyInfo { limit: 1, period_start: Zero::zero(), used: 0 },
+		);
+		RegisteredVoters::<T, I>::insert(
+			&caller,
+			VoterInfo { registered_at: Zero::zero(), expires_at: None },
+		);
+		RegisteredVotersCount::<T, I>::put(1);
+		for proposal_id in 0..v {
+			Votes::<T, I>::insert(
+				&caller,
+				proposal_id,
+				VoteInfo {
+					proposal_id,
+					aye: true,
+					power: 1u32.into(),
+					conviction: Conviction::None,
+				},
+			);
+		}
+		for index in 0..d {
+			let delegator: T::AccountId = account("delegator", index, 0);
+			Delegations::<T, I>::insert(&delegator, &caller);
+			Delegators::<T, I>::insert(&caller, delegator, ());
+		}
+
+		#[extrinsic_call]
+		unregister_voter(RawOrigin::Signed(deputy), caller.clone());
+
+		assert!(!RegisteredVoters::<T, I>::contains_key(&caller));
+	}
+
+	#[benchmark]
+	fn self_register() {
+		let caller: T::AccountId = whitelisted_caller();
+		fund::<T, I>(&caller);
+
 		#[extrinsic_call]
-		do_something(RawOrigin::Signed(caller), value);
+		self_register(RawOrigin::Signed(caller.clone()));
 
-		assert_eq!(Something::<T>::get(), Some(value));
+		assert!(RegistrationBonds::<T, I>::contains_key(&caller));
 	}
 
+	/// Unregisters a self-registered voter with votes on open proposals if `v` is 1 and delegators
+	/// if `d` is 1, returning its bond.
 	#[benchmark]
-	fn cause_error() {
-		Something::<T>::put(100u32);
+	fn self_unregister(v: Linear<0, 1>, d: Linear<0, 1>) -> Result<(), BenchmarkError> {
 		let caller: T::AccountId = whitelisted_caller();
+		fund::<T, I>(&caller);
+		Pallet::<T, I>::self_register(RawOrigin::Signed(caller.clone()).into())?;
+		for proposal_id in 0..v {
+			Votes::<T, I>::insert(
+				&caller,
+				proposal_id,
+				VoteInfo {
+					proposal_id,
+					aye: true,
+					power: 1u32.into(),
+					conviction: Conviction::None,
+				},
+			);
+		}
+		for index in 0..d {
+			let delegator: T::AccountId = account("delegator", index, 0);
+			Delegations::<T, I>::insert(&delegator, &caller);
+			Delegators::<T, I>::insert(&caller, delegator, ());
+		}
+
+		#[extrinsic_call]
+		self_unregister(RawOrigin::Signed(caller.clone()));
+
+		assert!(!RegistrationBonds::<T, I>::contains_key(&caller));
+		Ok(())
+	}
+
+	/// Migrates a voter with `e` votes and delegators.
+	#[benchmark]
+	fn migrate_voter(e: Linear<0, { T::MaxMigratedEntries::get() }>) -> Result<(), BenchmarkError> {
+		let old = funded_voter::<T, I>("old", 0)?;
+		let new: T::AccountId = account("new", 0, SEED);
+		// The collateral is moved on hold to the new account, which must exist
+		fund::<T, I>(&new);
+		<T::NativeBalance as fungible::hold::Mutate<T::AccountId>>::hold(
+			&HoldReason::VoteCollateral.into(),
+			&old,
+			1_000u32.into(),
+		)?;
+		frame_system::Pallet::<T>::inc_consumers(&old)?;
+		let votes = e / 2;
+		for proposal_id in 0..votes {
+			Votes::<T, I>::insert(
+				&old,
+				proposal_id,
+				VoteInfo {
+					proposal_id,
+					aye: true,
+					power: 1u32.into(),
+					conviction: Conviction::None,
+				},
+			);
+		}
+		for index in votes..e {
+			let delegator: T::AccountId = account("delegator", index, SEED);
+			Delegations::<T, I>::insert(&delegator, &old);
+			Delegators::<T, I>::insert(&old, delegator, ());
+		}
+
+		#[extrinsic_call]
+		migrate_voter(RawOrigin::Signed(old.clone()), old.clone(), new.clone());
+
+		assert!(RegisteredVoters::<T, I>::contains_key(&new));
+		Ok(())
+	}
+
+	#[benchmark]
+	fn appoint_deputy() -> Result<(), BenchmarkError> {
+		let origin =
+			T::RegistrarOrigin::try_successful_origin().map_err(|_| BenchmarkError::Weightless)?;
+		let who: T::AccountId = account("deputy", 0, SEED);
+
+		#[extrinsic_call]
+		appoint_deputy(origin as T::RuntimeOrigin, who.clone(), 10);
+
+		assert!(Deputies::<T, I>::contains_key(&who));
+		Ok(())
+	}
+
+	#[benchmark]
+	fn revoke_deputy() -> Result<(), BenchmarkError> {
+		let origin =
+			T::RegistrarOrigin::try_successful_origin().map_err(|_| BenchmarkError::Weightless)?;
+		let who: T::AccountId = account("deputy", 0, SEED);
+		Deputies::<T, I>::insert(
+			&who,
+			DeputyInfo { limit: 10, period_start: Zero::zero(), used: 0 },
+		);
+		DeputiesCount::<T, I>::put(1);
+
+		#[extrinsic_call]
+		revoke_deputy(origin as T::RuntimeOrigin, who.clone());
+
+		assert!(!Deputies::<T, I>::contains_key(&who));
+		Ok(())
+	}
+
+	#[benchmark]
+	fn sync_voters() -> Result<(), BenchmarkError> {
+		let origin =
+			T::RegistrarOrigin::try_successful_origin().map_err(|_| BenchmarkError::Weightless)?;
+		let who: T::AccountId = account("voter", 0, SEED);
+		let preimage_hash = T::BenchmarkHelper::note_preimage(vec![(who, true)].encode());
+
+		#[extrinsic_call]
+		sync_voters(origin as T::RuntimeOrigin, preimage_hash);
+
+		assert!(VoterSyncs::<T, I>::contains_key(preimage_hash));
+		Ok(())
+	}
+
+	/// Creates a private proposal allowing `a` accounts.
+	#[benchmark]
+	fn create_proposal(a: Linear<0, { T::AccountSizeLimit::get() }>) -> Result<(), BenchmarkError> {
+		let now = setup_block::<T, I>();
+		let caller = funded_voter::<T, I>("creator", 0)?;
+		let end_block = now.saturating_add(T::ProposalMinimumDuration::get().max(1).into());
+		let proposal_id = NextProposalId::<T, I>::get();
+
+		#[extrinsic_call]
+		create_proposal(
+			RawOrigin::Signed(caller),
+			BoundedVec::default(),
+			ProposalKind::Private,
+			Some(account_list::<T, I>("member", a)),
+			now,
+			end_block,
+			Default::default(),
+		);
+
+		assert!(Proposals::<T, I>::contains_key(proposal_id));
+		Ok(())
+	}
+
+	/// Cancels as spam a private proposal allowing `a` accounts, slashing its deposit.
+	#[benchmark]
+	fn cancel_proposal(a: Linear<0, { T::AccountSizeLimit::get() }>) -> Result<(), BenchmarkError> {
+		let now = setup_block::<T, I>();
+		let caller = funded_voter::<T, I>("creator", 0)?;
+		let proposal_id = open_proposal::<T, I>(
+			&caller,
+			ProposalKind::Private,
+			Some(account_list::<T, I>("member", a)),
+			now.saturating_add(1u32.into()),
+			Default::default(),
+		)?;
+		Pallet::<T, I>::hold_deposit(proposal_id, &caller)?;
+
+		#[extrinsic_call]
+		cancel_proposal(RawOrigin::Root, proposal_id, CancellationReason::Spam);
+
+		assert!(!Proposals::<T, I>::contains_key(proposal_id));
+		Ok(())
+	}
+
+	/// Closes a proposal sampling `s` votes for its audit and drawing `w` lottery winners.
+	#[benchmark]
+	fn close_proposal(
+		s: Linear<0, { T::AuditSampleSize::get() }>,
+		w: Linear<0, { T::MaxLotteryWinners::get() }>,
+	) -> Result<(), BenchmarkError> {
+		let now = setup_block::<T, I>();
+		let creator = funded_voter::<T, I>("creator", 0)?;
+		let settings =
+			ProposalSettings { lottery_winners: (w > 0).then_some(w), ..Default::default() };
+		let proposal_id =
+			open_proposal::<T, I>(&creator, ProposalKind::Public, None, now, settings)?;
+		if w > 0 {
+			Pallet::<T, I>::fund_lottery(
+				RawOrigin::Signed(creator.clone()).into(),
+				proposal_id,
+				deposit::<T, I>(),
+			)?;
+		}
+		cast_votes::<T, I>(proposal_id, s.max(w))?;
+		let proposal = Proposals::<T, I>::get(proposal_id).ok_or(BenchmarkError::Weightless)?;
+		frame_system::Pallet::<T>::set_block_number(proposal.end_block);
+
+		#[extrinsic_call]
+		close_proposal(RawOrigin::Signed(creator), proposal_id);
+
+		assert!(ArchivedProposals::<T, I>::contains_key(proposal_id));
+		Ok(())
+	}
+
+	/// Replaces a full account list with one of `a` accounts.
+	#[benchmark]
+	fn set_account_list(
+		a: Linear<0, { T::AccountSizeLimit::get() }>,
+	) -> Result<(), BenchmarkError> {
+		let now = setup_block::<T, I>();
+		let caller = funded_voter::<T, I>("creator", 0)?;
+		let proposal_id = open_proposal::<T, I>(
+			&caller,
+			ProposalKind::Private,
+			Some(account_list::<T, I>("member", T::AccountSizeLimit::get())),
+			now.saturating_add(1u32.into()),
+			Default::default(),
+		)?;
+		let account_list = Some(account_list::<T, I>("new_member", a));
+
+		#[extrinsic_call]
+		set_account_list(RawOrigin::Signed(caller), proposal_id, account_list.clone());
+
+		let proposal = Proposals::<T, I>::get(proposal_id).ok_or(BenchmarkError::Weightless)?;
+		assert_eq!(proposal.account_list, account_list);
+		Ok(())
+	}
+
+	#[benchmark]
+	fn set_eligibility_bitmap() -> Result<(), BenchmarkError> {
+		let now = setup_block::<T, I>();
+		let caller = funded_voter::<T, I>("creator", 0)?;
+		let proposal_id = open_proposal::<T, I>(
+			&caller,
+			ProposalKind::Private,
+			None,
+			now.saturating_add(1u32.into()),
+			Default::default(),
+		)?;
+		let bitmap =
+			BoundedVec::truncate_from(vec![u8::MAX; T::MaxEligibilityBitmapLen::get() as usize]);
+
+		#[extrinsic_call]
+		set_eligibility_bitmap(RawOrigin::Signed(caller), proposal_id, Some(bitmap));
+
+		assert!(EligibilityBitmaps::<T, I>::contains_key(proposal_id));
+		Ok(())
+	}
+
+	/// Replaces the vote slots of a full account list with the ones of `a` accounts.
+	#[benchmark]
+	fn register_expected_voters(
+		a: Linear<0, { T::AccountSizeLimit::get() }>,
+	) -> Result<(), BenchmarkError> {
+		let now = setup_block::<T, I>();
+		let caller = funded_voter::<T, I>("creator", 0)?;
+		let proposal_id = open_proposal::<T, I>(
+			&caller,
+			ProposalKind::Private,
+			Some(account_list::<T, I>("member", T::AccountSizeLimit::get())),
+			now.saturating_add(1u32.into()),
+			Default::default(),
+		)?;
+		Pallet::<T, I>::register_expected_voters(
+			RawOrigin::Signed(caller.clone()).into(),
+			proposal_id,
+		)?;
+		Pallet::<T, I>::set_account_list(
+			RawOrigin::Signed(caller.clone()).into(),
+			proposal_id,
+			Some(account_list::<T, I>("new_member", a)),
+		)?;
+
+		#[extrinsic_call]
+		register_expected_voters(RawOrigin::Signed(caller), proposal_id);
+
+		assert_eq!(ExpectedVoters::<T, I>::iter_key_prefix(proposal_id).count(), a as usize);
+		Ok(())
+	}
+
+	#[benchmark]
+	fn set_voting_asset() -> Result<(), BenchmarkError> {
+		let origin =
+			T::AssetOrigin::try_successful_origin().map_err(|_| BenchmarkError::Weightless)?;
+		let asset = T::BenchmarkHelper::asset_id();
+
+		#[extrinsic_call]
+		set_voting_asset(origin as T::RuntimeOrigin, asset, true);
+
+		assert!(AllowedAssets::<T, I>::contains_key(asset));
+		Ok(())
+	}
+
+	#[benchmark]
+	fn set_proposal_asset() -> Result<(), BenchmarkError> {
+		let now = setup_block::<T, I>();
+		let caller = funded_voter::<T, I>("creator", 0)?;
+		let proposal_id = open_proposal::<T, I>(
+			&caller,
+			ProposalKind::Public,
+			None,
+			now.saturating_add(1u32.into()),
+			Default::default(),
+		)?;
+		let asset = T::BenchmarkHelper::asset_id();
+		AllowedAssets::<T, I>::insert(asset, ());
+
+		#[extrinsic_call]
+		set_proposal_asset(RawOrigin::Signed(caller), proposal_id, Some(asset));
+
+		assert_eq!(ProposalAssets::<T, I>::get(proposal_id), Some(asset));
+		Ok(())
+	}
+
+	#[benchmark]
+	fn set_proposal_call() -> Result<(), BenchmarkError> {
+		let now = setup_block::<T, I>();
+		let caller = funded_voter::<T, I>("creator", 0)?;
+		let proposal_id = open_proposal::<T, I>(
+			&caller,
+			ProposalKind::Public,
+			None,
+			now.saturating_add(1u32.into()),
+			Default::default(),
+		)?;
+		// The worst case is a call in a preimage, read to check its weight
+		let call: <T as Config<I>>::RuntimeCall =
+			frame_system::Call::<T>::remark { remark: vec![] }.into();
+		let encoded = call.encode();
+		let len = encoded.len() as u32;
+		let hash = T::BenchmarkHelper::note_preimage(encoded);
+
+		#[extrinsic_call]
+		set_proposal_call(
+			RawOrigin::Signed(caller),
+			proposal_id,
+			Some(BoundedCallOf::<T, I>::Lookup { hash, len }),
+		);
+
+		assert!(ProposalCalls::<T, I>::contains_key(proposal_id));
+		Ok(())
+	}
+
+	#[benchmark]
+	fn set_options() -> Result<(), BenchmarkError> {
+		let now = setup_block::<T, I>();
+		let caller = funded_voter::<T, I>("creator", 0)?;
+		let proposal_id = open_proposal::<T, I>(
+			&caller,
+			ProposalKind::Public,
+			None,
+			now.saturating_add(1u32.into()),
+			Default::default(),
+		)?;
+		let options = options::<T, I>();
+
+		#[extrinsic_call]
+		set_options(RawOrigin::Signed(caller), proposal_id, options.clone());
+
+		assert_eq!(
+			Proposals::<T, I>::get(proposal_id).map(|proposal| proposal.options),
+			Some(options)
+		);
+		Ok(())
+	}
+
+	#[benchmark]
+	fn set_kind_role() -> Result<(), BenchmarkError> {
+		let origin =
+			T::RoleOrigin::try_successful_origin().map_err(|_| BenchmarkError::Weightless)?;
+
+		#[extrinsic_call]
+		set_kind_role(origin as T::RuntimeOrigin, ProposalKind::Public, Some(0));
+
+		assert_eq!(KindRoles::<T, I>::get(ProposalKind::Public), Some(0));
+		Ok(())
+	}
+
+	#[benchmark]
+	fn set_creator_role() -> Result<(), BenchmarkError> {
+		let origin =
+			T::RoleOrigin::try_successful_origin().map_err(|_| BenchmarkError::Weightless)?;
+		let who: T::AccountId = account("creator", 0, SEED);
+
+		#[extrinsic_call]
+		set_creator_role(origin as T::RuntimeOrigin, who.clone(), 0, true);
+
+		assert!(CreatorRoles::<T, I>::contains_key(&who, 0));
+		Ok(())
+	}
+
+	/// Votes on a public proposal whose account list of `a` accounts is scanned.
+	#[benchmark]
+	fn vote(a: Linear<0, { T::AccountSizeLimit::get() }>) -> Result<(), BenchmarkError> {
+		let now = setup_block::<T, I>();
+		let creator = funded_voter::<T, I>("creator", 0)?;
+		let proposal_id = open_proposal::<T, I>(
+			&creator,
+			ProposalKind::Public,
+			Some(account_list::<T, I>("banned", a)),
+			now,
+			Default::default(),
+		)?;
+		let caller = funded_voter::<T, I>("voter", 0)?;
+
+		#[extrinsic_call]
+		vote(
+			RawOrigin::Signed(caller.clone()),
+			proposal_id,
+			true,
+			1u32.into(),
+			Conviction::Locked6x,
+		);
+
+		assert!(Votes::<T, I>::contains_key(&caller, proposal_id));
+		Ok(())
+	}
+
+	/// Votes on a private proposal with a membership proof of the maximum depth.
+	#[benchmark]
+	fn vote_with_proof() -> Result<(), BenchmarkError> {
+		let now = setup_block::<T, I>();
+		let creator = funded_voter::<T, I>("creator", 0)?;
+		let caller = funded_voter::<T, I>("voter", 0)?;
+		let proof: Vec<[u8; 32]> = (0..MAX_PROOF_DEPTH).map(|index| [index as u8; 32]).collect();
+		let members_root = proof
+			.iter()
+			.fold(membership_leaf(&caller), |node, sibling| merkle_parent(&node, sibling));
+		let settings = ProposalSettings { members_root: Some(members_root), ..Default::default() };
+		let proposal_id =
+			open_proposal::<T, I>(&creator, ProposalKind::Private, None, now, settings)?;
+
+		#[extrinsic_call]
+		vote_with_proof(
+			RawOrigin::Signed(caller.clone()),
+			proposal_id,
+			true,
+			1u32.into(),
+			BoundedVec::truncate_from(proof),
+		);
+
+		assert!(Votes::<T, I>::contains_key(&caller, proposal_id));
+		Ok(())
+	}
+
+	/// Delegates to the first of a chain of `h` delegates, walked to rule out a cycle.
+	#[benchmark]
+	fn delegate(h: Linear<1, { T::MaxDelegationDepth::get() }>) -> Result<(), BenchmarkError> {
+		let caller = funded_voter::<T, I>("delegator", 0)?;
+		let to: T::AccountId = account("to", 0, SEED);
+		delegation_chain::<T, I>(&to, h - 1);
+
+		#[extrinsic_call]
+		delegate(RawOrigin::Signed(caller.clone()), to.clone());
+
+		assert_eq!(Delegations::<T, I>::get(&caller), Some(to));
+		Ok(())
+	}
+
+	#[benchmark]
+	fn undelegate() -> Result<(), BenchmarkError> {
+		let caller = funded_voter::<T, I>("delegator", 0)?;
+		delegation_chain::<T, I>(&caller, 1);
+
+		#[extrinsic_call]
+		undelegate(RawOrigin::Signed(caller.clone()));
+
+		assert!(!Delegations::<T, I>::contains_key(&caller));
+		Ok(())
+	}
+
+	/// Votes `h` hops away from the delegator on a public proposal whose account list of `a`
+	/// accounts is scanned.
+	#[benchmark]
+	fn vote_as_delegate(
+		a: Linear<0, { T::AccountSizeLimit::get() }>,
+		h: Linear<1, { T::MaxDelegationDepth::get() }>,
+	) -> Result<(), BenchmarkError> {
+		let now = setup_block::<T, I>();
+		let creator = funded_voter::<T, I>("creator", 0)?;
+		let proposal_id = open_proposal::<T, I>(
+			&creator,
+			ProposalKind::Public,
+			Some(account_list::<T, I>("banned", a)),
+			now,
+			Default::default(),
+		)?;
+		let delegator = funded_voter::<T, I>("delegator", 0)?;
+		let caller = delegation_chain::<T, I>(&delegator, h);
+
+		#[extrinsic_call]
+		vote_as_delegate(
+			RawOrigin::Signed(caller),
+			delegator.clone(),
+			proposal_id,
+			true,
+			1u32.into(),
+		);
+
+		assert!(DelegatedVotes::<T, I>::contains_key(proposal_id, &delegator));
+		Ok(())
+	}
+
+	/// Recounts `l` votes.
+	#[benchmark]
+	fn recount(l: Linear<1, 100>) -> Result<(), BenchmarkError> {
+		let now = setup_block::<T, I>();
+		let creator = funded_voter::<T, I>("creator", 0)?;
+		let proposal_id =
+			open_proposal::<T, I>(&creator, ProposalKind::Public, None, now, Default::default())?;
+		cast_votes::<T, I>(proposal_id, l)?;
+
+		#[extrinsic_call]
+		recount(RawOrigin::Signed(creator), proposal_id, l);
+
+		Ok(())
+	}
+
+	/// Audits `l` votes.
+	#[benchmark]
+	fn audit_proposal(l: Linear<1, 100>) -> Result<(), BenchmarkError> {
+		let origin =
+			T::AdminOrigin::try_successful_origin().map_err(|_| BenchmarkError::Weightless)?;
+		let now = setup_block::<T, I>();
+		let creator = funded_voter::<T, I>("creator", 0)?;
+		let proposal_id =
+			open_proposal::<T, I>(&creator, ProposalKind::Public, None, now, Default::default())?;
+		cast_votes::<T, I>(proposal_id, l)?;
+
+		#[extrinsic_call]
+		audit_proposal(origin as T::RuntimeOrigin, proposal_id, l);
+
+		Ok(())
+	}
+
+	#[benchmark]
+	fn dispute() -> Result<(), BenchmarkError> {
+		let creator = funded_voter::<T, I>("creator", 0)?;
+		let proposal_id = closed_proposal::<T, I>(&creator, &creator)?;
+		let caller = funded_voter::<T, I>("challenger", 0)?;
+
+		#[extrinsic_call]
+		dispute(RawOrigin::Signed(caller), proposal_id);
+
+		assert!(Disputes::<T, I>::contains_key(proposal_id));
+		Ok(())
+	}
+
+	/// Resolves a dispute with a revote, the most expensive resolution.
+	#[benchmark]
+	fn resolve_dispute() -> Result<(), BenchmarkError> {
+		let origin = T::DisputeResolutionOrigin::try_successful_origin()
+			.map_err(|_| BenchmarkError::Weightless)?;
+		let creator = funded_voter::<T, I>("creator", 0)?;
+		let proposal_id = closed_proposal::<T, I>(&creator, &creator)?;
+		let challenger = funded_voter::<T, I>("challenger", 0)?;
+		Pallet::<T, I>::dispute(RawOrigin::Signed(challenger).into(), proposal_id)?;
+		let start_block = frame_system::Pallet::<T>::block_number();
+		let end_block = start_block.saturating_add(T::ProposalMinimumDuration::get().max(1).into());
+
+		#[extrinsic_call]
+		resolve_dispute(
+			origin as T::RuntimeOrigin,
+			proposal_id,
+			DisputeResolution::Revote { start_block, end_block },
+		);
+
+		assert!(!Disputes::<T, I>::contains_key(proposal_id));
+		Ok(())
+	}
+
+	#[benchmark]
+	fn override_outcome() -> Result<(), BenchmarkError> {
+		let origin =
+			T::OverrideOrigin::try_successful_origin().map_err(|_| BenchmarkError::Weightless)?;
+		let creator = funded_voter::<T, I>("creator", 0)?;
+		let proposal_id = closed_proposal::<T, I>(&creator, &creator)?;
+
+		#[extrinsic_call]
+		override_outcome(origin as T::RuntimeOrigin, proposal_id, OutcomeOverride::Ratify);
+
+		let archive =
+			ArchivedProposals::<T, I>::get(proposal_id).ok_or(BenchmarkError::Weightless)?;
+		assert!(archive.overridden.is_some());
+		Ok(())
+	}
+
+	/// Answers every question of a bundle whose account list of `a` accounts is scanned.
+	#[benchmark]
+	fn vote_questions(a: Linear<0, { T::AccountSizeLimit::get() }>) -> Result<(), BenchmarkError> {
+		let now = setup_block::<T, I>();
+		let creator = funded_voter::<T, I>("creator", 0)?;
+		let questions = T::MaxQuestions::get();
+		let settings = ProposalSettings { questions, ..Default::default() };
+		let proposal_id = open_proposal::<T, I>(
+			&creator,
+			ProposalKind::Public,
+			Some(account_list::<T, I>("banned", a)),
+			now,
+			settings,
+		)?;
+		let caller = funded_voter::<T, I>("voter", 0)?;
+		let answers: BoundedVec<(bool, PowerOf<T, I>), T::MaxQuestions> =
+			BoundedVec::truncate_from(vec![(true, 1u32.into()); questions as usize]);
+
+		#[extrinsic_call]
+		vote_questions(RawOrigin::Signed(caller.clone()), proposal_id, answers, None);
+
+		assert!(Answers::<T, I>::contains_key(proposal_id, &caller));
+		Ok(())
+	}
+
+	#[benchmark]
+	fn vote_option(a: Linear<0, { T::AccountSizeLimit::get() }>) -> Result<(), BenchmarkError> {
+		let now = setup_block::<T, I>();
+		let creator = funded_voter::<T, I>("creator", 0)?;
+		let proposal_id = open_proposal::<T, I>(
+			&creator,
+			ProposalKind::Public,
+			Some(account_list::<T, I>("banned", a)),
+			now,
+			Default::default(),
+		)?;
+		Proposals::<T, I>::mutate(proposal_id, |maybe_proposal| {
+			if let Some(proposal) = maybe_proposal {
+				proposal.options = options::<T, I>();
+			}
+		});
+		let caller = funded_voter::<T, I>("voter", 0)?;
+
+		#[extrinsic_call]
+		vote_option(RawOrigin::Signed(caller.clone()), proposal_id, 0, 1u32.into(), None);
+
+		assert!(OptionChoices::<T, I>::contains_key(proposal_id, &caller));
+		Ok(())
+	}
+
+	/// Commits to a vote on a secret ballot whose account list of `a` accounts is scanned.
+	#[benchmark]
+	fn commit_vote(a: Linear<0, { T::AccountSizeLimit::get() }>) -> Result<(), BenchmarkError> {
+		let now = setup_block::<T, I>();
+		let creator = funded_voter::<T, I>("creator", 0)?;
+		let settings = ProposalSettings { reveal_period: Some(10), ..Default::default() };
+		let proposal_id = open_proposal::<T, I>(
+			&creator,
+			ProposalKind::Public,
+			Some(account_list::<T, I>("banned", a)),
+			now,
+			settings,
+		)?;
+		let caller = funded_voter::<T, I>("voter", 0)?;
+		let commitment =
+			Pallet::<T, I>::vote_commitment(&caller, proposal_id, true, 1u32.into(), [0; 32]);
+
+		#[extrinsic_call]
+		commit_vote(RawOrigin::Signed(caller.clone()), proposal_id, commitment, 1u32.into(), None);
+
+		assert!(Commitments::<T, I>::contains_key(proposal_id, &caller));
+		Ok(())
+	}
+
+	#[benchmark]
+	fn reveal_vote() -> Result<(), BenchmarkError> {
+		let now = setup_block::<T, I>();
+		let creator = funded_voter::<T, I>("creator", 0)?;
+		let settings = ProposalSettings { reveal_period: Some(10), ..Default::default() };
+		let proposal_id =
+			open_proposal::<T, I>(&creator, ProposalKind::Public, None, now, settings)?;
+		let caller = funded_voter::<T, I>("voter", 0)?;
+		let commitment =
+			Pallet::<T, I>::vote_commitment(&caller, proposal_id, true, 1u32.into(), [0; 32]);
+		Pallet::<T, I>::commit_vote(
+			RawOrigin::Signed(caller.clone()).into(),
+			proposal_id,
+			commitment,
+			2u32.into(),
+			None,
+		)?;
+		let proposal = Proposals::<T, I>::get(proposal_id).ok_or(BenchmarkError::Weightless)?;
+		frame_system::Pallet::<T>::set_block_number(proposal.end_block);
+
+		#[extrinsic_call]
+		reveal_vote(RawOrigin::Signed(caller.clone()), proposal_id, true, 1u32.into(), [0; 32]);
+
+		assert!(Votes::<T, I>::contains_key(&caller, proposal_id));
+		Ok(())
+	}
+
+	#[benchmark]
+	fn claim() -> Result<(), BenchmarkError> {
+		let caller = funded_voter::<T, I>("creator", 0)?;
+		let proposal_id = closed_proposal::<T, I>(&caller, &caller)?;
+
+		#[extrinsic_call]
+		claim(RawOrigin::Signed(caller.clone()), proposal_id);
+
+		assert!(!Votes::<T, I>::contains_key(&caller, proposal_id));
+		Ok(())
+	}
+
+	#[benchmark]
+	fn claim_to() -> Result<(), BenchmarkError> {
+		let caller = funded_voter::<T, I>("creator", 0)?;
+		let proposal_id = closed_proposal::<T, I>(&caller, &caller)?;
+		let beneficiary: T::AccountId = account("beneficiary", 0, SEED);
+
+		#[extrinsic_call]
+		claim_to(RawOrigin::Signed(caller.clone()), proposal_id, beneficiary);
+
+		assert!(!Votes::<T, I>::contains_key(&caller, proposal_id));
+		Ok(())
+	}
+
+	#[benchmark]
+	fn claim_for() -> Result<(), BenchmarkError> {
+		let voter = funded_voter::<T, I>("creator", 0)?;
+		let proposal_id = closed_proposal::<T, I>(&voter, &voter)?;
+		let grace_end = frame_system::Pallet::<T>::block_number()
+			.saturating_add(T::ClaimGracePeriod::get().into());
+		frame_system::Pallet::<T>::set_block_number(grace_end);
+		let caller: T::AccountId = whitelisted_caller();
+
+		#[extrinsic_call]
+		claim_for(RawOrigin::Signed(caller), voter.clone(), proposal_id);
+
+		assert!(!Votes::<T, I>::contains_key(&voter, proposal_id));
+		Ok(())
+	}
+
+	/// Adds `a` accounts to the account list of a private proposal.
+	#[benchmark]
+	fn update_account_list(
+		a: Linear<0, { T::AccountSizeLimit::get() }>,
+	) -> Result<(), BenchmarkError> {
+		let now = setup_block::<T, I>();
+		let caller = funded_voter::<T, I>("creator", 0)?;
+		let proposal_id = open_proposal::<T, I>(
+			&caller,
+			ProposalKind::Private,
+			None,
+			now.saturating_add(1u32.into()),
+			Default::default(),
+		)?;
+		let to_add = account_list::<T, I>("member", a);
+
+		#[extrinsic_call]
+		update_account_list(
+			RawOrigin::Signed(caller),
+			proposal_id,
+			to_add.clone(),
+			BoundedVec::default(),
+		);
+
+		let proposal = Proposals::<T, I>::get(proposal_id).ok_or(BenchmarkError::Weightless)?;
+		assert_eq!(proposal.account_list, Some(to_add));
+		Ok(())
+	}
+
+	/// Copies an account list of `a` accounts over a full one.
+	#[benchmark]
+	fn copy_account_list(
+		a: Linear<0, { T::AccountSizeLimit::get() }>,
+	) -> Result<(), BenchmarkError> {
+		let now = setup_block::<T, I>();
+		let caller = funded_voter::<T, I>("creator", 0)?;
+		let start_block = now.saturating_add(1u32.into());
+		let source = open_proposal::<T, I>(
+			&caller,
+			ProposalKind::Private,
+			Some(account_list::<T, I>("member", a)),
+			start_block,
+			Default::default(),
+		)?;
+		let proposal_id = open_proposal::<T, I>(
+			&caller,
+			ProposalKind::Private,
+			Some(account_list::<T, I>("old_member", T::AccountSizeLimit::get())),
+			start_block,
+			Default::default(),
+		)?;
+
+		#[extrinsic_call]
+		copy_account_list(RawOrigin::Signed(caller), proposal_id, source);
+
+		let proposal = Proposals::<T, I>::get(proposal_id).ok_or(BenchmarkError::Weightless)?;
+		assert_eq!(proposal.account_list.map(|list| list.len() as u32), Some(a));
+		Ok(())
+	}
+
+	#[benchmark]
+	fn sponsor_votes() -> Result<(), BenchmarkError> {
+		let now = setup_block::<T, I>();
+		let caller = funded_voter::<T, I>("creator", 0)?;
+		let proposal_id =
+			open_proposal::<T, I>(&caller, ProposalKind::Public, None, now, Default::default())?;
+
+		#[extrinsic_call]
+		sponsor_votes(RawOrigin::Signed(caller), proposal_id, deposit::<T, I>());
+
+		assert!(Sponsorships::<T, I>::contains_key(proposal_id));
+		Ok(())
+	}
+
+	#[benchmark]
+	fn fund_lottery() -> Result<(), BenchmarkError> {
+		let now = setup_block::<T, I>();
+		let caller = funded_voter::<T, I>("creator", 0)?;
+		let settings = ProposalSettings { lottery_winners: Some(1), ..Default::default() };
+		let proposal_id =
+			open_proposal::<T, I>(&caller, ProposalKind::Public, None, now, settings)?;
+
+		#[extrinsic_call]
+		fund_lottery(RawOrigin::Signed(caller), proposal_id, deposit::<T, I>());
+
+		assert!(LotteryPots::<T, I>::contains_key(proposal_id));
+		Ok(())
+	}
+
+	#[benchmark]
+	fn renew_registration() -> Result<(), BenchmarkError> {
+		let caller = funded_voter::<T, I>("voter", 0)?;
+
+		#[extrinsic_call]
+		renew_registration(RawOrigin::Signed(caller.clone()), caller.clone());
+
+		Ok(())
+	}
+
+	#[benchmark]
+	fn take_balance_snapshot() -> Result<(), BenchmarkError> {
+		let now = setup_block::<T, I>();
+		let caller = funded_voter::<T, I>("voter", 0)?;
+
+		#[extrinsic_call]
+		take_balance_snapshot(RawOrigin::Signed(caller.clone()));
+
+		let snapshot = BalanceSnapshots::<T, I>::get(&caller).ok_or(BenchmarkError::Weightless)?;
+		assert_eq!(snapshot.0, now);
+		Ok(())
+	}
+
+	#[benchmark]
+	fn second() -> Result<(), BenchmarkError> {
+		let now = setup_block::<T, I>();
+		let creator = funded_voter::<T, I>("creator", 0)?;
+		let proposal_id = open_proposal::<T, I>(
+			&creator,
+			ProposalKind::Public,
+			None,
+			now.saturating_add(1u32.into()),
+			Default::default(),
+		)?;
+		let caller = funded_voter::<T, I>("voter", 0)?;
+
+		#[extrinsic_call]
+		second(RawOrigin::Signed(caller.clone()), proposal_id);
+
+		assert!(Seconds::<T, I>::contains_key(proposal_id, &caller));
+		Ok(())
+	}
+
+	#[benchmark]
+	fn submit_public_proposal() -> Result<(), BenchmarkError> {
+		if T::LaunchPeriod::get() == 0 {
+			return Err(BenchmarkError::Weightless)
+		}
+		let caller = funded_voter::<T, I>("creator", 0)?;
+		let proposal_id = NextProposalId::<T, I>::get();
+
+		#[extrinsic_call]
+		submit_public_proposal(
+			RawOrigin::Signed(caller),
+			BoundedVec::default(),
+			None,
+			T::ProposalMinimumDuration::get().max(1),
+			Default::default(),
+		);
+
+		assert!(QueuedProposals::<T, I>::contains_key(proposal_id));
+		Ok(())
+	}
+
+	#[benchmark]
+	fn create_emergency_proposal() -> Result<(), BenchmarkError> {
+		let origin =
+			T::EmergencyOrigin::try_successful_origin().map_err(|_| BenchmarkError::Weightless)?;
+		setup_block::<T, I>();
+		let proposal_id = NextProposalId::<T, I>::get();
+
+		#[extrinsic_call]
+		create_emergency_proposal(
+			origin as T::RuntimeOrigin,
+			BoundedVec::default(),
+			T::EmergencyMaxDuration::get(),
+			Default::default(),
+		);
+
+		assert!(Proposals::<T, I>::contains_key(proposal_id));
+		Ok(())
+	}
+
+	/// Creates an external private proposal allowing `a` accounts.
+	#[benchmark]
+	fn create_external_proposal(
+		a: Linear<0, { T::AccountSizeLimit::get() }>,
+	) -> Result<(), BenchmarkError> {
+		let origin =
+			T::ExternalOrigin::try_successful_origin().map_err(|_| BenchmarkError::Weightless)?;
+		let now = setup_block::<T, I>();
+		let end_block = now.saturating_add(T::ProposalMinimumDuration::get().max(1).into());
+		let proposal_id = NextProposalId::<T, I>::get();
+
+		#[extrinsic_call]
+		create_external_proposal(
+			origin as T::RuntimeOrigin,
+			BoundedVec::default(),
+			ProposalKind::Private,
+			Some(account_list::<T, I>("member", a)),
+			now,
+			end_block,
+			Default::default(),
+		);
+
+		assert!(Proposals::<T, I>::contains_key(proposal_id));
+		Ok(())
+	}
+
+	#[benchmark]
+	fn create_org() {
+		let admin: T::AccountId = account("admin", 0, SEED);
+		let org = NextOrgId::<T, I>::get();
+
+		#[extrinsic_call]
+		create_org(RawOrigin::Root, admin);
+
+		assert!(Orgs::<T, I>::contains_key(org));
+	}
+
+	#[benchmark]
+	fn set_org_admin() -> Result<(), BenchmarkError> {
+		let caller: T::AccountId = account("admin", 0, SEED);
+		let org = new_org::<T, I>(&caller)?;
+		let admin: T::AccountId = account("admin", 1, SEED);
+
+		#[extrinsic_call]
+		set_org_admin(RawOrigin::Signed(caller), org, admin.clone());
+
+		let info = Orgs::<T, I>::get(org).ok_or(BenchmarkError::Weightless)?;
+		assert_eq!(info.admin, admin);
+		Ok(())
+	}
+
+	#[benchmark]
+	fn add_org_member() -> Result<(), BenchmarkError> {
+		let caller: T::AccountId = account("admin", 0, SEED);
+		let org = new_org::<T, I>(&caller)?;
+		let who = funded_voter::<T, I>("member", 0)?;
+
+		#[extrinsic_call]
+		add_org_member(RawOrigin::Signed(caller), org, who.clone());
+
+		assert!(OrgMembers::<T, I>::contains_key(org, &who));
+		Ok(())
+	}
+
+	#[benchmark]
+	fn remove_org_member() -> Result<(), BenchmarkError> {
+		let caller: T::AccountId = account("admin", 0, SEED);
+		let org = new_org::<T, I>(&caller)?;
+		let who = funded_voter::<T, I>("member", 0)?;
+		Pallet::<T, I>::add_org_member(RawOrigin::Signed(caller.clone()).into(), org, who.clone())?;
+
+		#[extrinsic_call]
+		remove_org_member(RawOrigin::Signed(caller), org, who.clone());
+
+		assert!(!OrgMembers::<T, I>::contains_key(org, &who));
+		Ok(())
+	}
+
+	#[benchmark]
+	fn set_org_parameters() -> Result<(), BenchmarkError> {
+		let caller: T::AccountId = account("admin", 0, SEED);
+		let org = new_org::<T, I>(&caller)?;
+		let parameters = OrgParameters {
+			minimum_duration: Some(T::ProposalMinimumDuration::get()),
+			maximum_duration: Some(T::ProposalMaximumDuration::get()),
+		};
+
+		#[extrinsic_call]
+		set_org_parameters(RawOrigin::Signed(caller), org, parameters.clone());
+
+		let info = Orgs::<T, I>::get(org).ok_or(BenchmarkError::Weightless)?;
+		assert_eq!(info.parameters, parameters);
+		Ok(())
+	}
+
+	/// Creates a private proposal of an organisation allowing `a` accounts.
+	#[benchmark]
+	fn create_org_proposal(
+		a: Linear<0, { T::AccountSizeLimit::get() }>,
+	) -> Result<(), BenchmarkError> {
+		let now = setup_block::<T, I>();
+		let caller = funded_voter::<T, I>("creator", 0)?;
+		let org = new_org::<T, I>(&caller)?;
+		Pallet::<T, I>::add_org_member(
+			RawOrigin::Signed(caller.clone()).into(),
+			org,
+			caller.clone(),
+		)?;
+		let end_block = now.saturating_add(T::ProposalMinimumDuration::get().max(1).into());
+		let proposal_id = NextProposalId::<T, I>::get();
+
+		#[extrinsic_call]
+		create_org_proposal(
+			RawOrigin::Signed(caller),
+			org,
+			BoundedVec::default(),
+			ProposalKind::Private,
+			Some(account_list::<T, I>("member", a)),
+			now,
+			end_block,
+			Default::default(),
+		);
+
+		assert!(Proposals::<T, I>::contains_key(proposal_id));
+		Ok(())
+	}
+
+	#[benchmark]
+	fn set_proposal_limits() {
+		let limits = ProposalLimits {
+			minimum_duration: Some(T::ProposalMinimumDuration::get()),
+			maximum_duration: Some(T::ProposalMaximumDuration::get()),
+			delay_limit: Some(T::ProposalDelayLimit::get()),
+			emergency_max_duration: Some(T::EmergencyMaxDuration::get()),
+			claim_grace_period: Some(T::ClaimGracePeriod::get()),
+		};
+
 		#[extrinsic_call]
-		cause_error(RawOrigin::Signed(caller));
+		set_proposal_limits(RawOrigin::Root, limits.clone());
 
-		assert_eq!(Something::<T>::get(), Some(101u32));
+		assert_eq!(ProposalLimitOverrides::<T, I>::get(), limits);
 	}
 
 	impl_benchmark_test_suite!(Voting, crate::mock::new_test_ext(), crate::mock::Test);
@@ -0,0 +1,252 @@
+//! Mirrors of the storage and event types of the pallet that are generic over the account,
+//! balance, block number and asset types rather than over the runtime configuration, so
+//! off-chain services and subxt-based bots can decode the storage and the events of the pallet
+//! without the `Config` of a runtime. They share the SCALE encoding of the pallet types, bounded
+//! vectors being decoded as plain vectors.
+
+pub use crate::types::{
+	ArchiveProof, ArchivedProposal, AuditIssue, CancellationReason, CompressedArchive, Conviction,
+	DisputeResolution, EpochReport, OptionLabel, OrgId, OrgParameters, OutcomeOverride, ProposalId,
+	ProposalKind, ProposalLimits, ProposalOutcome, ProposalSettings, ProposalStatus, RoleId,
+	Threshold, VoteCost, VoteInfo, VoteRatio,
+};
+use codec::{Decode, Encode};
+use frame_support::{
+	sp_runtime::{DispatchError, DispatchResult, Perbill},
+	traits::Hash as PreimageHash,
+};
+
+/// The tally of a proposal: the 'aye' votes and the total of votes.
+pub type Tally = VoteRatio;
+
+/// A proposal in progress, as stored in `Proposals`.
+#[derive(Clone, PartialEq, Eq, Debug, Encode, Decode)]
+pub struct ProposalData<AccountId, BlockNumber> {
+	/// The data related to this proposal.
+	pub offchain_data: Vec<u8>,
+	/// The tally of the proposal, empty while a hidden tally is running.
+	pub ratio: Tally,
+	/// The proposal kind.
+	pub kind: ProposalKind,
+	/// The proposal creator.
+	pub creator: AccountId,
+	/// The banned accounts of a public proposal, or the allowed accounts of a private proposal.
+	pub account_list: Option<Vec<AccountId>>,
+	/// `BlockNumber` at which the proposal will accept votes.
+	pub start_block: BlockNumber,
+	/// `BlockNumber` at which the proposal will no longer accept votes.
+	pub end_block: BlockNumber,
+	/// The optional rules set by the creator.
+	pub settings: ProposalSettings,
+	/// The number of distinct accounts holding a vote for this proposal.
+	pub voters_count: u32,
+	/// The number of distinct registered voters who seconded this proposal.
+	pub seconds: u32,
+	/// Whether the proposal was submitted by the external origin rather than a registered voter.
+	pub external: bool,
+	/// The quorum denominator recorded at the start of the proposal.
+	pub electorate: Option<u32>,
+	/// The organisation hosting the proposal.
+	pub org: Option<OrgId>,
+	/// The options of a multi-option proposal, empty for an aye/nay proposal.
+	pub options: Vec<OptionLabel>,
+}
+
+/// The events of the pallet, in the order of the pallet `Event` so their indices match.
+#[derive(Clone, PartialEq, Eq, Debug, Encode, Decode)]
+pub enum Event<AccountId, Balance, BlockNumber, AssetId> {
+	/// A new voter is registered
+	NewVoterRegistered { who: AccountId },
+	/// A voter is unregistered
+	VoterUnregistered { who: AccountId },
+	/// A deputy registrar was appointed, or its limit updated
+	DeputyAppointed { who: AccountId, limit: u32 },
+	/// A deputy registrar was revoked
+	DeputyRevoked { who: AccountId },
+	/// The registration, votes, delegations and held collateral of a voter were moved to
+	/// another account
+	VoterMigrated { old: AccountId, new: AccountId, entries: u32 },
+	/// A voter took a snapshot of its balance to back its future votes
+	BalanceSnapshotTaken { who: AccountId, balance: Balance },
+	/// A voter renewed its registration
+	VoterRenewed { who: AccountId, expires_at: Option<BlockNumber> },
+	/// A voter is unregistered because its registration expired
+	VoterExpired { who: AccountId },
+	/// A new proposal is created
+	ProposalCreated {
+		proposal_id: ProposalId,
+		offchain_data: Vec<u8>,
+		creator: AccountId,
+		kind: ProposalKind,
+		account_list: Option<Vec<AccountId>>,
+		start_block: BlockNumber,
+		end_block: BlockNumber,
+		settings: ProposalSettings,
+	},
+	/// A public proposal is submitted to the launch queue
+	ProposalQueued { proposal_id: ProposalId, creator: AccountId },
+	/// A queued public proposal is promoted to a voting proposal
+	ProposalLaunched { proposal_id: ProposalId },
+	/// A staged proposal is seconded by a registered voter
+	ProposalSeconded { proposal_id: ProposalId, who: AccountId, seconds: u32 },
+	/// A scheduled proposal reached its start block and is open to votes
+	ProposalStarted { proposal_id: ProposalId },
+	/// A proposal did not gather enough seconds before its start and is closed
+	ProposalExpired { proposal_id: ProposalId },
+	/// A proposal that did not start yet is cancelled
+	ProposalCancelled { proposal_id: ProposalId, reason: CancellationReason },
+	/// A proposal is closed and the vote is completed
+	VoteCompleted { proposal_id: ProposalId, ratio: (u128, u128), approved: bool },
+	/// A new account list is set before a proposal has started
+	AccountListSet { proposal_id: ProposalId, account_list: Option<Vec<AccountId>> },
+	/// The eligibility bitmap of a private proposal is set before it has started, `allowed`
+	/// being the number of voters it allows
+	EligibilityBitmapSet { proposal_id: ProposalId, allowed: u32 },
+	/// A vote slot was pre-registered for each of the `count` members of the account list of
+	/// a private proposal
+	ExpectedVotersRegistered { proposal_id: ProposalId, count: u32 },
+	/// An asset was added to or removed from the allow-list of the voting assets
+	VotingAssetUpdated { asset: AssetId, allowed: bool },
+	/// The asset a proposal is voted with was set, `None` for the native balance
+	ProposalAssetSet { proposal_id: ProposalId, asset: Option<AssetId> },
+	/// Creating proposals of a kind was restricted to a role, or opened to all registered
+	/// voters with `None`
+	KindRoleSet { kind: ProposalKind, role: Option<RoleId> },
+	/// A role was granted to a proposal creator
+	CreatorRoleGranted { who: AccountId, role: RoleId },
+	/// A role was revoked from a proposal creator
+	CreatorRoleRevoked { who: AccountId, role: RoleId },
+	/// The account list of a proposal that did not start yet is edited, only the accounts
+	/// actually added or removed are reported
+	AccountListUpdated { proposal_id: ProposalId, added: Vec<AccountId>, removed: Vec<AccountId> },
+	/// A new vote was added to an in progress proposal
+	VoteAdded { proposal_id: ProposalId, voter: AccountId, aye: bool, power: Balance },
+	/// A new vote was added to an in progress proposal with anonymous events
+	AnonymousVoteAdded { proposal_id: ProposalId, aye: bool, power: Balance },
+	/// The tally of a proposal overflowed and was saturated, this should never happen with a
+	/// sane `MaxPower`
+	TallySaturated { proposal_id: ProposalId },
+	/// The turnout of a proposal exceeded its turnout cap, every contribution was scaled by
+	/// `scale`
+	TurnoutScaled { proposal_id: ProposalId, scale: Perbill },
+	/// The policy adopted by a proposal will be reviewed by a sunset review
+	SunsetReviewScheduled { policy_id: ProposalId, review_id: ProposalId, start_block: BlockNumber },
+	/// A sunset review was not approved, the policy it reviewed is repealed
+	PolicyRepealed { policy_id: ProposalId, review_id: ProposalId },
+	/// A vote was removed from an in progress proposal
+	VoteDropped { proposal_id: ProposalId, voter: AccountId },
+	/// A voter answered the questions of a bundled proposal
+	QuestionsVoted { proposal_id: ProposalId, voter: AccountId, answers: Vec<(bool, Balance)> },
+	/// The questions of a bundled proposal with anonymous events were answered
+	AnonymousQuestionsVoted { proposal_id: ProposalId, answers: Vec<(bool, Balance)> },
+	/// The options of a proposal were set, none for an aye/nay proposal
+	OptionsSet { proposal_id: ProposalId, options: Vec<OptionLabel> },
+	/// A voter chose an option of a multi-option proposal
+	OptionVoted { proposal_id: ProposalId, voter: AccountId, option: u32, power: Balance },
+	/// An option of a multi-option proposal with anonymous events was chosen
+	AnonymousOptionVoted { proposal_id: ProposalId, option: u32, power: Balance },
+	/// A voter committed to a vote on a secret ballot, or replaced its commitment
+	VoteCommitted { proposal_id: ProposalId, voter: AccountId },
+	/// A vote committed on a secret ballot was revealed and tallied
+	VoteRevealed { proposal_id: ProposalId, voter: AccountId, aye: bool, power: Balance },
+	/// The options of a multi-option proposal were tallied when closing it, `winner` is the
+	/// option leading alone if any
+	OptionsCompleted { proposal_id: ProposalId, tallies: Vec<u128>, winner: Option<u32> },
+	/// The jury of a sortition proposal was drawn
+	JuryDrawn { proposal_id: ProposalId, jurors: u32 },
+	/// The questions of a bundled proposal were tallied when closing it
+	QuestionsCompleted { proposal_id: ProposalId, outcomes: Vec<(VoteRatio, bool)> },
+	/// A new vote was added to an in progress proposal
+	BalanceClaimed { who: AccountId, amount: Balance },
+	/// The collateral of a voter was released and accounted to a beneficiary
+	BalanceClaimedTo { who: AccountId, beneficiary: AccountId, amount: Balance },
+	/// The collateral of a voter was released by another account after the grace period
+	BalanceClaimedFor { who: AccountId, caller: AccountId, amount: Balance },
+	/// Some voters of a closed proposal had their collateral released during this block
+	FreezesReleased { proposal_id: ProposalId, released: u32 },
+	/// All the voters of a closed proposal had their collateral released
+	FreezesReleaseCompleted { proposal_id: ProposalId },
+	/// Participation fees collected by a proposal are paid out of its pot
+	FeesPaid { proposal_id: ProposalId, to: AccountId, amount: Balance },
+	/// The creator of a proposal deposited an amount to reimburse the fees of its voters
+	VotesSponsored { proposal_id: ProposalId, amount: Balance },
+	/// A new voter of a sponsored proposal is reimbursed its transaction fee
+	VoteRefunded { proposal_id: ProposalId, voter: AccountId, amount: Balance },
+	/// What is left of a sponsorship is returned to the creator once the proposal is archived
+	SponsorshipReturned { proposal_id: ProposalId, amount: Balance },
+	/// The turnout lottery of a proposal is funded by the creator or the treasury account
+	LotteryFunded { proposal_id: ProposalId, funder: AccountId, amount: Balance },
+	/// The winners of the turnout lottery of a closed proposal are drawn, each winning `prize`
+	LotteryDrawn { proposal_id: ProposalId, winners: Vec<AccountId>, prize: Balance },
+	/// A winner of the turnout lottery of a proposal is paid its prize
+	LotteryPrizePaid { proposal_id: ProposalId, who: AccountId, amount: Balance },
+	/// A voter delegated its votes
+	Delegated { who: AccountId, to: AccountId },
+	/// A voter stopped delegating its votes
+	Undelegated { who: AccountId },
+	/// The delegation of a voter was revoked since its delegate was unregistered
+	DelegationRevoked { who: AccountId, delegate: AccountId },
+	/// A delegate voted on behalf of a delegator, `hops` delegations away
+	DelegatedVoteCast {
+		proposal_id: ProposalId,
+		delegator: AccountId,
+		delegate: AccountId,
+		hops: u32,
+	},
+	/// The tally of a proposal was re-derived from its votes and replaced the running tally,
+	/// the differences being the recounted minus the previous amounts
+	TallyRecounted { proposal_id: ProposalId, ayes_diff: i128, total_diff: i128 },
+	/// An audit found a discrepancy in the vote of `voter`
+	AuditFinding { proposal_id: ProposalId, voter: AccountId, issue: AuditIssue<Balance> },
+	/// All the votes of a proposal were audited
+	ProposalAudited { proposal_id: ProposalId },
+	/// A random sample of the votes of a proposal was audited at close, `findings` of them
+	/// being reported by an `AuditFinding`
+	SampleAudited { proposal_id: ProposalId, sampled: u32, findings: u32 },
+	/// The running tally of an open proposal, emitted every `TallyCheckpointPeriod` blocks
+	TallyCheckpoint { proposal_id: ProposalId, tally: VoteRatio },
+	/// The outcome of a closed proposal was disputed
+	ProposalDisputed { proposal_id: ProposalId, challenger: AccountId },
+	/// A dispute was resolved and its bond settled
+	DisputeResolved { proposal_id: ProposalId, resolution: DisputeResolution<BlockNumber> },
+	/// A new organisation is created
+	OrgCreated { org: OrgId, admin: AccountId },
+	/// The admin of an organisation changed
+	OrgAdminSet { org: OrgId, admin: AccountId },
+	/// A registered voter joined an organisation
+	OrgMemberAdded { org: OrgId, who: AccountId },
+	/// A member left an organisation
+	OrgMemberRemoved { org: OrgId, who: AccountId },
+	/// The parameters of an organisation changed
+	OrgParametersSet { org: OrgId, parameters: OrgParameters },
+	/// A proposal was created in an organisation, `index` being its ID within it
+	OrgProposalCreated { org: OrgId, index: u32, proposal_id: ProposalId },
+	/// The outcome of a closed proposal was overridden by the origin mapped to `by`
+	OutcomeOverridden { proposal_id: ProposalId, outcome: OutcomeOverride, by: AccountId },
+	/// An epoch ended and its report was written
+	EpochReported { epoch: u32, report: EpochReport },
+	/// A sync of the voter registry from the `accounts` of a preimage started
+	VoterSyncStarted { preimage_hash: PreimageHash, accounts: u32 },
+	/// A sync of the voter registry completed, `skipped` accounts could not be registered
+	VotersSynced { preimage_hash: PreimageHash, added: u32, removed: u32, skipped: u32 },
+	/// The archive of a proposal was pruned and folded into the archive tree of the epoch `era`
+	ArchiveCompressed { proposal_id: ProposalId, era: u32, root: [u8; 32] },
+	/// The call a proposal enacts if it is approved was set, identified by the hash of its
+	/// encoding, or removed with `None`
+	ProposalCallSet { proposal_id: ProposalId, call_hash: Option<[u8; 32]> },
+	/// The call of an approved proposal was dispatched from `EnactmentOrigin`
+	ProposalEnacted { proposal_id: ProposalId, result: DispatchResult },
+	/// The enactment of the call of a proposal failed with `error`, its retry number `retry` was
+	/// scheduled
+	EnactmentRetryScheduled { proposal_id: ProposalId, retry: u32, error: DispatchError },
+	/// The deposit of a proposal was returned to its creator once the proposal was archived
+	ProposalDepositReturned { proposal_id: ProposalId, amount: Balance },
+	/// The deposit of a proposal cancelled as spam by Root was lost to the treasury account
+	ProposalDepositSlashed { proposal_id: ProposalId, amount: Balance },
+	/// An account registered itself as a voter, placing `bond` on hold
+	VoterSelfRegistered { who: AccountId, bond: Balance },
+	/// The registration bond of a voter was returned once its registration was removed
+	RegistrationBondReturned { who: AccountId, amount: Balance },
+	/// Root overrode the limits of the proposals
+	ProposalLimitsSet { limits: ProposalLimits },
+}
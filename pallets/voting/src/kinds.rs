@@ -0,0 +1,52 @@
+use crate::{Config, ProposalData, ProposalKind, RegisteredVotersCount};
+use frame_support::traits::Get;
+
+/// The rules of each proposal kind, configured by the runtime. New kinds are added as
+/// `ProposalKind::Custom` and given their rules by a handler, without editing the vote and close
+/// logic of the pallet.
+pub trait KindHandler<T: Config<I>, I: 'static = ()> {
+	/// Whether proposals of `kind` can be submitted by registered voters and the external origin.
+	fn can_create(kind: &ProposalKind) -> bool;
+
+	/// Whether the account list of `proposal` allows `who` to vote.
+	fn is_allowed(proposal: &ProposalData<T, I>, who: &T::AccountId) -> bool;
+
+	/// The number of voters allowed to vote on `proposal`, the denominator of its quorum.
+	fn electorate(proposal: &ProposalData<T, I>) -> u32;
+
+	/// Whether `proposal` is approved once closed, `electorate` being recorded at its start.
+	fn is_approved(proposal: &ProposalData<T, I>, electorate: u32) -> bool;
+}
+
+/// The built-in kinds: the account list bans voters from public and emergency proposals and
+/// allows the voters of private proposals, emergency proposals also need a supermajority. Custom
+/// and unknown kinds cannot be created.
+impl<T: Config<I>, I: 'static> KindHandler<T, I> for () {
+	fn can_create(kind: &ProposalKind) -> bool {
+		!matches!(kind, ProposalKind::Custom(_) | ProposalKind::Unknown(_))
+	}
+
+	fn is_allowed(proposal: &ProposalData<T, I>, who: &T::AccountId) -> bool {
+		let Some(account_list) = &proposal.account_list else { return true };
+		match proposal.kind {
+			ProposalKind::Private => account_list.contains(who),
+			_ => !account_list.contains(who),
+		}
+	}
+
+	fn electorate(proposal: &ProposalData<T, I>) -> u32 {
+		match (&proposal.kind, &proposal.account_list) {
+			(ProposalKind::Private, Some(account_list)) => account_list.len() as u32,
+			_ => RegisteredVotersCount::<T, I>::get(),
+		}
+	}
+
+	fn is_approved(proposal: &ProposalData<T, I>, electorate: u32) -> bool {
+		match proposal.kind {
+			ProposalKind::Emergency =>
+				proposal.is_approved(electorate) &&
+					proposal.has_supermajority(T::EmergencySupermajority::get()),
+			_ => proposal.is_approved(electorate),
+		}
+	}
+}
@@ -1,19 +1,47 @@
 #![cfg_attr(not(feature = "std"), no_std)]
 
 use frame_support::{
-	dispatch::Vec,
+	dispatch::{GetDispatchInfo, PostDispatchInfo, Vec},
 	pallet_prelude::*,
-	sp_runtime::{traits::Zero, SaturatedConversion, Saturating},
+	sp_runtime::{
+		traits::{AccountIdConversion, CheckedMul, Dispatchable, Hash, Zero},
+		DigestItem, Perbill, Percent, SaturatedConversion, Saturating,
+	},
+	storage::with_storage_layer,
 	traits::{
 		fungible,
-		tokens::{Fortitude, Preservation},
+		schedule::{v3::Named as ScheduleNamed, DispatchTime, LOWEST_PRIORITY},
+		tokens::{fungibles, nonfungibles_v2, Fortitude, Precision, Preservation, Restriction},
+		Bounded, Contains, Hash as PreimageHash, OriginTrait, QueryPreimage, Randomness,
+		StorePreimage,
 	},
+	weights::Weight,
+	Hashable, PalletId,
 };
 use frame_system::pallet_prelude::BlockNumberFor;
 
+pub use kinds::KindHandler;
 pub use pallet::*;
-pub use types::{ProposalData, ProposalId, ProposalKind, VoteInfo, VoteRatio};
+pub use pba_interface::math;
+use types::merkle_proof;
+pub use types::{
+	archive_leaf, bitmap_contains, bitmap_len, plurality_winner, tallied_amount, verify_archive,
+	verify_membership, verify_vote, vote_leaf, webhook_payload, ArchiveProof, ArchivedProposal,
+	AuditIssue, CancellationReason, CleanupLane, CleanupTask, CompressedArchive, Conviction,
+	CreateError, DeputyInfo, Dispute, DisputeResolution, EpochReport, EpochStats, FeePayout,
+	FeePot, HoldHealth, LockReport, MembershipProof, MigrationCursor, NoNfts, OnRepeal,
+	OptionLabel, OrgId, OrgInfo, OrgParameters, OutcomeOverride, PersonhoodProvider, ProposalData,
+	ProposalId, ProposalKind, ProposalLimits, ProposalOutcome, ProposalSettings, ProposalStatus,
+	QueuedProposal, Recount, RoleId, Threshold, VoteCost, VoteInfo, VoteProof, VoteRatio,
+	VoteShortfall, VoterAdmission, VoterInfo, VoterSync, VotesTree, MAX_CONVICTION_PERIODS,
+	MAX_RECOUNT_CURSOR, MAX_ROLE_QUORUMS, TALLY_COMMITMENT_ENGINE_ID, WEBHOOKS_STORAGE_KEY,
+	WEBHOOK_TIMEOUT_MS,
+};
 
+#[cfg(feature = "std")]
+pub mod client_types;
+mod kinds;
+pub mod migrations;
 #[cfg(test)]
 mod mock;
 mod types;
@@ -23,39 +51,78 @@ mod tests;
 
 #[cfg(feature = "runtime-benchmarks")]
 mod benchmarking;
+pub mod weights;
+pub use weights::WeightInfo;
+
+/// Provides the benchmarks with the state the pallet cannot create on its own.
+#[cfg(feature = "runtime-benchmarks")]
+pub trait BenchmarkHelper<AssetId> {
+	/// An asset proposals can be voted with.
+	fn asset_id() -> AssetId;
+	/// Notes `data` as a preimage of the `Preimages` and returns its hash.
+	fn note_preimage(data: Vec<u8>) -> PreimageHash;
+}
 
 #[frame_support::pallet]
 pub mod pallet {
 	use super::*;
 	use frame_system::pallet_prelude::*;
 
+	/// The in-code storage version.
+	const STORAGE_VERSION: StorageVersion = StorageVersion::new(12);
+
 	#[pallet::pallet]
-	pub struct Pallet<T>(_);
+	#[pallet::storage_version(STORAGE_VERSION)]
+	pub struct Pallet<T, I = ()>(PhantomData<(T, I)>);
 
-	pub type BalanceOf<T> = <<T as Config>::NativeBalance as fungible::Inspect<
+	pub type BalanceOf<T, I = ()> = <<T as Config<I>>::NativeBalance as fungible::Inspect<
 		<T as frame_system::Config>::AccountId,
 	>>::Balance;
-	pub type FreezeIdOf<T> = <<T as Config>::NativeBalance as fungible::freeze::Inspect<
+	/// The power of a vote, expressed in the balance type its quadratic cost is paid in.
+	pub type PowerOf<T, I = ()> = BalanceOf<T, I>;
+	pub type QueuedProposalOf<T, I = ()> = QueuedProposal<
+		<T as frame_system::Config>::AccountId,
+		<T as Config<I>>::AccountSizeLimit,
+		<T as Config<I>>::ProposalOffchainDataLimit,
+	>;
+	pub type CollectionIdOf<T, I = ()> = <<T as Config<I>>::Nfts as nonfungibles_v2::Inspect<
 		<T as frame_system::Config>::AccountId,
-	>>::Id;
+	>>::CollectionId;
+	pub type AssetIdOf<T, I = ()> = <<T as Config<I>>::Assets as fungibles::Inspect<
+		<T as frame_system::Config>::AccountId,
+	>>::AssetId;
+	/// The call of a proposal, inline or in a preimage of `Preimages`.
+	pub type BoundedCallOf<T, I = ()> = Bounded<<T as Config<I>>::RuntimeCall>;
+	/// The origin the `Scheduler` dispatches the retries of the enactments from.
+	pub type PalletsOriginOf<T> =
+		<<T as frame_system::Config>::RuntimeOrigin as OriginTrait>::PalletsOrigin;
 
 	/// Configure the pallet by specifying the parameters and types on which it depends.
 	#[pallet::config]
-	pub trait Config: frame_system::Config {
+	pub trait Config<I: 'static = ()>: frame_system::Config {
 		/// Because this pallet emits events, it depends on the runtime's definition of an event.
-		type RuntimeEvent: From<Event<Self>> + IsType<<Self as frame_system::Config>::RuntimeEvent>;
+		type RuntimeEvent: From<Event<Self, I>>
+			+ TryInto<Event<Self, I>>
+			+ IsType<<Self as frame_system::Config>::RuntimeEvent>;
+
+		/// Weight information for extrinsics in this pallet.
+		type WeightInfo: WeightInfo;
 
 		/// Type to access the Balances Pallet.
 		type NativeBalance: fungible::Inspect<Self::AccountId>
 			+ fungible::Mutate<Self::AccountId>
-			+ fungible::hold::Inspect<Self::AccountId>
-			+ fungible::hold::Mutate<Self::AccountId>
-			+ fungible::freeze::Inspect<Self::AccountId>
-			+ fungible::freeze::Mutate<Self::AccountId>;
+			+ fungible::hold::Inspect<Self::AccountId, Reason = Self::RuntimeHoldReason>
+			+ fungible::hold::Mutate<Self::AccountId, Reason = Self::RuntimeHoldReason>
+			+ fungible::freeze::Inspect<Self::AccountId, Id = Self::RuntimeFreezeReason>
+			+ fungible::freeze::Mutate<Self::AccountId, Id = Self::RuntimeFreezeReason>;
 
-		/// Freeze identifier used by the pallet
-		#[pallet::constant]
-		type FreezeIdForPallet: Get<FreezeIdOf<Self>>;
+		/// The overarching hold reason, holding the collateral of the votes under
+		/// `HoldReason::VoteCollateral`.
+		type RuntimeHoldReason: From<HoldReason>;
+
+		/// The overarching freeze reason, under which the collateral was frozen as
+		/// `FreezeReason::Voting` before it was held.
+		type RuntimeFreezeReason: From<FreezeReason>;
 
 		/// Maximum offchain data length.
 		#[pallet::constant]
@@ -65,469 +132,6763 @@ pub mod pallet {
 		#[pallet::constant]
 		type AccountSizeLimit: Get<u32>;
 
-		/// Maximum duration for a proposal.
+		/// Maximum length in bytes of the eligibility bitmap of a private proposal, eight voters
+		/// being covered by each byte.
 		#[pallet::constant]
+		type MaxEligibilityBitmapLen: Get<u32>;
+
+		/// Maximum duration for a proposal, unless Root overrides it with `set_proposal_limits`.
 		type ProposalMaximumDuration: Get<u32>;
 
-		/// Minimum duration for a proposal.
-		#[pallet::constant]
+		/// Minimum duration for a proposal, unless Root overrides it with `set_proposal_limits`.
 		type ProposalMinimumDuration: Get<u32>;
 
-		/// Maximum delay for a proposal to start.
-		#[pallet::constant]
+		/// Maximum delay for a proposal to start, unless Root overrides it with
+		/// `set_proposal_limits`.
 		type ProposalDelayLimit: Get<u32>;
-	}
 
-	/// All well-known voters registered to participate in proposal voting
-	#[pallet::storage]
-	#[pallet::getter(fn registered_voters)]
-	pub type RegisteredVoters<T: Config> =
-		StorageMap<_, Blake2_128Concat, T::AccountId, (), OptionQuery>;
+		/// Voice credits each voter can spend per epoch on shared budget proposals.
+		#[pallet::constant]
+		type VoiceCredits: Get<BalanceOf<Self, I>>;
 
-	/// The ID that will be used by the next proposal created
-	#[pallet::storage]
-	#[pallet::getter(fn next_proposal_id)]
-	pub type NextProposalId<T: Config> = StorageValue<_, u32, ValueQuery>;
+		/// Duration of an epoch in blocks.
+		#[pallet::constant]
+		type EpochDuration: Get<u32>;
 
-	/// All proposals staged or in progress
-	#[pallet::storage]
-	#[pallet::getter(fn proposals)]
-	pub type Proposals<T: Config> = StorageMap<
-		_,
-		Blake2_128Concat,
-		ProposalId,
-		ProposalData<T, T::AccountId, T::AccountSizeLimit, T::ProposalOffchainDataLimit>,
-		OptionQuery,
-	>;
+		/// Number of blocks an archived proposal is kept before being pruned.
+		#[pallet::constant]
+		type ArchiveRetention: Get<u32>;
 
-	/// All votes for proposals in progress.
-	/// The key is the proposal ID and the voter ID, to ensure it's unique.
-	#[pallet::storage]
-	#[pallet::getter(fn votes)]
-	pub type Votes<T: Config> = StorageDoubleMap<
-		_,
-		Blake2_256,
-		T::AccountId,
-		Blake2_256,
-		ProposalId,
-		VoteInfo,
-		OptionQuery,
-	>;
+		/// Number of blocks after a proposal is closed from which anyone can release the
+		/// collateral of its voters with `claim_for`, unless Root overrides it with
+		/// `set_proposal_limits`.
+		type ClaimGracePeriod: Get<u32>;
 
-	// Pallets use events to inform users when important changes are made.
-	// https://docs.substrate.io/main-docs/build/events-errors/
-	#[pallet::event]
-	#[pallet::generate_deposit(pub(super) fn deposit_event)]
-	pub enum Event<T: Config> {
-		/// A new voter is registered
-		NewVoterRegistered { who: T::AccountId },
-		/// A voter is unregistered
-		VoterUnregistered { who: T::AccountId },
-		/// A new proposal is created
-		ProposalCreated {
-			proposal_id: ProposalId,
-			offchain_data: BoundedVec<u8, T::ProposalOffchainDataLimit>,
-			creator: T::AccountId,
-			kind: ProposalKind,
-			account_list: Option<BoundedVec<T::AccountId, T::AccountSizeLimit>>,
-			start_block: BlockNumberFor<T>,
-			end_block: BlockNumberFor<T>,
-		},
-		/// A proposal that did not start yet is cancelled
-		ProposalCancelled { proposal_id: ProposalId },
-		/// A proposal is closed and the vote is completed
-		VoteCompleted { proposal_id: ProposalId, ratio: (u128, u128) },
-		/// A new account list is set before a proposal has started
-		AccountListSet {
-			proposal_id: ProposalId,
-			account_list: Option<BoundedVec<T::AccountId, T::AccountSizeLimit>>,
-		},
-		/// A new vote was added to an in progress proposal
-		VoteAdded { proposal_id: ProposalId, voter: T::AccountId, aye: bool, power: u128 },
-		/// A vote was removed from an in progress proposal
-		VoteDropped { proposal_id: ProposalId, voter: T::AccountId },
-		/// A new vote was added to an in progress proposal
-		BalanceClaimed { who: T::AccountId, amount: BalanceOf<T> },
-	}
+		/// Number of blocks the collateral of a vote stays held after its proposal is closed, for
+		/// each lock period of its conviction.
+		#[pallet::constant]
+		type ConvictionPeriod: Get<u32>;
 
-	// Errors inform users that something went wrong.
-	#[pallet::error]
-	pub enum Error<T> {
-		/// Origin has no permission to operate
-		OriginNoPermission,
-		/// A user is trying to vote, but is not registered in the `RegisteredVoters` storage.
-		VoterNotRegistered,
-		/// A proposal does not exist in storage
-		ProposalDoesNotExist,
-		/// A proposal has already started
-		ProposalHasAlreadyStarted,
-		/// A proposal has already ended
-		ProposalHasAlreadyEnded,
-		/// A proposal has not started yet
-		ProposalHasNotStartedYet,
-		/// A proposal has not ended yet
-		ProposalHasNotEndedYet,
-		/// A proposal cannot start in the past
-		ProposalCannotStartInThePast,
-		/// A proposal cannot end before starting
-		ProposalCannotFinishBeforeStarting,
-		/// The proposal duration is too long
-		ProposalDurationIsTooLong,
-		/// The proposal duration is too short
-		ProposalDurationIsTooShort,
-		/// The delay for a proposal to start is too far away
-		ProposalStartIsTooFarAway,
-		/// A claim is possible only for closed proposal
-		ProposalNotClosed,
-		/// The voter has insufficient free funds to vote with power
-		InsufficientBalance,
-		/// The new vote is already the active vote
-		IdenticVote,
-		/// Proposal claim does not exist
-		ClaimDoesNotExist,
-	}
+		/// Maximum number of voters whose collateral is released per block once their proposal is
+		/// closed.
+		#[pallet::constant]
+		type MaxReleasesPerBlock: Get<u32>;
 
-	// Dispatchable functions allows users to interact with the pallet and invoke state changes.
-	// These functions materialize as "extrinsics", which are often compared to transactions.
-	// Dispatchable functions must be annotated with a weight and must return a DispatchResult.
-	#[pallet::call]
-	impl<T: Config> Pallet<T> {
-		#[pallet::call_index(0)]
-		#[pallet::weight(10_000 + T::DbWeight::get().writes(1).ref_time())]
-		pub fn register_voter(origin: OriginFor<T>, who: T::AccountId) -> DispatchResult {
-			ensure_root(origin)?;
-			RegisteredVoters::<T>::insert(&who, ());
-			Self::deposit_event(Event::<T>::NewVoterRegistered { who });
-			Ok(())
-		}
+		/// Maximum number of ended proposals closed per block in `on_initialize`.
+		#[pallet::constant]
+		type MaxClosesPerBlock: Get<u32>;
 
-		#[pallet::call_index(1)]
-		#[pallet::weight(10_000 + T::DbWeight::get().writes(1).ref_time())]
-		pub fn unregister_voter(origin: OriginFor<T>, who: T::AccountId) -> DispatchResult {
-			let maybe_caller = ensure_signed_or_root(origin)?;
-			ensure!(
-				(maybe_caller.is_none() || maybe_caller.clone().unwrap() == who),
-				Error::<T>::OriginNoPermission
-			);
+		/// Maximum power of a single vote. Tallies cannot overflow as long as `MaxPower` squared
+		/// times the number of voters fits in a `u128`.
+		#[pallet::constant]
+		type MaxPower: Get<PowerOf<Self, I>>;
 
-			for vote in Votes::<T>::iter_prefix_values(who.clone()) {
-				Pallet::<T>::unfreeze(&who.clone(), vote.power, 0)?;
+		/// Maximum number of staged or in progress proposals of each kind.
+		#[pallet::constant]
+		type MaxProposalsPerKind: Get<u32>;
 
-				Proposals::<T>::try_mutate(vote.proposal_id, |maybe_proposal| -> DispatchResult {
-					if let Some(proposal) = maybe_proposal {
-						proposal.remove_ratio(vote.aye, vote.power, 0);
-					}
-					Ok(().into())
-				})?;
-			}
+		/// Maximum number of questions bundled in a single proposal.
+		#[pallet::constant]
+		type MaxQuestions: Get<u32>;
 
-			let _ = Votes::<T>::clear_prefix(who.clone(), u32::MAX, None);
-			RegisteredVoters::<T>::remove(&who);
-			Self::deposit_event(Event::<T>::VoterUnregistered { who });
-			Ok(())
-		}
+		/// Maximum number of options of a multi-option proposal.
+		#[pallet::constant]
+		type MaxOptions: Get<u32>;
 
-		#[pallet::call_index(2)]
-		#[pallet::weight(10_000 + T::DbWeight::get().writes(1).ref_time())]
-		pub fn create_proposal(
-			origin: OriginFor<T>,
-			offchain_data: BoundedVec<u8, T::ProposalOffchainDataLimit>,
-			kind: ProposalKind,
-			account_list: Option<BoundedVec<T::AccountId, T::AccountSizeLimit>>,
-			start_block: BlockNumberFor<T>,
-			end_block: BlockNumberFor<T>,
-		) -> DispatchResult {
-			let caller = ensure_signed(origin)?;
-			ensure!(
-				RegisteredVoters::<T>::get(caller.clone()).is_some(),
-				Error::<T>::VoterNotRegistered
-			);
+		/// Source of randomness used to draw the jury of sortition proposals.
+		type Randomness: Randomness<Self::Hash, BlockNumberFor<Self>>;
 
-			let current_block = Pallet::<T>::get_current_block_number();
-			ensure!(current_block <= start_block, Error::<T>::ProposalCannotStartInThePast);
-			ensure!(start_block < end_block, Error::<T>::ProposalCannotFinishBeforeStarting);
+		/// Maximum size of the jury drawn for a sortition proposal.
+		#[pallet::constant]
+		type MaxJurySize: Get<u32>;
 
-			let duration = end_block.saturating_sub(start_block);
-			let buffer = start_block.saturating_sub(current_block);
-			ensure!(
-				buffer <= T::ProposalDelayLimit::get().into(),
-				Error::<T>::ProposalStartIsTooFarAway
-			);
-			ensure!(
-				duration >= T::ProposalMinimumDuration::get().into(),
-				Error::<T>::ProposalDurationIsTooShort
-			);
-			ensure!(
-				duration <= T::ProposalMaximumDuration::get().into(),
-				Error::<T>::ProposalDurationIsTooLong
-			);
+		/// Maximum number of winners drawn by the turnout lottery of a proposal.
+		#[pallet::constant]
+		type MaxLotteryWinners: Get<u32>;
 
-			// TODO: ensure account_list not empty for private proposals?
+		/// Maximum number of hops between a delegator and the delegate voting on its behalf.
+		#[pallet::constant]
+		type MaxDelegationDepth: Get<u32>;
 
-			let proposal_id = Pallet::<T>::get_next_proposal_id();
-			let proposal = ProposalData::new(
-				offchain_data.clone(),
-				kind.clone(),
-				caller.clone(),
-				account_list.clone(),
-				start_block,
-				end_block,
-			);
+		/// Share of the tallied amount of a delegated vote lost at each delegation hop.
+		#[pallet::constant]
+		type DelegationAttenuation: Get<Percent>;
 
-			Proposals::<T>::insert(proposal_id, proposal);
+		/// Maximum number of votes, votes cast as a delegate and delegators moved at once by
+		/// `migrate_voter`.
+		#[pallet::constant]
+		type MaxMigratedEntries: Get<u32>;
 
-			let event = Event::ProposalCreated {
-				proposal_id,
-				offchain_data,
-				creator: caller,
-				kind,
-				account_list,
-				start_block,
-				end_block,
-			};
-			Self::deposit_event(event);
+		/// Bonus added to the tallied amount of a vote for each full epoch the voter has been
+		/// continuously registered before the proposal start.
+		#[pallet::constant]
+		type TenureBonusPerEpoch: Get<Percent>;
 
-			Ok(())
-		}
+		/// Maximum tenure bonus of a voter.
+		#[pallet::constant]
+		type MaxTenureBonus: Get<Percent>;
 
-		#[pallet::call_index(3)]
-		#[pallet::weight(10_000 + T::DbWeight::get().writes(1).ref_time())]
-		pub fn cancel_proposal(origin: OriginFor<T>, proposal_id: ProposalId) -> DispatchResult {
-			let caller = ensure_signed_or_root(origin)?;
+		/// Number of blocks a voter registration lasts before it must be renewed, `None` for
+		/// registrations that never expire.
+		#[pallet::constant]
+		type RegistrationTtl: Get<Option<u32>>;
 
-			let current_block = Pallet::<T>::get_current_block_number();
-			let proposal =
-				Proposals::<T>::get(proposal_id).ok_or(Error::<T>::ProposalDoesNotExist)?;
+		/// Minimum balance an account must hold to be registered as a voter and to vote.
+		#[pallet::constant]
+		type MinVoterBalance: Get<BalanceOf<Self, I>>;
 
-			ensure!(
-				(caller.is_none() || proposal.is_creator(&caller.unwrap())),
-				Error::<T>::OriginNoPermission
-			);
-			ensure!(!proposal.has_started(&current_block), Error::<T>::ProposalHasAlreadyStarted);
+		/// Number of blocks the balance backing votes must have been held, according to the
+		/// last balance snapshot of the voter. Zero to disable the requirement.
+		#[pallet::constant]
+		type BalanceAge: Get<u32>;
 
-			Proposals::<T>::remove(proposal_id);
-			Self::deposit_event(Event::<T>::ProposalCancelled { proposal_id });
-			Ok(())
-		}
+		/// Number of distinct registered voters who must second a proposal before its start for
+		/// it to open for voting.
+		#[pallet::constant]
+		type SecondsThreshold: Get<u32>;
 
-		#[pallet::call_index(4)]
-		#[pallet::weight(10_000 + T::DbWeight::get().writes(1).ref_time())]
-		pub fn close_proposal(
-			origin: OriginFor<T>,
-			proposal_id: ProposalId,
-		) -> DispatchResultWithPostInfo {
-			ensure_signed(origin)?;
+		/// Period in blocks at which the most seconded queued public proposal is promoted to a
+		/// voting proposal, zero to disable the launch queue.
+		#[pallet::constant]
+		type LaunchPeriod: Get<u32>;
 
-			let current_block = Pallet::<T>::get_current_block_number();
-			let proposal =
-				Proposals::<T>::get(proposal_id).ok_or(Error::<T>::ProposalDoesNotExist)?;
+		/// Period in blocks at which the tally of each open proposal is emitted in a
+		/// `TallyCheckpoint` event, zero to disable the checkpoints.
+		#[pallet::constant]
+		type TallyCheckpointPeriod: Get<u32>;
 
-			ensure!(proposal.has_ended(&current_block), Error::<T>::ProposalHasNotEndedYet);
+		/// Maximum number of proposals read per block for the tally checkpoints and for the tally
+		/// commitment, a round carrying over the following blocks.
+		#[pallet::constant]
+		type MaxCheckpointsPerBlock: Get<u32>;
 
-			Proposals::<T>::remove(proposal_id);
-			Self::deposit_event(Event::<T>::VoteCompleted { proposal_id, ratio: proposal.ratio });
-			Ok(Pays::No.into())
-		}
+		/// Whether a commitment to the tallies of the open proposals is published in the block
+		/// digest each epoch, for light clients and bridges.
+		#[pallet::constant]
+		type PublishTallyCommitment: Get<bool>;
 
-		#[pallet::call_index(5)]
-		#[pallet::weight(10_000 + T::DbWeight::get().writes(1).ref_time())]
-		pub fn set_account_list(
-			origin: OriginFor<T>,
-			proposal_id: ProposalId,
-			account_list: Option<BoundedVec<T::AccountId, T::AccountSizeLimit>>,
-		) -> DispatchResult {
-			let caller = ensure_signed_or_root(origin)?;
+		/// Origin allowed to create emergency proposals, mapped to the account recorded as their
+		/// creator.
+		type EmergencyOrigin: EnsureOrigin<Self::RuntimeOrigin, Success = Self::AccountId>;
 
-			let current_block = Pallet::<T>::get_current_block_number();
-			let proposal =
-				Proposals::<T>::get(proposal_id).ok_or(Error::<T>::ProposalDoesNotExist)?;
+		/// Origin allowed to create external proposals without being a registered voter, mapped to
+		/// the account recorded as their creator.
+		type ExternalOrigin: EnsureOrigin<Self::RuntimeOrigin, Success = Self::AccountId>;
 
-			ensure!(
-				(caller.is_none() || proposal.is_creator(&caller.unwrap())),
-				Error::<T>::OriginNoPermission
-			);
-			ensure!(!proposal.has_started(&current_block), Error::<T>::ProposalHasAlreadyStarted);
+		/// Maximum duration for an emergency proposal, unless Root overrides it with
+		/// `set_proposal_limits`.
+		type EmergencyMaxDuration: Get<u32>;
 
-			Proposals::<T>::insert(
-				proposal_id,
-				ProposalData { account_list: account_list.clone(), ..proposal },
-			);
-			Self::deposit_event(Event::<T>::AccountListSet { proposal_id, account_list });
-			Ok(())
-		}
+		/// Share of the total votes 'aye' votes must exceed for an emergency proposal to be
+		/// approved.
+		#[pallet::constant]
+		type EmergencySupermajority: Get<Perbill>;
 
-		#[pallet::call_index(6)]
-		#[pallet::weight(10_000 + T::DbWeight::get().writes(1).ref_time())]
-		pub fn vote(
-			origin: OriginFor<T>,
-			proposal_id: ProposalId,
-			aye: bool,
-			power: u128,
-		) -> DispatchResult {
-			let caller = ensure_signed(origin)?;
-			ensure!(
-				RegisteredVoters::<T>::get(caller.clone()).is_some(),
-				Error::<T>::VoterNotRegistered
-			);
+		/// Identifier from which the pot accounts holding the participation fees of each proposal
+		/// are derived.
+		#[pallet::constant]
+		type PalletId: Get<PalletId>;
 
-			let current_block = Pallet::<T>::get_current_block_number();
+		/// Account receiving the participation fees not paid out to voters or closers.
+		type TreasuryAccount: Get<Self::AccountId>;
 
-			Proposals::<T>::try_mutate(proposal_id, |maybe_proposal| -> DispatchResult {
-				let proposal = maybe_proposal.as_mut().ok_or(Error::<T>::ProposalDoesNotExist)?;
+		/// Amount reimbursed to each new voter of a sponsored proposal, meant to cover the
+		/// transaction fee of a vote.
+		#[pallet::constant]
+		type VoteRefund: Get<BalanceOf<Self, I>>;
 
-				ensure!(proposal.has_started(&current_block), Error::<T>::ProposalHasNotStartedYet);
-				ensure!(!proposal.has_ended(&current_block), Error::<T>::ProposalHasAlreadyEnded);
+		/// Number of blocks after a proposal is closed during which its outcome can be disputed.
+		/// Zero disables disputes.
+		#[pallet::constant]
+		type DisputePeriod: Get<u32>;
 
-				let maybe_account_list = proposal.clone().account_list;
-				if let Some(account_list) = maybe_account_list {
-					let allowed_voter = match proposal.kind {
-						ProposalKind::Public => !account_list.contains(&caller),
-						ProposalKind::Private => account_list.contains(&caller),
-					};
-					ensure!(allowed_voter, Error::<T>::OriginNoPermission)
-				}
+		/// Amount bonded to dispute an outcome, lost to the treasury account if the outcome is
+		/// confirmed.
+		#[pallet::constant]
+		type DisputeBond: Get<BalanceOf<Self, I>>;
 
-				let maybe_vote = Votes::<T>::get(caller.clone(), proposal_id);
-				if let Some(vote) = maybe_vote {
-					ensure!(!(vote.power == power && vote.aye == aye), Error::<T>::IdenticVote); // TODO: Is useful?
-					let prev_power = vote.power;
-					if prev_power.lt(&power) {
-						Pallet::<T>::freeze(&caller, prev_power, power)?;
-						proposal.add_ratio(aye, prev_power, power);
-					} else {
-						Pallet::<T>::unfreeze(&caller, prev_power, power)?;
-						proposal.remove_ratio(aye, prev_power, power);
-					}
-				} else {
-					Pallet::<T>::freeze(&caller, 0, power)?;
-					proposal.add_ratio(aye, 0, power);
-				}
+		/// Amount held from the creator of a proposal created with `create_proposal`, returned
+		/// once the proposal is archived, or lost to the treasury account if Root cancels it as
+		/// spam.
+		#[pallet::constant]
+		type ProposalDeposit: Get<BalanceOf<Self, I>>;
 
-				if power.is_zero() {
-					Votes::<T>::remove(caller.clone(), proposal_id);
-					Self::deposit_event(Event::VoteDropped { proposal_id, voter: caller });
-				} else {
-					Votes::<T>::insert(
-						caller.clone(),
-						proposal_id,
-						VoteInfo { proposal_id, aye, power },
-					);
-					Self::deposit_event(Event::VoteAdded {
-						proposal_id,
-						voter: caller,
-						aye,
-						power,
-					});
-				}
+		/// Amount held from the accounts registering themselves with `self_register`, returned
+		/// when their registration is removed.
+		#[pallet::constant]
+		type RegistrationBond: Get<BalanceOf<Self, I>>;
 
-				// TODO: check if majority is doable in quadratic quorum voting; I don't think so
-				// if proposal.has_majority() {
-				// 	let ratio = proposal.ratio;
-				// 	*maybe_proposal = None;
-				// 	Self::deposit_event(Event::<T>::VoteCompleted { proposal_id, ratio });
-				// }
+		/// Origin resolving the disputes.
+		type DisputeResolutionOrigin: EnsureOrigin<Self::RuntimeOrigin>;
 
-				Ok(().into())
-			})?;
+		/// Origin allowed to ratify or veto the outcome of a closed proposal, e.g. a
+		/// supermajority of a council, mapped to the account recorded in the archive.
+		type OverrideOrigin: EnsureOrigin<Self::RuntimeOrigin, Success = Self::AccountId>;
 
-			Ok(())
-		}
+		/// Number of blocks after a proposal is closed during which its outcome can be
+		/// overridden. Zero disables overrides.
+		#[pallet::constant]
+		type OverridePeriod: Get<u32>;
 
-		#[pallet::call_index(7)]
-		#[pallet::weight(10_000 + T::DbWeight::get().writes(1).ref_time())]
-		pub fn claim(origin: OriginFor<T>, proposal_id: ProposalId) -> DispatchResult {
-			let caller = ensure_signed(origin)?;
-			ensure!(
-				RegisteredVoters::<T>::get(caller.clone()).is_some(),
-				Error::<T>::VoterNotRegistered
-			);
-			ensure!(Proposals::<T>::get(proposal_id).is_none(), Error::<T>::ProposalNotClosed);
+		/// Origin auditing the votes of the proposals.
+		type AdminOrigin: EnsureOrigin<Self::RuntimeOrigin>;
 
-			let vote = Votes::<T>::get(caller.clone(), proposal_id)
-				.ok_or(Error::<T>::ClaimDoesNotExist)?;
+		/// Number of votes randomly drawn from each proposal closed to audit their hold, zero to
+		/// disable the sample audits.
+		#[pallet::constant]
+		type AuditSampleSize: Get<u32>;
 
-			Pallet::<T>::unfreeze(&caller, vote.power, 0)?;
-			Votes::<T>::remove(caller.clone(), proposal_id);
-			let amount = Pallet::<T>::calculate_quadratic_amount(vote.power);
-			Self::deposit_event(Event::BalanceClaimed { who: caller, amount });
+		/// Attests that an account is a unique person before it can be registered as a voter.
+		type PersonhoodProvider: PersonhoodProvider<Self::AccountId>;
 
+		/// The accounts with a verified on-chain identity, e.g. with a positive judgement of
+		/// `pallet-identity`, the only ones which can be registered as voters. `Everything`
+		/// disables the check.
+		type IdentityVerifier: Contains<Self::AccountId>;
+
+		/// The eligibility, electorate and approval rules of each proposal kind.
+		type KindHandler: KindHandler<Self, I>;
+
+		/// Repeals the policy of a proposal when its sunset review is not approved.
+		type OnRepeal: OnRepeal;
+
+		/// The NFTs recognised as membership passes, e.g. `pallet_nfts`.
+		type Nfts: nonfungibles_v2::InspectEnumerable<Self::AccountId>;
+
+		/// The collections of `Nfts` whose items are membership passes, each with the bonus its
+		/// holders get on their tallied amount when a proposal enables the NFT bonus.
+		type NftCollections: Get<Vec<(CollectionIdOf<Self, I>, Percent)>>;
+
+		/// Origin syncing the voter registry from a preimage and appointing the deputy
+		/// registrars.
+		type RegistrarOrigin: EnsureOrigin<Self::RuntimeOrigin>;
+
+		/// Maximum number of deputy registrars.
+		#[pallet::constant]
+		type MaxDeputies: Get<u32>;
+
+		/// Period in blocks over which the voters registered or unregistered by each deputy
+		/// registrar are limited.
+		#[pallet::constant]
+		type DeputyPeriod: Get<u32>;
+
+		/// The preimages holding the account lists of the voter registry syncs and the calls of
+		/// the proposals too large to be inlined, e.g. `pallet_preimage`.
+		type Preimages: QueryPreimage + StorePreimage;
+
+		/// The assets proposals can be voted with instead of the native balance, e.g.
+		/// `pallet_assets`.
+		type Assets: fungibles::Mutate<Self::AccountId, Balance = BalanceOf<Self, I>>;
+
+		/// Origin maintaining the allow-list of the voting assets.
+		type AssetOrigin: EnsureOrigin<Self::RuntimeOrigin>;
+
+		/// Origin granting the creator roles and restricting proposal kinds to them.
+		type RoleOrigin: EnsureOrigin<Self::RuntimeOrigin>;
+
+		/// The calls approved proposals enact.
+		type RuntimeCall: Parameter
+			+ Dispatchable<RuntimeOrigin = Self::RuntimeOrigin, PostInfo = PostDispatchInfo>
+			+ GetDispatchInfo
+			+ From<frame_system::Call<Self>>
+			+ From<Call<Self, I>>;
+
+		/// Origin the call of an approved proposal is dispatched from, e.g. Root.
+		type EnactmentOrigin: Get<Self::RuntimeOrigin>;
+
+		/// Maximum size of the encoded call of a proposal, inline or in a preimage.
+		#[pallet::constant]
+		type MaxCallSize: Get<u32>;
+
+		/// Maximum weight of the call of a proposal, charged to every enactment.
+		#[pallet::constant]
+		type MaxEnactmentWeight: Get<Weight>;
+
+		/// The scheduler retrying the enactments which failed, e.g. `pallet_scheduler`.
+		type Scheduler: ScheduleNamed<
+			BlockNumberFor<Self>,
+			<Self as Config<I>>::RuntimeCall,
+			PalletsOriginOf<Self>,
+		>;
+
+		/// Maximum number of retries of a failed enactment before its failure is recorded in the
+		/// archive of the proposal.
+		#[pallet::constant]
+		type MaxEnactmentRetries: Get<u32>;
+
+		/// Number of blocks between two attempts to enact the call of a proposal.
+		#[pallet::constant]
+		type EnactmentRetryPeriod: Get<u32>;
+
+		/// Sets up the assets and preimages of the benchmarks.
+		#[cfg(feature = "runtime-benchmarks")]
+		type BenchmarkHelper: BenchmarkHelper<AssetIdOf<Self, I>>;
+	}
+
+	/// The reasons for which the pallet holds funds.
+	#[pallet::composite_enum]
+	pub enum HoldReason {
+		/// The collateral of the votes cast with the native balance, released once claimed.
+		#[codec(index = 0)]
+		VoteCollateral,
+		/// The deposit of the proposals created with `create_proposal`, returned once archived.
+		#[codec(index = 1)]
+		ProposalDeposit,
+		/// The bond of the voters registered with `self_register`, returned once unregistered.
+		#[codec(index = 2)]
+		RegistrationBond,
+	}
+
+	/// The reasons for which the pallet freezes funds.
+	#[pallet::composite_enum]
+	pub enum FreezeReason {
+		/// The collateral of the votes, frozen before it was held. Only the migrations thaw it.
+		#[codec(index = 0)]
+		Voting,
+	}
+
+	/// All well-known voters registered to participate in proposal voting
+	#[pallet::storage]
+	#[pallet::getter(fn registered_voters)]
+	pub type RegisteredVoters<T: Config<I>, I: 'static = ()> =
+		StorageMap<_, Blake2_128Concat, T::AccountId, VoterInfo<BlockNumberFor<T>>, OptionQuery>;
+
+	/// The stable index of each account ever registered, the position of its bit in the
+	/// eligibility bitmaps. An index is kept across registrations and never reused.
+	#[pallet::storage]
+	#[pallet::getter(fn voter_index)]
+	pub type VoterIndices<T: Config<I>, I: 'static = ()> =
+		StorageMap<_, Blake2_128Concat, T::AccountId, u32, OptionQuery>;
+
+	/// The index assigned to the next account registered for the first time
+	#[pallet::storage]
+	pub type NextVoterIndex<T: Config<I>, I: 'static = ()> = StorageValue<_, u32, ValueQuery>;
+
+	/// The bond held from each voter registered with `self_register`, until its registration is
+	/// removed
+	#[pallet::storage]
+	#[pallet::getter(fn registration_bonds)]
+	pub type RegistrationBonds<T: Config<I>, I: 'static = ()> =
+		StorageMap<_, Blake2_128Concat, T::AccountId, BalanceOf<T, I>, OptionQuery>;
+
+	/// The number of voters in `RegisteredVoters`
+	#[pallet::storage]
+	#[pallet::getter(fn registered_voters_count)]
+	pub type RegisteredVotersCount<T: Config<I>, I: 'static = ()> =
+		StorageValue<_, u32, ValueQuery>;
+
+	/// The deputy registrars, allowed to register and unregister voters up to their limit
+	#[pallet::storage]
+	#[pallet::getter(fn deputies)]
+	pub type Deputies<T: Config<I>, I: 'static = ()> =
+		StorageMap<_, Blake2_128Concat, T::AccountId, DeputyInfo<BlockNumberFor<T>>, OptionQuery>;
+
+	/// The number of deputy registrars in `Deputies`
+	#[pallet::storage]
+	#[pallet::getter(fn deputies_count)]
+	pub type DeputiesCount<T: Config<I>, I: 'static = ()> = StorageValue<_, u32, ValueQuery>;
+
+	/// The limits of the proposals overridden by Root, the config types applying to the ones
+	/// left unset
+	#[pallet::storage]
+	#[pallet::getter(fn proposal_limits)]
+	pub type ProposalLimitOverrides<T: Config<I>, I: 'static = ()> =
+		StorageValue<_, ProposalLimits, ValueQuery>;
+
+	/// The organisations hosted by the pallet, each with its own voters and proposal IDs
+	#[pallet::storage]
+	#[pallet::getter(fn orgs)]
+	pub type Orgs<T: Config<I>, I: 'static = ()> =
+		StorageMap<_, Twox64Concat, OrgId, OrgInfo<T::AccountId>, OptionQuery>;
+
+	/// The ID that will be used by the next organisation created
+	#[pallet::storage]
+	#[pallet::getter(fn next_org_id)]
+	pub type NextOrgId<T: Config<I>, I: 'static = ()> = StorageValue<_, OrgId, ValueQuery>;
+
+	/// The registered voters admitted in each organisation and the block they joined it at
+	#[pallet::storage]
+	#[pallet::getter(fn org_members)]
+	pub type OrgMembers<T: Config<I>, I: 'static = ()> = StorageDoubleMap<
+		_,
+		Twox64Concat,
+		OrgId,
+		Blake2_128Concat,
+		T::AccountId,
+		BlockNumberFor<T>,
+		OptionQuery,
+	>;
+
+	/// The proposals of each organisation by their index within it
+	#[pallet::storage]
+	#[pallet::getter(fn org_proposals)]
+	pub type OrgProposals<T: Config<I>, I: 'static = ()> =
+		StorageDoubleMap<_, Twox64Concat, OrgId, Twox64Concat, u32, ProposalId, OptionQuery>;
+
+	/// The ID that will be used by the next proposal created
+	#[pallet::storage]
+	#[pallet::getter(fn next_proposal_id)]
+	pub type NextProposalId<T: Config<I>, I: 'static = ()> = StorageValue<_, u32, ValueQuery>;
+
+	/// All proposals staged or in progress
+	#[pallet::storage]
+	#[pallet::getter(fn proposals)]
+	pub type Proposals<T: Config<I>, I: 'static = ()> =
+		StorageMap<_, Blake2_128Concat, ProposalId, ProposalData<T, I>, OptionQuery>;
+
+	/// The IDs of the staged or in progress proposals, indexed by kind
+	#[pallet::storage]
+	#[pallet::getter(fn proposals_by_kind)]
+	pub type ProposalsByKind<T: Config<I>, I: 'static = ()> = StorageMap<
+		_,
+		Twox64Concat,
+		ProposalKind,
+		BoundedVec<ProposalId, T::MaxProposalsPerKind>,
+		ValueQuery,
+	>;
+
+	/// The public proposals waiting to be promoted, in submission order
+	#[pallet::storage]
+	#[pallet::getter(fn launch_queue)]
+	pub type LaunchQueue<T: Config<I>, I: 'static = ()> =
+		StorageValue<_, BoundedVec<ProposalId, T::MaxProposalsPerKind>, ValueQuery>;
+
+	/// The content of the queued public proposals
+	#[pallet::storage]
+	#[pallet::getter(fn queued_proposals)]
+	pub type QueuedProposals<T: Config<I>, I: 'static = ()> =
+		StorageMap<_, Blake2_128Concat, ProposalId, QueuedProposalOf<T, I>, OptionQuery>;
+
+	/// The registered voters who seconded a staged proposal
+	#[pallet::storage]
+	#[pallet::getter(fn seconds)]
+	pub type Seconds<T: Config<I>, I: 'static = ()> =
+		StorageDoubleMap<_, Blake2_128Concat, ProposalId, Blake2_128Concat, T::AccountId, ()>;
+
+	/// The outcome of all cancelled or closed proposals
+	#[pallet::storage]
+	#[pallet::getter(fn archived_proposals)]
+	pub type ArchivedProposals<T: Config<I>, I: 'static = ()> = StorageMap<
+		_,
+		Blake2_128Concat,
+		ProposalId,
+		ArchivedProposal<T::AccountId, BlockNumberFor<T>>,
+		OptionQuery,
+	>;
+
+	/// The lifecycle status of the proposals, from their creation until their archive is pruned
+	#[pallet::storage]
+	#[pallet::getter(fn proposal_status)]
+	pub type ProposalStatuses<T: Config<I>, I: 'static = ()> =
+		StorageMap<_, Blake2_128Concat, ProposalId, ProposalStatus, OptionQuery>;
+
+	/// Deferred cleanup tasks processed in `on_idle`, keyed by lane and position in the lane.
+	/// Each task is stored with the block from which it can be processed.
+	#[pallet::storage]
+	#[pallet::getter(fn cleanup_queue)]
+	pub type CleanupQueue<T: Config<I>, I: 'static = ()> = StorageDoubleMap<
+		_,
+		Twox64Concat,
+		CleanupLane,
+		Twox64Concat,
+		u32,
+		(BlockNumberFor<T>, CleanupTask<T::AccountId>),
+		OptionQuery,
+	>;
+
+	/// The head and tail positions of each cleanup lane
+	#[pallet::storage]
+	#[pallet::getter(fn cleanup_queue_bounds)]
+	pub type CleanupQueueBounds<T: Config<I>, I: 'static = ()> =
+		StorageMap<_, Twox64Concat, CleanupLane, (u32, u32), ValueQuery>;
+
+	/// All votes for proposals in progress.
+	/// The key is the proposal ID and the voter ID, to ensure it's unique.
+	#[pallet::storage]
+	#[pallet::getter(fn votes)]
+	pub type Votes<T: Config<I>, I: 'static = ()> = StorageDoubleMap<
+		_,
+		Blake2_256,
+		T::AccountId,
+		Blake2_256,
+		ProposalId,
+		VoteInfo<PowerOf<T, I>>,
+		OptionQuery,
+	>;
+
+	/// The last balance snapshot of each voter: the block it was taken at and the balance held.
+	#[pallet::storage]
+	#[pallet::getter(fn balance_snapshots)]
+	pub type BalanceSnapshots<T: Config<I>, I: 'static = ()> = StorageMap<
+		_,
+		Blake2_128Concat,
+		T::AccountId,
+		(BlockNumberFor<T>, BalanceOf<T, I>),
+		OptionQuery,
+	>;
+
+	/// The voice credits spent by each voter during an epoch: the epoch index and the amount spent.
+	#[pallet::storage]
+	#[pallet::getter(fn spent_credits)]
+	pub type SpentCredits<T: Config<I>, I: 'static = ()> =
+		StorageMap<_, Blake2_128Concat, T::AccountId, (u32, BalanceOf<T, I>), ValueQuery>;
+
+	/// The running tally of the proposals in progress with a hidden tally, kept out of the
+	/// proposal until it is closed.
+	#[pallet::storage]
+	pub type HiddenTallies<T: Config<I>, I: 'static = ()> =
+		StorageMap<_, Blake2_128Concat, ProposalId, VoteRatio, ValueQuery>;
+
+	/// The contribution of each voter to the tally of a proposal in progress: the vote side and
+	/// the amount of votes. Used to apply the whale cap deterministically when closing.
+	#[pallet::storage]
+	#[pallet::getter(fn contributions)]
+	pub type Contributions<T: Config<I>, I: 'static = ()> = StorageDoubleMap<
+		_,
+		Blake2_128Concat,
+		ProposalId,
+		Blake2_128Concat,
+		T::AccountId,
+		(bool, u128),
+		OptionQuery,
+	>;
+
+	/// The running tally of each question of the bundled proposals in progress.
+	#[pallet::storage]
+	#[pallet::getter(fn question_tallies)]
+	pub type QuestionTallies<T: Config<I>, I: 'static = ()> = StorageMap<
+		_,
+		Blake2_128Concat,
+		ProposalId,
+		BoundedVec<VoteRatio, T::MaxQuestions>,
+		ValueQuery,
+	>;
+
+	/// The answers of each voter to the questions of a bundled proposal in progress: the vote
+	/// side and the tallied amount for each question.
+	#[pallet::storage]
+	#[pallet::getter(fn answers)]
+	pub type Answers<T: Config<I>, I: 'static = ()> = StorageDoubleMap<
+		_,
+		Blake2_128Concat,
+		ProposalId,
+		Blake2_128Concat,
+		T::AccountId,
+		BoundedVec<(bool, u128), T::MaxQuestions>,
+		OptionQuery,
+	>;
+
+	/// The running tally of each option of the multi-option proposals in progress.
+	#[pallet::storage]
+	#[pallet::getter(fn option_tallies)]
+	pub type OptionTallies<T: Config<I>, I: 'static = ()> =
+		StorageMap<_, Blake2_128Concat, ProposalId, BoundedVec<u128, T::MaxOptions>, ValueQuery>;
+
+	/// The option chosen by each voter of a multi-option proposal in progress and its tallied
+	/// amount.
+	#[pallet::storage]
+	#[pallet::getter(fn option_choices)]
+	pub type OptionChoices<T: Config<I>, I: 'static = ()> = StorageDoubleMap<
+		_,
+		Blake2_128Concat,
+		ProposalId,
+		Blake2_128Concat,
+		T::AccountId,
+		(u32, u128),
+		OptionQuery,
+	>;
+
+	/// The commitment of each voter of a secret ballot not revealed yet, with the maximum power
+	/// whose collateral it holds. Unrevealed commitments are released once the proposal is
+	/// closed.
+	#[pallet::storage]
+	#[pallet::getter(fn commitments)]
+	pub type Commitments<T: Config<I>, I: 'static = ()> = StorageDoubleMap<
+		_,
+		Blake2_128Concat,
+		ProposalId,
+		Blake2_128Concat,
+		T::AccountId,
+		(T::Hash, PowerOf<T, I>),
+		OptionQuery,
+	>;
+
+	/// The number of commitments of each voter not revealed nor released yet
+	#[pallet::storage]
+	#[pallet::getter(fn pending_commitments)]
+	pub type PendingCommitments<T: Config<I>, I: 'static = ()> =
+		StorageMap<_, Blake2_128Concat, T::AccountId, u32, ValueQuery>;
+
+	/// The merkle tree of the votes cast on each proposal, kept with its archive so that the
+	/// inclusion of a vote in the final tally can be proven after the close
+	#[pallet::storage]
+	#[pallet::getter(fn votes_tree)]
+	pub type VotesTrees<T: Config<I>, I: 'static = ()> =
+		StorageMap<_, Twox64Concat, ProposalId, VotesTree, ValueQuery>;
+
+	/// The leaves of the votes tree of each proposal by index: every vote cast, changed or
+	/// dropped, a dropped vote having a zero power
+	#[pallet::storage]
+	#[pallet::getter(fn vote_log)]
+	pub type VoteLog<T: Config<I>, I: 'static = ()> = StorageDoubleMap<
+		_,
+		Twox64Concat,
+		ProposalId,
+		Twox64Concat,
+		u32,
+		(T::AccountId, VoteInfo<PowerOf<T, I>>),
+		OptionQuery,
+	>;
+
+	/// The merkle tree of the pruned archives of each epoch, keyed by the epoch in which they
+	/// were archived. Kept permanently so history remains provable after the archives are pruned
+	#[pallet::storage]
+	#[pallet::getter(fn archive_tree)]
+	pub type ArchiveTrees<T: Config<I>, I: 'static = ()> =
+		StorageMap<_, Twox64Concat, u32, VotesTree, ValueQuery>;
+
+	/// The leaves of the archive tree of each epoch by index: the outcome and the votes root of
+	/// each pruned archive
+	#[pallet::storage]
+	#[pallet::getter(fn compressed_archives)]
+	pub type CompressedArchives<T: Config<I>, I: 'static = ()> =
+		StorageDoubleMap<_, Twox64Concat, u32, Twox64Concat, u32, CompressedArchive, OptionQuery>;
+
+	/// The participation fees collected by each proposal charging a vote fee, until they are paid
+	/// out.
+	#[pallet::storage]
+	#[pallet::getter(fn fee_pots)]
+	pub type FeePots<T: Config<I>, I: 'static = ()> =
+		StorageMap<_, Blake2_128Concat, ProposalId, FeePot<BalanceOf<T, I>>, OptionQuery>;
+
+	/// The vote slots pre-registered for the members of the account list of a private proposal,
+	/// the ones left without a vote at close being counted as abstentions.
+	#[pallet::storage]
+	pub type ExpectedVoters<T: Config<I>, I: 'static = ()> = StorageDoubleMap<
+		_,
+		Twox64Concat,
+		ProposalId,
+		Blake2_128Concat,
+		T::AccountId,
+		(),
+		OptionQuery,
+	>;
+
+	/// The private proposals each account is allowed to vote on by their account list, so wallets
+	/// can find them without scanning every list.
+	#[pallet::storage]
+	pub type EligibleProposals<T: Config<I>, I: 'static = ()> = StorageDoubleMap<
+		_,
+		Blake2_128Concat,
+		T::AccountId,
+		Twox64Concat,
+		ProposalId,
+		(),
+		OptionQuery,
+	>;
+
+	/// What is left of the amount deposited by the creator of a proposal to reimburse the
+	/// transaction fees of its voters.
+	#[pallet::storage]
+	#[pallet::getter(fn sponsorships)]
+	pub type Sponsorships<T: Config<I>, I: 'static = ()> =
+		StorageMap<_, Blake2_128Concat, ProposalId, BalanceOf<T, I>, OptionQuery>;
+
+	/// The deposit held from the creator of each proposal created with `create_proposal`, until
+	/// the proposal is archived.
+	#[pallet::storage]
+	#[pallet::getter(fn proposal_deposits)]
+	pub type ProposalDeposits<T: Config<I>, I: 'static = ()> =
+		StorageMap<_, Twox64Concat, ProposalId, BalanceOf<T, I>, OptionQuery>;
+
+	/// The amount funded for the turnout lottery of a proposal, until its winners are drawn.
+	#[pallet::storage]
+	#[pallet::getter(fn lottery_pots)]
+	pub type LotteryPots<T: Config<I>, I: 'static = ()> =
+		StorageMap<_, Blake2_128Concat, ProposalId, BalanceOf<T, I>, OptionQuery>;
+
+	/// The prizes won in the turnout lottery of a closed proposal, paid when the collateral of
+	/// the winner is claimed or released.
+	#[pallet::storage]
+	#[pallet::getter(fn lottery_prizes)]
+	pub type LotteryPrizes<T: Config<I>, I: 'static = ()> = StorageDoubleMap<
+		_,
+		Blake2_128Concat,
+		ProposalId,
+		Blake2_128Concat,
+		T::AccountId,
+		BalanceOf<T, I>,
+		OptionQuery,
+	>;
+
+	/// The running statistics of the current epoch
+	#[pallet::storage]
+	pub type EpochStatistics<T: Config<I>, I: 'static = ()> =
+		StorageValue<_, EpochStats, ValueQuery>;
+
+	/// The last epoch each account cast a new vote in, to count the unique voters of an epoch
+	#[pallet::storage]
+	pub type EpochVoters<T: Config<I>, I: 'static = ()> =
+		StorageMap<_, Blake2_128Concat, T::AccountId, u32, OptionQuery>;
+
+	/// The report of each ended epoch, written at the epoch boundary
+	#[pallet::storage]
+	#[pallet::getter(fn epoch_reports)]
+	pub type EpochReports<T: Config<I>, I: 'static = ()> =
+		StorageMap<_, Twox64Concat, u32, EpochReport, OptionQuery>;
+
+	/// The voter registry syncs in progress, by hash of the preimage holding their account list
+	#[pallet::storage]
+	#[pallet::getter(fn voter_syncs)]
+	pub type VoterSyncs<T: Config<I>, I: 'static = ()> =
+		StorageMap<_, Blake2_128Concat, PreimageHash, VoterSync, OptionQuery>;
+
+	/// The proposals starting at each block, recording their electorate and drawing their jury.
+	#[pallet::storage]
+	#[pallet::getter(fn scheduled_starts)]
+	pub type ScheduledStarts<T: Config<I>, I: 'static = ()> = StorageMap<
+		_,
+		Twox64Concat,
+		BlockNumberFor<T>,
+		BoundedVec<ProposalId, T::MaxProposalsPerKind>,
+		ValueQuery,
+	>;
+
+	/// The proposals ending at each block, closed in `on_initialize`. A proposal is removed once
+	/// cancelled, expired or closed.
+	#[pallet::storage]
+	#[pallet::getter(fn scheduled_ends)]
+	pub type ScheduledEnds<T: Config<I>, I: 'static = ()> = StorageMap<
+		_,
+		Twox64Concat,
+		BlockNumberFor<T>,
+		BoundedVec<ProposalId, T::MaxProposalsPerKind>,
+		ValueQuery,
+	>;
+
+	/// The account each voter delegates its votes to. Delegations chain: the delegate of a
+	/// delegate can also vote on behalf of the first delegator, up to `MaxDelegationDepth` hops.
+	#[pallet::storage]
+	#[pallet::getter(fn delegations)]
+	pub type Delegations<T: Config<I>, I: 'static = ()> =
+		StorageMap<_, Blake2_128Concat, T::AccountId, T::AccountId, OptionQuery>;
+
+	/// The delegators of each delegate, to revoke the delegations it received once unregistered.
+	#[pallet::storage]
+	pub type Delegators<T: Config<I>, I: 'static = ()> = StorageDoubleMap<
+		_,
+		Blake2_128Concat,
+		T::AccountId,
+		Blake2_128Concat,
+		T::AccountId,
+		(),
+		OptionQuery,
+	>;
+
+	/// The votes cast by a delegate on behalf of a delegator, with the delegate and the number of
+	/// delegation hops between them. A direct vote of the delegator overrides them.
+	#[pallet::storage]
+	#[pallet::getter(fn delegated_votes)]
+	pub type DelegatedVotes<T: Config<I>, I: 'static = ()> = StorageDoubleMap<
+		_,
+		Twox64Concat,
+		ProposalId,
+		Blake2_128Concat,
+		T::AccountId,
+		(T::AccountId, u32),
+		OptionQuery,
+	>;
+
+	/// The delegated votes cast by each delegate, to drop them once it is unregistered.
+	#[pallet::storage]
+	pub type DelegateCasts<T: Config<I>, I: 'static = ()> = StorageDoubleMap<
+		_,
+		Blake2_128Concat,
+		T::AccountId,
+		Blake2_128Concat,
+		(ProposalId, T::AccountId),
+		(),
+		OptionQuery,
+	>;
+
+	/// The recounts in progress of the tally of the proposals.
+	#[pallet::storage]
+	#[pallet::getter(fn recounts)]
+	pub type Recounts<T: Config<I>, I: 'static = ()> =
+		StorageMap<_, Twox64Concat, ProposalId, Recount, OptionQuery>;
+
+	/// The raw storage key of the last proposal read by the round of tally checkpoints in
+	/// progress.
+	#[pallet::storage]
+	pub type CheckpointCursor<T: Config<I>, I: 'static = ()> =
+		StorageValue<_, BoundedVec<u8, ConstU32<MAX_RECOUNT_CURSOR>>, OptionQuery>;
+
+	/// The raw storage key of the last proposal read by the round of the tally commitment in
+	/// progress, and the commitment to the tallies read so far.
+	#[pallet::storage]
+	pub type CommitmentRound<T: Config<I>, I: 'static = ()> =
+		StorageValue<_, (BoundedVec<u8, ConstU32<MAX_RECOUNT_CURSOR>>, [u8; 32]), OptionQuery>;
+
+	/// The stepped migration of the storage in progress: the version it migrates to and its
+	/// cursor. The hooks of the pallet are suspended until it completes, and the runtime is
+	/// expected to filter the calls of the pallet meanwhile.
+	#[pallet::storage]
+	#[pallet::getter(fn ongoing_migration)]
+	pub type OngoingMigration<T: Config<I>, I: 'static = ()> =
+		StorageValue<_, (u16, MigrationCursor), OptionQuery>;
+
+	/// The raw storage key of the last contribution audited in the audits in progress.
+	#[pallet::storage]
+	#[pallet::getter(fn audit_cursors)]
+	pub type AuditCursors<T: Config<I>, I: 'static = ()> = StorageMap<
+		_,
+		Twox64Concat,
+		ProposalId,
+		BoundedVec<u8, ConstU32<MAX_RECOUNT_CURSOR>>,
+		OptionQuery,
+	>;
+
+	/// The raw storage key of the last vote visited by the drains of the votes of unregistered
+	/// voters, empty until a drain starts. A voter cannot register again until its drain is over.
+	#[pallet::storage]
+	pub type DrainCursors<T: Config<I>, I: 'static = ()> = StorageMap<
+		_,
+		Blake2_128Concat,
+		T::AccountId,
+		BoundedVec<u8, ConstU32<MAX_RECOUNT_CURSOR>>,
+		OptionQuery,
+	>;
+
+	/// The vote cost of the closed proposals whose cost is not simply frozen, until their votes
+	/// are cleaned up, so claims follow the policy of the proposal.
+	#[pallet::storage]
+	#[pallet::getter(fn closed_vote_costs)]
+	pub type ClosedVoteCosts<T: Config<I>, I: 'static = ()> =
+		StorageMap<_, Twox64Concat, ProposalId, VoteCost, OptionQuery>;
+
+	/// The block from which the collateral of the votes can be claimed, for the closed proposals
+	/// with a claim delay, until their archive is pruned.
+	#[pallet::storage]
+	#[pallet::getter(fn claims_open_at)]
+	pub type ClaimsOpenAt<T: Config<I>, I: 'static = ()> =
+		StorageMap<_, Twox64Concat, ProposalId, BlockNumberFor<T>, OptionQuery>;
+
+	/// The voters allowed to vote on a private proposal, as a bitmap over `VoterIndices`: the
+	/// voter of index `i` is allowed if bit `i % 8` of byte `i / 8` is set. It takes precedence
+	/// over the account list and is kept with the archive of the proposal.
+	#[pallet::storage]
+	#[pallet::getter(fn eligibility_bitmaps)]
+	pub type EligibilityBitmaps<T: Config<I>, I: 'static = ()> = StorageMap<
+		_,
+		Twox64Concat,
+		ProposalId,
+		BoundedVec<u8, T::MaxEligibilityBitmapLen>,
+		OptionQuery,
+	>;
+
+	/// The assets proposal creators can pick to be voted with
+	#[pallet::storage]
+	#[pallet::getter(fn allowed_assets)]
+	pub type AllowedAssets<T: Config<I>, I: 'static = ()> =
+		StorageMap<_, Twox64Concat, AssetIdOf<T, I>, (), OptionQuery>;
+
+	/// The asset each proposal is voted with, the native balance when unset. It is kept with the
+	/// archive of the proposal, the collateral of its votes being escrowed until released.
+	#[pallet::storage]
+	#[pallet::getter(fn proposal_assets)]
+	pub type ProposalAssets<T: Config<I>, I: 'static = ()> =
+		StorageMap<_, Twox64Concat, ProposalId, AssetIdOf<T, I>, OptionQuery>;
+
+	/// The call each proposal dispatches from `EnactmentOrigin` if it is approved, until its
+	/// outcome can no longer be disputed or overridden, or it is cancelled. A call too large to be
+	/// inlined is a requested preimage of `Preimages`.
+	#[pallet::storage]
+	#[pallet::getter(fn proposal_calls)]
+	pub type ProposalCalls<T: Config<I>, I: 'static = ()> =
+		StorageMap<_, Twox64Concat, ProposalId, BoundedCallOf<T, I>, OptionQuery>;
+
+	/// The number of retries scheduled so far for the call of each proposal whose enactment
+	/// failed, until it is enacted or its retries ran out.
+	#[pallet::storage]
+	#[pallet::getter(fn enactment_retries)]
+	pub type EnactmentRetries<T: Config<I>, I: 'static = ()> =
+		StorageMap<_, Twox64Concat, ProposalId, u32, ValueQuery>;
+
+	/// The role required to create proposals of a kind with `create_proposal`. Any registered
+	/// voter can create the kinds without one.
+	#[pallet::storage]
+	#[pallet::getter(fn kind_roles)]
+	pub type KindRoles<T: Config<I>, I: 'static = ()> =
+		StorageMap<_, Twox64Concat, ProposalKind, RoleId, OptionQuery>;
+
+	/// The roles granted to proposal creators
+	#[pallet::storage]
+	#[pallet::getter(fn creator_roles)]
+	pub type CreatorRoles<T: Config<I>, I: 'static = ()> =
+		StorageDoubleMap<_, Blake2_128Concat, T::AccountId, Twox64Concat, RoleId, ()>;
+
+	/// The number of voters of a proposal in progress holding each role of its role quorums
+	#[pallet::storage]
+	#[pallet::getter(fn role_turnouts)]
+	pub type RoleTurnouts<T: Config<I>, I: 'static = ()> =
+		StorageDoubleMap<_, Twox64Concat, ProposalId, Twox64Concat, RoleId, u32, ValueQuery>;
+
+	/// The policy reviewed by each sunset review
+	#[pallet::storage]
+	#[pallet::getter(fn sunset_reviews)]
+	pub type SunsetReviews<T: Config<I>, I: 'static = ()> =
+		StorageMap<_, Twox64Concat, ProposalId, ProposalId, OptionQuery>;
+
+	/// The closed proposals within their dispute period, kept to schedule a revote.
+	#[pallet::storage]
+	pub type DisputableProposals<T: Config<I>, I: 'static = ()> =
+		StorageMap<_, Twox64Concat, ProposalId, ProposalData<T, I>, OptionQuery>;
+
+	/// The disputed outcomes pending resolution.
+	#[pallet::storage]
+	#[pallet::getter(fn disputes)]
+	pub type Disputes<T: Config<I>, I: 'static = ()> = StorageMap<
+		_,
+		Twox64Concat,
+		ProposalId,
+		Dispute<T::AccountId, BalanceOf<T, I>>,
+		OptionQuery,
+	>;
+
+	/// The jurors drawn among the registered voters for a sortition proposal, the only accounts
+	/// allowed to vote on it.
+	#[pallet::storage]
+	#[pallet::getter(fn juries)]
+	pub type Juries<T: Config<I>, I: 'static = ()> = StorageMap<
+		_,
+		Blake2_128Concat,
+		ProposalId,
+		BoundedVec<T::AccountId, T::MaxJurySize>,
+		OptionQuery,
+	>;
+
+	/// The tally of each question of a closed bundled proposal and whether it was approved, kept
+	/// as long as the proposal archive.
+	#[pallet::storage]
+	#[pallet::getter(fn question_outcomes)]
+	pub type QuestionOutcomes<T: Config<I>, I: 'static = ()> = StorageMap<
+		_,
+		Blake2_128Concat,
+		ProposalId,
+		BoundedVec<(VoteRatio, bool), T::MaxQuestions>,
+		OptionQuery,
+	>;
+
+	/// The tally of each option of a closed multi-option proposal and the winning option, kept as
+	/// long as the proposal archive.
+	#[pallet::storage]
+	#[pallet::getter(fn option_outcomes)]
+	pub type OptionOutcomes<T: Config<I>, I: 'static = ()> = StorageMap<
+		_,
+		Blake2_128Concat,
+		ProposalId,
+		(BoundedVec<u128, T::MaxOptions>, Option<u32>),
+		OptionQuery,
+	>;
+
+	// Pallets use events to inform users when important changes are made.
+	// https://docs.substrate.io/main-docs/build/events-errors/
+	// New events must also be added, in the same position, to `client_types::Event`.
+	#[pallet::event]
+	#[pallet::generate_deposit(pub(super) fn deposit_event)]
+	pub enum Event<T: Config<I>, I: 'static = ()> {
+		/// A new voter is registered
+		NewVoterRegistered { who: T::AccountId },
+		/// A voter is unregistered
+		VoterUnregistered { who: T::AccountId },
+		/// A deputy registrar was appointed, or its limit updated
+		DeputyAppointed { who: T::AccountId, limit: u32 },
+		/// A deputy registrar was revoked
+		DeputyRevoked { who: T::AccountId },
+		/// The registration, votes, delegations and held collateral of a voter were moved to
+		/// another account
+		VoterMigrated { old: T::AccountId, new: T::AccountId, entries: u32 },
+		/// A voter took a snapshot of its balance to back its future votes
+		BalanceSnapshotTaken { who: T::AccountId, balance: BalanceOf<T, I> },
+		/// A voter renewed its registration
+		VoterRenewed { who: T::AccountId, expires_at: Option<BlockNumberFor<T>> },
+		/// A voter is unregistered because its registration expired
+		VoterExpired { who: T::AccountId },
+		/// A new proposal is created
+		ProposalCreated {
+			proposal_id: ProposalId,
+			offchain_data: BoundedVec<u8, T::ProposalOffchainDataLimit>,
+			creator: T::AccountId,
+			kind: ProposalKind,
+			account_list: Option<BoundedVec<T::AccountId, T::AccountSizeLimit>>,
+			start_block: BlockNumberFor<T>,
+			end_block: BlockNumberFor<T>,
+			settings: ProposalSettings,
+		},
+		/// A public proposal is submitted to the launch queue
+		ProposalQueued { proposal_id: ProposalId, creator: T::AccountId },
+		/// A queued public proposal is promoted to a voting proposal
+		ProposalLaunched { proposal_id: ProposalId },
+		/// A staged proposal is seconded by a registered voter
+		ProposalSeconded { proposal_id: ProposalId, who: T::AccountId, seconds: u32 },
+		/// A scheduled proposal reached its start block and is open to votes
+		ProposalStarted { proposal_id: ProposalId },
+		/// A proposal did not gather enough seconds before its start and is closed
+		ProposalExpired { proposal_id: ProposalId },
+		/// A proposal that did not start yet is cancelled
+		ProposalCancelled { proposal_id: ProposalId, reason: CancellationReason },
+		/// A proposal is closed and the vote is completed
+		VoteCompleted { proposal_id: ProposalId, ratio: (u128, u128), approved: bool },
+		/// A new account list is set before a proposal has started
+		AccountListSet {
+			proposal_id: ProposalId,
+			account_list: Option<BoundedVec<T::AccountId, T::AccountSizeLimit>>,
+		},
+		/// The eligibility bitmap of a private proposal is set before it has started, `allowed`
+		/// being the number of voters it allows
+		EligibilityBitmapSet { proposal_id: ProposalId, allowed: u32 },
+		/// A vote slot was pre-registered for each of the `count` members of the account list of
+		/// a private proposal
+		ExpectedVotersRegistered { proposal_id: ProposalId, count: u32 },
+		/// An asset was added to or removed from the allow-list of the voting assets
+		VotingAssetUpdated { asset: AssetIdOf<T, I>, allowed: bool },
+		/// The asset a proposal is voted with was set, `None` for the native balance
+		ProposalAssetSet { proposal_id: ProposalId, asset: Option<AssetIdOf<T, I>> },
+		/// Creating proposals of a kind was restricted to a role, or opened to all registered
+		/// voters with `None`
+		KindRoleSet { kind: ProposalKind, role: Option<RoleId> },
+		/// A role was granted to a proposal creator
+		CreatorRoleGranted { who: T::AccountId, role: RoleId },
+		/// A role was revoked from a proposal creator
+		CreatorRoleRevoked { who: T::AccountId, role: RoleId },
+		/// The account list of a proposal that did not start yet is edited, only the accounts
+		/// actually added or removed are reported
+		AccountListUpdated {
+			proposal_id: ProposalId,
+			added: BoundedVec<T::AccountId, T::AccountSizeLimit>,
+			removed: BoundedVec<T::AccountId, T::AccountSizeLimit>,
+		},
+		/// A new vote was added to an in progress proposal
+		VoteAdded { proposal_id: ProposalId, voter: T::AccountId, aye: bool, power: PowerOf<T, I> },
+		/// A new vote was added to an in progress proposal with anonymous events
+		AnonymousVoteAdded { proposal_id: ProposalId, aye: bool, power: PowerOf<T, I> },
+		/// The tally of a proposal overflowed and was saturated, this should never happen with a
+		/// sane `MaxPower`
+		TallySaturated { proposal_id: ProposalId },
+		/// The turnout of a proposal exceeded its turnout cap, every contribution was scaled by
+		/// `scale`
+		TurnoutScaled { proposal_id: ProposalId, scale: Perbill },
+		/// The policy adopted by a proposal will be reviewed by a sunset review
+		SunsetReviewScheduled {
+			policy_id: ProposalId,
+			review_id: ProposalId,
+			start_block: BlockNumberFor<T>,
+		},
+		/// A sunset review was not approved, the policy it reviewed is repealed
+		PolicyRepealed { policy_id: ProposalId, review_id: ProposalId },
+		/// A vote was removed from an in progress proposal
+		VoteDropped { proposal_id: ProposalId, voter: T::AccountId },
+		/// A voter answered the questions of a bundled proposal
+		QuestionsVoted {
+			proposal_id: ProposalId,
+			voter: T::AccountId,
+			answers: BoundedVec<(bool, PowerOf<T, I>), T::MaxQuestions>,
+		},
+		/// The questions of a bundled proposal with anonymous events were answered
+		AnonymousQuestionsVoted {
+			proposal_id: ProposalId,
+			answers: BoundedVec<(bool, PowerOf<T, I>), T::MaxQuestions>,
+		},
+		/// The options of a proposal were set, none for an aye/nay proposal
+		OptionsSet { proposal_id: ProposalId, options: BoundedVec<OptionLabel, T::MaxOptions> },
+		/// A voter chose an option of a multi-option proposal
+		OptionVoted {
+			proposal_id: ProposalId,
+			voter: T::AccountId,
+			option: u32,
+			power: PowerOf<T, I>,
+		},
+		/// An option of a multi-option proposal with anonymous events was chosen
+		AnonymousOptionVoted { proposal_id: ProposalId, option: u32, power: PowerOf<T, I> },
+		/// A voter committed to a vote on a secret ballot, or replaced its commitment
+		VoteCommitted { proposal_id: ProposalId, voter: T::AccountId },
+		/// A vote committed on a secret ballot was revealed and tallied
+		VoteRevealed {
+			proposal_id: ProposalId,
+			voter: T::AccountId,
+			aye: bool,
+			power: PowerOf<T, I>,
+		},
+		/// The options of a multi-option proposal were tallied when closing it, `winner` is the
+		/// option leading alone if any
+		OptionsCompleted {
+			proposal_id: ProposalId,
+			tallies: BoundedVec<u128, T::MaxOptions>,
+			winner: Option<u32>,
+		},
+		/// The jury of a sortition proposal was drawn
+		JuryDrawn { proposal_id: ProposalId, jurors: u32 },
+		/// The questions of a bundled proposal were tallied when closing it
+		QuestionsCompleted {
+			proposal_id: ProposalId,
+			outcomes: BoundedVec<(VoteRatio, bool), T::MaxQuestions>,
+		},
+		/// A new vote was added to an in progress proposal
+		BalanceClaimed { who: T::AccountId, amount: BalanceOf<T, I> },
+		/// The collateral of a voter was released and accounted to a beneficiary
+		BalanceClaimedTo { who: T::AccountId, beneficiary: T::AccountId, amount: BalanceOf<T, I> },
+		/// The collateral of a voter was released by another account after the grace period
+		BalanceClaimedFor { who: T::AccountId, caller: T::AccountId, amount: BalanceOf<T, I> },
+		/// Some voters of a closed proposal had their collateral released during this block
+		FreezesReleased { proposal_id: ProposalId, released: u32 },
+		/// All the voters of a closed proposal had their collateral released
+		FreezesReleaseCompleted { proposal_id: ProposalId },
+		/// Participation fees collected by a proposal are paid out of its pot
+		FeesPaid { proposal_id: ProposalId, to: T::AccountId, amount: BalanceOf<T, I> },
+		/// The creator of a proposal deposited an amount to reimburse the fees of its voters
+		VotesSponsored { proposal_id: ProposalId, amount: BalanceOf<T, I> },
+		/// A new voter of a sponsored proposal is reimbursed its transaction fee
+		VoteRefunded { proposal_id: ProposalId, voter: T::AccountId, amount: BalanceOf<T, I> },
+		/// What is left of a sponsorship is returned to the creator once the proposal is archived
+		SponsorshipReturned { proposal_id: ProposalId, amount: BalanceOf<T, I> },
+		/// The turnout lottery of a proposal is funded by the creator or the treasury account
+		LotteryFunded { proposal_id: ProposalId, funder: T::AccountId, amount: BalanceOf<T, I> },
+		/// The winners of the turnout lottery of a closed proposal are drawn, each winning `prize`
+		LotteryDrawn {
+			proposal_id: ProposalId,
+			winners: BoundedVec<T::AccountId, T::MaxLotteryWinners>,
+			prize: BalanceOf<T, I>,
+		},
+		/// The prize of a winner of the turnout lottery of a proposal is paid to `who`, the
+		/// beneficiary of its claim
+		LotteryPrizePaid { proposal_id: ProposalId, who: T::AccountId, amount: BalanceOf<T, I> },
+		/// A voter delegated its votes
+		Delegated { who: T::AccountId, to: T::AccountId },
+		/// A voter stopped delegating its votes
+		Undelegated { who: T::AccountId },
+		/// The delegation of a voter was revoked since its delegate was unregistered
+		DelegationRevoked { who: T::AccountId, delegate: T::AccountId },
+		/// A delegate voted on behalf of a delegator, `hops` delegations away
+		DelegatedVoteCast {
+			proposal_id: ProposalId,
+			delegator: T::AccountId,
+			delegate: T::AccountId,
+			hops: u32,
+		},
+		/// The tally of a proposal was re-derived from its votes and replaced the running tally,
+		/// the differences being the recounted minus the previous amounts
+		TallyRecounted { proposal_id: ProposalId, ayes_diff: i128, total_diff: i128 },
+		/// An audit found a discrepancy in the vote of `voter`
+		AuditFinding {
+			proposal_id: ProposalId,
+			voter: T::AccountId,
+			issue: AuditIssue<BalanceOf<T, I>>,
+		},
+		/// All the votes of a proposal were audited
+		ProposalAudited { proposal_id: ProposalId },
+		/// A random sample of the votes of a proposal was audited at close, `findings` of them
+		/// being reported by an `AuditFinding`
+		SampleAudited { proposal_id: ProposalId, sampled: u32, findings: u32 },
+		/// The running tally of an open proposal, emitted every `TallyCheckpointPeriod` blocks
+		TallyCheckpoint { proposal_id: ProposalId, tally: VoteRatio },
+		/// The outcome of a closed proposal was disputed
+		ProposalDisputed { proposal_id: ProposalId, challenger: T::AccountId },
+		/// A dispute was resolved and its bond settled
+		DisputeResolved {
+			proposal_id: ProposalId,
+			resolution: DisputeResolution<BlockNumberFor<T>>,
+		},
+		/// A new organisation is created
+		OrgCreated { org: OrgId, admin: T::AccountId },
+		/// The admin of an organisation changed
+		OrgAdminSet { org: OrgId, admin: T::AccountId },
+		/// A registered voter joined an organisation
+		OrgMemberAdded { org: OrgId, who: T::AccountId },
+		/// A member left an organisation
+		OrgMemberRemoved { org: OrgId, who: T::AccountId },
+		/// The parameters of an organisation changed
+		OrgParametersSet { org: OrgId, parameters: OrgParameters },
+		/// A proposal was created in an organisation, `index` being its ID within it
+		OrgProposalCreated { org: OrgId, index: u32, proposal_id: ProposalId },
+		/// The outcome of a closed proposal was overridden by the origin mapped to `by`
+		OutcomeOverridden { proposal_id: ProposalId, outcome: OutcomeOverride, by: T::AccountId },
+		/// An epoch ended and its report was written
+		EpochReported { epoch: u32, report: EpochReport },
+		/// A sync of the voter registry from the `accounts` of a preimage started
+		VoterSyncStarted { preimage_hash: PreimageHash, accounts: u32 },
+		/// A sync of the voter registry completed, `skipped` accounts could not be registered
+		VotersSynced { preimage_hash: PreimageHash, added: u32, removed: u32, skipped: u32 },
+		/// The archive of a proposal was pruned and folded into the archive tree of the epoch
+		/// `era`
+		ArchiveCompressed { proposal_id: ProposalId, era: u32, root: [u8; 32] },
+		/// The call a proposal enacts if it is approved was set, identified by the hash of its
+		/// encoding, or removed with `None`
+		ProposalCallSet { proposal_id: ProposalId, call_hash: Option<[u8; 32]> },
+		/// The call of an approved proposal was dispatched from `EnactmentOrigin`, or failed to be
+		/// once its retries ran out
+		ProposalEnacted { proposal_id: ProposalId, result: DispatchResult },
+		/// The enactment of the call of a proposal failed with `error`, its retry number `retry`
+		/// was scheduled
+		EnactmentRetryScheduled { proposal_id: ProposalId, retry: u32, error: DispatchError },
+		/// The deposit of a proposal was returned to its creator once the proposal was archived
+		ProposalDepositReturned { proposal_id: ProposalId, amount: BalanceOf<T, I> },
+		/// The deposit of a proposal cancelled as spam by Root was lost to the treasury account
+		ProposalDepositSlashed { proposal_id: ProposalId, amount: BalanceOf<T, I> },
+		/// An account registered itself as a voter, placing `bond` on hold
+		VoterSelfRegistered { who: T::AccountId, bond: BalanceOf<T, I> },
+		/// The registration bond of a voter was returned once its registration was removed
+		RegistrationBondReturned { who: T::AccountId, amount: BalanceOf<T, I> },
+		/// Root overrode the limits of the proposals
+		ProposalLimitsSet { limits: ProposalLimits },
+	}
+
+	#[pallet::hooks]
+	impl<T: Config<I>, I: 'static> Hooks<BlockNumberFor<T>> for Pallet<T, I> {
+		fn on_initialize(n: BlockNumberFor<T>) -> Weight {
+			// The storage is read at the current layout, it waits for the stepped migration
+			if Pallet::<T, I>::migrating() {
+				return T::DbWeight::get().reads(1)
+			}
+			let mut weight = Pallet::<T, I>::close_ended_proposals(n)
+				.saturating_add(Pallet::<T, I>::start_scheduled_proposals(n))
+				.saturating_add(Pallet::<T, I>::checkpoint_tallies(n))
+				.saturating_add(Pallet::<T, I>::commit_tallies(n));
+			let epoch_duration: BlockNumberFor<T> = T::EpochDuration::get().into();
+			if (n % epoch_duration).is_zero() {
+				weight = weight.saturating_add(Pallet::<T, I>::report_epoch());
+			}
+			let launch_period: BlockNumberFor<T> = T::LaunchPeriod::get().into();
+			if !launch_period.is_zero() && (n % launch_period).is_zero() {
+				weight.saturating_add(Pallet::<T, I>::launch_next_proposal(n))
+			} else {
+				weight
+			}
+		}
+
+		fn on_idle(_n: BlockNumberFor<T>, remaining_weight: Weight) -> Weight {
+			Pallet::<T, I>::process_cleanup_queue(remaining_weight)
+		}
+
+		fn offchain_worker(n: BlockNumberFor<T>) {
+			Pallet::<T, I>::notify_webhooks(n);
+		}
+
+		fn integrity_test() {
+			assert!(
+				T::ProposalMinimumDuration::get() <= T::ProposalMaximumDuration::get(),
+				"`ProposalMinimumDuration` must not be greater than `ProposalMaximumDuration`"
+			);
+			assert!(
+				T::ProposalMaximumDuration::get() > 0,
+				"`ProposalMaximumDuration` must not be zero"
+			);
+			assert!(
+				T::ProposalOffchainDataLimit::get() > 0,
+				"`ProposalOffchainDataLimit` must not be zero"
+			);
+			assert!(T::AccountSizeLimit::get() > 0, "`AccountSizeLimit` must not be zero");
+			assert!(T::EpochDuration::get() > 0, "`EpochDuration` must not be zero");
+			assert!(T::MaxReleasesPerBlock::get() > 0, "`MaxReleasesPerBlock` must not be zero");
+			assert!(T::MaxProposalsPerKind::get() > 0, "`MaxProposalsPerKind` must not be zero");
+			assert!(T::EmergencyMaxDuration::get() > 0, "`EmergencyMaxDuration` must not be zero");
+			assert!(
+				T::DisputePeriod::get() <= T::ArchiveRetention::get(),
+				"`DisputePeriod` must not be greater than `ArchiveRetention`"
+			);
+			assert!(
+				T::OverridePeriod::get() <= T::ArchiveRetention::get(),
+				"`OverridePeriod` must not be greater than `ArchiveRetention`"
+			);
+			assert!(T::EnactmentRetryPeriod::get() > 0, "`EnactmentRetryPeriod` must not be zero");
+			assert!(
+				Pallet::<T, I>::enactment_delay().saturating_add(
+					T::MaxEnactmentRetries::get().saturating_mul(T::EnactmentRetryPeriod::get())
+				) <= T::ArchiveRetention::get(),
+				"The last enactment retry must not be later than `ArchiveRetention`"
+			);
+			assert!(
+				T::ConvictionPeriod::get().saturating_mul(MAX_CONVICTION_PERIODS) <=
+					T::ArchiveRetention::get(),
+				"The longest conviction lock must not be greater than `ArchiveRetention`"
+			);
+			assert!(
+				T::MaxPower::get().checked_mul(&T::MaxPower::get()).is_some(),
+				"The quadratic amount of `MaxPower` must fit in the balance type"
+			);
+			assert!(
+				tallied_amount(T::MaxPower::get(), Percent::zero()).is_some(),
+				"The quadratic amount of `MaxPower` must fit in a `u128`"
+			);
+
+			let max_block_length = *T::BlockLength::get().max.get(DispatchClass::Normal);
+			assert!(
+				ProposalData::<T, I>::max_encoded_len() <= max_block_length as usize,
+				"A proposal with `AccountSizeLimit` accounts must fit in a block"
+			);
+		}
+	}
+
+	// Errors inform users that something went wrong.
+	#[pallet::error]
+	pub enum Error<T, I = ()> {
+		/// Origin has no permission to operate
+		OriginNoPermission,
+		/// A user is trying to vote, but is not registered in the `RegisteredVoters` storage.
+		VoterNotRegistered,
+		/// A proposal does not exist in storage
+		ProposalDoesNotExist,
+		/// A proposal has already started
+		ProposalHasAlreadyStarted,
+		/// A proposal has already ended
+		ProposalHasAlreadyEnded,
+		/// A proposal has not started yet
+		ProposalHasNotStartedYet,
+		/// A proposal has not ended yet
+		ProposalHasNotEndedYet,
+		/// A proposal cannot start in the past
+		ProposalCannotStartInThePast,
+		/// A proposal cannot end before starting
+		ProposalCannotFinishBeforeStarting,
+		/// The proposal duration is too long
+		ProposalDurationIsTooLong,
+		/// The proposal duration is too short
+		ProposalDurationIsTooShort,
+		/// The delay for a proposal to start is too far away
+		ProposalStartIsTooFarAway,
+		/// A claim is possible only for closed proposal
+		ProposalNotClosed,
+		/// The voter has insufficient free funds to vote with power
+		InsufficientBalance,
+		/// The voter has insufficient voice credits left for the current epoch
+		InsufficientCredits,
+		/// The new vote is already the active vote
+		IdenticVote,
+		/// Proposal claim does not exist
+		ClaimDoesNotExist,
+		/// The power step of a proposal cannot be zero
+		InvalidPowerStep,
+		/// The vote power is not a multiple of the proposal power step
+		PowerNotMultipleOfStep,
+		/// Only private proposals can commit their account list to a members root
+		MembersRootOnlyForPrivate,
+		/// Only private proposals can have an eligibility bitmap
+		EligibilityBitmapOnlyForPrivate,
+		/// Only private proposals with an account list can pre-register their expected voters
+		ExpectedVotersOnlyForPrivate,
+		/// The proposal account list is committed, a membership proof must be provided
+		MembershipProofRequired,
+		/// The account is not attested as a unique person by the personhood provider
+		PersonhoodNotAttested,
+		/// The vote power is greater than `MaxPower`
+		PowerTooHigh,
+		/// There are already `MaxProposalsPerKind` proposals of this kind
+		TooManyProposals,
+		/// The voter registration is expired, the voter must register again
+		RegistrationExpired,
+		/// The account balance is below `MinVoterBalance`
+		BalanceBelowMinimum,
+		/// The voter already seconded this proposal
+		AlreadySeconded,
+		/// The proposal did not gather enough seconds to open for voting
+		ProposalNotSeconded,
+		/// The launch queue is disabled, `LaunchPeriod` is zero
+		LaunchQueueDisabled,
+		/// Emergency proposals can only be created with `create_emergency_proposal`
+		EmergencyProposalNotAllowed,
+		/// The `KindHandler` does not allow creating proposals of this kind
+		ProposalKindNotAllowed,
+		/// The proposal is gated by NFT ownership and the voter holds no membership pass
+		NftPassRequired,
+		/// The proposal relies on NFT ownership but no `NftCollections` is configured
+		NoNftCollections,
+		/// The account list of the proposal cannot hold more accounts
+		AccountListFull,
+		/// The claim grace period of the proposal has not elapsed yet
+		ClaimGracePeriodNotElapsed,
+		/// A proposal cannot bundle more than `MaxQuestions` questions
+		TooManyQuestions,
+		/// Bundled proposals do not support the whale cap, the turnout cap nor the hidden tally
+		InvalidBundleSettings,
+		/// The answers do not match the questions of the proposal
+		QuestionsMismatch,
+		/// A multi-option proposal needs at least two options, and does not support bundled
+		/// questions, the whale cap, the turnout cap, the hidden tally, sunset reviews, lotteries
+		/// nor fees paid out to the winning side
+		InvalidOptions,
+		/// The vote does not match the options of the proposal
+		OptionsMismatch,
+		/// The jury size must be between one and `MaxJurySize`
+		InvalidJurySize,
+		/// The jury of the proposal has not been drawn yet
+		JuryNotDrawn,
+		/// Only the jurors drawn for the proposal can vote
+		NotInJury,
+		/// The held balance is not backed by a snapshot older than `BalanceAge`
+		BalanceTooRecent,
+		/// The account list can only be copied from a proposal of the same kind
+		AccountListKindMismatch,
+		/// The vote fee must be at least the existential deposit, and winning side payouts are
+		/// not supported by bundled proposals
+		InvalidVoteFee,
+		/// The delegation would loop back to the delegator
+		DelegationCycle,
+		/// The voter does not delegate its votes
+		NotDelegating,
+		/// The caller is not a delegate of the delegator within `MaxDelegationDepth` hops
+		NotDelegate,
+		/// The delegator voted directly, its delegates cannot override the vote
+		DirectVoteTakesPrecedence,
+		/// The proposal is not closed or its dispute period is over
+		DisputePeriodOver,
+		/// The outcome of the proposal is already disputed
+		AlreadyDisputed,
+		/// The outcome of the proposal is not disputed
+		NotDisputed,
+		/// Shared budget votes are paid with voice credits, they have no hold to audit
+		SharedBudgetNotFrozen,
+		/// The organisation does not exist
+		OrgDoesNotExist,
+		/// The voter is not a member of the organisation
+		NotOrgMember,
+		/// The voter is already a member of the organisation
+		AlreadyOrgMember,
+		/// The durations of the organisation are out of the pallet bounds or out of order
+		InvalidOrgParameters,
+		/// A paid vote cost cannot be combined with a shared budget nor a secret ballot
+		InvalidVoteCost,
+		/// The votes of the proposal paid their cost, there is nothing to claim
+		ClaimDisabled,
+		/// The votes of the proposal paid their cost, they have no hold to audit
+		CostNotFrozen,
+		/// The lottery winners must be between one and `MaxLotteryWinners`, without bundled
+		/// questions
+		InvalidLottery,
+		/// The proposal has no turnout lottery
+		LotteryNotEnabled,
+		/// The ballots of the proposal cannot be changed once cast
+		BallotImmutable,
+		/// The votes already cast on the proposal cannot be changed anymore
+		EditWindowClosed,
+		/// The outcome can only be overridden less than `OverridePeriod` blocks after closing
+		OverridePeriodOver,
+		/// The outcome of the proposal was already overridden
+		AlreadyOverridden,
+		/// Only the outcome of a completed proposal can be overridden
+		OutcomeNotOverridable,
+		/// A sync of the voter registry from this preimage is already in progress
+		VoterSyncInProgress,
+		/// The preimage is not available
+		PreimageNotFound,
+		/// The preimage is not an encoded list of accounts to add or remove
+		InvalidVoterSync,
+		/// The asset is not in the allow-list of the voting assets
+		AssetNotAllowed,
+		/// A proposal spending voice credits cannot be voted with an asset
+		AssetWithSharedBudget,
+		/// The kind of proposal is restricted to a role the creator was not granted
+		MissingCreatorRole,
+		/// The metadata of the proposal exceeds `ProposalOffchainDataLimit`
+		OffchainDataTooLong,
+		/// The account is or was registered, or has delegators
+		AccountInUse,
+		/// The voter has more than `MaxMigratedEntries` votes, votes cast as a delegate and
+		/// delegators to move
+		TooManyEntriesToMigrate,
+		/// The turnout cap is zero or below the turnout quorum
+		InvalidTurnoutCap,
+		/// A role quorum requires no voter or its role has another quorum
+		InvalidRoleQuorum,
+		/// The sunset period of a proposal is zero
+		InvalidSunsetPeriod,
+		/// There are already `MaxDeputies` deputy registrars
+		TooManyDeputies,
+		/// The account is not a deputy registrar
+		NotDeputy,
+		/// The deputy registrar reached its limit for the current period
+		DeputyLimitReached,
+		/// The proposal is a secret ballot, its votes are committed with `commit_vote` and
+		/// revealed with `reveal_vote`
+		SecretBallot,
+		/// The proposal is not a secret ballot
+		NotSecretBallot,
+		/// The reveal period of a secret ballot is zero
+		InvalidRevealPeriod,
+		/// A commitment must hold the collateral of a non-zero power
+		InvalidCommitment,
+		/// The voter has no commitment to reveal on the proposal
+		CommitmentNotFound,
+		/// The revealed vote does not match the commitment or exceeds its maximum power
+		InvalidReveal,
+		/// The voter has commitments to reveal or to release, which cannot be moved
+		CommitmentsPending,
+		/// A vote with conviction needs a proposal holding its cost as collateral without burning
+		/// any of it
+		ConvictionNotSupported,
+		/// The collateral of the vote is still locked by its conviction
+		ConvictionLocked,
+		/// The claim delay is zero or greater than the archive retention
+		InvalidClaimDelay,
+		/// The claim delay of the proposal is not over
+		ClaimNotYetAvailable,
+		/// The encoded call is larger than `MaxCallSize`
+		CallTooLarge,
+		/// The weight of the call is greater than `MaxEnactmentWeight`
+		CallTooHeavy,
+		/// The call is not inlined nor a noted preimage, or does not decode
+		CallUnavailable,
+		/// The voter cap is zero or set on a secret ballot
+		InvalidMaxVoters,
+		/// The proposal reached its voter cap
+		VoterCapReached,
+		/// The account is already a registered voter
+		AlreadyRegistered,
+		/// The voter did not register itself with `self_register`
+		NotSelfRegistered,
+		/// The account has no identity verified by the `IdentityVerifier`
+		IdentityNotVerified,
+		/// The votes of the account are still being dropped since it was unregistered
+		VotesDraining,
+		/// The enactment of the call of the proposal is not being retried
+		NoEnactmentRetry,
+		/// The durations of the limits are zero or out of order
+		InvalidProposalLimits,
+	}
+
+	// Dispatchable functions allows users to interact with the pallet and invoke state changes.
+	// These functions materialize as "extrinsics", which are often compared to transactions.
+	// Dispatchable functions must be annotated with a weight and must return a DispatchResult.
+	#[pallet::call]
+	impl<T: Config<I>, I: 'static> Pallet<T, I> {
+		/// Registers `who` as a voter. Deputy registrars can register voters up to their limit.
+		#[pallet::call_index(0)]
+		#[pallet::weight(T::WeightInfo::register_voter())]
+		pub fn register_voter(origin: OriginFor<T>, who: T::AccountId) -> DispatchResult {
+			if let Some(deputy) = ensure_signed_or_root(origin)? {
+				Pallet::<T, I>::use_deputy(&deputy, DispatchError::BadOrigin)?;
+			}
+			if Pallet::<T, I>::register(&who)? {
+				Self::deposit_event(Event::<T, I>::NewVoterRegistered { who });
+			}
+			Ok(())
+		}
+
+		/// Unregisters `who`, by itself, by root or by a deputy registrar up to its limit.
+		#[pallet::call_index(1)]
+		#[pallet::weight(T::WeightInfo::unregister_voter(1, 1))]
+		pub fn unregister_voter(
+			origin: OriginFor<T>,
+			who: T::AccountId,
+		) -> DispatchResultWithPostInfo {
+			match ensure_signed_or_root(origin)? {
+				Some(caller) if caller != who =>
+					Pallet::<T, I>::use_deputy(&caller, Error::<T, I>::OriginNoPermission.into())?,
+				_ => (),
+			}
+
+			let (has_votes, is_delegate) = Pallet::<T, I>::deregister(&who);
+			Self::deposit_event(Event::<T, I>::VoterUnregistered { who });
+			Ok(Some(T::WeightInfo::unregister_voter(has_votes.into(), is_delegate.into())).into())
+		}
+
+		/// Registers the caller as a voter, placing `RegistrationBond` on hold. The bond is
+		/// returned when the registration is removed, whether with `self_unregister`, by a
+		/// registrar or once expired.
+		#[pallet::call_index(51)]
+		#[pallet::weight(T::WeightInfo::self_register())]
+		pub fn self_register(origin: OriginFor<T>) -> DispatchResult {
+			use frame_support::traits::fungible::MutateHold;
+
+			let who = ensure_signed(origin)?;
+			ensure!(
+				!RegisteredVoters::<T, I>::contains_key(&who),
+				Error::<T, I>::AlreadyRegistered
+			);
+
+			// The minimum balance is checked before the bond leaves the free balance
+			Pallet::<T, I>::register(&who)?;
+			let bond = T::RegistrationBond::get();
+			if !bond.is_zero() {
+				T::NativeBalance::hold(&HoldReason::RegistrationBond.into(), &who, bond)
+					.map_err(|_| Error::<T, I>::InsufficientBalance)?;
+			}
+			RegistrationBonds::<T, I>::insert(&who, bond);
+			Self::deposit_event(Event::<T, I>::VoterSelfRegistered { who, bond });
+			Ok(())
+		}
+
+		/// Unregisters the caller, registered with `self_register`, and returns its bond.
+		#[pallet::call_index(52)]
+		#[pallet::weight(T::WeightInfo::self_unregister(1, 1))]
+		pub fn self_unregister(origin: OriginFor<T>) -> DispatchResultWithPostInfo {
+			let who = ensure_signed(origin)?;
+			ensure!(
+				RegistrationBonds::<T, I>::contains_key(&who),
+				Error::<T, I>::NotSelfRegistered
+			);
+
+			let (has_votes, is_delegate) = Pallet::<T, I>::deregister(&who);
+			Self::deposit_event(Event::<T, I>::VoterUnregistered { who });
+			Ok(Some(T::WeightInfo::self_unregister(has_votes.into(), is_delegate.into())).into())
+		}
+
+		/// Moves the registration, votes, delegations and held collateral of `old` to `new`,
+		/// which must never have been registered, e.g. when a voter rotates its keys. The held
+		/// collateral is transferred along and held on `new`, which must exist. Account lists,
+		/// juries, organisation memberships, creator roles and seconds are not moved.
+		#[pallet::call_index(42)]
+		#[pallet::weight(T::WeightInfo::migrate_voter(T::MaxMigratedEntries::get()))]
+		pub fn migrate_voter(
+			origin: OriginFor<T>,
+			old: T::AccountId,
+			new: T::AccountId,
+		) -> DispatchResultWithPostInfo {
+			let maybe_caller = ensure_signed_or_root(origin)?;
+			ensure!(
+				(maybe_caller.is_none() || maybe_caller.unwrap() == old),
+				Error::<T, I>::OriginNoPermission
+			);
+
+			let entries = Pallet::<T, I>::migrate(&old, &new)?;
+			Self::deposit_event(Event::<T, I>::VoterMigrated { old, new, entries });
+			Ok(Some(T::WeightInfo::migrate_voter(entries)).into())
+		}
+
+		/// Appoints `who` as a deputy registrar, allowed to register voters and unregister them
+		/// with `register_voter` and `unregister_voter`, up to `limit` voters per `DeputyPeriod`.
+		/// Appointing a deputy again updates its limit.
+		#[pallet::call_index(45)]
+		#[pallet::weight(T::WeightInfo::appoint_deputy())]
+		pub fn appoint_deputy(
+			origin: OriginFor<T>,
+			who: T::AccountId,
+			limit: u32,
+		) -> DispatchResult {
+			T::RegistrarOrigin::ensure_origin(origin)?;
+
+			if let Some(deputy) = Deputies::<T, I>::get(&who) {
+				Deputies::<T, I>::insert(&who, DeputyInfo { limit, ..deputy });
+			} else {
+				let count = DeputiesCount::<T, I>::get();
+				ensure!(count < T::MaxDeputies::get(), Error::<T, I>::TooManyDeputies);
+				let period_start = Pallet::<T, I>::get_current_block_number();
+				Deputies::<T, I>::insert(&who, DeputyInfo { limit, period_start, used: 0 });
+				DeputiesCount::<T, I>::put(count.saturating_add(1));
+			}
+			Self::deposit_event(Event::<T, I>::DeputyAppointed { who, limit });
+			Ok(())
+		}
+
+		/// Revokes the deputy registrar `who`. The voters it registered stay registered.
+		#[pallet::call_index(46)]
+		#[pallet::weight(T::WeightInfo::revoke_deputy())]
+		pub fn revoke_deputy(origin: OriginFor<T>, who: T::AccountId) -> DispatchResult {
+			T::RegistrarOrigin::ensure_origin(origin)?;
+
+			Deputies::<T, I>::take(&who).ok_or(Error::<T, I>::NotDeputy)?;
+			DeputiesCount::<T, I>::mutate(|count| *count = count.saturating_sub(1));
+			Self::deposit_event(Event::<T, I>::DeputyRevoked { who });
+			Ok(())
+		}
+
+		/// Syncs the voter registry with the SCALE encoded `Vec<(AccountId, bool)>` noted as the
+		/// preimage of `preimage_hash`, registering the accounts flagged `true` and unregistering
+		/// the others. The accounts are applied lazily by the cleanup queue, the preimage being
+		/// requested until the sync completes.
+		#[pallet::call_index(36)]
+		#[pallet::weight(T::WeightInfo::sync_voters())]
+		pub fn sync_voters(origin: OriginFor<T>, preimage_hash: PreimageHash) -> DispatchResult {
+			T::RegistrarOrigin::ensure_origin(origin)?;
+			ensure!(
+				!VoterSyncs::<T, I>::contains_key(preimage_hash),
+				Error::<T, I>::VoterSyncInProgress
+			);
+			let len = T::Preimages::len(&preimage_hash).ok_or(Error::<T, I>::PreimageNotFound)?;
+			let data = T::Preimages::fetch(&preimage_hash, Some(len))
+				.map_err(|_| Error::<T, I>::PreimageNotFound)?;
+			let mut input = &data[..];
+			let accounts = codec::Compact::<u32>::decode(&mut input)
+				.map_err(|_| Error::<T, I>::InvalidVoterSync)?
+				.0;
+			let cursor = len.saturating_sub(input.len() as u32);
+
+			T::Preimages::request(&preimage_hash);
+			VoterSyncs::<T, I>::insert(
+				preimage_hash,
+				VoterSync { len, cursor, remaining: accounts, ..Default::default() },
+			);
+			Pallet::<T, I>::enqueue_cleanup(
+				CleanupLane::Immediate,
+				Pallet::<T, I>::get_current_block_number(),
+				CleanupTask::SyncVoters { preimage_hash },
+			);
+
+			Self::deposit_event(Event::<T, I>::VoterSyncStarted { preimage_hash, accounts });
+			Ok(())
+		}
+
+		#[pallet::call_index(2)]
+		#[pallet::weight(T::WeightInfo::create_proposal(
+			account_list.as_ref().map_or(0, |list| list.len() as u32)
+		))]
+		pub fn create_proposal(
+			origin: OriginFor<T>,
+			offchain_data: BoundedVec<u8, T::ProposalOffchainDataLimit>,
+			kind: ProposalKind,
+			account_list: Option<BoundedVec<T::AccountId, T::AccountSizeLimit>>,
+			start_block: BlockNumberFor<T>,
+			end_block: BlockNumberFor<T>,
+			settings: ProposalSettings,
+		) -> DispatchResult {
+			let caller = ensure_signed(origin)?;
+			ensure!(
+				RegisteredVoters::<T, I>::get(caller.clone()).is_some(),
+				Error::<T, I>::VoterNotRegistered
+			);
+			if let Some(role) = KindRoles::<T, I>::get(&kind) {
+				ensure!(
+					CreatorRoles::<T, I>::contains_key(&caller, role),
+					Error::<T, I>::MissingCreatorRole
+				);
+			}
+
+			let proposal = ProposalData::<T, I>::new(
+				offchain_data,
+				kind,
+				caller.clone(),
+				account_list,
+				start_block,
+				end_block,
+				settings,
+			);
+			Pallet::<T, I>::do_create_proposal(proposal)?;
+			let proposal_id = NextProposalId::<T, I>::get().saturating_sub(1);
+			Pallet::<T, I>::hold_deposit(proposal_id, &caller)
+		}
+
+		#[pallet::call_index(3)]
+		#[pallet::weight(T::WeightInfo::cancel_proposal(T::AccountSizeLimit::get()))]
+		pub fn cancel_proposal(
+			origin: OriginFor<T>,
+			proposal_id: ProposalId,
+			reason: CancellationReason,
+		) -> DispatchResult {
+			let caller = ensure_signed_or_root(origin)?;
+
+			let current_block = Pallet::<T, I>::get_current_block_number();
+			let proposal =
+				Proposals::<T, I>::get(proposal_id).ok_or(Error::<T, I>::ProposalDoesNotExist)?;
+
+			let by_root = caller.is_none();
+			ensure!(
+				(by_root || proposal.is_creator(&caller.unwrap())),
+				Error::<T, I>::OriginNoPermission
+			);
+			ensure!(
+				!proposal.has_started(&current_block),
+				Error::<T, I>::ProposalHasAlreadyStarted
+			);
+
+			Proposals::<T, I>::remove(proposal_id);
+			SunsetReviews::<T, I>::remove(proposal_id);
+			Pallet::<T, I>::drop_call(proposal_id);
+			// Only Root cancelling a proposal as spam forfeits its deposit
+			if by_root && reason == CancellationReason::Spam {
+				Pallet::<T, I>::slash_deposit(proposal_id, &proposal.creator);
+			}
+			Pallet::<T, I>::unindex_proposal(proposal_id, &proposal);
+			Pallet::<T, I>::archive(
+				proposal_id,
+				proposal.creator,
+				ProposalOutcome::Cancelled { reason },
+			);
+			Self::deposit_event(Event::<T, I>::ProposalCancelled { proposal_id, reason });
+			Ok(())
+		}
+
+		#[pallet::call_index(4)]
+		#[pallet::weight(Pallet::<T, I>::close_weight())]
+		pub fn close_proposal(
+			origin: OriginFor<T>,
+			proposal_id: ProposalId,
+		) -> DispatchResultWithPostInfo {
+			let closer = ensure_signed(origin)?;
+
+			Pallet::<T, I>::do_close_proposal(proposal_id, Some(closer))?;
+			Ok(Pays::No.into())
+		}
+
+		#[pallet::call_index(5)]
+		#[pallet::weight(T::WeightInfo::set_account_list(T::AccountSizeLimit::get()))]
+		pub fn set_account_list(
+			origin: OriginFor<T>,
+			proposal_id: ProposalId,
+			account_list: Option<BoundedVec<T::AccountId, T::AccountSizeLimit>>,
+		) -> DispatchResult {
+			let caller = ensure_signed_or_root(origin)?;
+
+			let current_block = Pallet::<T, I>::get_current_block_number();
+			let proposal =
+				Proposals::<T, I>::get(proposal_id).ok_or(Error::<T, I>::ProposalDoesNotExist)?;
+
+			ensure!(
+				(caller.is_none() || proposal.is_creator(&caller.unwrap())),
+				Error::<T, I>::OriginNoPermission
+			);
+			ensure!(
+				!proposal.has_started(&current_block),
+				Error::<T, I>::ProposalHasAlreadyStarted
+			);
+
+			Pallet::<T, I>::index_eligibility(proposal_id, &proposal, false);
+			let proposal = ProposalData { account_list: account_list.clone(), ..proposal };
+			Pallet::<T, I>::index_eligibility(proposal_id, &proposal, true);
+			Proposals::<T, I>::insert(proposal_id, proposal);
+			Self::deposit_event(Event::<T, I>::AccountListSet { proposal_id, account_list });
+			Ok(())
+		}
+
+		/// Sets the voters allowed to vote on a private proposal as a bitmap over their
+		/// `VoterIndices`, a compact alternative to the account list for large electorates.
+		#[pallet::call_index(27)]
+		#[pallet::weight(T::WeightInfo::set_eligibility_bitmap())]
+		pub fn set_eligibility_bitmap(
+			origin: OriginFor<T>,
+			proposal_id: ProposalId,
+			bitmap: Option<BoundedVec<u8, T::MaxEligibilityBitmapLen>>,
+		) -> DispatchResult {
+			let caller = ensure_signed_or_root(origin)?;
+
+			let current_block = Pallet::<T, I>::get_current_block_number();
+			let proposal =
+				Proposals::<T, I>::get(proposal_id).ok_or(Error::<T, I>::ProposalDoesNotExist)?;
+
+			ensure!(
+				(caller.is_none() || proposal.is_creator(&caller.unwrap())),
+				Error::<T, I>::OriginNoPermission
+			);
+			ensure!(
+				!proposal.has_started(&current_block),
+				Error::<T, I>::ProposalHasAlreadyStarted
+			);
+			ensure!(
+				proposal.kind == ProposalKind::Private,
+				Error::<T, I>::EligibilityBitmapOnlyForPrivate
+			);
+
+			let allowed = bitmap.as_ref().map_or(0, |bitmap| bitmap_len(bitmap));
+			EligibilityBitmaps::<T, I>::set(proposal_id, bitmap);
+			Self::deposit_event(Event::<T, I>::EligibilityBitmapSet { proposal_id, allowed });
+			Ok(())
+		}
+
+		/// Pre-registers a vote slot for each member of the account list of a private proposal
+		/// that has not started yet, replacing the slots registered before. The slots of the
+		/// members still allowed who did not vote are counted as abstentions in the archive of
+		/// the proposal when it is closed.
+		#[pallet::call_index(49)]
+		#[pallet::weight(T::WeightInfo::register_expected_voters(T::AccountSizeLimit::get()))]
+		pub fn register_expected_voters(
+			origin: OriginFor<T>,
+			proposal_id: ProposalId,
+		) -> DispatchResult {
+			let caller = ensure_signed(origin)?;
+
+			let current_block = Pallet::<T, I>::get_current_block_number();
+			let proposal =
+				Proposals::<T, I>::get(proposal_id).ok_or(Error::<T, I>::ProposalDoesNotExist)?;
+
+			ensure!(proposal.is_creator(&caller), Error::<T, I>::OriginNoPermission);
+			ensure!(
+				!proposal.has_started(&current_block),
+				Error::<T, I>::ProposalHasAlreadyStarted
+			);
+			let (ProposalKind::Private, Some(account_list)) =
+				(&proposal.kind, &proposal.account_list)
+			else {
+				return Err(Error::<T, I>::ExpectedVotersOnlyForPrivate.into())
+			};
+
+			let _ =
+				ExpectedVoters::<T, I>::clear_prefix(proposal_id, T::AccountSizeLimit::get(), None);
+			for who in account_list {
+				ExpectedVoters::<T, I>::insert(proposal_id, who, ());
+			}
+			let count = account_list.len() as u32;
+			Self::deposit_event(Event::<T, I>::ExpectedVotersRegistered { proposal_id, count });
+			Ok(())
+		}
+
+		/// Adds an asset to the allow-list of the voting assets, or removes it. Proposals already
+		/// set to a removed asset keep it.
+		#[pallet::call_index(38)]
+		#[pallet::weight(T::WeightInfo::set_voting_asset())]
+		pub fn set_voting_asset(
+			origin: OriginFor<T>,
+			asset: AssetIdOf<T, I>,
+			allowed: bool,
+		) -> DispatchResult {
+			T::AssetOrigin::ensure_origin(origin)?;
+
+			if allowed {
+				AllowedAssets::<T, I>::insert(asset, ());
+			} else {
+				AllowedAssets::<T, I>::remove(asset);
+			}
+			Self::deposit_event(Event::<T, I>::VotingAssetUpdated { asset, allowed });
+			Ok(())
+		}
+
+		/// Sets the allowed asset a proposal is voted with before it starts, `None` for the
+		/// native balance. The collateral of its votes is escrowed in the asset instead of held.
+		#[pallet::call_index(39)]
+		#[pallet::weight(T::WeightInfo::set_proposal_asset())]
+		pub fn set_proposal_asset(
+			origin: OriginFor<T>,
+			proposal_id: ProposalId,
+			asset: Option<AssetIdOf<T, I>>,
+		) -> DispatchResult {
+			let caller = ensure_signed_or_root(origin)?;
+
+			let current_block = Pallet::<T, I>::get_current_block_number();
+			let proposal =
+				Proposals::<T, I>::get(proposal_id).ok_or(Error::<T, I>::ProposalDoesNotExist)?;
+
+			ensure!(
+				(caller.is_none() || proposal.is_creator(&caller.unwrap())),
+				Error::<T, I>::OriginNoPermission
+			);
+			ensure!(
+				!proposal.has_started(&current_block),
+				Error::<T, I>::ProposalHasAlreadyStarted
+			);
+			if let Some(asset) = asset {
+				ensure!(AllowedAssets::<T, I>::contains_key(asset), Error::<T, I>::AssetNotAllowed);
+				ensure!(!proposal.settings.shared_budget, Error::<T, I>::AssetWithSharedBudget);
+			}
+
+			ProposalAssets::<T, I>::set(proposal_id, asset);
+			Self::deposit_event(Event::<T, I>::ProposalAssetSet { proposal_id, asset });
+			Ok(())
+		}
+
+		/// Sets the call a proposal dispatches from `EnactmentOrigin` when it is closed approved,
+		/// before it starts, or removes it with `None`. A call too large to be inlined must be
+		/// noted as a preimage of `Preimages` beforehand, it is requested until it is dropped.
+		#[pallet::call_index(50)]
+		#[pallet::weight(T::WeightInfo::set_proposal_call())]
+		pub fn set_proposal_call(
+			origin: OriginFor<T>,
+			proposal_id: ProposalId,
+			call: Option<BoundedCallOf<T, I>>,
+		) -> DispatchResult {
+			let caller = ensure_signed_or_root(origin)?;
+
+			let current_block = Pallet::<T, I>::get_current_block_number();
+			let proposal =
+				Proposals::<T, I>::get(proposal_id).ok_or(Error::<T, I>::ProposalDoesNotExist)?;
+
+			ensure!(
+				(caller.is_none() || proposal.is_creator(&caller.unwrap())),
+				Error::<T, I>::OriginNoPermission
+			);
+			ensure!(
+				!proposal.has_started(&current_block),
+				Error::<T, I>::ProposalHasAlreadyStarted
+			);
+
+			if let Some(call) = &call {
+				ensure!(
+					call.len().map_or(false, |len| len <= T::MaxCallSize::get()),
+					Error::<T, I>::CallTooLarge
+				);
+				let (decoded, _) =
+					T::Preimages::peek(call).map_err(|_| Error::<T, I>::CallUnavailable)?;
+				ensure!(
+					decoded.get_dispatch_info().weight.all_lte(T::MaxEnactmentWeight::get()),
+					Error::<T, I>::CallTooHeavy
+				);
+				T::Preimages::hold(call);
+			}
+
+			// The previous call is dropped once the new one holds its preimage, they may share it
+			Pallet::<T, I>::drop_call(proposal_id);
+			let call_hash = call.map(|call| {
+				let hash = call.hash();
+				ProposalCalls::<T, I>::insert(proposal_id, call);
+				hash.into()
+			});
+			Self::deposit_event(Event::<T, I>::ProposalCallSet { proposal_id, call_hash });
+			Ok(())
+		}
+
+		/// Retries to enact the call of a closed proposal whose enactment failed. Scheduled by the
+		/// pallet with the `Scheduler`, from Root.
+		#[pallet::call_index(53)]
+		#[pallet::weight(Pallet::<T, I>::enactment_weight())]
+		pub fn retry_enactment(origin: OriginFor<T>, proposal_id: ProposalId) -> DispatchResult {
+			ensure_root(origin)?;
+			ensure!(
+				EnactmentRetries::<T, I>::contains_key(proposal_id),
+				Error::<T, I>::NoEnactmentRetry
+			);
+
+			Pallet::<T, I>::enact(proposal_id);
+			Ok(())
+		}
+
+		/// Sets the options of a proposal before it starts, making it a multi-option proposal
+		/// voted with `vote_option`, or an aye/nay proposal again without options.
+		#[pallet::call_index(43)]
+		#[pallet::weight(T::WeightInfo::set_options())]
+		pub fn set_options(
+			origin: OriginFor<T>,
+			proposal_id: ProposalId,
+			options: BoundedVec<OptionLabel, T::MaxOptions>,
+		) -> DispatchResult {
+			let caller = ensure_signed_or_root(origin)?;
+
+			let current_block = Pallet::<T, I>::get_current_block_number();
+			let proposal =
+				Proposals::<T, I>::get(proposal_id).ok_or(Error::<T, I>::ProposalDoesNotExist)?;
+
+			ensure!(
+				(caller.is_none() || proposal.is_creator(&caller.unwrap())),
+				Error::<T, I>::OriginNoPermission
+			);
+			ensure!(
+				!proposal.has_started(&current_block),
+				Error::<T, I>::ProposalHasAlreadyStarted
+			);
+			ensure!(
+				options.is_empty() || (options.len() >= 2 && proposal.settings.supports_options()),
+				Error::<T, I>::InvalidOptions
+			);
+
+			let proposal = ProposalData { options: options.clone(), ..proposal };
+			Proposals::<T, I>::insert(proposal_id, proposal);
+			Self::deposit_event(Event::<T, I>::OptionsSet { proposal_id, options });
+			Ok(())
+		}
+
+		/// Restricts creating proposals of `kind` with `create_proposal` to the creators granted
+		/// `role`, or opens it to all registered voters with `None`. Existing proposals are kept.
+		#[pallet::call_index(40)]
+		#[pallet::weight(T::WeightInfo::set_kind_role())]
+		pub fn set_kind_role(
+			origin: OriginFor<T>,
+			kind: ProposalKind,
+			role: Option<RoleId>,
+		) -> DispatchResult {
+			T::RoleOrigin::ensure_origin(origin)?;
+
+			KindRoles::<T, I>::set(&kind, role);
+			Self::deposit_event(Event::<T, I>::KindRoleSet { kind, role });
+			Ok(())
+		}
+
+		/// Grants `role` to `who`, or revokes it.
+		#[pallet::call_index(41)]
+		#[pallet::weight(T::WeightInfo::set_creator_role())]
+		pub fn set_creator_role(
+			origin: OriginFor<T>,
+			who: T::AccountId,
+			role: RoleId,
+			granted: bool,
+		) -> DispatchResult {
+			T::RoleOrigin::ensure_origin(origin)?;
+
+			if granted {
+				CreatorRoles::<T, I>::insert(&who, role, ());
+				Self::deposit_event(Event::<T, I>::CreatorRoleGranted { who, role });
+			} else {
+				CreatorRoles::<T, I>::remove(&who, role);
+				Self::deposit_event(Event::<T, I>::CreatorRoleRevoked { who, role });
+			}
+			Ok(())
+		}
+
+		#[pallet::call_index(6)]
+		#[pallet::weight(
+			T::WeightInfo::vote(T::AccountSizeLimit::get())
+				.saturating_add(Pallet::<T, I>::close_weight())
+		)]
+		pub fn vote(
+			origin: OriginFor<T>,
+			proposal_id: ProposalId,
+			aye: bool,
+			power: PowerOf<T, I>,
+			conviction: Conviction,
+		) -> DispatchResultWithPostInfo {
+			let caller = ensure_signed(origin)?;
+			Pallet::<T, I>::do_vote(caller, proposal_id, aye, power, conviction, None)
+		}
+
+		#[pallet::call_index(8)]
+		#[pallet::weight(T::WeightInfo::vote_with_proof())]
+		pub fn vote_with_proof(
+			origin: OriginFor<T>,
+			proposal_id: ProposalId,
+			aye: bool,
+			power: PowerOf<T, I>,
+			proof: MembershipProof,
+		) -> DispatchResultWithPostInfo {
+			let caller = ensure_signed(origin)?;
+			Pallet::<T, I>::do_vote(caller, proposal_id, aye, power, Conviction::None, Some(proof))
+		}
+
+		/// Delegates the votes of the caller to `to`, who can then vote on its behalf, as can the
+		/// delegates of `to` up to `MaxDelegationDepth` hops. Delegating again replaces the
+		/// delegate.
+		#[pallet::call_index(21)]
+		#[pallet::weight(T::WeightInfo::delegate(T::MaxDelegationDepth::get()))]
+		pub fn delegate(origin: OriginFor<T>, to: T::AccountId) -> DispatchResult {
+			let caller = ensure_signed(origin)?;
+			ensure!(
+				RegisteredVoters::<T, I>::contains_key(&caller),
+				Error::<T, I>::VoterNotRegistered
+			);
+
+			// Only the hops a delegated vote can follow need to be free of the caller
+			let mut delegate = to.clone();
+			for _ in 0..T::MaxDelegationDepth::get() {
+				ensure!(delegate != caller, Error::<T, I>::DelegationCycle);
+				match Delegations::<T, I>::get(&delegate) {
+					Some(next) => delegate = next,
+					None => break,
+				}
+			}
+
+			if let Some(prev) = Delegations::<T, I>::get(&caller) {
+				Delegators::<T, I>::remove(prev, &caller);
+			}
+			Delegations::<T, I>::insert(&caller, &to);
+			Delegators::<T, I>::insert(&to, &caller, ());
+			Self::deposit_event(Event::<T, I>::Delegated { who: caller, to });
+			Ok(())
+		}
+
+		#[pallet::call_index(22)]
+		#[pallet::weight(T::WeightInfo::undelegate())]
+		pub fn undelegate(origin: OriginFor<T>) -> DispatchResult {
+			let caller = ensure_signed(origin)?;
+			let delegate =
+				Delegations::<T, I>::take(&caller).ok_or(Error::<T, I>::NotDelegating)?;
+			Delegators::<T, I>::remove(delegate, &caller);
+			Self::deposit_event(Event::<T, I>::Undelegated { who: caller });
+			Ok(())
+		}
+
+		/// Votes on behalf of `delegator`, whose delegation chain must reach the caller. The vote
+		/// holds the collateral of the delegator and its tallied amount is attenuated by
+		/// `DelegationAttenuation` at each hop. Voting again replaces the delegated vote, zero
+		/// power removes it.
+		#[pallet::call_index(23)]
+		#[pallet::weight(T::WeightInfo::vote_as_delegate(
+			T::AccountSizeLimit::get(),
+			T::MaxDelegationDepth::get()
+		))]
+		pub fn vote_as_delegate(
+			origin: OriginFor<T>,
+			delegator: T::AccountId,
+			proposal_id: ProposalId,
+			aye: bool,
+			power: PowerOf<T, I>,
+		) -> DispatchResult {
+			let caller = ensure_signed(origin)?;
+			Pallet::<T, I>::do_vote_as_delegate(caller, delegator, proposal_id, aye, power)
+		}
+
+		/// Re-derives the tally of a proposal from its votes, recounting at most `limit` votes
+		/// per call. Once all the votes are recounted, the result replaces the running tally.
+		/// The recount starts over if the tally moves in between two calls.
+		#[pallet::call_index(24)]
+		#[pallet::weight(T::WeightInfo::recount(*limit))]
+		pub fn recount(
+			origin: OriginFor<T>,
+			proposal_id: ProposalId,
+			limit: u32,
+		) -> DispatchResult {
+			ensure_signed(origin)?;
+			Pallet::<T, I>::do_recount(proposal_id, limit)
+		}
+
+		/// Checks that the hold of each voter of a proposal in progress covers the quadratic cost
+		/// of its vote, at most `limit` votes per call, each discrepancy being reported by an
+		/// `AuditFinding`. The audit resumes where the previous call stopped.
+		#[pallet::call_index(34)]
+		#[pallet::weight(T::WeightInfo::audit_proposal(*limit))]
+		pub fn audit_proposal(
+			origin: OriginFor<T>,
+			proposal_id: ProposalId,
+			limit: u32,
+		) -> DispatchResult {
+			T::AdminOrigin::ensure_origin(origin)?;
+			Pallet::<T, I>::do_audit(proposal_id, limit)
+		}
+
+		/// Disputes the outcome of a proposal closed less than `DisputePeriod` blocks ago, bonding
+		/// `DisputeBond`. The outcome is withheld until the `DisputeResolutionOrigin` resolves
+		/// the dispute.
+		#[pallet::call_index(25)]
+		#[pallet::weight(T::WeightInfo::dispute())]
+		pub fn dispute(origin: OriginFor<T>, proposal_id: ProposalId) -> DispatchResult {
+			let challenger = ensure_signed(origin)?;
+
+			let archive = ArchivedProposals::<T, I>::get(proposal_id)
+				.ok_or(Error::<T, I>::DisputePeriodOver)?;
+			let period_end = archive.archived_at.saturating_add(T::DisputePeriod::get().into());
+			ensure!(
+				DisputableProposals::<T, I>::contains_key(proposal_id) &&
+					Pallet::<T, I>::get_current_block_number() < period_end,
+				Error::<T, I>::DisputePeriodOver
+			);
+			ensure!(!Disputes::<T, I>::contains_key(proposal_id), Error::<T, I>::AlreadyDisputed);
+
+			let bond = T::DisputeBond::get();
+			<T::NativeBalance as fungible::Mutate<T::AccountId>>::transfer(
+				&challenger,
+				&Pallet::<T, I>::dispute_account(proposal_id),
+				bond,
+				Preservation::Preserve,
+			)?;
+			Disputes::<T, I>::insert(proposal_id, Dispute { challenger: challenger.clone(), bond });
+			ProposalStatuses::<T, I>::insert(proposal_id, ProposalStatus::Suspended);
+
+			Self::deposit_event(Event::<T, I>::ProposalDisputed { proposal_id, challenger });
+			Ok(())
+		}
+
+		/// Resolves the dispute of an outcome: confirming it forfeits the bond to the treasury
+		/// account, overturning it or scheduling a revote returns the bond to the challenger. The
+		/// call of the proposal withheld by the dispute is enacted with the resolved outcome, or
+		/// carried over to the revote.
+		#[pallet::call_index(26)]
+		#[pallet::weight(
+			T::WeightInfo::resolve_dispute().saturating_add(Pallet::<T, I>::enactment_weight())
+		)]
+		pub fn resolve_dispute(
+			origin: OriginFor<T>,
+			proposal_id: ProposalId,
+			resolution: DisputeResolution<BlockNumberFor<T>>,
+		) -> DispatchResult {
+			T::DisputeResolutionOrigin::ensure_origin(origin)?;
+
+			let dispute = Disputes::<T, I>::take(proposal_id).ok_or(Error::<T, I>::NotDisputed)?;
+			let closed =
+				DisputableProposals::<T, I>::take(proposal_id).ok_or(Error::<T, I>::NotDisputed)?;
+			let mut archive =
+				ArchivedProposals::<T, I>::get(proposal_id).ok_or(Error::<T, I>::NotDisputed)?;
+
+			let beneficiary = match &resolution {
+				DisputeResolution::Confirm => T::TreasuryAccount::get(),
+				DisputeResolution::Overturn => {
+					if let ProposalOutcome::Completed { ratio, approved } = archive.outcome {
+						archive.outcome = ProposalOutcome::Completed { ratio, approved: !approved };
+					}
+					dispute.challenger
+				},
+				DisputeResolution::Revote { start_block, end_block } => {
+					let revote = Pallet::<T, I>::schedule_revote(
+						proposal_id,
+						closed,
+						*start_block,
+						*end_block,
+					)?;
+					archive.outcome = ProposalOutcome::Revoted { revote };
+					dispute.challenger
+				},
+			};
+			<T::NativeBalance as fungible::Mutate<T::AccountId>>::transfer(
+				&Pallet::<T, I>::dispute_account(proposal_id),
+				&beneficiary,
+				dispute.bond,
+				Preservation::Expendable,
+			)?;
+			ProposalStatuses::<T, I>::insert(proposal_id, ProposalStatus::from(archive.outcome));
+			let enactment_at =
+				archive.archived_at.saturating_add(Pallet::<T, I>::enactment_delay().into());
+			ArchivedProposals::<T, I>::insert(proposal_id, archive);
+			// Once the periods are over, the queued enactment already skipped the disputed call
+			if enactment_at <= Pallet::<T, I>::get_current_block_number() {
+				Pallet::<T, I>::enact(proposal_id);
+			}
+
+			Self::deposit_event(Event::<T, I>::DisputeResolved { proposal_id, resolution });
+			Ok(())
+		}
+
+		/// Ratifies or vetoes the outcome of a proposal closed less than `OverridePeriod` blocks
+		/// ago. The override and the account of the origin are recorded in the archive, the tally
+		/// is kept as counted. A disputed outcome must be resolved first.
+		#[pallet::call_index(37)]
+		#[pallet::weight(T::WeightInfo::override_outcome())]
+		pub fn override_outcome(
+			origin: OriginFor<T>,
+			proposal_id: ProposalId,
+			outcome: OutcomeOverride,
+		) -> DispatchResult {
+			let by = T::OverrideOrigin::ensure_origin(origin)?;
+
+			ensure!(!Disputes::<T, I>::contains_key(proposal_id), Error::<T, I>::AlreadyDisputed);
+			ArchivedProposals::<T, I>::try_mutate(proposal_id, |maybe_archive| {
+				let archive = maybe_archive.as_mut().ok_or(Error::<T, I>::OverridePeriodOver)?;
+				let period_end =
+					archive.archived_at.saturating_add(T::OverridePeriod::get().into());
+				ensure!(
+					Pallet::<T, I>::get_current_block_number() < period_end,
+					Error::<T, I>::OverridePeriodOver
+				);
+				ensure!(archive.overridden.is_none(), Error::<T, I>::AlreadyOverridden);
+				let ProposalOutcome::Completed { ratio, .. } = archive.outcome else {
+					return Err(Error::<T, I>::OutcomeNotOverridable)
+				};
+				let approved = outcome == OutcomeOverride::Ratify;
+				archive.outcome = ProposalOutcome::Completed { ratio, approved };
+				archive.overridden = Some((outcome, by.clone()));
+				Ok::<_, Error<T, I>>(())
+			})?;
+
+			Self::deposit_event(Event::<T, I>::OutcomeOverridden { proposal_id, outcome, by });
+			Ok(())
+		}
+
+		/// Votes on each question of a bundled proposal. A single hold backs all the answers,
+		/// sized by the most powerful one. Zero power on every question removes the vote.
+		#[pallet::call_index(17)]
+		#[pallet::weight(T::WeightInfo::vote_questions(T::AccountSizeLimit::get()))]
+		pub fn vote_questions(
+			origin: OriginFor<T>,
+			proposal_id: ProposalId,
+			answers: BoundedVec<(bool, PowerOf<T, I>), T::MaxQuestions>,
+			proof: Option<MembershipProof>,
+		) -> DispatchResultWithPostInfo {
+			let caller = ensure_signed(origin)?;
+			Pallet::<T, I>::do_vote_questions(caller, proposal_id, answers, proof)
+		}
+
+		/// Votes for one option of a multi-option proposal with `power`, replacing the previous
+		/// choice of the voter. Zero power removes the vote.
+		#[pallet::call_index(44)]
+		#[pallet::weight(T::WeightInfo::vote_option(T::AccountSizeLimit::get()))]
+		pub fn vote_option(
+			origin: OriginFor<T>,
+			proposal_id: ProposalId,
+			option: u32,
+			power: PowerOf<T, I>,
+			proof: Option<MembershipProof>,
+		) -> DispatchResultWithPostInfo {
+			let caller = ensure_signed(origin)?;
+			Pallet::<T, I>::do_vote_option(caller, proposal_id, option, power, proof)
+		}
+
+		/// Commits to a vote on a secret ballot with the `commitment` computed by
+		/// `vote_commitment`, holding the collateral of `max_power`. Committing again replaces
+		/// the commitment. The vote is revealed with `reveal_vote` once the voting window ended.
+		#[pallet::call_index(47)]
+		#[pallet::weight(T::WeightInfo::commit_vote(T::AccountSizeLimit::get()))]
+		pub fn commit_vote(
+			origin: OriginFor<T>,
+			proposal_id: ProposalId,
+			commitment: T::Hash,
+			max_power: PowerOf<T, I>,
+			proof: Option<MembershipProof>,
+		) -> DispatchResultWithPostInfo {
+			let caller = ensure_signed(origin)?;
+			Pallet::<T, I>::do_commit_vote(caller, proposal_id, commitment, max_power, proof)
+		}
+
+		/// Reveals the vote committed on a secret ballot during its reveal window and tallies it.
+		/// The collateral held beyond `power` is released.
+		#[pallet::call_index(48)]
+		#[pallet::weight(T::WeightInfo::reveal_vote())]
+		pub fn reveal_vote(
+			origin: OriginFor<T>,
+			proposal_id: ProposalId,
+			aye: bool,
+			power: PowerOf<T, I>,
+			salt: [u8; 32],
+		) -> DispatchResult {
+			let caller = ensure_signed(origin)?;
+			Pallet::<T, I>::do_reveal_vote(caller, proposal_id, aye, power, salt)
+		}
+
+		#[pallet::call_index(7)]
+		#[pallet::weight(T::WeightInfo::claim())]
+		pub fn claim(origin: OriginFor<T>, proposal_id: ProposalId) -> DispatchResult {
+			let caller = ensure_signed(origin)?;
+			let amount = Pallet::<T, I>::do_claim(&caller, proposal_id, &caller)?;
+			Self::deposit_event(Event::BalanceClaimed { who: caller, amount });
+
+			Ok(())
+		}
+
+		/// Same as `claim` but the released amount is accounted to `beneficiary`, e.g. for
+		/// custodial setups or keys rotated between vote and claim.
+		#[pallet::call_index(10)]
+		#[pallet::weight(T::WeightInfo::claim_to())]
+		pub fn claim_to(
+			origin: OriginFor<T>,
+			proposal_id: ProposalId,
+			beneficiary: T::AccountId,
+		) -> DispatchResult {
+			let caller = ensure_signed(origin)?;
+			let amount = Pallet::<T, I>::do_claim(&caller, proposal_id, &beneficiary)?;
+			Self::deposit_event(Event::BalanceClaimedTo { who: caller, beneficiary, amount });
+
+			Ok(())
+		}
+
+		/// Releases the collateral of a `voter` on a closed proposal once `ClaimGracePeriod` has
+		/// elapsed, so funds of inattentive voters do not stay held. Anyone can call it, the
+		/// caller gets nothing.
+		#[pallet::call_index(16)]
+		#[pallet::weight(T::WeightInfo::claim_for())]
+		pub fn claim_for(
+			origin: OriginFor<T>,
+			voter: T::AccountId,
+			proposal_id: ProposalId,
+		) -> DispatchResult {
+			let caller = ensure_signed(origin)?;
+			// A pruned archive is past the retention period
+			if let Some(archive) = ArchivedProposals::<T, I>::get(proposal_id) {
+				let grace_end =
+					archive.archived_at.saturating_add(Pallet::<T, I>::claim_grace_period().into());
+				ensure!(
+					Pallet::<T, I>::get_current_block_number() >= grace_end,
+					Error::<T, I>::ClaimGracePeriodNotElapsed
+				);
+			}
+			let amount = Pallet::<T, I>::do_claim(&voter, proposal_id, &voter)?;
+			Self::deposit_event(Event::BalanceClaimedFor { who: voter, caller, amount });
+
+			Ok(())
+		}
+
+		#[pallet::call_index(9)]
+		#[pallet::weight(T::WeightInfo::update_account_list((to_add.len() + to_remove.len()) as u32))]
+		pub fn update_account_list(
+			origin: OriginFor<T>,
+			proposal_id: ProposalId,
+			to_add: BoundedVec<T::AccountId, T::AccountSizeLimit>,
+			to_remove: BoundedVec<T::AccountId, T::AccountSizeLimit>,
+		) -> DispatchResult {
+			let caller = ensure_signed_or_root(origin)?;
+
+			let current_block = Pallet::<T, I>::get_current_block_number();
+			let (added, removed) = Proposals::<T, I>::try_mutate(proposal_id, |maybe_proposal| {
+				let proposal =
+					maybe_proposal.as_mut().ok_or(Error::<T, I>::ProposalDoesNotExist)?;
+
+				ensure!(
+					(caller.is_none() || proposal.is_creator(&caller.unwrap())),
+					Error::<T, I>::OriginNoPermission
+				);
+				ensure!(
+					!proposal.has_started(&current_block),
+					Error::<T, I>::ProposalHasAlreadyStarted
+				);
+
+				let private = proposal.kind == ProposalKind::Private;
+				let account_list = proposal.account_list.get_or_insert_with(BoundedVec::default);
+				let mut added = BoundedVec::<T::AccountId, T::AccountSizeLimit>::default();
+				let mut removed = BoundedVec::<T::AccountId, T::AccountSizeLimit>::default();
+
+				for account in to_remove {
+					if let Some(index) = account_list.iter().position(|a| *a == account) {
+						account_list.remove(index);
+						if private {
+							EligibleProposals::<T, I>::remove(&account, proposal_id);
+						}
+						// `to_remove` is bounded by the same limit, the push cannot fail
+						let _ = removed.try_push(account);
+					}
+				}
+				for account in to_add {
+					if !account_list.contains(&account) {
+						account_list
+							.try_push(account.clone())
+							.map_err(|_| Error::<T, I>::AccountListFull)?;
+						if private {
+							EligibleProposals::<T, I>::insert(&account, proposal_id, ());
+						}
+						let _ = added.try_push(account);
+					}
+				}
+
+				Ok::<_, DispatchError>((added, removed))
+			})?;
+
+			Self::deposit_event(Event::<T, I>::AccountListUpdated { proposal_id, added, removed });
+			Ok(())
+		}
+
+		/// Replaces the account list of a proposal that did not start yet with the one of the
+		/// `source` proposal, so recurring votes among the same members don't resubmit it.
+		#[pallet::call_index(19)]
+		#[pallet::weight(T::WeightInfo::copy_account_list(T::AccountSizeLimit::get()))]
+		pub fn copy_account_list(
+			origin: OriginFor<T>,
+			proposal_id: ProposalId,
+			source: ProposalId,
+		) -> DispatchResult {
+			let caller = ensure_signed_or_root(origin)?;
+
+			let current_block = Pallet::<T, I>::get_current_block_number();
+			let source =
+				Proposals::<T, I>::get(source).ok_or(Error::<T, I>::ProposalDoesNotExist)?;
+			let account_list = Proposals::<T, I>::try_mutate(proposal_id, |maybe_proposal| {
+				let proposal =
+					maybe_proposal.as_mut().ok_or(Error::<T, I>::ProposalDoesNotExist)?;
+
+				ensure!(
+					(caller.is_none() || proposal.is_creator(&caller.unwrap())),
+					Error::<T, I>::OriginNoPermission
+				);
+				ensure!(
+					!proposal.has_started(&current_block),
+					Error::<T, I>::ProposalHasAlreadyStarted
+				);
+				// A public list bans voters while a private one allows them
+				ensure!(proposal.kind == source.kind, Error::<T, I>::AccountListKindMismatch);
+
+				Pallet::<T, I>::index_eligibility(proposal_id, proposal, false);
+				proposal.account_list = source.account_list;
+				Pallet::<T, I>::index_eligibility(proposal_id, proposal, true);
+				Ok::<_, DispatchError>(proposal.account_list.clone())
+			})?;
+
+			Self::deposit_event(Event::<T, I>::AccountListSet { proposal_id, account_list });
+			Ok(())
+		}
+
+		/// Deposits `amount` from the creator to reimburse the transaction fee of each new voter
+		/// of the proposal with `VoteRefund`, so the creator bears the cost of participation.
+		/// Sponsoring again tops up the deposit, what is left is returned once the proposal is
+		/// archived.
+		#[pallet::call_index(20)]
+		#[pallet::weight(T::WeightInfo::sponsor_votes())]
+		pub fn sponsor_votes(
+			origin: OriginFor<T>,
+			proposal_id: ProposalId,
+			amount: BalanceOf<T, I>,
+		) -> DispatchResult {
+			let caller = ensure_signed(origin)?;
+
+			let current_block = Pallet::<T, I>::get_current_block_number();
+			let proposal =
+				Proposals::<T, I>::get(proposal_id).ok_or(Error::<T, I>::ProposalDoesNotExist)?;
+			ensure!(proposal.is_creator(&caller), Error::<T, I>::OriginNoPermission);
+			ensure!(!proposal.has_ended(&current_block), Error::<T, I>::ProposalHasAlreadyEnded);
+
+			<T::NativeBalance as fungible::Mutate<T::AccountId>>::transfer(
+				&caller,
+				&Pallet::<T, I>::sponsorship_account(proposal_id),
+				amount,
+				Preservation::Preserve,
+			)?;
+			Sponsorships::<T, I>::mutate(proposal_id, |sponsorship| {
+				*sponsorship = Some(sponsorship.unwrap_or_default().saturating_add(amount));
+			});
+
+			Self::deposit_event(Event::<T, I>::VotesSponsored { proposal_id, amount });
+			Ok(())
+		}
+
+		/// Funds the turnout lottery of a proposal with `amount`, from the creator or, with
+		/// `AdminOrigin`, from the treasury account. Funding again tops up the pot, shared by the
+		/// winners drawn when the proposal is closed.
+		#[pallet::call_index(35)]
+		#[pallet::weight(T::WeightInfo::fund_lottery())]
+		pub fn fund_lottery(
+			origin: OriginFor<T>,
+			proposal_id: ProposalId,
+			amount: BalanceOf<T, I>,
+		) -> DispatchResult {
+			let maybe_caller = match T::AdminOrigin::try_origin(origin) {
+				Ok(_) => None,
+				Err(origin) => Some(ensure_signed(origin)?),
+			};
+
+			let current_block = Pallet::<T, I>::get_current_block_number();
+			let proposal =
+				Proposals::<T, I>::get(proposal_id).ok_or(Error::<T, I>::ProposalDoesNotExist)?;
+			if let Some(caller) = &maybe_caller {
+				ensure!(proposal.is_creator(caller), Error::<T, I>::OriginNoPermission);
+			}
+			ensure!(proposal.settings.lottery_winners.is_some(), Error::<T, I>::LotteryNotEnabled);
+			ensure!(!proposal.has_ended(&current_block), Error::<T, I>::ProposalHasAlreadyEnded);
+
+			let funder = maybe_caller.unwrap_or_else(T::TreasuryAccount::get);
+			<T::NativeBalance as fungible::Mutate<T::AccountId>>::transfer(
+				&funder,
+				&Pallet::<T, I>::lottery_account(proposal_id),
+				amount,
+				Preservation::Preserve,
+			)?;
+			LotteryPots::<T, I>::mutate(proposal_id, |pot| {
+				*pot = Some(pot.unwrap_or_default().saturating_add(amount));
+			});
+
+			Self::deposit_event(Event::<T, I>::LotteryFunded { proposal_id, funder, amount });
+			Ok(())
+		}
+
+		#[pallet::call_index(11)]
+		#[pallet::weight(T::WeightInfo::renew_registration())]
+		pub fn renew_registration(origin: OriginFor<T>, who: T::AccountId) -> DispatchResult {
+			let maybe_caller = ensure_signed_or_root(origin)?;
+			ensure!(
+				(maybe_caller.is_none() || maybe_caller.clone().unwrap() == who),
+				Error::<T, I>::OriginNoPermission
+			);
+
+			let current_block = Pallet::<T, I>::get_current_block_number();
+			let expires_at = RegisteredVoters::<T, I>::try_mutate(&who, |maybe_voter| {
+				let voter = maybe_voter.as_mut().ok_or(Error::<T, I>::VoterNotRegistered)?;
+				ensure!(!voter.is_expired(&current_block), Error::<T, I>::RegistrationExpired);
+				voter.expires_at = Pallet::<T, I>::schedule_expiry(&who, current_block);
+				Ok::<_, DispatchError>(voter.expires_at)
+			})?;
+
+			Self::deposit_event(Event::<T, I>::VoterRenewed { who, expires_at });
+			Ok(())
+		}
+
+		/// Records the current balance of the caller, which backs its votes once `BalanceAge`
+		/// blocks have passed. Taking a new snapshot restarts its age.
+		#[pallet::call_index(18)]
+		#[pallet::weight(T::WeightInfo::take_balance_snapshot())]
+		pub fn take_balance_snapshot(origin: OriginFor<T>) -> DispatchResult {
+			let caller = ensure_signed(origin)?;
+			ensure!(
+				RegisteredVoters::<T, I>::contains_key(&caller),
+				Error::<T, I>::VoterNotRegistered
+			);
+			Pallet::<T, I>::snapshot_balance(&caller);
+			Ok(())
+		}
+
+		#[pallet::call_index(12)]
+		#[pallet::weight(T::WeightInfo::second())]
+		pub fn second(origin: OriginFor<T>, proposal_id: ProposalId) -> DispatchResult {
+			let caller = ensure_signed(origin)?;
+
+			let current_block = Pallet::<T, I>::get_current_block_number();
+			let voter =
+				RegisteredVoters::<T, I>::get(&caller).ok_or(Error::<T, I>::VoterNotRegistered)?;
+			ensure!(!voter.is_expired(&current_block), Error::<T, I>::RegistrationExpired);
+			ensure!(
+				!Seconds::<T, I>::contains_key(proposal_id, &caller),
+				Error::<T, I>::AlreadySeconded
+			);
+
+			let seconds = if let Some(mut queued) = QueuedProposals::<T, I>::get(proposal_id) {
+				queued.seconds = queued.seconds.saturating_add(1);
+				QueuedProposals::<T, I>::insert(proposal_id, queued.clone());
+				queued.seconds
+			} else {
+				Proposals::<T, I>::try_mutate(proposal_id, |maybe_proposal| {
+					let proposal =
+						maybe_proposal.as_mut().ok_or(Error::<T, I>::ProposalDoesNotExist)?;
+					ensure!(
+						!proposal.has_started(&current_block),
+						Error::<T, I>::ProposalHasAlreadyStarted
+					);
+					if let Some(org) = proposal.org {
+						ensure!(
+							OrgMembers::<T, I>::contains_key(org, &caller),
+							Error::<T, I>::NotOrgMember
+						);
+					}
+					proposal.seconds = proposal.seconds.saturating_add(1);
+					Ok::<_, DispatchError>(proposal.seconds)
+				})?
+			};
+			Seconds::<T, I>::insert(proposal_id, &caller, ());
+
+			Self::deposit_event(Event::<T, I>::ProposalSeconded {
+				proposal_id,
+				who: caller,
+				seconds,
+			});
+			Ok(())
+		}
+
+		#[pallet::call_index(13)]
+		#[pallet::weight(T::WeightInfo::submit_public_proposal())]
+		pub fn submit_public_proposal(
+			origin: OriginFor<T>,
+			offchain_data: BoundedVec<u8, T::ProposalOffchainDataLimit>,
+			account_list: Option<BoundedVec<T::AccountId, T::AccountSizeLimit>>,
+			duration: u32,
+			settings: ProposalSettings,
+		) -> DispatchResult {
+			let caller = ensure_signed(origin)?;
+			ensure!(
+				RegisteredVoters::<T, I>::get(caller.clone()).is_some(),
+				Error::<T, I>::VoterNotRegistered
+			);
+			ensure!(T::LaunchPeriod::get() > 0, Error::<T, I>::LaunchQueueDisabled);
+			ensure!(
+				duration >= Pallet::<T, I>::minimum_duration(),
+				Error::<T, I>::ProposalDurationIsTooShort
+			);
+			ensure!(
+				duration <= Pallet::<T, I>::maximum_duration(),
+				Error::<T, I>::ProposalDurationIsTooLong
+			);
+			ensure!(settings.power_step != Some(0), Error::<T, I>::InvalidPowerStep);
+			ensure!(settings.members_root.is_none(), Error::<T, I>::MembersRootOnlyForPrivate);
+			Pallet::<T, I>::ensure_valid_settings(&settings)?;
+
+			let proposal_id = Pallet::<T, I>::get_next_proposal_id();
+			LaunchQueue::<T, I>::try_append(proposal_id)
+				.map_err(|_| Error::<T, I>::TooManyProposals)?;
+			QueuedProposals::<T, I>::insert(
+				proposal_id,
+				QueuedProposal {
+					offchain_data,
+					creator: caller.clone(),
+					account_list,
+					duration,
+					settings,
+					seconds: 0,
+				},
+			);
+			ProposalStatuses::<T, I>::insert(proposal_id, ProposalStatus::Pending);
+
+			Self::deposit_event(Event::<T, I>::ProposalQueued { proposal_id, creator: caller });
+			Ok(())
+		}
+
+		/// Creates an emergency proposal starting right away, exempt from the delay, minimum
+		/// duration and seconding constraints.
+		#[pallet::call_index(14)]
+		#[pallet::weight(T::WeightInfo::create_emergency_proposal())]
+		pub fn create_emergency_proposal(
+			origin: OriginFor<T>,
+			offchain_data: BoundedVec<u8, T::ProposalOffchainDataLimit>,
+			duration: u32,
+			settings: ProposalSettings,
+		) -> DispatchResult {
+			let creator = T::EmergencyOrigin::ensure_origin(origin)?;
+			ensure!(duration > 0, Error::<T, I>::ProposalDurationIsTooShort);
+			ensure!(
+				duration <= Pallet::<T, I>::emergency_max_duration(),
+				Error::<T, I>::ProposalDurationIsTooLong
+			);
+			ensure!(settings.power_step != Some(0), Error::<T, I>::InvalidPowerStep);
+			ensure!(settings.members_root.is_none(), Error::<T, I>::MembersRootOnlyForPrivate);
+			// The supermajority applies to the proposal tally only
+			ensure!(settings.questions == 0, Error::<T, I>::InvalidBundleSettings);
+			Pallet::<T, I>::ensure_valid_settings(&settings)?;
+
+			let start_block = Pallet::<T, I>::get_current_block_number();
+			let end_block = start_block.saturating_add(duration.into());
+			let proposal_id = Pallet::<T, I>::get_next_proposal_id();
+			let mut proposal = ProposalData::<T, I>::new(
+				offchain_data.clone(),
+				ProposalKind::Emergency,
+				creator.clone(),
+				None,
+				start_block,
+				end_block,
+				settings.clone(),
+			);
+
+			ProposalsByKind::<T, I>::try_append(ProposalKind::Emergency, proposal_id)
+				.map_err(|_| Error::<T, I>::TooManyProposals)?;
+			Pallet::<T, I>::schedule_start(proposal_id, &mut proposal)?;
+			Proposals::<T, I>::insert(proposal_id, proposal);
+
+			Self::deposit_event(Event::ProposalCreated {
+				proposal_id,
+				offchain_data,
+				creator,
+				kind: ProposalKind::Emergency,
+				account_list: None,
+				start_block,
+				end_block,
+				settings,
+			});
+			Ok(())
+		}
+
+		/// Creates a proposal on behalf of the `ExternalOrigin`, bypassing the creator
+		/// registration requirement. The proposal is tagged as external.
+		#[pallet::call_index(15)]
+		#[pallet::weight(T::WeightInfo::create_external_proposal(
+			account_list.as_ref().map_or(0, |list| list.len() as u32)
+		))]
+		pub fn create_external_proposal(
+			origin: OriginFor<T>,
+			offchain_data: BoundedVec<u8, T::ProposalOffchainDataLimit>,
+			kind: ProposalKind,
+			account_list: Option<BoundedVec<T::AccountId, T::AccountSizeLimit>>,
+			start_block: BlockNumberFor<T>,
+			end_block: BlockNumberFor<T>,
+			settings: ProposalSettings,
+		) -> DispatchResult {
+			let creator = T::ExternalOrigin::ensure_origin(origin)?;
+
+			let mut proposal = ProposalData::<T, I>::new(
+				offchain_data,
+				kind,
+				creator,
+				account_list,
+				start_block,
+				end_block,
+				settings,
+			);
+			proposal.external = true;
+			Pallet::<T, I>::do_create_proposal(proposal)
+		}
+
+		/// Creates an organisation administered by `admin`, with its own members, parameters and
+		/// proposal IDs.
+		#[pallet::call_index(28)]
+		#[pallet::weight(T::WeightInfo::create_org())]
+		pub fn create_org(origin: OriginFor<T>, admin: T::AccountId) -> DispatchResult {
+			ensure_root(origin)?;
+
+			let org = NextOrgId::<T, I>::get();
+			NextOrgId::<T, I>::put(org.checked_add(1).expect("Overflow u32 check; qed."));
+			Orgs::<T, I>::insert(
+				org,
+				OrgInfo {
+					admin: admin.clone(),
+					members_count: 0,
+					next_proposal_index: 0,
+					parameters: OrgParameters::default(),
+				},
+			);
+			Self::deposit_event(Event::<T, I>::OrgCreated { org, admin });
+			Ok(())
+		}
+
+		/// Hands the administration of an organisation over to `admin`.
+		#[pallet::call_index(29)]
+		#[pallet::weight(T::WeightInfo::set_org_admin())]
+		pub fn set_org_admin(
+			origin: OriginFor<T>,
+			org: OrgId,
+			admin: T::AccountId,
+		) -> DispatchResult {
+			let caller = ensure_signed_or_root(origin)?;
+
+			Orgs::<T, I>::try_mutate(org, |maybe_org| {
+				let info = maybe_org.as_mut().ok_or(Error::<T, I>::OrgDoesNotExist)?;
+				ensure!(
+					caller.map_or(true, |caller| caller == info.admin),
+					Error::<T, I>::OriginNoPermission
+				);
+				info.admin = admin.clone();
+				Ok::<_, DispatchError>(())
+			})?;
+			Self::deposit_event(Event::<T, I>::OrgAdminSet { org, admin });
+			Ok(())
+		}
+
+		/// Admits a registered voter in an organisation, its tenure there starting now.
+		#[pallet::call_index(30)]
+		#[pallet::weight(T::WeightInfo::add_org_member())]
+		pub fn add_org_member(
+			origin: OriginFor<T>,
+			org: OrgId,
+			who: T::AccountId,
+		) -> DispatchResult {
+			let caller = ensure_signed(origin)?;
+			ensure!(
+				RegisteredVoters::<T, I>::contains_key(&who),
+				Error::<T, I>::VoterNotRegistered
+			);
+			ensure!(!OrgMembers::<T, I>::contains_key(org, &who), Error::<T, I>::AlreadyOrgMember);
+
+			Orgs::<T, I>::try_mutate(org, |maybe_org| {
+				let info = maybe_org.as_mut().ok_or(Error::<T, I>::OrgDoesNotExist)?;
+				ensure!(caller == info.admin, Error::<T, I>::OriginNoPermission);
+				info.members_count = info.members_count.saturating_add(1);
+				Ok::<_, DispatchError>(())
+			})?;
+			OrgMembers::<T, I>::insert(org, &who, Pallet::<T, I>::get_current_block_number());
+			Self::deposit_event(Event::<T, I>::OrgMemberAdded { org, who });
+			Ok(())
+		}
+
+		/// Removes a member from an organisation, by its admin or by the member itself. The votes
+		/// already cast stay counted.
+		#[pallet::call_index(31)]
+		#[pallet::weight(T::WeightInfo::remove_org_member())]
+		pub fn remove_org_member(
+			origin: OriginFor<T>,
+			org: OrgId,
+			who: T::AccountId,
+		) -> DispatchResult {
+			let caller = ensure_signed(origin)?;
+			ensure!(OrgMembers::<T, I>::contains_key(org, &who), Error::<T, I>::NotOrgMember);
+
+			Orgs::<T, I>::try_mutate(org, |maybe_org| {
+				let info = maybe_org.as_mut().ok_or(Error::<T, I>::OrgDoesNotExist)?;
+				ensure!(caller == info.admin || caller == who, Error::<T, I>::OriginNoPermission);
+				info.members_count = info.members_count.saturating_sub(1);
+				Ok::<_, DispatchError>(())
+			})?;
+			OrgMembers::<T, I>::remove(org, &who);
+			Self::deposit_event(Event::<T, I>::OrgMemberRemoved { org, who });
+			Ok(())
+		}
+
+		/// Sets the rules of the proposals of an organisation, each within the bounds of the
+		/// pallet limit it overrides.
+		#[pallet::call_index(32)]
+		#[pallet::weight(T::WeightInfo::set_org_parameters())]
+		pub fn set_org_parameters(
+			origin: OriginFor<T>,
+			org: OrgId,
+			parameters: OrgParameters,
+		) -> DispatchResult {
+			let caller = ensure_signed(origin)?;
+
+			let (pallet_minimum, pallet_maximum) = Pallet::<T, I>::duration_bounds(None);
+			let minimum_duration = parameters.minimum_duration.unwrap_or(pallet_minimum);
+			let maximum_duration = parameters.maximum_duration.unwrap_or(pallet_maximum);
+			ensure!(
+				pallet_minimum <= minimum_duration &&
+					minimum_duration <= maximum_duration &&
+					maximum_duration <= pallet_maximum,
+				Error::<T, I>::InvalidOrgParameters
+			);
+
+			Orgs::<T, I>::try_mutate(org, |maybe_org| {
+				let info = maybe_org.as_mut().ok_or(Error::<T, I>::OrgDoesNotExist)?;
+				ensure!(caller == info.admin, Error::<T, I>::OriginNoPermission);
+				info.parameters = parameters.clone();
+				Ok::<_, DispatchError>(())
+			})?;
+			Self::deposit_event(Event::<T, I>::OrgParametersSet { org, parameters });
+			Ok(())
+		}
+
+		/// Overrides the limits of the proposals, the config types applying to the ones left
+		/// unset. Proposals already created keep their schedule.
+		#[pallet::call_index(54)]
+		#[pallet::weight(T::WeightInfo::set_proposal_limits())]
+		pub fn set_proposal_limits(origin: OriginFor<T>, limits: ProposalLimits) -> DispatchResult {
+			ensure_root(origin)?;
+
+			let minimum_duration =
+				limits.minimum_duration.unwrap_or_else(T::ProposalMinimumDuration::get);
+			let maximum_duration =
+				limits.maximum_duration.unwrap_or_else(T::ProposalMaximumDuration::get);
+			let emergency_max_duration =
+				limits.emergency_max_duration.unwrap_or_else(T::EmergencyMaxDuration::get);
+			ensure!(
+				minimum_duration <= maximum_duration &&
+					maximum_duration > 0 &&
+					emergency_max_duration > 0,
+				Error::<T, I>::InvalidProposalLimits
+			);
+
+			ProposalLimitOverrides::<T, I>::put(limits.clone());
+			Self::deposit_event(Event::<T, I>::ProposalLimitsSet { limits });
+			Ok(())
+		}
+
+		/// Creates a proposal in an organisation, only its members can second and vote on it.
+		#[pallet::call_index(33)]
+		#[pallet::weight(T::WeightInfo::create_org_proposal(
+			account_list.as_ref().map_or(0, |list| list.len() as u32)
+		))]
+		pub fn create_org_proposal(
+			origin: OriginFor<T>,
+			org: OrgId,
+			offchain_data: BoundedVec<u8, T::ProposalOffchainDataLimit>,
+			kind: ProposalKind,
+			account_list: Option<BoundedVec<T::AccountId, T::AccountSizeLimit>>,
+			start_block: BlockNumberFor<T>,
+			end_block: BlockNumberFor<T>,
+			settings: ProposalSettings,
+		) -> DispatchResult {
+			let caller = ensure_signed(origin)?;
+			ensure!(Orgs::<T, I>::contains_key(org), Error::<T, I>::OrgDoesNotExist);
+			ensure!(
+				RegisteredVoters::<T, I>::contains_key(&caller),
+				Error::<T, I>::VoterNotRegistered
+			);
+			ensure!(OrgMembers::<T, I>::contains_key(org, &caller), Error::<T, I>::NotOrgMember);
+
+			let mut proposal = ProposalData::<T, I>::new(
+				offchain_data,
+				kind,
+				caller,
+				account_list,
+				start_block,
+				end_block,
+				settings,
+			);
+			proposal.org = Some(org);
+			Pallet::<T, I>::do_create_proposal(proposal)
+		}
+	}
+}
+
+impl<T: Config<I>, I: 'static> Pallet<T, I> {
+	fn get_next_proposal_id() -> ProposalId {
+		let proposal_id = NextProposalId::<T, I>::get();
+		let next_id = proposal_id.checked_add(1).expect("Overflow u32 check; qed.");
+		NextProposalId::<T, I>::put(next_id);
+		proposal_id
+	}
+
+	fn get_current_block_number() -> BlockNumberFor<T> {
+		frame_system::Pallet::<T>::block_number()
+	}
+
+	/// Whether a stepped migration of the storage is in progress, during which the runtime should
+	/// filter the calls of the pallet.
+	pub fn migrating() -> bool {
+		OngoingMigration::<T, I>::exists()
+	}
+
+	/// The minimum duration of a proposal, as overridden by Root or `ProposalMinimumDuration`.
+	pub fn minimum_duration() -> u32 {
+		ProposalLimitOverrides::<T, I>::get()
+			.minimum_duration
+			.unwrap_or_else(T::ProposalMinimumDuration::get)
+	}
+
+	/// The maximum duration of a proposal, as overridden by Root or `ProposalMaximumDuration`.
+	pub fn maximum_duration() -> u32 {
+		ProposalLimitOverrides::<T, I>::get()
+			.maximum_duration
+			.unwrap_or_else(T::ProposalMaximumDuration::get)
+	}
+
+	/// The maximum delay before a proposal starts, as overridden by Root or
+	/// `ProposalDelayLimit`.
+	pub fn delay_limit() -> u32 {
+		ProposalLimitOverrides::<T, I>::get()
+			.delay_limit
+			.unwrap_or_else(T::ProposalDelayLimit::get)
+	}
+
+	/// The maximum duration of an emergency proposal, as overridden by Root or
+	/// `EmergencyMaxDuration`.
+	pub fn emergency_max_duration() -> u32 {
+		ProposalLimitOverrides::<T, I>::get()
+			.emergency_max_duration
+			.unwrap_or_else(T::EmergencyMaxDuration::get)
+	}
+
+	/// The grace period before anyone can claim for a voter, as overridden by Root or
+	/// `ClaimGracePeriod`.
+	pub fn claim_grace_period() -> u32 {
+		ProposalLimitOverrides::<T, I>::get()
+			.claim_grace_period
+			.unwrap_or_else(T::ClaimGracePeriod::get)
+	}
+
+	/// The minimum and maximum durations of the proposals of `org`, the pallet limits for the
+	/// proposals outside organisations.
+	fn duration_bounds(org: Option<OrgId>) -> (u32, u32) {
+		let parameters =
+			org.and_then(Orgs::<T, I>::get).map(|info| info.parameters).unwrap_or_default();
+		(
+			parameters.minimum_duration.unwrap_or_else(Pallet::<T, I>::minimum_duration),
+			parameters.maximum_duration.unwrap_or_else(Pallet::<T, I>::maximum_duration),
+		)
+	}
+
+	/// Gives a new proposal of an organisation the next ID within it.
+	fn index_in_org(proposal_id: ProposalId, proposal: &ProposalData<T, I>) {
+		let Some(org) = proposal.org else { return };
+		let Some(index) = Orgs::<T, I>::mutate(org, |maybe_org| {
+			maybe_org.as_mut().map(|info| {
+				let index = info.next_proposal_index;
+				info.next_proposal_index = index.saturating_add(1);
+				index
+			})
+		}) else {
+			return
+		};
+		OrgProposals::<T, I>::insert(org, index, proposal_id);
+		Self::deposit_event(Event::OrgProposalCreated { org, index, proposal_id });
+	}
+
+	/// Checks the schedule and the kind of a new proposal, shared by `create_proposal` and its
+	/// dry run.
+	fn ensure_valid_proposal(
+		kind: &ProposalKind,
+		start_block: BlockNumberFor<T>,
+		end_block: BlockNumberFor<T>,
+		org: Option<OrgId>,
+	) -> Result<(), CreateError> {
+		let current_block = Pallet::<T, I>::get_current_block_number();
+		ensure!(current_block <= start_block, CreateError::ProposalCannotStartInThePast);
+		ensure!(start_block < end_block, CreateError::ProposalCannotFinishBeforeStarting);
+
+		let duration = end_block.saturating_sub(start_block);
+		let buffer = start_block.saturating_sub(current_block);
+		let (minimum_duration, maximum_duration) = Pallet::<T, I>::duration_bounds(org);
+		ensure!(
+			buffer <= Pallet::<T, I>::delay_limit().into(),
+			CreateError::ProposalStartIsTooFarAway
+		);
+		ensure!(duration >= minimum_duration.into(), CreateError::ProposalDurationIsTooShort);
+		ensure!(duration <= maximum_duration.into(), CreateError::ProposalDurationIsTooLong);
+
+		ensure!(*kind != ProposalKind::Emergency, CreateError::EmergencyProposalNotAllowed);
+		// Sunset reviews are only created by the pallet
+		ensure!(*kind != ProposalKind::SunsetReview, CreateError::ProposalKindNotAllowed);
+		// Kinds of a later version of the pallet have no rules here
+		ensure!(!matches!(kind, ProposalKind::Unknown(_)), CreateError::ProposalKindNotAllowed);
+		ensure!(T::KindHandler::can_create(kind), CreateError::ProposalKindNotAllowed);
+		Ok(())
+	}
+
+	fn do_create_proposal(mut proposal: ProposalData<T, I>) -> DispatchResult {
+		let (kind, start_block, end_block) =
+			(proposal.kind.clone(), proposal.start_block, proposal.end_block);
+		let settings = &proposal.settings;
+
+		Pallet::<T, I>::ensure_valid_proposal(&kind, start_block, end_block, proposal.org)
+			.map_err(Error::<T, I>::from)?;
+		ensure!(settings.power_step != Some(0), Error::<T, I>::InvalidPowerStep);
+		ensure!(
+			settings.members_root.is_none() || kind == ProposalKind::Private,
+			Error::<T, I>::MembersRootOnlyForPrivate
+		);
+		Pallet::<T, I>::ensure_valid_settings(settings)?;
+
+		// TODO: ensure account_list not empty for private proposals?
+
+		let proposal_id = Pallet::<T, I>::get_next_proposal_id();
+		ProposalsByKind::<T, I>::try_append(kind.clone(), proposal_id)
+			.map_err(|_| Error::<T, I>::TooManyProposals)?;
+		Pallet::<T, I>::schedule_start(proposal_id, &mut proposal)?;
+		Pallet::<T, I>::index_eligibility(proposal_id, &proposal, true);
+		Proposals::<T, I>::insert(proposal_id, proposal.clone());
+		Pallet::<T, I>::index_in_org(proposal_id, &proposal);
+
+		let event = Event::ProposalCreated {
+			proposal_id,
+			offchain_data: proposal.offchain_data,
+			creator: proposal.creator,
+			kind,
+			account_list: proposal.account_list,
+			start_block,
+			end_block,
+			settings: proposal.settings,
+		};
+		Self::deposit_event(event);
+
+		Ok(())
+	}
+
+	/// Promotes the most seconded queued public proposal reaching `SecondsThreshold` to a voting
+	/// proposal starting at `now`. The earliest submitted proposal wins a tie.
+	fn launch_next_proposal(now: BlockNumberFor<T>) -> Weight {
+		let queue = LaunchQueue::<T, I>::get();
+		let threshold = T::SecondsThreshold::get();
+		let weight = Weight::from_parts(10_000, 0)
+			.saturating_add(T::DbWeight::get().reads(queue.len() as u64 + 1));
+
+		let mut best: Option<(usize, QueuedProposalOf<T, I>)> = None;
+		for (index, proposal_id) in queue.iter().enumerate() {
+			let Some(queued) = QueuedProposals::<T, I>::get(proposal_id) else { continue };
+			let is_better = best.as_ref().map_or(true, |(_, best)| queued.seconds > best.seconds);
+			if queued.seconds >= threshold && is_better {
+				best = Some((index, queued));
+			}
+		}
+		let Some((index, queued)) = best else { return weight };
+
+		let proposal_id = queue[index];
+		if ProposalsByKind::<T, I>::try_append(ProposalKind::Public, proposal_id).is_err() {
+			// Kept in the queue until a public proposal ends
+			return weight
+		}
+		LaunchQueue::<T, I>::mutate(|queue| {
+			queue.remove(index);
+		});
+		QueuedProposals::<T, I>::remove(proposal_id);
+
+		let end_block = now.saturating_add(queued.duration.into());
+		let jury_size = queued.settings.jury_size;
+		let mut proposal = ProposalData::<T, I>::new(
+			queued.offchain_data.clone(),
+			ProposalKind::Public,
+			queued.creator.clone(),
+			queued.account_list.clone(),
+			now,
+			end_block,
+			queued.settings.clone(),
+		);
+		proposal.seconds = queued.seconds;
+		proposal.electorate = Some(Pallet::<T, I>::current_electorate(proposal_id, &proposal));
+		Pallet::<T, I>::schedule_end(proposal_id, proposal.closes_at());
+		Proposals::<T, I>::insert(proposal_id, proposal);
+		ProposalStatuses::<T, I>::insert(proposal_id, ProposalStatus::Active);
+
+		Self::deposit_event(Event::ProposalCreated {
+			proposal_id,
+			offchain_data: queued.offchain_data,
+			creator: queued.creator,
+			kind: ProposalKind::Public,
+			account_list: queued.account_list,
+			start_block: now,
+			end_block,
+			settings: queued.settings,
+		});
+		Self::deposit_event(Event::ProposalLaunched { proposal_id });
+		let weight = weight.saturating_add(T::DbWeight::get().reads_writes(1, 4));
+		match jury_size {
+			Some(jury_size) =>
+				weight.saturating_add(Pallet::<T, I>::draw_jury(proposal_id, jury_size)),
+			None => weight,
+		}
+	}
+
+	fn unindex_proposal(proposal_id: ProposalId, proposal: &ProposalData<T, I>) {
+		ProposalsByKind::<T, I>::mutate(&proposal.kind, |proposal_ids| {
+			proposal_ids.retain(|id| *id != proposal_id);
+		});
+		Pallet::<T, I>::unschedule_end(proposal_id, proposal.closes_at());
+		Pallet::<T, I>::index_eligibility(proposal_id, proposal, false);
+		let _ = RoleTurnouts::<T, I>::clear_prefix(proposal_id, MAX_ROLE_QUORUMS, None);
+		let _ = ExpectedVoters::<T, I>::clear_prefix(proposal_id, T::AccountSizeLimit::get(), None);
+	}
+
+	/// Adds or removes the accounts allowed to vote on a private proposal to the
+	/// `EligibleProposals` index.
+	fn index_eligibility(proposal_id: ProposalId, proposal: &ProposalData<T, I>, eligible: bool) {
+		let (ProposalKind::Private, Some(account_list)) = (&proposal.kind, &proposal.account_list)
+		else {
+			return
+		};
+		for who in account_list {
+			if eligible {
+				EligibleProposals::<T, I>::insert(who, proposal_id, ());
+			} else {
+				EligibleProposals::<T, I>::remove(who, proposal_id);
+			}
+		}
+	}
+
+	/// The number of voters pre-registered on a proposal, and still allowed by its account list,
+	/// who did not vote, or `None` if no voter was pre-registered. The slots are removed.
+	fn count_abstentions(proposal_id: ProposalId, proposal: &ProposalData<T, I>) -> Option<u32> {
+		let mut slots = ExpectedVoters::<T, I>::drain_prefix(proposal_id).peekable();
+		slots.peek()?;
+		let abstentions = slots
+			.filter(|(who, _)| {
+				T::KindHandler::is_allowed(proposal, who) &&
+					!Votes::<T, I>::contains_key(who, proposal_id)
+			})
+			.count();
+		Some(abstentions as u32)
+	}
+
+	/// The private proposals whose account list allows `who` to vote. The proposals allowing
+	/// voters with an eligibility bitmap are not indexed.
+	pub fn eligible_proposals(who: &T::AccountId) -> Vec<ProposalId> {
+		EligibleProposals::<T, I>::iter_key_prefix(who).collect()
+	}
+
+	/// The published tally of a proposal: the running tally while it is open, a hidden tally
+	/// staying empty, then the final tally until its archive is pruned.
+	pub fn tally(proposal_id: ProposalId) -> Option<VoteRatio> {
+		if let Some(proposal) = Proposals::<T, I>::get(proposal_id) {
+			return Some(proposal.ratio)
+		}
+		match ArchivedProposals::<T, I>::get(proposal_id)?.outcome {
+			ProposalOutcome::Completed { ratio, .. } => Some(ratio),
+			_ => None,
+		}
+	}
+
+	/// How a proposal ended, until its archive is pruned. A disputed outcome is withheld until
+	/// the dispute is resolved.
+	pub fn outcome(proposal_id: ProposalId) -> Option<ProposalOutcome> {
+		match ProposalStatuses::<T, I>::get(proposal_id)? {
+			ProposalStatus::Closed { outcome } => Some(outcome),
+			ProposalStatus::Cancelled { reason } => Some(ProposalOutcome::Cancelled { reason }),
+			_ => None,
+		}
+	}
+
+	pub fn is_registered(who: &T::AccountId) -> bool {
+		RegisteredVoters::<T, I>::contains_key(who)
+	}
+
+	/// The balance of `who` held as collateral of its votes, across all proposals.
+	pub fn held_for_voting(who: &T::AccountId) -> BalanceOf<T, I> {
+		<T::NativeBalance as fungible::hold::Inspect<T::AccountId>>::balance_on_hold(
+			&HoldReason::VoteCollateral.into(),
+			who,
+		)
+	}
+
+	/// The collateral held for the votes of `who` against the cost of its votes and of its
+	/// unrevealed commitments, so wallets and monitors can detect an accounting drift. The votes
+	/// of a closed proposal count until the
+	/// release of its collateral completes, and the votes with an asset are escrowed rather than
+	/// held. Meant for off-chain queries, it reads every open proposal and queued cleanup.
+	pub fn hold_health(who: &T::AccountId) -> HoldHealth<BalanceOf<T, I>> {
+		let open = Proposals::<T, I>::iter()
+			.filter(|(_, proposal)| proposal.settings.freezes_cost())
+			.map(|(proposal_id, _)| proposal_id);
+		let releasing = CleanupQueue::<T, I>::iter_values().filter_map(|(_, task)| match task {
+			CleanupTask::ReleaseFreezes { proposal_id, frozen: true } => Some(proposal_id),
+			_ => None,
+		});
+		let expected_from_votes = open
+			.chain(releasing)
+			.filter(|proposal_id| !ProposalAssets::<T, I>::contains_key(proposal_id))
+			.flat_map(|proposal_id| {
+				let vote = Votes::<T, I>::get(who, proposal_id).map(|vote| vote.power);
+				let commitment = Commitments::<T, I>::get(proposal_id, who).map(|(_, power)| power);
+				vote.into_iter().chain(commitment)
+			})
+			.fold(Zero::zero(), |expected: BalanceOf<T, I>, power| {
+				expected.saturating_add(Pallet::<T, I>::calculate_quadratic_amount(power))
+			});
+		let actual_held = Pallet::<T, I>::held_for_voting(who);
+		let drift = actual_held.max(expected_from_votes) - actual_held.min(expected_from_votes);
+		HoldHealth { expected_from_votes, actual_held, drift }
+	}
+
+	/// How the collateral held for the votes of `who` overlaps with its other holds and with the
+	/// locks and freezes of other pallets. A lock counts the held balance towards its amount, so
+	/// the collateral of the votes and e.g. a staking lock share the same funds up to the lock.
+	pub fn lock_report(who: &T::AccountId) -> LockReport<BalanceOf<T, I>> {
+		use frame_support::traits::fungible::{Inspect, InspectHold};
+
+		let total = T::NativeBalance::total_balance(who);
+		let held = T::NativeBalance::total_balance_on_hold(who);
+		let held_for_voting = Pallet::<T, I>::held_for_voting(who);
+		let spendable =
+			T::NativeBalance::reducible_balance(who, Preservation::Preserve, Fortitude::Polite);
+		let max_additional_power = math::max_power(spendable).min(T::MaxPower::get());
+		LockReport {
+			total,
+			held_for_voting,
+			held_elsewhere: held.saturating_sub(held_for_voting),
+			restricted: total.saturating_sub(held).saturating_sub(spendable),
+			spendable,
+			max_additional_power,
+		}
+	}
+
+	/// The last vote of `who` on a proposal, with the proof of its inclusion in the votes tree of
+	/// the proposal, so auditors can check that the vote was tallied. `None` when the voter has
+	/// no vote or dropped it. Meant for off-chain queries, it reads every leaf of the tree.
+	pub fn vote_proof(
+		proposal_id: ProposalId,
+		who: &T::AccountId,
+	) -> Option<VoteProof<PowerOf<T, I>>> {
+		let tree = VotesTrees::<T, I>::get(proposal_id);
+		let log: Vec<_> = (0..tree.leaves)
+			.filter_map(|index| VoteLog::<T, I>::get(proposal_id, index))
+			.collect();
+		let leaf_index = log.iter().rposition(|(voter, _)| voter == who)?;
+		let vote = log[leaf_index].1.clone();
+		if vote.power.is_zero() {
+			return None
+		}
+
+		let nodes = log.iter().map(|(voter, vote)| vote_leaf(voter, vote)).collect();
+		Some(VoteProof {
+			vote,
+			leaf_index: leaf_index as u32,
+			proof: merkle_proof(nodes, leaf_index),
+			root: tree.root,
+		})
+	}
+
+	/// A pruned archive of the epoch `era`, with the proof of its inclusion in the archive tree
+	/// of the epoch, so its outcome and votes remain provable. `None` while the archive is not
+	/// pruned. Meant for off-chain queries, it reads every leaf of the tree.
+	pub fn archive_proof(era: u32, proposal_id: ProposalId) -> Option<ArchiveProof> {
+		let tree = ArchiveTrees::<T, I>::get(era);
+		let archives: Vec<_> = (0..tree.leaves)
+			.filter_map(|index| CompressedArchives::<T, I>::get(era, index))
+			.collect();
+		let leaf_index = archives.iter().position(|archive| archive.proposal_id == proposal_id)?;
+		let nodes = archives.iter().map(archive_leaf).collect();
+		Some(ArchiveProof {
+			archive: archives[leaf_index].clone(),
+			leaf_index: leaf_index as u32,
+			proof: merkle_proof(nodes, leaf_index),
+			root: tree.root,
+		})
+	}
+
+	/// Dry runs the checks of `create_proposal` on a proposal of `creator`, so forms can be
+	/// validated without submitting an extrinsic bound to fail. The settings and the offchain
+	/// data are left out.
+	pub fn validate_proposal(
+		creator: &T::AccountId,
+		kind: ProposalKind,
+		account_list_len: u32,
+		start_block: BlockNumberFor<T>,
+		end_block: BlockNumberFor<T>,
+	) -> Result<(), CreateError> {
+		ensure!(RegisteredVoters::<T, I>::contains_key(creator), CreateError::VoterNotRegistered);
+		if let Some(role) = KindRoles::<T, I>::get(&kind) {
+			ensure!(
+				CreatorRoles::<T, I>::contains_key(creator, role),
+				CreateError::MissingCreatorRole
+			);
+		}
+		ensure!(account_list_len <= T::AccountSizeLimit::get(), CreateError::AccountListFull);
+		Pallet::<T, I>::ensure_valid_proposal(&kind, start_block, end_block, None)?;
+
+		let max_proposals = T::MaxProposalsPerKind::get() as usize;
+		let proposals = ProposalsByKind::<T, I>::decode_len(&kind).unwrap_or_default();
+		ensure!(proposals < max_proposals, CreateError::TooManyProposals);
+		let current_block = Pallet::<T, I>::get_current_block_number();
+		if start_block > current_block {
+			let starting = ScheduledStarts::<T, I>::decode_len(start_block).unwrap_or_default();
+			ensure!(starting < max_proposals, CreateError::TooManyProposals);
+		}
+		let spendable = <T::NativeBalance as fungible::Inspect<T::AccountId>>::reducible_balance(
+			creator,
+			Preservation::Preserve,
+			Fortitude::Polite,
+		);
+		ensure!(spendable >= T::ProposalDeposit::get(), CreateError::InsufficientBalance);
+		Ok(())
+	}
+
+	/// What `who` is short of to vote with `power` on an open proposal, so wallets can tell how
+	/// much more balance, or voice credits for a shared budget, `vote` needs before it fails with
+	/// `InsufficientBalance` or `InsufficientCredits`. `None` when the cost is covered.
+	pub fn check_vote(
+		who: &T::AccountId,
+		proposal_id: ProposalId,
+		power: PowerOf<T, I>,
+	) -> Option<VoteShortfall<BalanceOf<T, I>>> {
+		let proposal = Proposals::<T, I>::get(proposal_id)?;
+		let prev_power =
+			Votes::<T, I>::get(who, proposal_id).map_or(Zero::zero(), |vote| vote.power);
+		let required = Pallet::<T, I>::calculate_quadratic_amount(power)
+			.saturating_sub(Pallet::<T, I>::calculate_quadratic_amount(prev_power));
+		let available = if proposal.settings.shared_budget {
+			let (spent_epoch, spent) = SpentCredits::<T, I>::get(who);
+			let spent =
+				if spent_epoch == Pallet::<T, I>::current_epoch() { spent } else { Zero::zero() };
+			T::VoiceCredits::get().saturating_sub(spent)
+		} else {
+			Pallet::<T, I>::reducible_balance(proposal_id, who)
+		};
+		(required > available).then_some(VoteShortfall { required, available })
+	}
+
+	/// Closes a proposal that ended, or expires it if it was not seconded before its start.
+	/// Without `closer`, the fees paid out to the closer go to the treasury account.
+	fn do_close_proposal(proposal_id: ProposalId, closer: Option<T::AccountId>) -> DispatchResult {
+		let current_block = Pallet::<T, I>::get_current_block_number();
+		let mut proposal =
+			Proposals::<T, I>::get(proposal_id).ok_or(Error::<T, I>::ProposalDoesNotExist)?;
+
+		// A proposal not seconded before its start expires, it has no vote to release
+		if !proposal.is_seconded(T::SecondsThreshold::get()) {
+			ensure!(proposal.has_started(&current_block), Error::<T, I>::ProposalHasNotStartedYet);
+			Proposals::<T, I>::remove(proposal_id);
+			Pallet::<T, I>::drop_call(proposal_id);
+			Pallet::<T, I>::unindex_proposal(proposal_id, &proposal);
+			Pallet::<T, I>::archive(proposal_id, proposal.creator, ProposalOutcome::Expired);
+			Self::deposit_event(Event::<T, I>::ProposalExpired { proposal_id });
+			return Ok(())
+		}
+		ensure!(proposal.can_close(&current_block), Error::<T, I>::ProposalHasNotEndedYet);
+		Pallet::<T, I>::complete_proposal(proposal_id, proposal, closer, current_block);
+		Ok(())
+	}
+
+	/// Closes a private proposal as soon as a vote settles its outcome, the votes already cast
+	/// being final and the voters of its account list who did not vote yet not being able to
+	/// change it. Returns whether it was closed.
+	fn close_if_settled(proposal_id: ProposalId) -> bool {
+		let Some(proposal) = Proposals::<T, I>::get(proposal_id) else { return false };
+		// An eligibility bitmap allows voters beyond the account list
+		if EligibilityBitmaps::<T, I>::contains_key(proposal_id) {
+			return false
+		}
+		let electorate = proposal
+			.electorate
+			.unwrap_or_else(|| Pallet::<T, I>::current_electorate(proposal_id, &proposal));
+		// The largest amount a voter can add: `MaxPower` with the full bonus and conviction
+		let mut max_amount =
+			tallied_amount(T::MaxPower::get(), Percent::from_percent(100)).unwrap_or(u128::MAX);
+		if proposal.settings.vote_cost == VoteCost::Frozen && !proposal.settings.shared_budget {
+			max_amount = Conviction::Locked6x.apply(max_amount).unwrap_or(u128::MAX);
+		}
+		let current_block = Pallet::<T, I>::get_current_block_number();
+		if proposal.settled_outcome(electorate, max_amount, &current_block).is_none() {
+			return false
+		}
+		Pallet::<T, I>::complete_proposal(proposal_id, proposal, None, current_block);
+		true
+	}
+
+	/// Tallies a proposal which can be closed and archives its outcome.
+	fn complete_proposal(
+		proposal_id: ProposalId,
+		mut proposal: ProposalData<T, I>,
+		closer: Option<T::AccountId>,
+		current_block: BlockNumberFor<T>,
+	) {
+		Pallet::<T, I>::reveal_tally(proposal_id, &mut proposal);
+		HiddenTallies::<T, I>::remove(proposal_id);
+		Recounts::<T, I>::remove(proposal_id);
+		AuditCursors::<T, I>::remove(proposal_id);
+		if let Some(whale_cap) = proposal.settings.whale_cap {
+			Pallet::<T, I>::apply_whale_cap(proposal_id, &mut proposal, whale_cap);
+		}
+		let turnout_scale =
+			proposal.settings.turnout_cap.and_then(|cap| proposal.apply_turnout_cap(cap));
+		if let Some(scale) = turnout_scale {
+			Self::deposit_event(Event::<T, I>::TurnoutScaled { proposal_id, scale });
+		}
+		if proposal.settings.freezes_cost() {
+			Pallet::<T, I>::audit_sample(proposal_id);
+		}
+		if let Some(winners) = proposal.settings.lottery_winners {
+			Pallet::<T, I>::draw_lottery(proposal_id, winners);
+		}
+		if proposal.settings.vote_cost != VoteCost::Frozen {
+			ClosedVoteCosts::<T, I>::insert(proposal_id, proposal.settings.vote_cost);
+		}
+		if let Some(delay) = proposal.settings.claim_delay {
+			ClaimsOpenAt::<T, I>::insert(proposal_id, current_block.saturating_add(delay.into()));
+		}
+		Pallet::<T, I>::enqueue_cleanup(
+			CleanupLane::Immediate,
+			current_block,
+			CleanupTask::ReleaseFreezes { proposal_id, frozen: proposal.settings.freezes_cost() },
+		);
+
+		let electorate = proposal
+			.electorate
+			.unwrap_or_else(|| Pallet::<T, I>::current_electorate(proposal_id, &proposal));
+		// A bundled proposal is approved when all its questions are
+		let approved = if proposal.settings.questions > 0 {
+			Pallet::<T, I>::close_questions(proposal_id, &proposal, electorate)
+		} else if !proposal.options.is_empty() {
+			Pallet::<T, I>::close_options(proposal_id, &mut proposal, electorate)
+		} else {
+			T::KindHandler::is_approved(&proposal, electorate) &&
+				Pallet::<T, I>::reaches_issuance_quorum(proposal_id, &proposal, proposal.ratio.1)
+		} && Pallet::<T, I>::reaches_role_quorums(proposal_id, &proposal);
+		Pallet::<T, I>::pay_out_fees(proposal_id, &proposal, closer, approved);
+		EpochStatistics::<T, I>::mutate(|stats| {
+			stats.proposals_closed.saturating_inc();
+			stats.total_turnout.saturating_accrue(proposal.ratio.1);
+		});
+		let abstentions = Pallet::<T, I>::count_abstentions(proposal_id, &proposal);
+		Proposals::<T, I>::remove(proposal_id);
+		Pallet::<T, I>::unindex_proposal(proposal_id, &proposal);
+		Pallet::<T, I>::archive(
+			proposal_id,
+			proposal.creator.clone(),
+			ProposalOutcome::Completed { ratio: proposal.ratio, approved },
+		);
+		let voter_cap = proposal
+			.settings
+			.max_voters
+			.map(|max_voters| (proposal.voters_count, max_voters));
+		if turnout_scale.is_some() || abstentions.is_some() || voter_cap.is_some() {
+			ArchivedProposals::<T, I>::mutate(proposal_id, |archive| {
+				if let Some(archive) = archive {
+					archive.turnout_scale = turnout_scale;
+					archive.abstentions = abstentions;
+					archive.voter_cap = voter_cap;
+				}
+			});
+		}
+		Self::deposit_event(Event::<T, I>::VoteCompleted {
+			proposal_id,
+			ratio: proposal.ratio,
+			approved,
+		});
+		Pallet::<T, I>::schedule_enactment(proposal_id, current_block);
+		Pallet::<T, I>::review_policy(proposal_id, &proposal, approved, current_block);
+		Pallet::<T, I>::open_dispute_period(proposal_id, proposal, current_block);
+	}
+
+	/// Defers the enactment of the call of a closed proposal until its outcome can no longer be
+	/// disputed or overridden.
+	fn schedule_enactment(proposal_id: ProposalId, current_block: BlockNumberFor<T>) {
+		if !ProposalCalls::<T, I>::contains_key(proposal_id) {
+			return
+		}
+		Pallet::<T, I>::enqueue_cleanup(
+			CleanupLane::Enactment,
+			current_block.saturating_add(Pallet::<T, I>::enactment_delay().into()),
+			CleanupTask::EnactCall { proposal_id },
+		);
+	}
+
+	/// The number of blocks after which the outcome of a closed proposal can no longer be
+	/// disputed or overridden.
+	fn enactment_delay() -> u32 {
+		T::DisputePeriod::get().max(T::OverridePeriod::get())
+	}
+
+	/// Dispatches the call of a closed proposal from `EnactmentOrigin` if its archived outcome is
+	/// approved. The call of a rejected proposal is dropped. A failed enactment is retried up to
+	/// `MaxEnactmentRetries` times, the last failure being recorded in the archive.
+	fn enact(proposal_id: ProposalId) {
+		let Some(call) = ProposalCalls::<T, I>::get(proposal_id) else { return };
+		let approved = matches!(
+			ArchivedProposals::<T, I>::get(proposal_id).map(|archive| archive.outcome),
+			Some(ProposalOutcome::Completed { approved: true, .. })
+		);
+		if approved {
+			// The preimage is fetched and checked against its hash, it stays requested for a retry
+			let result = T::Preimages::peek(&call).and_then(|(call, _)| {
+				call.dispatch(T::EnactmentOrigin::get()).map(|_| ()).map_err(|e| e.error)
+			});
+			if let Err(error) = result {
+				if let Some(retry) = Pallet::<T, I>::schedule_enactment_retry(proposal_id) {
+					Self::deposit_event(Event::<T, I>::EnactmentRetryScheduled {
+						proposal_id,
+						retry,
+						error,
+					});
+					return
+				}
+				ArchivedProposals::<T, I>::mutate(proposal_id, |archive| {
+					if let Some(archive) = archive {
+						archive.enactment_failure = Some(error);
+					}
+				});
+			}
+			Self::deposit_event(Event::<T, I>::ProposalEnacted { proposal_id, result });
+		}
+		ProposalCalls::<T, I>::remove(proposal_id);
+		EnactmentRetries::<T, I>::remove(proposal_id);
+		T::Preimages::drop(&call);
+	}
+
+	/// Schedules the next retry of the enactment of the call of a proposal with the `Scheduler`,
+	/// unless its retries ran out. Returns the number of the retry.
+	fn schedule_enactment_retry(proposal_id: ProposalId) -> Option<u32> {
+		let retry = EnactmentRetries::<T, I>::get(proposal_id).saturating_add(1);
+		if retry > T::MaxEnactmentRetries::get() {
+			return None
+		}
+		let call =
+			<T as Config<I>>::RuntimeCall::from(Call::<T, I>::retry_enactment { proposal_id });
+		let when = Pallet::<T, I>::get_current_block_number()
+			.saturating_add(T::EnactmentRetryPeriod::get().into());
+		T::Scheduler::schedule_named(
+			(T::PalletId::get(), b"enactment", proposal_id, retry)
+				.using_encoded(frame_support::sp_io::hashing::blake2_256),
+			DispatchTime::At(when),
+			None,
+			LOWEST_PRIORITY,
+			frame_system::RawOrigin::Root.into(),
+			T::Preimages::bound(call).ok()?,
+		)
+		.ok()?;
+		EnactmentRetries::<T, I>::insert(proposal_id, retry);
+		Some(retry)
+	}
+
+	/// Removes the call of a proposal, unrequesting its preimage.
+	fn drop_call(proposal_id: ProposalId) {
+		if let Some(call) = ProposalCalls::<T, I>::take(proposal_id) {
+			T::Preimages::drop(&call);
+		}
+	}
+
+	fn archive(proposal_id: ProposalId, creator: T::AccountId, outcome: ProposalOutcome) {
+		let archived_at = Pallet::<T, I>::get_current_block_number();
+		Juries::<T, I>::remove(proposal_id);
+		Pallet::<T, I>::return_sponsorship(proposal_id, &creator);
+		Pallet::<T, I>::release_deposit(proposal_id, &creator);
+		Pallet::<T, I>::sweep_lottery(proposal_id);
+		ProposalStatuses::<T, I>::insert(proposal_id, ProposalStatus::from(outcome));
+		ArchivedProposals::<T, I>::insert(
+			proposal_id,
+			ArchivedProposal {
+				creator,
+				outcome,
+				archived_at,
+				overridden: None,
+				turnout_scale: None,
+				abstentions: None,
+				voter_cap: None,
+				enactment_failure: None,
+			},
+		);
+		Pallet::<T, I>::enqueue_cleanup(
+			CleanupLane::Retention,
+			archived_at.saturating_add(T::ArchiveRetention::get().into()),
+			CleanupTask::PruneArchive { proposal_id },
+		);
+		if Seconds::<T, I>::iter_key_prefix(proposal_id).next().is_some() {
+			Pallet::<T, I>::enqueue_cleanup(
+				CleanupLane::Immediate,
+				archived_at,
+				CleanupTask::ClearSeconds { proposal_id },
+			);
+		}
+	}
+
+	/// Folds the outcome and the votes root of a pruned archive into the archive tree of the
+	/// epoch in which it was archived.
+	fn compress_archive(
+		proposal_id: ProposalId,
+		outcome: ProposalOutcome,
+		archived_at: BlockNumberFor<T>,
+		votes_root: [u8; 32],
+	) {
+		let era = Pallet::<T, I>::epoch_at(archived_at);
+		let archive = CompressedArchive { proposal_id, outcome, votes_root };
+		let root = ArchiveTrees::<T, I>::mutate(era, |tree| {
+			tree.append(archive_leaf(&archive));
+			CompressedArchives::<T, I>::insert(era, tree.leaves.saturating_sub(1), archive);
+			tree.root
+		});
+		Self::deposit_event(Event::ArchiveCompressed { proposal_id, era, root });
+	}
+
+	fn enqueue_cleanup(
+		lane: CleanupLane,
+		due_block: BlockNumberFor<T>,
+		task: CleanupTask<T::AccountId>,
+	) {
+		CleanupQueueBounds::<T, I>::mutate(lane, |(_, tail)| {
+			CleanupQueue::<T, I>::insert(lane, *tail, (due_block, task));
+			*tail = tail.wrapping_add(1);
+		});
+	}
+
+	/// The worst case weight of closing a proposal, queueing the enactment of its call included.
+	fn close_weight() -> Weight {
+		T::WeightInfo::close_proposal(T::AuditSampleSize::get(), T::MaxLotteryWinners::get())
+			.saturating_add(T::DbWeight::get().reads_writes(2, 2))
+	}
+
+	/// The worst case weight of enacting the call of a closed proposal: reading its archive,
+	/// reading and unrequesting its preimage, dispatching it, and scheduling a retry or recording
+	/// the failure.
+	fn enactment_weight() -> Weight {
+		T::DbWeight::get()
+			.reads_writes(6, 5)
+			.saturating_add(Weight::from_parts(0, T::MaxCallSize::get().into()))
+			.saturating_add(T::MaxEnactmentWeight::get())
+	}
+
+	/// The worst case weight of a single cleanup step, the cursor of a drain included.
+	fn cleanup_step_weight() -> Weight {
+		Weight::from_parts(10_000, 0).saturating_add(T::DbWeight::get().reads_writes(6, 6))
+	}
+
+	/// Processes the due cleanup tasks, lane by lane, as long as the remaining weight allows it.
+	/// The releases of closed proposals are limited to `MaxReleasesPerBlock` steps per block and
+	/// their progress is reported with events.
+	fn process_cleanup_queue(remaining_weight: Weight) -> Weight {
+		// The tasks read the storage at the current layout, they wait for the stepped migration
+		if Pallet::<T, I>::migrating() {
+			return Pallet::<T, I>::step_migration(remaining_weight)
+		}
+		let current_block = Pallet::<T, I>::get_current_block_number();
+		let step_weight = Pallet::<T, I>::cleanup_step_weight();
+		let max_releases = T::MaxReleasesPerBlock::get();
+		let mut consumed_weight = Weight::zero();
+		let mut releases: u32 = 0;
+		let mut release_batch: Option<(ProposalId, u32)> = None;
+
+		let lanes = [
+			CleanupLane::Immediate,
+			CleanupLane::Retention,
+			CleanupLane::Expiry,
+			CleanupLane::DisputePeriod,
+			CleanupLane::Enactment,
+		];
+		'lanes: for lane in lanes {
+			loop {
+				let next_weight = consumed_weight.saturating_add(step_weight);
+				if next_weight.any_gt(remaining_weight) {
+					break 'lanes
+				}
+				consumed_weight = next_weight;
+
+				let Some((position, task)) =
+					Pallet::<T, I>::next_due_cleanup_task(lane, current_block)
+				else {
+					break
+				};
+				// Enacting a call dispatches it, on top of the step
+				if let CleanupTask::EnactCall { .. } = task {
+					let next_weight =
+						consumed_weight.saturating_add(Pallet::<T, I>::enactment_weight());
+					if next_weight.any_gt(remaining_weight) {
+						break 'lanes
+					}
+					consumed_weight = next_weight;
+				}
+				let release_id = match task {
+					CleanupTask::ReleaseFreezes { proposal_id, .. } => Some(proposal_id),
+					_ => None,
+				};
+				if release_id.is_some() && releases >= max_releases {
+					break
+				}
+
+				let completed = Pallet::<T, I>::process_cleanup_step(&task);
+				if completed {
+					CleanupQueue::<T, I>::remove(lane, position);
+					CleanupQueueBounds::<T, I>::mutate(lane, |(head, _)| {
+						*head = head.wrapping_add(1)
+					});
+				}
+
+				if let Some(proposal_id) = release_id {
+					releases.saturating_inc();
+					if completed {
+						Pallet::<T, I>::deposit_release_progress(release_batch.take());
+						Self::deposit_event(Event::FreezesReleaseCompleted { proposal_id });
+					} else {
+						let (_, released) = release_batch.get_or_insert((proposal_id, 0));
+						released.saturating_inc();
+					}
+				}
+			}
+		}
+
+		Pallet::<T, I>::deposit_release_progress(release_batch);
+		consumed_weight
+	}
+
+	/// Steps the ongoing stepped migration, one entry or leaf per cleanup step, as long as the
+	/// remaining weight allows it.
+	fn step_migration(remaining_weight: Weight) -> Weight {
+		let step_weight = Pallet::<T, I>::cleanup_step_weight();
+		let mut consumed_weight = T::DbWeight::get().reads(1);
+		loop {
+			let next_weight = consumed_weight.saturating_add(step_weight);
+			if next_weight.any_gt(remaining_weight) {
+				break
+			}
+			let Some(weight) = migrations::step::<T, I>() else { break };
+			consumed_weight = next_weight.saturating_add(weight);
+		}
+		consumed_weight
+	}
+
+	fn deposit_release_progress(release_batch: Option<(ProposalId, u32)>) {
+		if let Some((proposal_id, released)) = release_batch {
+			Self::deposit_event(Event::FreezesReleased { proposal_id, released });
+		}
+	}
+
+	/// Returns the task at the head of a lane and its position if it is due.
+	fn next_due_cleanup_task(
+		lane: CleanupLane,
+		current_block: BlockNumberFor<T>,
+	) -> Option<(u32, CleanupTask<T::AccountId>)> {
+		let (mut head, tail) = CleanupQueueBounds::<T, I>::get(lane);
+		while head != tail {
+			match CleanupQueue::<T, I>::get(lane, head) {
+				Some((due_block, task)) =>
+					return if due_block <= current_block { Some((head, task)) } else { None },
+				None => {
+					// Defensive: skip a missing task
+					head = head.wrapping_add(1);
+					CleanupQueueBounds::<T, I>::insert(lane, (head, tail));
+				},
+			}
+		}
+		None
+	}
+
+	/// Processes one step of a cleanup task. Returns `true` once the task is completed.
+	fn process_cleanup_step(task: &CleanupTask<T::AccountId>) -> bool {
+		match task {
+			CleanupTask::ReleaseFreezes { proposal_id, frozen } => {
+				let Some(voter) = Contributions::<T, I>::iter_key_prefix(proposal_id)
+					.next()
+					.or_else(|| Answers::<T, I>::iter_key_prefix(proposal_id).next())
+					.or_else(|| OptionChoices::<T, I>::iter_key_prefix(proposal_id).next())
+					.or_else(|| Commitments::<T, I>::iter_key_prefix(proposal_id).next())
+				else {
+					Pallet::<T, I>::sweep_fees(*proposal_id);
+					ClosedVoteCosts::<T, I>::remove(proposal_id);
+					return true
+				};
+				Contributions::<T, I>::remove(proposal_id, &voter);
+				Answers::<T, I>::remove(proposal_id, &voter);
+				OptionChoices::<T, I>::remove(proposal_id, &voter);
+				// The collateral of a commitment never revealed is released as is
+				if let Some((_, max_power)) =
+					Pallet::<T, I>::remove_commitment(*proposal_id, &voter)
+				{
+					let fee = ClosedVoteCosts::<T, I>::get(proposal_id)
+						.map_or(Percent::zero(), |vote_cost| vote_cost.release_fee());
+					// Defensive: releasing a hold or returning an escrow cannot fail
+					let burned =
+						Pallet::<T, I>::release_collateral(&voter, *proposal_id, fee, max_power)
+							.unwrap_or_default();
+					let amount = Pallet::<T, I>::calculate_quadratic_amount(max_power)
+						.saturating_sub(burned);
+					Self::deposit_event(Event::BalanceClaimed { who: voter.clone(), amount });
+				}
+				Pallet::<T, I>::remove_delegated_vote(*proposal_id, &voter);
+				Pallet::<T, I>::pay_lottery_prize(*proposal_id, &voter, &voter);
+				if let Some(vote) = Votes::<T, I>::get(&voter, proposal_id) {
+					Pallet::<T, I>::pay_fee_reward(*proposal_id, &voter, vote.aye);
+					// The collateral of a vote with conviction or a claim delay is left to `claim`
+					// once unlocked
+					if *frozen &&
+						(vote.conviction != Conviction::None ||
+							ClaimsOpenAt::<T, I>::contains_key(proposal_id))
+					{
+						return false
+					}
+					Votes::<T, I>::remove(&voter, proposal_id);
+					if *frozen {
+						let released = Pallet::<T, I>::calculate_quadratic_amount(vote.power);
+						let fee = ClosedVoteCosts::<T, I>::get(proposal_id)
+							.map_or(Percent::zero(), |vote_cost| vote_cost.release_fee());
+						// Defensive: releasing a hold or returning an escrow cannot fail
+						let burned = Pallet::<T, I>::release_collateral(
+							&voter,
+							*proposal_id,
+							fee,
+							vote.power,
+						)
+						.unwrap_or_default();
+						let amount = released.saturating_sub(burned);
+						Self::deposit_event(Event::BalanceClaimed { who: voter, amount });
+					}
+				}
+				false
+			},
+			CleanupTask::PruneArchive { proposal_id } => {
+				// The archive of a disputed proposal is kept until the dispute is resolved
+				if Disputes::<T, I>::contains_key(proposal_id) {
+					let retention = T::ArchiveRetention::get().into();
+					Pallet::<T, I>::enqueue_cleanup(
+						CleanupLane::Retention,
+						Pallet::<T, I>::get_current_block_number().saturating_add(retention),
+						CleanupTask::PruneArchive { proposal_id: *proposal_id },
+					);
+					return true
+				}
+				// The votes log is pruned one leaf per step
+				if let Some(index) = VoteLog::<T, I>::iter_key_prefix(proposal_id).next() {
+					VoteLog::<T, I>::remove(proposal_id, index);
+					return false
+				}
+				let votes_root = VotesTrees::<T, I>::take(proposal_id).root;
+				if let Some(archive) = ArchivedProposals::<T, I>::take(proposal_id) {
+					Pallet::<T, I>::compress_archive(
+						*proposal_id,
+						archive.outcome,
+						archive.archived_at,
+						votes_root,
+					);
+				}
+				ProposalStatuses::<T, I>::remove(proposal_id);
+				QuestionOutcomes::<T, I>::remove(proposal_id);
+				OptionOutcomes::<T, I>::remove(proposal_id);
+				EligibilityBitmaps::<T, I>::remove(proposal_id);
+				ProposalAssets::<T, I>::remove(proposal_id);
+				ClaimsOpenAt::<T, I>::remove(proposal_id);
+				true
+			},
+			CleanupTask::DrainVoter { who } => {
+				// The votes are visited one per step from the cursor
+				let mut votes = Votes::<T, I>::iter_prefix_values(who);
+				if let Some(cursor) = DrainCursors::<T, I>::get(who).filter(|c| !c.is_empty()) {
+					votes.set_last_raw_key(cursor.into_inner());
+				}
+				let Some(vote) = votes.next() else {
+					DrainCursors::<T, I>::remove(who);
+					return true
+				};
+				// Defensive: a raw key of a vote fits in a cursor
+				let cursor =
+					BoundedVec::try_from(votes.last_raw_key().to_vec()).unwrap_or_default();
+				DrainCursors::<T, I>::insert(who, cursor);
+				// The votes of closed proposals are released by their own task
+				if Proposals::<T, I>::contains_key(vote.proposal_id) {
+					Pallet::<T, I>::drop_vote(who, vote);
+				}
+				false
+			},
+			CleanupTask::RevokeDelegations { delegate } => {
+				// The revocation is abandoned if the delegate registered again
+				if RegisteredVoters::<T, I>::contains_key(delegate) {
+					return true
+				}
+				if let Some((proposal_id, delegator)) =
+					DelegateCasts::<T, I>::iter_key_prefix(delegate).next()
+				{
+					// The votes of closed proposals are released by their own task
+					match Votes::<T, I>::get(&delegator, proposal_id)
+						.filter(|_| Proposals::<T, I>::contains_key(proposal_id))
+					{
+						Some(vote) => {
+							Pallet::<T, I>::drop_vote(&delegator, vote);
+							Self::deposit_event(Event::VoteDropped {
+								proposal_id,
+								voter: delegator,
+							});
+						},
+						None => DelegateCasts::<T, I>::remove(delegate, (proposal_id, delegator)),
+					}
+					return false
+				}
+				let Some(delegator) = Delegators::<T, I>::iter_key_prefix(delegate).next() else {
+					return true
+				};
+				Delegations::<T, I>::remove(&delegator);
+				Delegators::<T, I>::remove(delegate, &delegator);
+				Self::deposit_event(Event::DelegationRevoked {
+					who: delegator,
+					delegate: delegate.clone(),
+				});
+				false
+			},
+			CleanupTask::ClearSeconds { proposal_id } => {
+				let Some(who) = Seconds::<T, I>::iter_key_prefix(proposal_id).next() else {
+					return true
+				};
+				Seconds::<T, I>::remove(proposal_id, who);
+				false
+			},
+			CleanupTask::ExpireVoter { who } => {
+				// A renewed registration has a later expiry task
+				let current_block = Pallet::<T, I>::get_current_block_number();
+				let expired = RegisteredVoters::<T, I>::get(who)
+					.map_or(false, |voter| voter.is_expired(&current_block));
+				if expired {
+					Pallet::<T, I>::deregister(who);
+					Self::deposit_event(Event::VoterExpired { who: who.clone() });
+				}
+				true
+			},
+			CleanupTask::EndDisputePeriod { proposal_id } => {
+				// A disputed proposal is kept until the dispute is resolved
+				if !Disputes::<T, I>::contains_key(proposal_id) {
+					DisputableProposals::<T, I>::remove(proposal_id);
+				}
+				true
+			},
+			CleanupTask::EnactCall { proposal_id } => {
+				// The call of a disputed proposal is enacted once the dispute is resolved
+				if !Disputes::<T, I>::contains_key(proposal_id) {
+					Pallet::<T, I>::enact(*proposal_id);
+				}
+				true
+			},
+			CleanupTask::SyncVoters { preimage_hash } => {
+				let Some(mut sync) = VoterSyncs::<T, I>::get(preimage_hash) else { return true };
+				// A missing preimage or a malformed account ends the sync
+				let next = (sync.remaining > 0)
+					.then(|| T::Preimages::fetch(preimage_hash, Some(sync.len)).ok())
+					.flatten()
+					.and_then(|data| {
+						let mut input = data.get(sync.cursor as usize..)?;
+						let before = input.len();
+						let entry = <(T::AccountId, bool)>::decode(&mut input).ok()?;
+						Some((entry, (before - input.len()) as u32))
+					});
+				let Some(((who, add), consumed)) = next else {
+					VoterSyncs::<T, I>::remove(preimage_hash);
+					T::Preimages::unrequest(preimage_hash);
+					Self::deposit_event(Event::VotersSynced {
+						preimage_hash: *preimage_hash,
+						added: sync.added,
+						removed: sync.removed,
+						skipped: sync.skipped,
+					});
+					return true
+				};
+
+				if add {
+					if !RegisteredVoters::<T, I>::contains_key(&who) {
+						if Pallet::<T, I>::register(&who).is_ok() {
+							sync.added.saturating_inc();
+							Self::deposit_event(Event::NewVoterRegistered { who });
+						} else {
+							sync.skipped.saturating_inc();
+						}
+					}
+				} else if RegisteredVoters::<T, I>::contains_key(&who) {
+					Pallet::<T, I>::deregister(&who);
+					sync.removed.saturating_inc();
+					Self::deposit_event(Event::VoterUnregistered { who });
+				}
+				sync.cursor.saturating_accrue(consumed);
+				sync.remaining.saturating_dec();
+				VoterSyncs::<T, I>::insert(preimage_hash, sync);
+				false
+			},
+		}
+	}
+
+	/// Registers an attested voter holding the minimum balance. Registering a voter again keeps
+	/// its registration. Returns `true` if `who` was not registered yet.
+	fn register(who: &T::AccountId) -> Result<bool, DispatchError> {
+		ensure!(T::PersonhoodProvider::is_attested(who), Error::<T, I>::PersonhoodNotAttested);
+		ensure!(T::IdentityVerifier::contains(who), Error::<T, I>::IdentityNotVerified);
+		ensure!(Pallet::<T, I>::has_min_balance(who), Error::<T, I>::BalanceBelowMinimum);
+		if RegisteredVoters::<T, I>::contains_key(who) {
+			return Ok(false)
+		}
+		ensure!(!DrainCursors::<T, I>::contains_key(who), Error::<T, I>::VotesDraining);
+		let registered_at = Pallet::<T, I>::get_current_block_number();
+		let expires_at = Pallet::<T, I>::schedule_expiry(who, registered_at);
+		RegisteredVoters::<T, I>::insert(who, VoterInfo { registered_at, expires_at });
+		RegisteredVotersCount::<T, I>::mutate(|count| *count = count.saturating_add(1));
+		Pallet::<T, I>::snapshot_balance(who);
+		if !VoterIndices::<T, I>::contains_key(who) {
+			VoterIndices::<T, I>::insert(who, NextVoterIndex::<T, I>::get());
+			NextVoterIndex::<T, I>::mutate(|index| index.saturating_inc());
+		}
+		Ok(true)
+	}
+
+	/// Counts a voter registered or unregistered by the deputy registrar `who` against its limit,
+	/// starting a new period once the current one is over. Fails with `not_deputy` if `who` is
+	/// not a deputy registrar.
+	fn use_deputy(who: &T::AccountId, not_deputy: DispatchError) -> DispatchResult {
+		Deputies::<T, I>::try_mutate(who, |maybe_deputy| {
+			let deputy = maybe_deputy.as_mut().ok_or(not_deputy)?;
+			let now = Pallet::<T, I>::get_current_block_number();
+			if now >= deputy.period_start.saturating_add(T::DeputyPeriod::get().into()) {
+				deputy.period_start = now;
+				deputy.used = 0;
+			}
+			ensure!(deputy.used < deputy.limit, Error::<T, I>::DeputyLimitReached);
+			deputy.used.saturating_inc();
 			Ok(())
+		})
+	}
+
+	/// Moves the registration of `old` to `new` with its votes, the votes it cast as a delegate,
+	/// its delegations and its held collateral. Returns the number of votes, votes cast as a
+	/// delegate and delegators moved.
+	fn migrate(old: &T::AccountId, new: &T::AccountId) -> Result<u32, DispatchError> {
+		use frame_support::traits::fungible::MutateHold;
+
+		ensure!(old != new, Error::<T, I>::AccountInUse);
+		// A commitment is bound to its voter
+		ensure!(!PendingCommitments::<T, I>::contains_key(old), Error::<T, I>::CommitmentsPending);
+		let voter = RegisteredVoters::<T, I>::take(old).ok_or(Error::<T, I>::VoterNotRegistered)?;
+		// An account registered once keeps its voter index, it cannot receive another one
+		ensure!(
+			!VoterIndices::<T, I>::contains_key(new) &&
+				Delegators::<T, I>::iter_key_prefix(new).next().is_none(),
+			Error::<T, I>::AccountInUse
+		);
+		// The votes are keyed with a non-reversible hasher, their proposal is read from the vote
+		let votes: Vec<_> = Votes::<T, I>::iter_prefix_values(old).collect();
+		let casts: Vec<_> = DelegateCasts::<T, I>::drain_prefix(old).collect();
+		let delegators: Vec<_> = Delegators::<T, I>::drain_prefix(old).collect();
+		let entries = votes.len().saturating_add(casts.len()).saturating_add(delegators.len());
+		ensure!(
+			entries <= T::MaxMigratedEntries::get() as usize,
+			Error::<T, I>::TooManyEntriesToMigrate
+		);
+
+		if let Some(expires_at) = voter.expires_at {
+			Pallet::<T, I>::enqueue_cleanup(
+				CleanupLane::Expiry,
+				expires_at,
+				CleanupTask::ExpireVoter { who: new.clone() },
+			);
+		}
+		RegisteredVoters::<T, I>::insert(new, voter);
+		if let Some(index) = VoterIndices::<T, I>::take(old) {
+			VoterIndices::<T, I>::insert(new, index);
+		}
+		if let Some(snapshot) = BalanceSnapshots::<T, I>::take(old) {
+			BalanceSnapshots::<T, I>::insert(new, snapshot);
+		}
+		SpentCredits::<T, I>::insert(new, SpentCredits::<T, I>::take(old));
+		if let Some(epoch) = EpochVoters::<T, I>::take(old) {
+			EpochVoters::<T, I>::insert(new, epoch);
+		}
+
+		let _ = Votes::<T, I>::clear_prefix(old, votes.len() as u32, None);
+		for vote in votes {
+			let proposal_id = vote.proposal_id;
+			// The votes tree of a closed proposal is final
+			if Proposals::<T, I>::contains_key(proposal_id) {
+				Pallet::<T, I>::log_vote(
+					proposal_id,
+					old,
+					vote.aye,
+					Zero::zero(),
+					Conviction::None,
+				);
+				Pallet::<T, I>::log_vote(proposal_id, new, vote.aye, vote.power, vote.conviction);
+			}
+			Votes::<T, I>::insert(new, proposal_id, vote);
+			if let Some(contribution) = Contributions::<T, I>::take(proposal_id, old) {
+				Contributions::<T, I>::insert(proposal_id, new, contribution);
+			}
+			if let Some(answers) = Answers::<T, I>::take(proposal_id, old) {
+				Answers::<T, I>::insert(proposal_id, new, answers);
+			}
+			if let Some(choice) = OptionChoices::<T, I>::take(proposal_id, old) {
+				OptionChoices::<T, I>::insert(proposal_id, new, choice);
+			}
+			if let Some(prize) = LotteryPrizes::<T, I>::take(proposal_id, old) {
+				LotteryPrizes::<T, I>::insert(proposal_id, new, prize);
+			}
+			if let Some((delegate, hops)) = DelegatedVotes::<T, I>::take(proposal_id, old) {
+				DelegateCasts::<T, I>::remove(&delegate, (proposal_id, old));
+				DelegateCasts::<T, I>::insert(&delegate, (proposal_id, new.clone()), ());
+				DelegatedVotes::<T, I>::insert(proposal_id, new, (delegate, hops));
+			}
+		}
+		for ((proposal_id, delegator), ()) in casts {
+			DelegatedVotes::<T, I>::mutate(proposal_id, &delegator, |maybe_cast| {
+				if let Some((delegate, _)) = maybe_cast {
+					*delegate = new.clone();
+				}
+			});
+			DelegateCasts::<T, I>::insert(new, (proposal_id, delegator), ());
+		}
+		if let Some(delegate) = Delegations::<T, I>::take(old) {
+			Delegators::<T, I>::remove(&delegate, old);
+			Delegators::<T, I>::insert(&delegate, new, ());
+			Delegations::<T, I>::insert(new, delegate);
+		}
+		for (delegator, ()) in delegators {
+			Delegations::<T, I>::insert(&delegator, new);
+			Delegators::<T, I>::insert(new, delegator, ());
+		}
+
+		// The collateral is transferred on hold, with the consumer reference
+		let held = Pallet::<T, I>::held_for_voting(old);
+		if !held.is_zero() {
+			T::NativeBalance::transfer_on_hold(
+				&HoldReason::VoteCollateral.into(),
+				old,
+				new,
+				held,
+				Precision::Exact,
+				Restriction::OnHold,
+				Fortitude::Polite,
+			)?;
+			frame_system::Pallet::<T>::dec_consumers(old);
+			frame_system::Pallet::<T>::inc_consumers(new)?;
+		}
+		// The registration bond follows the registration
+		if let Some(bond) = RegistrationBonds::<T, I>::take(old) {
+			if !bond.is_zero() {
+				T::NativeBalance::transfer_on_hold(
+					&HoldReason::RegistrationBond.into(),
+					old,
+					new,
+					bond,
+					Precision::Exact,
+					Restriction::OnHold,
+					Fortitude::Polite,
+				)?;
+			}
+			RegistrationBonds::<T, I>::insert(new, bond);
+		}
+		Ok(entries as u32)
+	}
+
+	/// Removes the registration of a voter, its votes are dropped lazily by the cleanup queue, as
+	/// are the votes it cast as a delegate and the delegations it received. Returns whether the
+	/// voter has votes to drop and whether it is a delegate.
+	fn deregister(who: &T::AccountId) -> (bool, bool) {
+		if RegisteredVoters::<T, I>::take(who).is_some() {
+			RegisteredVotersCount::<T, I>::mutate(|count| *count = count.saturating_sub(1));
+		}
+		Pallet::<T, I>::release_registration_bond(who);
+		BalanceSnapshots::<T, I>::remove(who);
+		if let Some(delegate) = Delegations::<T, I>::take(who) {
+			Delegators::<T, I>::remove(delegate, who);
+		}
+		let current_block = Pallet::<T, I>::get_current_block_number();
+		let has_votes = Votes::<T, I>::iter_prefix_values(who).next().is_some();
+		// A voter has a single drain in progress
+		if has_votes && !DrainCursors::<T, I>::contains_key(who) {
+			Pallet::<T, I>::enqueue_cleanup(
+				CleanupLane::Immediate,
+				current_block,
+				CleanupTask::DrainVoter { who: who.clone() },
+			);
+			DrainCursors::<T, I>::insert(who, BoundedVec::default());
+		}
+		let is_delegate = DelegateCasts::<T, I>::iter_key_prefix(who).next().is_some() ||
+			Delegators::<T, I>::iter_key_prefix(who).next().is_some();
+		if is_delegate {
+			Pallet::<T, I>::enqueue_cleanup(
+				CleanupLane::Immediate,
+				current_block,
+				CleanupTask::RevokeDelegations { delegate: who.clone() },
+			);
+		}
+		(has_votes, is_delegate)
+	}
+
+	/// Returns the bond of a voter registered with `self_register`.
+	fn release_registration_bond(who: &T::AccountId) {
+		use frame_support::traits::fungible::MutateHold;
+
+		let Some(bond) = RegistrationBonds::<T, I>::take(who) else { return };
+		if bond.is_zero() {
+			return
+		}
+		let released = T::NativeBalance::release(
+			&HoldReason::RegistrationBond.into(),
+			who,
+			bond,
+			Precision::BestEffort,
+		);
+		if let Ok(amount) = released {
+			Self::deposit_event(Event::RegistrationBondReturned { who: who.clone(), amount });
+		}
+	}
+
+	/// Forgets that the vote of `delegator` on a proposal was cast by a delegate.
+	fn remove_delegated_vote(proposal_id: ProposalId, delegator: &T::AccountId) {
+		if let Some((delegate, _)) = DelegatedVotes::<T, I>::take(proposal_id, delegator) {
+			DelegateCasts::<T, I>::remove(delegate, (proposal_id, delegator));
+		}
+	}
+
+	/// Schedules the expiry of a registration made or renewed at `from`, if registrations
+	/// expire, and returns the expiry block.
+	fn schedule_expiry(who: &T::AccountId, from: BlockNumberFor<T>) -> Option<BlockNumberFor<T>> {
+		let expires_at = from.saturating_add(T::RegistrationTtl::get()?.into());
+		Pallet::<T, I>::enqueue_cleanup(
+			CleanupLane::Expiry,
+			expires_at,
+			CleanupTask::ExpireVoter { who: who.clone() },
+		);
+		Some(expires_at)
+	}
+
+	/// Removes a vote of a proposal in progress, its collateral and its contribution to the tally.
+	fn drop_vote(who: &T::AccountId, vote: VoteInfo<PowerOf<T, I>>) {
+		Proposals::<T, I>::mutate(vote.proposal_id, |maybe_proposal| {
+			if let Some(proposal) = maybe_proposal {
+				// Defensive: releasing a hold cannot fail
+				let _ = Pallet::<T, I>::release_power(
+					who,
+					vote.proposal_id,
+					&proposal.settings,
+					vote.power,
+					Zero::zero(),
+				);
+				Pallet::<T, I>::log_vote(
+					vote.proposal_id,
+					who,
+					vote.aye,
+					Zero::zero(),
+					Conviction::None,
+				);
+				// The registration may be gone, the contribution holds the tallied amount
+				if let Some((aye, amount)) = Contributions::<T, I>::get(vote.proposal_id, who) {
+					Pallet::<T, I>::reveal_tally(vote.proposal_id, proposal);
+					proposal.discard_contribution(aye, amount);
+					Pallet::<T, I>::conceal_tally(vote.proposal_id, proposal);
+				}
+				if let Some(answers) = Answers::<T, I>::take(vote.proposal_id, who) {
+					let mut tallies = QuestionTallies::<T, I>::get(vote.proposal_id).into_inner();
+					Pallet::<T, I>::discard_answers(&mut tallies, &answers);
+					QuestionTallies::<T, I>::insert(
+						vote.proposal_id,
+						BoundedVec::truncate_from(tallies),
+					);
+				}
+				Pallet::<T, I>::discard_choice(vote.proposal_id, proposal, who);
+				proposal.remove_voter();
+				Pallet::<T, I>::count_role_voter(vote.proposal_id, &proposal.settings, who, false);
+			}
+		});
+		Contributions::<T, I>::remove(vote.proposal_id, who);
+		Pallet::<T, I>::remove_delegated_vote(vote.proposal_id, who);
+		Votes::<T, I>::remove(who, vote.proposal_id);
+	}
+
+	/// Number of delegation hops from `delegator` to `delegate`, if `delegate` is reached within
+	/// `MaxDelegationDepth` hops. A chain looping back to the delegator never reaches it.
+	fn delegation_hops(delegator: &T::AccountId, delegate: &T::AccountId) -> Option<u32> {
+		let mut current = delegator.clone();
+		for hops in 1..=T::MaxDelegationDepth::get() {
+			current = Delegations::<T, I>::get(&current)?;
+			if current == *delegator {
+				return None
+			}
+			if current == *delegate {
+				return Some(hops)
+			}
+		}
+		None
+	}
+
+	fn do_vote_as_delegate(
+		delegate: T::AccountId,
+		delegator: T::AccountId,
+		proposal_id: ProposalId,
+		aye: bool,
+		power: PowerOf<T, I>,
+	) -> DispatchResult {
+		let hops = Pallet::<T, I>::delegation_hops(&delegator, &delegate)
+			.ok_or(Error::<T, I>::NotDelegate)?;
+		let voter =
+			RegisteredVoters::<T, I>::get(&delegator).ok_or(Error::<T, I>::VoterNotRegistered)?;
+
+		let current_block = Pallet::<T, I>::get_current_block_number();
+		ensure!(!voter.is_expired(&current_block), Error::<T, I>::RegistrationExpired);
+		ensure!(Pallet::<T, I>::has_min_balance(&delegator), Error::<T, I>::BalanceBelowMinimum);
+
+		// A previous delegated vote is replaced as a whole, its hops may have changed
+		let prev_vote = Votes::<T, I>::get(&delegator, proposal_id);
+		let edits_vote = prev_vote.is_some();
+		if let Some(vote) = prev_vote {
+			ensure!(
+				DelegatedVotes::<T, I>::contains_key(proposal_id, &delegator),
+				Error::<T, I>::DirectVoteTakesPrecedence
+			);
+			Pallet::<T, I>::drop_vote(&delegator, vote);
+		}
+
+		let mut proposal =
+			Proposals::<T, I>::get(proposal_id).ok_or(Error::<T, I>::ProposalDoesNotExist)?;
+		Pallet::<T, I>::ensure_eligible(&delegator, proposal_id, &proposal, &current_block, None)?;
+		if edits_vote {
+			Pallet::<T, I>::ensure_editable(&proposal, &current_block)?;
+		}
+		ensure!(proposal.settings.reveal_period.is_none(), Error::<T, I>::SecretBallot);
+		ensure!(proposal.settings.questions == 0, Error::<T, I>::QuestionsMismatch);
+		ensure!(proposal.options.is_empty(), Error::<T, I>::OptionsMismatch);
+		ensure!(proposal.settings.is_valid_power(power), Error::<T, I>::PowerNotMultipleOfStep);
+		ensure!(power <= T::MaxPower::get(), Error::<T, I>::PowerTooHigh);
+
+		if power.is_zero() {
+			Self::deposit_event(Event::VoteDropped { proposal_id, voter: delegator });
+			return Ok(())
+		}
+
+		Pallet::<T, I>::lock_power(
+			&delegator,
+			proposal_id,
+			&proposal.settings,
+			Zero::zero(),
+			power,
+		)?;
+		Pallet::<T, I>::charge_vote_fee(&delegator, proposal_id, &proposal.settings)?;
+		Pallet::<T, I>::refund_vote(&delegator, proposal_id);
+
+		let bonus = Pallet::<T, I>::vote_bonus(&delegator, &voter, &proposal);
+		let amount =
+			Pallet::<T, I>::attenuate(tallied_amount(power, bonus).unwrap_or(u128::MAX), hops);
+
+		Pallet::<T, I>::reveal_tally(proposal_id, &mut proposal);
+		if proposal.add_contribution(aye, amount) && !proposal.settings.hidden_tally {
+			Self::deposit_event(Event::TallySaturated { proposal_id });
+		}
+		Pallet::<T, I>::conceal_tally(proposal_id, &mut proposal);
+		proposal.add_voter();
+		Pallet::<T, I>::count_role_voter(proposal_id, &proposal.settings, &delegator, true);
+		Pallet::<T, I>::note_epoch_voter(&delegator);
+		let anonymous = proposal.settings.anonymous_events;
+		Proposals::<T, I>::insert(proposal_id, proposal);
+
+		Votes::<T, I>::insert(
+			&delegator,
+			proposal_id,
+			VoteInfo { proposal_id, aye, power, conviction: Conviction::None },
+		);
+		Pallet::<T, I>::log_vote(proposal_id, &delegator, aye, power, Conviction::None);
+		Contributions::<T, I>::insert(proposal_id, &delegator, (aye, amount));
+		DelegatedVotes::<T, I>::insert(proposal_id, &delegator, (delegate.clone(), hops));
+		DelegateCasts::<T, I>::insert(&delegate, (proposal_id, delegator.clone()), ());
+		// The delegation would tell the voter of an anonymous vote
+		if anonymous {
+			Self::deposit_event(Event::AnonymousVoteAdded { proposal_id, aye, power });
+		} else {
+			Self::deposit_event(Event::VoteAdded {
+				proposal_id,
+				voter: delegator.clone(),
+				aye,
+				power,
+			});
+			Self::deposit_event(Event::DelegatedVoteCast {
+				proposal_id,
+				delegator,
+				delegate,
+				hops,
+			});
+		}
+		Ok(())
+	}
+
+	/// The tallied `amount` of a vote cast `hops` delegations away from the voter.
+	fn attenuate(amount: u128, hops: u32) -> u128 {
+		math::attenuate(amount, T::DelegationAttenuation::get(), hops)
+	}
+
+	fn do_recount(proposal_id: ProposalId, limit: u32) -> DispatchResult {
+		let mut proposal =
+			Proposals::<T, I>::get(proposal_id).ok_or(Error::<T, I>::ProposalDoesNotExist)?;
+		ensure!(proposal.settings.questions == 0, Error::<T, I>::QuestionsMismatch);
+		ensure!(proposal.options.is_empty(), Error::<T, I>::OptionsMismatch);
+		Pallet::<T, I>::reveal_tally(proposal_id, &mut proposal);
+
+		let started_from = (proposal.ratio, proposal.voters_count);
+		let mut recount = Recounts::<T, I>::get(proposal_id)
+			.filter(|recount| recount.started_from == started_from)
+			.unwrap_or(Recount { started_from, ..Default::default() });
+		let mut contributions = match &recount.cursor {
+			Some(cursor) => Contributions::<T, I>::iter_prefix_from(proposal_id, cursor.to_vec()),
+			None => Contributions::<T, I>::iter_prefix(proposal_id),
+		};
+
+		let mut recounted: u32 = 0;
+		loop {
+			let last_key = contributions.last_raw_key().to_vec();
+			let Some((voter, (_, amount))) = contributions.next() else { break };
+			if recounted == limit {
+				// Defensive: a cursor too long to be stored restarts the recount
+				recount.cursor = BoundedVec::try_from(last_key).ok();
+				Recounts::<T, I>::insert(proposal_id, recount);
+				return Ok(())
+			}
+			recounted.saturating_inc();
+
+			let Some(vote) = Votes::<T, I>::get(&voter, proposal_id) else { continue };
+			// The registration may be gone, the contribution holds the tallied amount
+			let amount = match RegisteredVoters::<T, I>::get(&voter) {
+				Some(info) => {
+					let bonus = Pallet::<T, I>::vote_bonus(&voter, &info, &proposal);
+					let tallied = tallied_amount(vote.power, bonus)
+						.and_then(|amount| vote.conviction.apply(amount))
+						.unwrap_or(u128::MAX);
+					let hops = DelegatedVotes::<T, I>::get(proposal_id, &voter)
+						.map_or(0, |(_, hops)| hops);
+					Pallet::<T, I>::attenuate(tallied, hops)
+				},
+				None => amount,
+			};
+			if vote.aye {
+				recount.tally.0 = recount.tally.0.saturating_add(amount);
+			}
+			recount.tally.1 = recount.tally.1.saturating_add(amount);
+		}
+
+		let signed = |amount: u128| i128::try_from(amount).unwrap_or(i128::MAX);
+		let ayes_diff = signed(recount.tally.0).saturating_sub(signed(proposal.ratio.0));
+		let total_diff = signed(recount.tally.1).saturating_sub(signed(proposal.ratio.1));
+		proposal.ratio = recount.tally;
+		Pallet::<T, I>::conceal_tally(proposal_id, &mut proposal);
+		Proposals::<T, I>::insert(proposal_id, proposal);
+		Recounts::<T, I>::remove(proposal_id);
+		Self::deposit_event(Event::TallyRecounted { proposal_id, ayes_diff, total_diff });
+		Ok(())
+	}
+
+	/// Audits the votes of a proposal in progress from the contribution following the cursor.
+	fn do_audit(proposal_id: ProposalId, limit: u32) -> DispatchResult {
+		let proposal =
+			Proposals::<T, I>::get(proposal_id).ok_or(Error::<T, I>::ProposalDoesNotExist)?;
+		ensure!(!proposal.settings.shared_budget, Error::<T, I>::SharedBudgetNotFrozen);
+		ensure!(proposal.settings.vote_cost.is_frozen(), Error::<T, I>::CostNotFrozen);
+
+		let mut contributions = match AuditCursors::<T, I>::get(proposal_id) {
+			Some(cursor) => Contributions::<T, I>::iter_prefix_from(proposal_id, cursor.to_vec()),
+			None => Contributions::<T, I>::iter_prefix(proposal_id),
+		};
+
+		let mut audited: u32 = 0;
+		loop {
+			let last_key = contributions.last_raw_key().to_vec();
+			let Some((voter, _)) = contributions.next() else { break };
+			if audited == limit {
+				// Defensive: a cursor too long to be stored restarts the audit
+				AuditCursors::<T, I>::set(proposal_id, BoundedVec::try_from(last_key).ok());
+				return Ok(())
+			}
+			audited.saturating_inc();
+
+			Pallet::<T, I>::audit_vote(proposal_id, voter);
+		}
+
+		AuditCursors::<T, I>::remove(proposal_id);
+		Self::deposit_event(Event::ProposalAudited { proposal_id });
+		Ok(())
+	}
+
+	/// Checks that `voter` holds a vote on a proposal and that its hold covers the quadratic
+	/// cost of the vote, reporting a discrepancy with an `AuditFinding`. Returns whether a
+	/// discrepancy was found.
+	fn audit_vote(proposal_id: ProposalId, voter: T::AccountId) -> bool {
+		let issue = match Votes::<T, I>::get(&voter, proposal_id) {
+			// The escrowed collateral of a proposal voted with an asset cannot fall short
+			Some(_) if ProposalAssets::<T, I>::contains_key(proposal_id) => return false,
+			Some(vote) => {
+				let cost = Pallet::<T, I>::calculate_quadratic_amount(vote.power);
+				let frozen = Pallet::<T, I>::held_for_voting(&voter);
+				if frozen >= cost {
+					return false
+				}
+				AuditIssue::FreezeShortfall { frozen, cost }
+			},
+			None => AuditIssue::VoteMissing,
+		};
+		Self::deposit_event(Event::AuditFinding { proposal_id, voter, issue });
+		true
+	}
+
+	/// Draws `count` distinct voters of a proposal, or all of them when fewer voted, with the
+	/// randomness of `subject`. Like `draw_jury`, the contributions of the proposal are reservoir
+	/// sampled, so that each voter has the same chance to be drawn.
+	fn draw_voters(proposal_id: ProposalId, subject: &[u8], count: u32) -> Vec<T::AccountId> {
+		let (seed, _) = T::Randomness::random(subject);
+		let mut voters = Vec::new();
+		let mut candidates: u32 = 0;
+		for who in Contributions::<T, I>::iter_key_prefix(proposal_id) {
+			if voters.len() < count as usize {
+				voters.push(who);
+			} else {
+				let hash = (seed, candidates).blake2_256();
+				let random = u32::decode(&mut &hash[..]).unwrap_or_default();
+				let index = random.checked_rem(candidates.saturating_add(1)).unwrap_or_default();
+				if let Some(voter) = voters.get_mut(index as usize) {
+					*voter = who;
+				}
+			}
+			candidates.saturating_inc();
+		}
+		voters
+	}
+
+	/// Audits a random sample of up to `AuditSampleSize` votes of a proposal being closed, before
+	/// their holds are released.
+	fn audit_sample(proposal_id: ProposalId) {
+		let sample_size = T::AuditSampleSize::get();
+		if sample_size == 0 {
+			return
+		}
+		let subject = (b"voting/audit", proposal_id).encode();
+		let sample = Pallet::<T, I>::draw_voters(proposal_id, &subject, sample_size);
+
+		let sampled = sample.len() as u32;
+		let findings = sample
+			.into_iter()
+			.filter(|voter| Pallet::<T, I>::audit_vote(proposal_id, voter.clone()))
+			.count() as u32;
+		Self::deposit_event(Event::SampleAudited { proposal_id, sampled, findings });
+	}
+
+	/// Checks that a vote already cast on `proposal` can still be changed at `current_block`.
+	fn ensure_editable(
+		proposal: &ProposalData<T, I>,
+		current_block: &BlockNumberFor<T>,
+	) -> DispatchResult {
+		ensure!(!proposal.settings.immutable_ballot, Error::<T, I>::BallotImmutable);
+		ensure!(!proposal.edit_window_closed(current_block), Error::<T, I>::EditWindowClosed);
+		Ok(())
+	}
+
+	/// Checks that `caller` can vote on `proposal` at `current_block` and returns the number of
+	/// accounts scanned in its account list.
+	fn ensure_eligible(
+		caller: &T::AccountId,
+		proposal_id: ProposalId,
+		proposal: &ProposalData<T, I>,
+		current_block: &BlockNumberFor<T>,
+		maybe_proof: Option<MembershipProof>,
+	) -> Result<u32, DispatchError> {
+		ensure!(proposal.has_started(current_block), Error::<T, I>::ProposalHasNotStartedYet);
+		ensure!(!proposal.has_ended(current_block), Error::<T, I>::ProposalHasAlreadyEnded);
+		ensure!(
+			proposal.is_seconded(T::SecondsThreshold::get()),
+			Error::<T, I>::ProposalNotSeconded
+		);
+		if let Some(org) = proposal.org {
+			ensure!(OrgMembers::<T, I>::contains_key(org, caller), Error::<T, I>::NotOrgMember);
+		}
+
+		let mut scanned_accounts: u32 = 0;
+		if let Some(members_root) = proposal.settings.members_root {
+			let proof = maybe_proof.ok_or(Error::<T, I>::MembershipProofRequired)?;
+			ensure!(
+				verify_membership(&members_root, caller, &proof),
+				Error::<T, I>::OriginNoPermission
+			);
+		} else if let Some(bitmap) = EligibilityBitmaps::<T, I>::get(proposal_id) {
+			let allowed_voter = VoterIndices::<T, I>::get(caller)
+				.map_or(false, |index| bitmap_contains(&bitmap, index));
+			ensure!(allowed_voter, Error::<T, I>::OriginNoPermission)
+		} else {
+			scanned_accounts = proposal.account_list.as_ref().map_or(0, |list| list.len() as u32);
+			ensure!(T::KindHandler::is_allowed(proposal, caller), Error::<T, I>::OriginNoPermission)
+		}
+		if proposal.settings.nft_gated {
+			ensure!(Pallet::<T, I>::nft_pass(caller).is_some(), Error::<T, I>::NftPassRequired);
+		}
+		if proposal.settings.jury_size.is_some() {
+			let jury = Juries::<T, I>::get(proposal_id).ok_or(Error::<T, I>::JuryNotDrawn)?;
+			ensure!(jury.contains(caller), Error::<T, I>::NotInJury);
+		}
+		if let Some(max_voters) = proposal.settings.max_voters {
+			ensure!(
+				proposal.voters_count < max_voters ||
+					Votes::<T, I>::contains_key(caller, proposal_id) ||
+					(proposal.settings.voter_admission == VoterAdmission::Priority &&
+						ExpectedVoters::<T, I>::contains_key(proposal_id, caller)),
+				Error::<T, I>::VoterCapReached
+			);
+		}
+		Ok(scanned_accounts)
+	}
+
+	fn do_vote(
+		caller: T::AccountId,
+		proposal_id: ProposalId,
+		aye: bool,
+		power: PowerOf<T, I>,
+		conviction: Conviction,
+		maybe_proof: Option<MembershipProof>,
+	) -> DispatchResultWithPostInfo {
+		let voter = RegisteredVoters::<T, I>::get(caller.clone())
+			.ok_or(Error::<T, I>::VoterNotRegistered)?;
+
+		let current_block = Pallet::<T, I>::get_current_block_number();
+		ensure!(!voter.is_expired(&current_block), Error::<T, I>::RegistrationExpired);
+		ensure!(Pallet::<T, I>::has_min_balance(&caller), Error::<T, I>::BalanceBelowMinimum);
+		let mut scanned_accounts: u32 = 0;
+		let with_proof = maybe_proof.is_some();
+
+		// A direct vote overrides the vote cast by a delegate
+		let edits_vote = Votes::<T, I>::contains_key(&caller, proposal_id);
+		if DelegatedVotes::<T, I>::contains_key(proposal_id, &caller) {
+			if let Some(vote) = Votes::<T, I>::get(&caller, proposal_id) {
+				Pallet::<T, I>::drop_vote(&caller, vote);
+			}
+		}
+
+		Proposals::<T, I>::try_mutate(proposal_id, |maybe_proposal| -> DispatchResult {
+			let proposal = maybe_proposal.as_mut().ok_or(Error::<T, I>::ProposalDoesNotExist)?;
+
+			scanned_accounts = Pallet::<T, I>::ensure_eligible(
+				&caller,
+				proposal_id,
+				proposal,
+				&current_block,
+				maybe_proof,
+			)?;
+			if edits_vote {
+				Pallet::<T, I>::ensure_editable(proposal, &current_block)?;
+			}
+			ensure!(proposal.settings.reveal_period.is_none(), Error::<T, I>::SecretBallot);
+			ensure!(proposal.settings.questions == 0, Error::<T, I>::QuestionsMismatch);
+			ensure!(proposal.options.is_empty(), Error::<T, I>::OptionsMismatch);
+			ensure!(proposal.settings.is_valid_power(power), Error::<T, I>::PowerNotMultipleOfStep);
+			ensure!(power <= T::MaxPower::get(), Error::<T, I>::PowerTooHigh);
+			// The collateral of a vote with conviction is kept held after the close
+			ensure!(
+				conviction == Conviction::None ||
+					(proposal.settings.vote_cost == VoteCost::Frozen &&
+						!proposal.settings.shared_budget),
+				Error::<T, I>::ConvictionNotSupported
+			);
+
+			let bonus = Pallet::<T, I>::vote_bonus(&caller, &voter, proposal);
+			let amount = tallied_amount(power, bonus)
+				.and_then(|amount| conviction.apply(amount))
+				.unwrap_or(u128::MAX);
+			let hidden_tally = proposal.settings.hidden_tally;
+			Pallet::<T, I>::reveal_tally(proposal_id, proposal);
+			let maybe_vote = Votes::<T, I>::get(caller.clone(), proposal_id);
+			if let Some(vote) = maybe_vote {
+				ensure!(
+					!(vote.power == power && vote.aye == aye && vote.conviction == conviction),
+					Error::<T, I>::IdenticVote
+				); // TODO: Is useful?
+				let prev_power = vote.power;
+				if prev_power.lt(&power) {
+					Pallet::<T, I>::lock_power(
+						&caller,
+						proposal_id,
+						&proposal.settings,
+						prev_power,
+						power,
+					)?;
+				} else {
+					Pallet::<T, I>::release_power(
+						&caller,
+						proposal_id,
+						&proposal.settings,
+						prev_power,
+						power,
+					)?;
+				}
+				// The previous contribution is replaced as a whole, the NFT bonus of the voter
+				// may have changed since
+				if let Some((prev_aye, prev_amount)) =
+					Contributions::<T, I>::get(proposal_id, &caller)
+				{
+					proposal.discard_contribution(prev_aye, prev_amount);
+				}
+				if proposal.add_contribution(aye, amount) && !hidden_tally {
+					Self::deposit_event(Event::TallySaturated { proposal_id });
+				}
+				if power.is_zero() {
+					proposal.remove_voter();
+					Pallet::<T, I>::count_role_voter(
+						proposal_id,
+						&proposal.settings,
+						&caller,
+						false,
+					);
+				}
+			} else {
+				Pallet::<T, I>::lock_power(
+					&caller,
+					proposal_id,
+					&proposal.settings,
+					Zero::zero(),
+					power,
+				)?;
+				if proposal.add_contribution(aye, amount) && !hidden_tally {
+					Self::deposit_event(Event::TallySaturated { proposal_id });
+				}
+				if !power.is_zero() {
+					Pallet::<T, I>::charge_vote_fee(&caller, proposal_id, &proposal.settings)?;
+					Pallet::<T, I>::refund_vote(&caller, proposal_id);
+					proposal.add_voter();
+					Pallet::<T, I>::count_role_voter(
+						proposal_id,
+						&proposal.settings,
+						&caller,
+						true,
+					);
+					Pallet::<T, I>::note_epoch_voter(&caller);
+				}
+			}
+			Pallet::<T, I>::conceal_tally(proposal_id, proposal);
+
+			Pallet::<T, I>::log_vote(proposal_id, &caller, aye, power, conviction);
+			if power.is_zero() {
+				Votes::<T, I>::remove(caller.clone(), proposal_id);
+				Contributions::<T, I>::remove(proposal_id, caller.clone());
+				Self::deposit_event(Event::VoteDropped { proposal_id, voter: caller });
+			} else {
+				Votes::<T, I>::insert(
+					caller.clone(),
+					proposal_id,
+					VoteInfo { proposal_id, aye, power, conviction },
+				);
+				Contributions::<T, I>::insert(proposal_id, caller.clone(), (aye, amount));
+				if proposal.settings.anonymous_events {
+					Self::deposit_event(Event::AnonymousVoteAdded { proposal_id, aye, power });
+				} else {
+					Self::deposit_event(Event::VoteAdded {
+						proposal_id,
+						voter: caller,
+						aye,
+						power,
+					});
+				}
+			}
+
+			Ok(().into())
+		})?;
+
+		// Votes without an account list to check are refunded the scan weight, votes with a proof
+		// are charged their own weight. The close of a settled proposal is charged on top.
+		if with_proof {
+			return Ok(None::<Weight>.into())
+		}
+		let mut weight = T::WeightInfo::vote(scanned_accounts);
+		if Pallet::<T, I>::close_if_settled(proposal_id) {
+			weight.saturating_accrue(Pallet::<T, I>::close_weight());
+		}
+		Ok(Some(weight).into())
+	}
+
+	fn do_vote_questions(
+		caller: T::AccountId,
+		proposal_id: ProposalId,
+		answers: BoundedVec<(bool, PowerOf<T, I>), T::MaxQuestions>,
+		maybe_proof: Option<MembershipProof>,
+	) -> DispatchResultWithPostInfo {
+		let voter = RegisteredVoters::<T, I>::get(caller.clone())
+			.ok_or(Error::<T, I>::VoterNotRegistered)?;
+
+		let current_block = Pallet::<T, I>::get_current_block_number();
+		ensure!(!voter.is_expired(&current_block), Error::<T, I>::RegistrationExpired);
+		ensure!(Pallet::<T, I>::has_min_balance(&caller), Error::<T, I>::BalanceBelowMinimum);
+
+		let mut proposal =
+			Proposals::<T, I>::get(proposal_id).ok_or(Error::<T, I>::ProposalDoesNotExist)?;
+		let scanned_accounts = Pallet::<T, I>::ensure_eligible(
+			&caller,
+			proposal_id,
+			&proposal,
+			&current_block,
+			maybe_proof,
+		)?;
+		let questions = proposal.settings.questions;
+		ensure!(
+			questions > 0 && answers.len() == questions as usize,
+			Error::<T, I>::QuestionsMismatch
+		);
+		for (_, power) in answers.iter() {
+			ensure!(
+				proposal.settings.is_valid_power(*power),
+				Error::<T, I>::PowerNotMultipleOfStep
+			);
+			ensure!(*power <= T::MaxPower::get(), Error::<T, I>::PowerTooHigh);
+		}
+
+		// A single collateral backs all the answers
+		let power = answers.iter().map(|(_, power)| *power).max().unwrap_or_default();
+		let maybe_vote = Votes::<T, I>::get(caller.clone(), proposal_id);
+		if maybe_vote.is_some() {
+			Pallet::<T, I>::ensure_editable(&proposal, &current_block)?;
+		}
+		let prev_power = maybe_vote.as_ref().map_or(Zero::zero(), |vote| vote.power);
+		if prev_power.lt(&power) {
+			Pallet::<T, I>::lock_power(
+				&caller,
+				proposal_id,
+				&proposal.settings,
+				prev_power,
+				power,
+			)?;
+		} else {
+			Pallet::<T, I>::release_power(
+				&caller,
+				proposal_id,
+				&proposal.settings,
+				prev_power,
+				power,
+			)?;
+		}
+
+		let bonus = Pallet::<T, I>::vote_bonus(&caller, &voter, &proposal);
+		let mut tallies = QuestionTallies::<T, I>::get(proposal_id).into_inner();
+		tallies.resize(questions as usize, VoteRatio::default());
+		if let Some(prev_answers) = Answers::<T, I>::get(proposal_id, &caller) {
+			Pallet::<T, I>::discard_answers(&mut tallies, &prev_answers);
+		}
+		let tallied_answers = BoundedVec::<_, T::MaxQuestions>::truncate_from(
+			answers
+				.iter()
+				.map(|(aye, power)| (*aye, tallied_amount(*power, bonus).unwrap_or(u128::MAX)))
+				.collect(),
+		);
+		for ((aye, amount), (ayes, total)) in tallied_answers.iter().zip(tallies.iter_mut()) {
+			if *aye {
+				*ayes = ayes.saturating_add(*amount);
+			}
+			*total = total.saturating_add(*amount);
+		}
+		QuestionTallies::<T, I>::insert(proposal_id, BoundedVec::truncate_from(tallies));
+
+		if power.is_zero() {
+			if maybe_vote.is_some() {
+				proposal.remove_voter();
+				Pallet::<T, I>::count_role_voter(proposal_id, &proposal.settings, &caller, false);
+			}
+			Votes::<T, I>::remove(caller.clone(), proposal_id);
+			Pallet::<T, I>::log_vote(proposal_id, &caller, true, power, Conviction::None);
+			Answers::<T, I>::remove(proposal_id, caller.clone());
+			Self::deposit_event(Event::VoteDropped { proposal_id, voter: caller });
+		} else {
+			if maybe_vote.is_none() {
+				Pallet::<T, I>::charge_vote_fee(&caller, proposal_id, &proposal.settings)?;
+				Pallet::<T, I>::refund_vote(&caller, proposal_id);
+				proposal.add_voter();
+				Pallet::<T, I>::count_role_voter(proposal_id, &proposal.settings, &caller, true);
+				Pallet::<T, I>::note_epoch_voter(&caller);
+			}
+			// The vote only records the collateral, the answers hold the sides
+			Votes::<T, I>::insert(
+				caller.clone(),
+				proposal_id,
+				VoteInfo { proposal_id, aye: true, power, conviction: Conviction::None },
+			);
+			Pallet::<T, I>::log_vote(proposal_id, &caller, true, power, Conviction::None);
+			Answers::<T, I>::insert(proposal_id, caller.clone(), tallied_answers);
+			if proposal.settings.anonymous_events {
+				Self::deposit_event(Event::AnonymousQuestionsVoted { proposal_id, answers });
+			} else {
+				Self::deposit_event(Event::QuestionsVoted { proposal_id, voter: caller, answers });
+			}
+		}
+		Proposals::<T, I>::insert(proposal_id, proposal);
+
+		Ok(Some(T::WeightInfo::vote_questions(scanned_accounts)).into())
+	}
+
+	fn do_vote_option(
+		caller: T::AccountId,
+		proposal_id: ProposalId,
+		option: u32,
+		power: PowerOf<T, I>,
+		maybe_proof: Option<MembershipProof>,
+	) -> DispatchResultWithPostInfo {
+		let voter = RegisteredVoters::<T, I>::get(caller.clone())
+			.ok_or(Error::<T, I>::VoterNotRegistered)?;
+
+		let current_block = Pallet::<T, I>::get_current_block_number();
+		ensure!(!voter.is_expired(&current_block), Error::<T, I>::RegistrationExpired);
+		ensure!(Pallet::<T, I>::has_min_balance(&caller), Error::<T, I>::BalanceBelowMinimum);
+
+		let mut proposal =
+			Proposals::<T, I>::get(proposal_id).ok_or(Error::<T, I>::ProposalDoesNotExist)?;
+		let scanned_accounts = Pallet::<T, I>::ensure_eligible(
+			&caller,
+			proposal_id,
+			&proposal,
+			&current_block,
+			maybe_proof,
+		)?;
+		let options = proposal.options.len();
+		ensure!((option as usize) < options, Error::<T, I>::OptionsMismatch);
+		ensure!(proposal.settings.is_valid_power(power), Error::<T, I>::PowerNotMultipleOfStep);
+		ensure!(power <= T::MaxPower::get(), Error::<T, I>::PowerTooHigh);
+
+		let maybe_vote = Votes::<T, I>::get(caller.clone(), proposal_id);
+		if maybe_vote.is_some() {
+			Pallet::<T, I>::ensure_editable(&proposal, &current_block)?;
+		}
+		let prev_power = maybe_vote.as_ref().map_or(Zero::zero(), |vote| vote.power);
+		if prev_power.lt(&power) {
+			Pallet::<T, I>::lock_power(
+				&caller,
+				proposal_id,
+				&proposal.settings,
+				prev_power,
+				power,
+			)?;
+		} else {
+			Pallet::<T, I>::release_power(
+				&caller,
+				proposal_id,
+				&proposal.settings,
+				prev_power,
+				power,
+			)?;
+		}
+
+		// The ratio of the proposal only counts the turnout until it is closed
+		let bonus = Pallet::<T, I>::vote_bonus(&caller, &voter, &proposal);
+		let mut tallies = OptionTallies::<T, I>::get(proposal_id).into_inner();
+		tallies.resize(options, 0);
+		if let Some((prev_option, prev_amount)) = OptionChoices::<T, I>::get(proposal_id, &caller) {
+			if let Some(tally) = tallies.get_mut(prev_option as usize) {
+				*tally = tally.saturating_sub(prev_amount);
+			}
+			proposal.discard_contribution(false, prev_amount);
+		}
+
+		if power.is_zero() {
+			if maybe_vote.is_some() {
+				proposal.remove_voter();
+				Pallet::<T, I>::count_role_voter(proposal_id, &proposal.settings, &caller, false);
+			}
+			Votes::<T, I>::remove(caller.clone(), proposal_id);
+			Pallet::<T, I>::log_vote(proposal_id, &caller, true, power, Conviction::None);
+			OptionChoices::<T, I>::remove(proposal_id, caller.clone());
+			Self::deposit_event(Event::VoteDropped { proposal_id, voter: caller });
+		} else {
+			if maybe_vote.is_none() {
+				Pallet::<T, I>::charge_vote_fee(&caller, proposal_id, &proposal.settings)?;
+				Pallet::<T, I>::refund_vote(&caller, proposal_id);
+				proposal.add_voter();
+				Pallet::<T, I>::count_role_voter(proposal_id, &proposal.settings, &caller, true);
+				Pallet::<T, I>::note_epoch_voter(&caller);
+			}
+			let amount = tallied_amount(power, bonus).unwrap_or(u128::MAX);
+			if let Some(tally) = tallies.get_mut(option as usize) {
+				*tally = tally.saturating_add(amount);
+			}
+			if proposal.add_contribution(false, amount) {
+				Self::deposit_event(Event::TallySaturated { proposal_id });
+			}
+			// The vote only records the collateral, the choice holds the option
+			Votes::<T, I>::insert(
+				caller.clone(),
+				proposal_id,
+				VoteInfo { proposal_id, aye: true, power, conviction: Conviction::None },
+			);
+			Pallet::<T, I>::log_vote(proposal_id, &caller, true, power, Conviction::None);
+			OptionChoices::<T, I>::insert(proposal_id, caller.clone(), (option, amount));
+			if proposal.settings.anonymous_events {
+				Self::deposit_event(Event::AnonymousOptionVoted { proposal_id, option, power });
+			} else {
+				Self::deposit_event(Event::OptionVoted {
+					proposal_id,
+					voter: caller,
+					option,
+					power,
+				});
+			}
+		}
+		OptionTallies::<T, I>::insert(proposal_id, BoundedVec::truncate_from(tallies));
+		Proposals::<T, I>::insert(proposal_id, proposal);
+
+		Ok(Some(T::WeightInfo::vote_option(scanned_accounts)).into())
+	}
+
+	/// The commitment of `who` to a vote on a secret ballot, binding the voter and the proposal
+	/// so that a commitment cannot be replayed.
+	pub fn vote_commitment(
+		who: &T::AccountId,
+		proposal_id: ProposalId,
+		aye: bool,
+		power: PowerOf<T, I>,
+		salt: [u8; 32],
+	) -> T::Hash {
+		T::Hashing::hash_of(&(who, proposal_id, aye, power, salt))
+	}
+
+	fn do_commit_vote(
+		caller: T::AccountId,
+		proposal_id: ProposalId,
+		commitment: T::Hash,
+		max_power: PowerOf<T, I>,
+		maybe_proof: Option<MembershipProof>,
+	) -> DispatchResultWithPostInfo {
+		let voter = RegisteredVoters::<T, I>::get(caller.clone())
+			.ok_or(Error::<T, I>::VoterNotRegistered)?;
+
+		let current_block = Pallet::<T, I>::get_current_block_number();
+		ensure!(!voter.is_expired(&current_block), Error::<T, I>::RegistrationExpired);
+		ensure!(Pallet::<T, I>::has_min_balance(&caller), Error::<T, I>::BalanceBelowMinimum);
+
+		let proposal =
+			Proposals::<T, I>::get(proposal_id).ok_or(Error::<T, I>::ProposalDoesNotExist)?;
+		let scanned_accounts = Pallet::<T, I>::ensure_eligible(
+			&caller,
+			proposal_id,
+			&proposal,
+			&current_block,
+			maybe_proof,
+		)?;
+		ensure!(proposal.settings.reveal_period.is_some(), Error::<T, I>::NotSecretBallot);
+		ensure!(!max_power.is_zero(), Error::<T, I>::InvalidCommitment);
+		ensure!(max_power <= T::MaxPower::get(), Error::<T, I>::PowerTooHigh);
+
+		let maybe_commitment = Commitments::<T, I>::get(proposal_id, &caller);
+		let prev_power = match maybe_commitment {
+			Some((_, prev_power)) => {
+				Pallet::<T, I>::ensure_editable(&proposal, &current_block)?;
+				prev_power
+			},
+			None => {
+				Pallet::<T, I>::charge_vote_fee(&caller, proposal_id, &proposal.settings)?;
+				Pallet::<T, I>::refund_vote(&caller, proposal_id);
+				PendingCommitments::<T, I>::mutate(&caller, |pending| pending.saturating_inc());
+				Zero::zero()
+			},
+		};
+		if prev_power.lt(&max_power) {
+			Pallet::<T, I>::lock_power(
+				&caller,
+				proposal_id,
+				&proposal.settings,
+				prev_power,
+				max_power,
+			)?;
+		} else {
+			Pallet::<T, I>::release_power(
+				&caller,
+				proposal_id,
+				&proposal.settings,
+				prev_power,
+				max_power,
+			)?;
+		}
+		Commitments::<T, I>::insert(proposal_id, &caller, (commitment, max_power));
+		Self::deposit_event(Event::VoteCommitted { proposal_id, voter: caller });
+
+		Ok(Some(T::WeightInfo::commit_vote(scanned_accounts)).into())
+	}
+
+	fn do_reveal_vote(
+		caller: T::AccountId,
+		proposal_id: ProposalId,
+		aye: bool,
+		power: PowerOf<T, I>,
+		salt: [u8; 32],
+	) -> DispatchResult {
+		let voter = RegisteredVoters::<T, I>::get(caller.clone())
+			.ok_or(Error::<T, I>::VoterNotRegistered)?;
+		let current_block = Pallet::<T, I>::get_current_block_number();
+		let mut proposal =
+			Proposals::<T, I>::get(proposal_id).ok_or(Error::<T, I>::ProposalDoesNotExist)?;
+		ensure!(proposal.settings.reveal_period.is_some(), Error::<T, I>::NotSecretBallot);
+		ensure!(proposal.has_ended(&current_block), Error::<T, I>::ProposalHasNotEndedYet);
+
+		let (commitment, max_power) = Commitments::<T, I>::get(proposal_id, &caller)
+			.ok_or(Error::<T, I>::CommitmentNotFound)?;
+		ensure!(
+			commitment == Pallet::<T, I>::vote_commitment(&caller, proposal_id, aye, power, salt) &&
+				!power.is_zero() &&
+				power <= max_power,
+			Error::<T, I>::InvalidReveal
+		);
+		ensure!(proposal.settings.is_valid_power(power), Error::<T, I>::PowerNotMultipleOfStep);
+		Pallet::<T, I>::release_power(&caller, proposal_id, &proposal.settings, max_power, power)?;
+
+		let bonus = Pallet::<T, I>::vote_bonus(&caller, &voter, &proposal);
+		let hidden_tally = proposal.settings.hidden_tally;
+		Pallet::<T, I>::reveal_tally(proposal_id, &mut proposal);
+		if proposal.add_ratio(aye, Zero::zero(), power, bonus) && !hidden_tally {
+			Self::deposit_event(Event::TallySaturated { proposal_id });
+		}
+		proposal.add_voter();
+		Pallet::<T, I>::count_role_voter(proposal_id, &proposal.settings, &caller, true);
+		Pallet::<T, I>::note_epoch_voter(&caller);
+		Pallet::<T, I>::conceal_tally(proposal_id, &mut proposal);
+
+		Pallet::<T, I>::remove_commitment(proposal_id, &caller);
+		Votes::<T, I>::insert(
+			caller.clone(),
+			proposal_id,
+			VoteInfo { proposal_id, aye, power, conviction: Conviction::None },
+		);
+		Pallet::<T, I>::log_vote(proposal_id, &caller, aye, power, Conviction::None);
+		Contributions::<T, I>::insert(
+			proposal_id,
+			caller.clone(),
+			(aye, tallied_amount(power, bonus).unwrap_or(u128::MAX)),
+		);
+		if proposal.settings.anonymous_events {
+			Self::deposit_event(Event::AnonymousVoteAdded { proposal_id, aye, power });
+		} else {
+			Self::deposit_event(Event::VoteRevealed { proposal_id, voter: caller, aye, power });
+		}
+		Proposals::<T, I>::insert(proposal_id, proposal);
+		Ok(())
+	}
+
+	/// Appends the vote of `who` on a proposal in progress to its votes tree, a dropped vote with
+	/// a zero power.
+	fn log_vote(
+		proposal_id: ProposalId,
+		who: &T::AccountId,
+		aye: bool,
+		power: PowerOf<T, I>,
+		conviction: Conviction,
+	) {
+		let vote = VoteInfo { proposal_id, aye, power, conviction };
+		VotesTrees::<T, I>::mutate(proposal_id, |tree| {
+			tree.append(vote_leaf(who, &vote));
+			VoteLog::<T, I>::insert(
+				proposal_id,
+				tree.leaves.saturating_sub(1),
+				(who.clone(), vote),
+			);
+		});
+	}
+
+	/// Removes the commitment of `who` on a secret ballot once revealed or released.
+	fn remove_commitment(
+		proposal_id: ProposalId,
+		who: &T::AccountId,
+	) -> Option<(T::Hash, PowerOf<T, I>)> {
+		let commitment = Commitments::<T, I>::take(proposal_id, who)?;
+		PendingCommitments::<T, I>::mutate_exists(who, |pending| {
+			*pending =
+				pending.map(|pending| pending.saturating_sub(1)).filter(|pending| *pending > 0);
+		});
+		Some(commitment)
+	}
+
+	/// Removes the choice of `who` from the option tallies of a multi-option proposal.
+	fn discard_choice(
+		proposal_id: ProposalId,
+		proposal: &mut ProposalData<T, I>,
+		who: &T::AccountId,
+	) {
+		let Some((option, amount)) = OptionChoices::<T, I>::take(proposal_id, who) else { return };
+		OptionTallies::<T, I>::mutate(proposal_id, |tallies| {
+			if let Some(tally) = tallies.get_mut(option as usize) {
+				*tally = tally.saturating_sub(amount);
+			}
+		});
+		proposal.discard_contribution(false, amount);
+	}
+
+	/// Removes the tallied `answers` of a voter from the question tallies.
+	fn discard_answers(tallies: &mut [VoteRatio], answers: &[(bool, u128)]) {
+		for ((aye, amount), (ayes, total)) in answers.iter().zip(tallies.iter_mut()) {
+			if *aye {
+				*ayes = ayes.saturating_sub(*amount);
+			}
+			*total = total.saturating_sub(*amount);
+		}
+	}
+
+	/// Records the outcome of each question of a closed bundled proposal and returns whether they
+	/// are all approved.
+	fn close_questions(
+		proposal_id: ProposalId,
+		proposal: &ProposalData<T, I>,
+		electorate: u32,
+	) -> bool {
+		let mut tallies = QuestionTallies::<T, I>::take(proposal_id).into_inner();
+		tallies.resize(proposal.settings.questions as usize, VoteRatio::default());
+		let outcomes = BoundedVec::<_, T::MaxQuestions>::truncate_from(
+			tallies
+				.into_iter()
+				.map(|ratio| {
+					let approved = proposal.is_question_approved(ratio, electorate) &&
+						Pallet::<T, I>::reaches_issuance_quorum(proposal_id, proposal, ratio.1);
+					(ratio, approved)
+				})
+				.collect(),
+		);
+
+		let approved = outcomes.iter().all(|(_, approved)| *approved);
+		QuestionOutcomes::<T, I>::insert(proposal_id, outcomes.clone());
+		Self::deposit_event(Event::QuestionsCompleted { proposal_id, outcomes });
+		approved
+	}
+
+	/// Records the tally of each option of a closed multi-option proposal and its winner, and
+	/// returns whether the winner is approved: it must lead alone and the proposal must reach its
+	/// quorums. The ratio of the proposal becomes the tally of the winner out of the turnout.
+	fn close_options(
+		proposal_id: ProposalId,
+		proposal: &mut ProposalData<T, I>,
+		electorate: u32,
+	) -> bool {
+		let mut tallies = OptionTallies::<T, I>::take(proposal_id).into_inner();
+		tallies.resize(proposal.options.len(), 0);
+		let winner = plurality_winner(&tallies);
+		proposal.ratio.0 = winner
+			.and_then(|winner| tallies.get(winner as usize).copied())
+			.unwrap_or_default();
+		let tallies = BoundedVec::<_, T::MaxOptions>::truncate_from(tallies);
+
+		OptionOutcomes::<T, I>::insert(proposal_id, (tallies.clone(), winner));
+		Self::deposit_event(Event::OptionsCompleted { proposal_id, tallies, winner });
+		winner.is_some() &&
+			proposal.has_quorum(electorate) &&
+			Pallet::<T, I>::reaches_issuance_quorum(proposal_id, proposal, proposal.ratio.1)
+	}
+
+	/// Whether `turnout` reaches the share of the total issuance required by a proposal. The
+	/// issuance is read at close, it is not known to the proposal.
+	fn reaches_issuance_quorum(
+		proposal_id: ProposalId,
+		proposal: &ProposalData<T, I>,
+		turnout: u128,
+	) -> bool {
+		let Some(quorum) = proposal.settings.issuance_quorum else { return true };
+		let issuance: u128 = match ProposalAssets::<T, I>::get(proposal_id) {
+			Some(asset) => <T::Assets as fungibles::Inspect<T::AccountId>>::total_issuance(asset),
+			None => <T::NativeBalance as fungible::Inspect<T::AccountId>>::total_issuance(),
+		}
+		.saturated_into();
+		turnout >= quorum.mul_ceil(issuance)
+	}
+
+	/// Whether each role quorum of a proposal is reached by the voters holding the role.
+	fn reaches_role_quorums(proposal_id: ProposalId, proposal: &ProposalData<T, I>) -> bool {
+		proposal
+			.settings
+			.role_quorums
+			.iter()
+			.all(|(role, quorum)| RoleTurnouts::<T, I>::get(proposal_id, role) >= *quorum)
+	}
+
+	/// Counts `who` in, or out, the voters of a proposal holding each role of its role quorums.
+	/// The roles are read when the vote is cast or dropped.
+	fn count_role_voter(
+		proposal_id: ProposalId,
+		settings: &ProposalSettings,
+		who: &T::AccountId,
+		joins: bool,
+	) {
+		for (role, _) in settings.role_quorums.iter() {
+			if CreatorRoles::<T, I>::contains_key(who, role) {
+				RoleTurnouts::<T, I>::mutate(proposal_id, role, |count| {
+					*count = if joins { count.saturating_add(1) } else { count.saturating_sub(1) };
+				});
+			}
+		}
+	}
+
+	/// Checks the bundled questions, the jury size and the caps of new proposal settings.
+	fn ensure_valid_settings(settings: &ProposalSettings) -> DispatchResult {
+		ensure!(settings.questions <= T::MaxQuestions::get(), Error::<T, I>::TooManyQuestions);
+		ensure!(
+			settings.questions == 0 ||
+				(settings.whale_cap.is_none() &&
+					settings.turnout_cap.is_none() &&
+					settings.sunset_after.is_none() &&
+					settings.reveal_period.is_none() &&
+					!settings.hidden_tally),
+			Error::<T, I>::InvalidBundleSettings
+		);
+		ensure!(
+			settings.turnout_cap.map_or(true, |cap| {
+				!cap.is_zero() && settings.turnout_quorum.map_or(true, |quorum| quorum <= cap)
+			}),
+			Error::<T, I>::InvalidTurnoutCap
+		);
+		let roles = &settings.role_quorums;
+		ensure!(
+			roles.iter().enumerate().all(|(index, (role, quorum))| {
+				*quorum > 0 && roles[..index].iter().all(|(other, _)| other != role)
+			}),
+			Error::<T, I>::InvalidRoleQuorum
+		);
+		ensure!(settings.sunset_after != Some(0), Error::<T, I>::InvalidSunsetPeriod);
+		ensure!(settings.reveal_period != Some(0), Error::<T, I>::InvalidRevealPeriod);
+		// The voters of a secret ballot are only counted when they reveal
+		ensure!(
+			settings
+				.max_voters
+				.map_or(true, |max| max > 0 && settings.reveal_period.is_none()),
+			Error::<T, I>::InvalidMaxVoters
+		);
+		// The claim delay is forgotten once the archive is pruned, so it must be over by then
+		ensure!(
+			settings
+				.claim_delay
+				.map_or(true, |delay| delay > 0 && delay <= T::ArchiveRetention::get()),
+			Error::<T, I>::InvalidClaimDelay
+		);
+		// The collateral is claimed as is, the vote cost being forgotten after the release
+		ensure!(
+			settings.claim_delay.is_none() ||
+				(!settings.shared_budget && settings.vote_cost == VoteCost::Frozen),
+			Error::<T, I>::InvalidVoteCost
+		);
+		// The collateral committed beyond the revealed power is released
+		ensure!(
+			settings.reveal_period.is_none() || settings.freezes_cost(),
+			Error::<T, I>::InvalidVoteCost
+		);
+		ensure!(
+			settings
+				.jury_size
+				.map_or(true, |size| size > 0 && size <= T::MaxJurySize::get()),
+			Error::<T, I>::InvalidJurySize
+		);
+		ensure!(
+			!(settings.nft_gated || settings.nft_bonus) || !T::NftCollections::get().is_empty(),
+			Error::<T, I>::NoNftCollections
+		);
+		ensure!(
+			!settings.shared_budget || settings.vote_cost == VoteCost::Frozen,
+			Error::<T, I>::InvalidVoteCost
+		);
+		ensure!(
+			settings.lottery_winners.map_or(true, |winners| {
+				winners > 0 && winners <= T::MaxLotteryWinners::get() && settings.questions == 0
+			}),
+			Error::<T, I>::InvalidLottery
+		);
+		if let Some(vote_fee) = settings.vote_fee {
+			// The first fee creates the pot account
+			let minimum_balance =
+				<T::NativeBalance as fungible::Inspect<T::AccountId>>::minimum_balance();
+			ensure!(
+				vote_fee.saturated_into::<BalanceOf<T, I>>() >= minimum_balance,
+				Error::<T, I>::InvalidVoteFee
+			);
+			ensure!(
+				settings.questions == 0 || settings.fee_payout != FeePayout::WinningSide,
+				Error::<T, I>::InvalidVoteFee
+			);
+		}
+		Ok(())
+	}
+
+	/// The account holding the participation fees of a proposal.
+	pub fn pot_account(proposal_id: ProposalId) -> T::AccountId {
+		T::PalletId::get().into_sub_account_truncating(proposal_id)
+	}
+
+	/// Charges the vote fee of the proposal, if any, to a new voter.
+	fn charge_vote_fee(
+		who: &T::AccountId,
+		proposal_id: ProposalId,
+		settings: &ProposalSettings,
+	) -> DispatchResult {
+		let Some(vote_fee) = settings.vote_fee else { return Ok(()) };
+		let fee: BalanceOf<T, I> = vote_fee.saturated_into();
+		<T::NativeBalance as fungible::Mutate<T::AccountId>>::transfer(
+			who,
+			&Pallet::<T, I>::pot_account(proposal_id),
+			fee,
+			Preservation::Preserve,
+		)
+		.map_err(|_| Error::<T, I>::InsufficientBalance)?;
+		FeePots::<T, I>::mutate(proposal_id, |maybe_pot| {
+			let pot = maybe_pot.get_or_insert_with(FeePot::default);
+			pot.collected = pot.collected.saturating_add(fee);
+		});
+		Ok(())
+	}
+
+	/// Pays out the fee pot of a closed proposal according to its `fee_payout`. The winning side
+	/// is rewarded later, one voter at a time, when the collateral of its voters is released.
+	fn pay_out_fees(
+		proposal_id: ProposalId,
+		proposal: &ProposalData<T, I>,
+		closer: Option<T::AccountId>,
+		approved: bool,
+	) {
+		let Some(mut pot) = FeePots::<T, I>::get(proposal_id) else { return };
+		match (proposal.settings.fee_payout, closer) {
+			(FeePayout::Treasury, _) | (FeePayout::Closer, None) =>
+				Pallet::<T, I>::sweep_fees(proposal_id),
+			(FeePayout::Closer, Some(closer)) => {
+				Pallet::<T, I>::pay_fees(proposal_id, closer, pot.collected);
+				FeePots::<T, I>::remove(proposal_id);
+			},
+			(FeePayout::WinningSide, _) => {
+				let voters: BalanceOf<T, I> = proposal.voters_count.max(1).saturated_into();
+				pot.reward = Some((approved, pot.collected / voters));
+				FeePots::<T, I>::insert(proposal_id, pot);
+			},
 		}
 	}
-}
 
-impl<T: Config> Pallet<T> {
-	fn get_next_proposal_id() -> ProposalId {
-		let proposal_id = NextProposalId::<T>::get();
-		let next_id = proposal_id.checked_add(1).expect("Overflow u32 check; qed.");
-		NextProposalId::<T>::put(next_id);
-		proposal_id
+	/// Pays the fee reward of a voter released from a closed proposal, if it voted for the
+	/// winning side.
+	fn pay_fee_reward(proposal_id: ProposalId, voter: &T::AccountId, aye: bool) {
+		let Some(FeePot { collected, reward: Some((winning_aye, share)) }) =
+			FeePots::<T, I>::get(proposal_id)
+		else {
+			return
+		};
+		if aye == winning_aye && !share.is_zero() {
+			Pallet::<T, I>::pay_fees(proposal_id, voter.clone(), share);
+			FeePots::<T, I>::insert(
+				proposal_id,
+				FeePot { collected: collected.saturating_sub(share), reward: Some((aye, share)) },
+			);
+		}
 	}
 
-	fn get_current_block_number() -> BlockNumberFor<T> {
-		frame_system::Pallet::<T>::block_number()
+	/// Sends what is left in the fee pot of a proposal to the treasury account.
+	fn sweep_fees(proposal_id: ProposalId) {
+		if let Some(pot) = FeePots::<T, I>::take(proposal_id) {
+			Pallet::<T, I>::pay_fees(proposal_id, T::TreasuryAccount::get(), pot.collected);
+		}
+	}
+
+	fn pay_fees(proposal_id: ProposalId, to: T::AccountId, amount: BalanceOf<T, I>) {
+		// Defensive: the pot holds at least the collected amount
+		let paid = <T::NativeBalance as fungible::Mutate<T::AccountId>>::transfer(
+			&Pallet::<T, I>::pot_account(proposal_id),
+			&to,
+			amount,
+			Preservation::Expendable,
+		);
+		if paid.is_ok() {
+			Self::deposit_event(Event::FeesPaid { proposal_id, to, amount });
+		}
+	}
+
+	/// The account holding the bond of a disputed outcome.
+	pub fn dispute_account(proposal_id: ProposalId) -> T::AccountId {
+		T::PalletId::get().into_sub_account_truncating((b"dispute", proposal_id))
+	}
+
+	/// Keeps a closed proposal for `DisputePeriod` blocks so a revote can be scheduled if its
+	/// outcome is disputed.
+	fn open_dispute_period(
+		proposal_id: ProposalId,
+		proposal: ProposalData<T, I>,
+		current_block: BlockNumberFor<T>,
+	) {
+		let period = T::DisputePeriod::get();
+		if period.is_zero() {
+			return
+		}
+		DisputableProposals::<T, I>::insert(proposal_id, proposal);
+		Pallet::<T, I>::enqueue_cleanup(
+			CleanupLane::DisputePeriod,
+			current_block.saturating_add(period.into()),
+			CleanupTask::EndDisputePeriod { proposal_id },
+		);
+	}
+
+	/// Repeals the policy reviewed by a sunset review which is not approved, and schedules the
+	/// next review of an approved proposal with a sunset period.
+	fn review_policy(
+		proposal_id: ProposalId,
+		proposal: &ProposalData<T, I>,
+		approved: bool,
+		current_block: BlockNumberFor<T>,
+	) {
+		let policy_id = SunsetReviews::<T, I>::get(proposal_id);
+		if !approved {
+			if let Some(policy_id) = policy_id {
+				T::OnRepeal::on_repeal(policy_id, proposal_id);
+				Self::deposit_event(Event::<T, I>::PolicyRepealed {
+					policy_id,
+					review_id: proposal_id,
+				});
+			}
+			return
+		}
+		let Some(period) = proposal.settings.sunset_after else { return };
+
+		// The review is open to all the eligible voters of the organisation, for as long as the
+		// proposal was
+		let start_block = current_block.saturating_add(period.into());
+		let end_block =
+			start_block.saturating_add(proposal.end_block.saturating_sub(proposal.start_block));
+		let review = ProposalData {
+			kind: ProposalKind::SunsetReview,
+			creator: T::PalletId::get().into_account_truncating(),
+			account_list: None,
+			settings: ProposalSettings { members_root: None, ..proposal.settings.clone() },
+			..proposal.clone()
+		};
+		// A review which cannot be scheduled, e.g. when there are too many reviews in progress,
+		// leaves the policy in force
+		let Ok(review_id) = with_storage_layer(|| {
+			Pallet::<T, I>::schedule_proposal(review, start_block, end_block)
+		}) else {
+			return
+		};
+		let policy_id = policy_id.unwrap_or(proposal_id);
+		SunsetReviews::<T, I>::insert(review_id, policy_id);
+		Self::deposit_event(Event::<T, I>::SunsetReviewScheduled {
+			policy_id,
+			review_id,
+			start_block,
+		});
+	}
+
+	/// Creates a new proposal voting again on a disputed proposal between `start_block` and
+	/// `end_block`, with the same kind, creator, account list and settings.
+	fn schedule_revote(
+		closed_id: ProposalId,
+		closed: ProposalData<T, I>,
+		start_block: BlockNumberFor<T>,
+		end_block: BlockNumberFor<T>,
+	) -> Result<ProposalId, DispatchError> {
+		let proposal_id = Pallet::<T, I>::schedule_proposal(closed, start_block, end_block)?;
+		EligibilityBitmaps::<T, I>::set(proposal_id, EligibilityBitmaps::<T, I>::get(closed_id));
+		if let Some(policy_id) = SunsetReviews::<T, I>::get(closed_id) {
+			SunsetReviews::<T, I>::insert(proposal_id, policy_id);
+		}
+		if let Some(call) = ProposalCalls::<T, I>::take(closed_id) {
+			ProposalCalls::<T, I>::insert(proposal_id, call);
+		}
+		Ok(proposal_id)
+	}
+
+	/// Creates a new proposal from a closed one, voting between `start_block` and `end_block`
+	/// from a fresh tally.
+	fn schedule_proposal(
+		closed: ProposalData<T, I>,
+		start_block: BlockNumberFor<T>,
+		end_block: BlockNumberFor<T>,
+	) -> Result<ProposalId, DispatchError> {
+		let current_block = Pallet::<T, I>::get_current_block_number();
+		ensure!(current_block <= start_block, Error::<T, I>::ProposalCannotStartInThePast);
+		ensure!(start_block < end_block, Error::<T, I>::ProposalCannotFinishBeforeStarting);
+
+		let mut proposal = ProposalData {
+			start_block,
+			end_block,
+			ratio: VoteRatio::default(),
+			voters_count: 0,
+			electorate: None,
+			..closed
+		};
+		let proposal_id = Pallet::<T, I>::get_next_proposal_id();
+		ProposalsByKind::<T, I>::try_append(proposal.kind.clone(), proposal_id)
+			.map_err(|_| Error::<T, I>::TooManyProposals)?;
+		Pallet::<T, I>::schedule_start(proposal_id, &mut proposal)?;
+		Pallet::<T, I>::index_eligibility(proposal_id, &proposal, true);
+		Proposals::<T, I>::insert(proposal_id, proposal.clone());
+		Pallet::<T, I>::index_in_org(proposal_id, &proposal);
+
+		Self::deposit_event(Event::ProposalCreated {
+			proposal_id,
+			offchain_data: proposal.offchain_data,
+			creator: proposal.creator,
+			kind: proposal.kind,
+			account_list: proposal.account_list,
+			start_block,
+			end_block,
+			settings: proposal.settings,
+		});
+		Ok(proposal_id)
+	}
+
+	/// The account holding the sponsorship of a proposal, kept apart from its fee pot.
+	pub fn sponsorship_account(proposal_id: ProposalId) -> T::AccountId {
+		T::PalletId::get().into_sub_account_truncating((b"sponsor", proposal_id))
+	}
+
+	/// Reimburses a new voter of a sponsored proposal with `VoteRefund`, or with what is left of
+	/// the sponsorship.
+	fn refund_vote(voter: &T::AccountId, proposal_id: ProposalId) {
+		let Some(sponsorship) = Sponsorships::<T, I>::get(proposal_id) else { return };
+		let amount = T::VoteRefund::get().min(sponsorship);
+		if amount.is_zero() {
+			return
+		}
+		let refunded = <T::NativeBalance as fungible::Mutate<T::AccountId>>::transfer(
+			&Pallet::<T, I>::sponsorship_account(proposal_id),
+			voter,
+			amount,
+			Preservation::Expendable,
+		);
+		// An exhausted sponsorship stops refunding votes
+		if refunded.is_ok() {
+			Sponsorships::<T, I>::insert(proposal_id, sponsorship.saturating_sub(amount));
+			Self::deposit_event(Event::VoteRefunded { proposal_id, voter: voter.clone(), amount });
+		}
+	}
+
+	/// Returns what is left of the sponsorship of a proposal to its creator.
+	fn return_sponsorship(proposal_id: ProposalId, creator: &T::AccountId) {
+		let Some(amount) = Sponsorships::<T, I>::take(proposal_id) else { return };
+		if amount.is_zero() {
+			return
+		}
+		let returned = <T::NativeBalance as fungible::Mutate<T::AccountId>>::transfer(
+			&Pallet::<T, I>::sponsorship_account(proposal_id),
+			creator,
+			amount,
+			Preservation::Expendable,
+		);
+		if returned.is_ok() {
+			Self::deposit_event(Event::SponsorshipReturned { proposal_id, amount });
+		}
+	}
+
+	/// Holds `ProposalDeposit` from the creator of a proposal.
+	fn hold_deposit(proposal_id: ProposalId, creator: &T::AccountId) -> DispatchResult {
+		use frame_support::traits::fungible::MutateHold;
+
+		let deposit = T::ProposalDeposit::get();
+		if deposit.is_zero() {
+			return Ok(())
+		}
+		T::NativeBalance::hold(&HoldReason::ProposalDeposit.into(), creator, deposit)
+			.map_err(|_| Error::<T, I>::InsufficientBalance)?;
+		ProposalDeposits::<T, I>::insert(proposal_id, deposit);
+		Ok(())
+	}
+
+	/// Returns the deposit of an archived proposal to its creator.
+	fn release_deposit(proposal_id: ProposalId, creator: &T::AccountId) {
+		use frame_support::traits::fungible::MutateHold;
+
+		let Some(deposit) = ProposalDeposits::<T, I>::take(proposal_id) else { return };
+		let released = T::NativeBalance::release(
+			&HoldReason::ProposalDeposit.into(),
+			creator,
+			deposit,
+			Precision::BestEffort,
+		);
+		if let Ok(amount) = released {
+			Self::deposit_event(Event::ProposalDepositReturned { proposal_id, amount });
+		}
+	}
+
+	/// Transfers the deposit of a proposal cancelled as spam to the treasury account, or burns it
+	/// if the treasury account cannot receive it.
+	fn slash_deposit(proposal_id: ProposalId, creator: &T::AccountId) {
+		use frame_support::traits::fungible::MutateHold;
+
+		let Some(deposit) = ProposalDeposits::<T, I>::take(proposal_id) else { return };
+		let reason = HoldReason::ProposalDeposit.into();
+		let slashed = T::NativeBalance::transfer_on_hold(
+			&reason,
+			creator,
+			&T::TreasuryAccount::get(),
+			deposit,
+			Precision::BestEffort,
+			Restriction::Free,
+			Fortitude::Force,
+		)
+		.or_else(|_| {
+			T::NativeBalance::burn_held(
+				&reason,
+				creator,
+				deposit,
+				Precision::BestEffort,
+				Fortitude::Force,
+			)
+		});
+		if let Ok(amount) = slashed {
+			Self::deposit_event(Event::ProposalDepositSlashed { proposal_id, amount });
+		}
+	}
+
+	/// The account holding the turnout lottery pot of a proposal.
+	pub fn lottery_account(proposal_id: ProposalId) -> T::AccountId {
+		T::PalletId::get().into_sub_account_truncating((b"lottery", proposal_id))
+	}
+
+	/// Draws up to `winners` voters of a proposal being closed to share its lottery pot equally.
+	/// What cannot be shared, or the whole pot without voters, goes to the treasury account.
+	fn draw_lottery(proposal_id: ProposalId, winners: u32) {
+		let Some(pot) = LotteryPots::<T, I>::take(proposal_id) else { return };
+		let subject = (b"voting/lottery", proposal_id).encode();
+		let drawn = Pallet::<T, I>::draw_voters(proposal_id, &subject, winners);
+
+		let drawn_count: BalanceOf<T, I> = (drawn.len() as u32).into();
+		let prize = if drawn.is_empty() { Zero::zero() } else { pot / drawn_count };
+		for winner in &drawn {
+			LotteryPrizes::<T, I>::insert(proposal_id, winner, prize);
+		}
+		let left = pot.saturating_sub(prize.saturating_mul(drawn_count));
+		Pallet::<T, I>::pay_lottery(proposal_id, &T::TreasuryAccount::get(), left);
+
+		let winners = BoundedVec::truncate_from(drawn);
+		Self::deposit_event(Event::LotteryDrawn { proposal_id, winners, prize });
+	}
+
+	/// Pays to `beneficiary` the prize of `voter` if it won the turnout lottery of a closed
+	/// proposal.
+	fn pay_lottery_prize(
+		proposal_id: ProposalId,
+		voter: &T::AccountId,
+		beneficiary: &T::AccountId,
+	) {
+		let Some(amount) = LotteryPrizes::<T, I>::take(proposal_id, voter) else { return };
+		if Pallet::<T, I>::pay_lottery(proposal_id, beneficiary, amount) {
+			Self::deposit_event(Event::LotteryPrizePaid {
+				proposal_id,
+				who: beneficiary.clone(),
+				amount,
+			});
+		}
+	}
+
+	/// Sends the lottery pot of a proposal archived before its winners are drawn to the treasury
+	/// account.
+	fn sweep_lottery(proposal_id: ProposalId) {
+		if let Some(pot) = LotteryPots::<T, I>::take(proposal_id) {
+			Pallet::<T, I>::pay_lottery(proposal_id, &T::TreasuryAccount::get(), pot);
+		}
+	}
+
+	fn pay_lottery(proposal_id: ProposalId, to: &T::AccountId, amount: BalanceOf<T, I>) -> bool {
+		if amount.is_zero() {
+			return false
+		}
+		// Defensive: the pot holds at least the funded amount
+		<T::NativeBalance as fungible::Mutate<T::AccountId>>::transfer(
+			&Pallet::<T, I>::lottery_account(proposal_id),
+			to,
+			amount,
+			Preservation::Expendable,
+		)
+		.is_ok()
+	}
+
+	/// The number of voters allowed to vote on a proposal: the voters of its eligibility bitmap,
+	/// the members of its organisation unless an account list restricts it, the electorate of its
+	/// kind otherwise.
+	fn current_electorate(proposal_id: ProposalId, proposal: &ProposalData<T, I>) -> u32 {
+		if let Some(bitmap) = EligibilityBitmaps::<T, I>::get(proposal_id) {
+			return bitmap_len(&bitmap)
+		}
+		if let Some(org) = proposal.org {
+			if proposal.kind != ProposalKind::Private || proposal.account_list.is_none() {
+				return Orgs::<T, I>::get(org).map_or(0, |info| info.members_count)
+			}
+		}
+		T::KindHandler::electorate(proposal)
+	}
+
+	/// Schedules the start and the end of a proposal, recording its status and electorate and
+	/// drawing its jury. A proposal starting right away is active and records its electorate now,
+	/// its jury is drawn in the next block, the draw being too heavy for an extrinsic.
+	fn schedule_start(
+		proposal_id: ProposalId,
+		proposal: &mut ProposalData<T, I>,
+	) -> DispatchResult {
+		Pallet::<T, I>::schedule_end(proposal_id, proposal.closes_at());
+		let current_block = Pallet::<T, I>::get_current_block_number();
+		if proposal.has_started(&current_block) {
+			ProposalStatuses::<T, I>::insert(proposal_id, ProposalStatus::Active);
+			proposal.electorate = Some(Pallet::<T, I>::current_electorate(proposal_id, proposal));
+			if proposal.settings.jury_size.is_none() {
+				return Ok(())
+			}
+		} else {
+			ProposalStatuses::<T, I>::insert(proposal_id, ProposalStatus::Pending);
+		}
+		let next_block = current_block.saturating_add(1u32.into());
+		ScheduledStarts::<T, I>::try_append(proposal.start_block.max(next_block), proposal_id)
+			.map_err(|_| Error::<T, I>::TooManyProposals.into())
+	}
+
+	/// Starts a round of tally checkpoints every `TallyCheckpointPeriod` blocks and emits the
+	/// tally of the next open proposals of the round in progress. Proposals not started yet and
+	/// hidden tallies are skipped.
+	fn checkpoint_tallies(n: BlockNumberFor<T>) -> Weight {
+		use frame_support::storage::StoragePrefixedMap;
+
+		let period: BlockNumberFor<T> = T::TallyCheckpointPeriod::get().into();
+		if !period.is_zero() && (n % period).is_zero() {
+			// Iterating from the map prefix starts at its first proposal. A round still in
+			// progress is dropped.
+			let prefix = Proposals::<T, I>::final_prefix().to_vec();
+			CheckpointCursor::<T, I>::put(BoundedVec::truncate_from(prefix));
+		}
+		let Some(cursor) = CheckpointCursor::<T, I>::get() else {
+			return T::DbWeight::get().reads(1)
+		};
+
+		let mut proposals = Proposals::<T, I>::iter_from(cursor.into_inner());
+		let mut read: u32 = 0;
+		loop {
+			if read == T::MaxCheckpointsPerBlock::get() {
+				// Defensive: a cursor too long to be stored ends the round
+				let last_key = proposals.last_raw_key().to_vec();
+				CheckpointCursor::<T, I>::set(BoundedVec::try_from(last_key).ok());
+				break
+			}
+			let Some((proposal_id, proposal)) = proposals.next() else {
+				CheckpointCursor::<T, I>::kill();
+				break
+			};
+			read.saturating_inc();
+
+			if proposal.has_started(&n) && !proposal.settings.hidden_tally {
+				let tally = proposal.ratio;
+				Self::deposit_event(Event::TallyCheckpoint { proposal_id, tally });
+			}
+		}
+		Weight::from_parts(10_000, 0)
+			.saturating_add(T::DbWeight::get().reads_writes(u64::from(read) + 1, 1))
+	}
+
+	/// Writes the report of the epoch which just ended from its running statistics.
+	fn report_epoch() -> Weight {
+		let epoch = Pallet::<T, I>::current_epoch().saturating_sub(1);
+		let stats = EpochStatistics::<T, I>::take();
+		let average_turnout = stats
+			.total_turnout
+			.checked_div(stats.proposals_closed.into())
+			.unwrap_or_default();
+		let report = EpochReport {
+			proposals_closed: stats.proposals_closed,
+			average_turnout,
+			unique_voters: stats.unique_voters,
+		};
+		EpochReports::<T, I>::insert(epoch, report.clone());
+		Self::deposit_event(Event::EpochReported { epoch, report });
+		T::DbWeight::get().reads_writes(1, 2)
+	}
+
+	/// Counts `who` in the unique voters of the current epoch, unless it already voted in it.
+	fn note_epoch_voter(who: &T::AccountId) {
+		let epoch = Pallet::<T, I>::current_epoch();
+		if EpochVoters::<T, I>::get(who) != Some(epoch) {
+			EpochVoters::<T, I>::insert(who, epoch);
+			EpochStatistics::<T, I>::mutate(|stats| stats.unique_voters.saturating_inc());
+		}
+	}
+
+	/// Starts a round of the tally commitment at the first block of each epoch and folds the
+	/// tally of the next open proposals of the round in progress into its commitment, published
+	/// in the block digest once the round read them all. Each open proposal updates the
+	/// commitment to the blake2-256 hash of the SCALE encoded `(commitment, ProposalId,
+	/// VoteRatio)`, from zero and in the order of the `Proposals` storage. Proposals not started
+	/// yet are skipped, hidden tallies are committed as stored, unrevealed.
+	fn commit_tallies(n: BlockNumberFor<T>) -> Weight {
+		use frame_support::storage::StoragePrefixedMap;
+
+		if !T::PublishTallyCommitment::get() {
+			return Weight::zero()
+		}
+		let epoch_duration: BlockNumberFor<T> = T::EpochDuration::get().into();
+		if (n % epoch_duration).is_zero() {
+			// Iterating from the map prefix starts at its first proposal. A round still in
+			// progress is dropped.
+			let prefix = Proposals::<T, I>::final_prefix().to_vec();
+			CommitmentRound::<T, I>::put((BoundedVec::truncate_from(prefix), [0u8; 32]));
+		}
+		let Some((cursor, mut commitment)) = CommitmentRound::<T, I>::get() else {
+			return T::DbWeight::get().reads(1)
+		};
+
+		let mut proposals = Proposals::<T, I>::iter_from(cursor.into_inner());
+		let mut read: u32 = 0;
+		loop {
+			if read == T::MaxCheckpointsPerBlock::get() {
+				// Defensive: a cursor too long to be stored ends the round unpublished
+				let last_key = proposals.last_raw_key().to_vec();
+				CommitmentRound::<T, I>::set(
+					BoundedVec::try_from(last_key).ok().map(|cursor| (cursor, commitment)),
+				);
+				break
+			}
+			let Some((proposal_id, proposal)) = proposals.next() else {
+				CommitmentRound::<T, I>::kill();
+				frame_system::Pallet::<T>::deposit_log(DigestItem::Consensus(
+					TALLY_COMMITMENT_ENGINE_ID,
+					commitment.to_vec(),
+				));
+				break
+			};
+			read.saturating_inc();
+
+			if proposal.has_started(&n) {
+				commitment = (commitment, proposal_id, proposal.ratio).blake2_256();
+			}
+		}
+		Weight::from_parts(10_000, 0)
+			.saturating_add(T::DbWeight::get().reads_writes(u64::from(read) + 1, 1))
+	}
+
+	/// Notifies the webhook endpoints set by the node operator under `WEBHOOKS_STORAGE_KEY` of
+	/// the proposals created, started and closed in block `n`. A proposal starting at its
+	/// creation has no `ProposalStarted` event and is notified as started along its creation.
+	/// Endpoints failing to answer in time miss the notification.
+	fn notify_webhooks(n: BlockNumberFor<T>) {
+		use frame_support::{
+			sp_io,
+			sp_runtime::offchain::{http, storage::StorageValueRef, Duration},
+		};
+
+		let storage = StorageValueRef::persistent(WEBHOOKS_STORAGE_KEY);
+		let Ok(Some(endpoints)) = storage.get::<Vec<Vec<u8>>>() else { return };
+		if endpoints.is_empty() {
+			return
+		}
+
+		let block: u64 = n.saturated_into();
+		let mut payloads = Vec::new();
+		for record in frame_system::Pallet::<T>::read_events_no_consensus() {
+			let event: <T as Config<I>>::RuntimeEvent = record.event.into();
+			let Ok(event) = event.try_into() else { continue };
+			match event {
+				Event::<T, I>::ProposalCreated { proposal_id, start_block, .. } => {
+					payloads.push(webhook_payload(b"ProposalCreated", proposal_id, block, None));
+					if start_block <= n {
+						payloads.push(webhook_payload(
+							b"ProposalStarted",
+							proposal_id,
+							block,
+							None,
+						));
+					}
+				},
+				Event::<T, I>::ProposalStarted { proposal_id } => {
+					payloads.push(webhook_payload(b"ProposalStarted", proposal_id, block, None));
+				},
+				Event::<T, I>::VoteCompleted { proposal_id, approved, .. } => {
+					payloads.push(webhook_payload(
+						b"VoteCompleted",
+						proposal_id,
+						block,
+						Some(approved),
+					));
+				},
+				_ => {},
+			}
+		}
+
+		let deadline = sp_io::offchain::timestamp().add(Duration::from_millis(WEBHOOK_TIMEOUT_MS));
+		let mut pending = Vec::new();
+		for endpoint in &endpoints {
+			let Ok(url) = core::str::from_utf8(endpoint) else { continue };
+			for payload in &payloads {
+				let request = http::Request::post(url, Vec::from([payload.clone()]))
+					.add_header("Content-Type", "application/json")
+					.deadline(deadline);
+				if let Ok(request) = request.send() {
+					pending.push(request);
+				}
+			}
+		}
+		let _ = http::PendingRequest::try_wait_all(pending, deadline);
+	}
+
+	/// Schedules the automatic close of a proposal ending at `end_block`. Once too many proposals
+	/// end at the same block, the next ones are left to `close_proposal`.
+	fn schedule_end(proposal_id: ProposalId, end_block: BlockNumberFor<T>) {
+		let _ = ScheduledEnds::<T, I>::try_append(end_block, proposal_id);
+	}
+
+	/// Removes a proposal from the proposals ending at `end_block`.
+	fn unschedule_end(proposal_id: ProposalId, end_block: BlockNumberFor<T>) {
+		ScheduledEnds::<T, I>::mutate_exists(end_block, |maybe_ending| {
+			if let Some(ending) = maybe_ending {
+				ending.retain(|id| *id != proposal_id);
+				if ending.is_empty() {
+					*maybe_ending = None;
+				}
+			}
+		});
+	}
+
+	/// The proposals ending at `block`, to be closed in its `on_initialize`.
+	pub fn proposals_ending_at(block: BlockNumberFor<T>) -> Vec<ProposalId> {
+		ScheduledEnds::<T, I>::get(block).into_inner()
+	}
+
+	/// Closes up to `MaxClosesPerBlock` proposals ended at block `n`, the others being carried
+	/// over to the next block. A proposal already closed or failing to close is skipped, the
+	/// latter being left to `close_proposal`.
+	fn close_ended_proposals(n: BlockNumberFor<T>) -> Weight {
+		let mut proposal_ids = ScheduledEnds::<T, I>::take(n).into_inner();
+		let mut weight = T::DbWeight::get().reads_writes(1, 1);
+		let max_closes = T::MaxClosesPerBlock::get() as usize;
+		if proposal_ids.len() > max_closes {
+			let mut carried_over = proposal_ids.split_off(max_closes);
+			let next_block = n.saturating_add(1u32.into());
+			// Carried over ahead of the proposals ending at the next block, the ones exceeding
+			// the bound are left to `close_proposal`
+			ScheduledEnds::<T, I>::mutate(next_block, |ending| {
+				carried_over.extend(core::mem::take(ending).into_inner());
+				*ending = BoundedVec::truncate_from(carried_over);
+			});
+			weight.saturating_accrue(T::DbWeight::get().reads_writes(1, 1));
+		}
+		for proposal_id in proposal_ids {
+			weight.saturating_accrue(T::DbWeight::get().reads(1));
+			if !Proposals::<T, I>::contains_key(proposal_id) {
+				continue
+			}
+			weight.saturating_accrue(Pallet::<T, I>::close_weight());
+			// Hooks are not dispatched in a storage layer, a failed close is discarded
+			let _ = with_storage_layer(|| Pallet::<T, I>::do_close_proposal(proposal_id, None));
+		}
+		weight
+	}
+
+	/// Activates the proposals starting at block `n`, recording their electorate and drawing
+	/// their jury.
+	fn start_scheduled_proposals(n: BlockNumberFor<T>) -> Weight {
+		let proposal_ids = ScheduledStarts::<T, I>::take(n);
+		let mut weight = T::DbWeight::get().reads_writes(1, 1);
+		for proposal_id in proposal_ids {
+			weight.saturating_accrue(T::DbWeight::get().reads_writes(2, 1));
+			// The proposal may have been cancelled before its start
+			let Some(mut proposal) = Proposals::<T, I>::get(proposal_id) else { continue };
+			// A proposal starting right away is only scheduled to draw its jury
+			if proposal.start_block == n {
+				ProposalStatuses::<T, I>::insert(proposal_id, ProposalStatus::Active);
+				Self::deposit_event(Event::ProposalStarted { proposal_id });
+			}
+			if proposal.electorate.is_none() {
+				proposal.electorate =
+					Some(Pallet::<T, I>::current_electorate(proposal_id, &proposal));
+				Proposals::<T, I>::insert(proposal_id, &proposal);
+			}
+			if let Some(jury_size) = proposal.settings.jury_size {
+				weight.saturating_accrue(Pallet::<T, I>::draw_jury(proposal_id, jury_size));
+			}
+		}
+		weight
+	}
+
+	/// Draws `jury_size` jurors among the registered voters with reservoir sampling, so that each
+	/// voter has the same chance to be drawn.
+	fn draw_jury(proposal_id: ProposalId, jury_size: u32) -> Weight {
+		let (seed, _) = T::Randomness::random(&(b"voting/jury", proposal_id).encode());
+		let mut jury = Vec::new();
+		let mut candidates: u32 = 0;
+		for who in RegisteredVoters::<T, I>::iter_keys() {
+			if jury.len() < jury_size as usize {
+				jury.push(who);
+			} else {
+				let hash = (seed, candidates).blake2_256();
+				let random = u32::decode(&mut &hash[..]).unwrap_or_default();
+				let index = random.checked_rem(candidates.saturating_add(1)).unwrap_or_default();
+				if let Some(juror) = jury.get_mut(index as usize) {
+					*juror = who;
+				}
+			}
+			candidates.saturating_inc();
+		}
+
+		let jurors = jury.len() as u32;
+		Juries::<T, I>::insert(proposal_id, BoundedVec::truncate_from(jury));
+		Self::deposit_event(Event::JuryDrawn { proposal_id, jurors });
+		Weight::from_parts(10_000, 0)
+			.saturating_add(T::DbWeight::get().reads_writes(candidates.into(), 1))
+	}
+
+	/// Releases the collateral of the `caller` vote on a closed proposal and returns its amount,
+	/// minus the fee burned for a partially burned cost. A lottery prize is paid along to
+	/// `beneficiary`.
+	fn do_claim(
+		caller: &T::AccountId,
+		proposal_id: ProposalId,
+		beneficiary: &T::AccountId,
+	) -> Result<BalanceOf<T, I>, DispatchError> {
+		ensure!(RegisteredVoters::<T, I>::get(caller).is_some(), Error::<T, I>::VoterNotRegistered);
+		ensure!(Proposals::<T, I>::get(proposal_id).is_none(), Error::<T, I>::ProposalNotClosed);
+		let vote_cost = ClosedVoteCosts::<T, I>::get(proposal_id).unwrap_or_default();
+		ensure!(vote_cost.is_frozen(), Error::<T, I>::ClaimDisabled);
+
+		let vote =
+			Votes::<T, I>::get(caller, proposal_id).ok_or(Error::<T, I>::ClaimDoesNotExist)?;
+		if let Some(open_at) = ClaimsOpenAt::<T, I>::get(proposal_id) {
+			ensure!(
+				Pallet::<T, I>::get_current_block_number() >= open_at,
+				Error::<T, I>::ClaimNotYetAvailable
+			);
+		}
+		// A pruned archive is past the longest conviction lock
+		if let Some(archive) = ArchivedProposals::<T, I>::get(proposal_id) {
+			let lock = T::ConvictionPeriod::get().saturating_mul(vote.conviction.lock_periods());
+			ensure!(
+				Pallet::<T, I>::get_current_block_number() >=
+					archive.archived_at.saturating_add(lock.into()),
+				Error::<T, I>::ConvictionLocked
+			);
+		}
+
+		let burned = Pallet::<T, I>::release_collateral(
+			caller,
+			proposal_id,
+			vote_cost.release_fee(),
+			vote.power,
+		)?;
+		Votes::<T, I>::remove(caller, proposal_id);
+		Pallet::<T, I>::pay_lottery_prize(proposal_id, caller, beneficiary);
+		let released = Pallet::<T, I>::calculate_quadratic_amount(vote.power);
+		Ok(released.saturating_sub(burned))
+	}
+
+	fn calculate_quadratic_amount(power: PowerOf<T, I>) -> BalanceOf<T, I> {
+		math::quadratic_cost(power)
+	}
+
+	/// Caps each contribution to a share of the total turnout, the excess is discarded. The cap is
+	/// computed once from the turnout before any discard so the result does not depend on the
+	/// iteration order.
+	fn apply_whale_cap(proposal_id: ProposalId, proposal: &mut ProposalData<T, I>, cap: Perbill) {
+		let max_contribution = cap.mul_floor(proposal.ratio.1);
+		for (aye, amount) in Contributions::<T, I>::iter_prefix_values(proposal_id) {
+			let excess = amount.saturating_sub(max_contribution);
+			if !excess.is_zero() {
+				proposal.discard_contribution(aye, excess);
+			}
+		}
+	}
+
+	/// Loads the running tally of a hidden tally proposal into the proposal.
+	fn reveal_tally(proposal_id: ProposalId, proposal: &mut ProposalData<T, I>) {
+		if proposal.settings.hidden_tally {
+			proposal.ratio = HiddenTallies::<T, I>::get(proposal_id);
+		}
+	}
+
+	/// Moves the running tally of a hidden tally proposal back out of the proposal.
+	fn conceal_tally(proposal_id: ProposalId, proposal: &mut ProposalData<T, I>) {
+		if proposal.settings.hidden_tally {
+			HiddenTallies::<T, I>::insert(proposal_id, proposal.ratio);
+			proposal.ratio = VoteRatio::default();
+		}
+	}
+
+	/// The bonus of `who` on `proposal`: its tenure bonus, computed at the proposal start so it
+	/// does not change during a vote, plus the bonus of its NFT pass if the proposal enables it.
+	/// The tenure in an organisation counts from joining it.
+	fn vote_bonus(
+		who: &T::AccountId,
+		voter: &VoterInfo<BlockNumberFor<T>>,
+		proposal: &ProposalData<T, I>,
+	) -> Percent {
+		let joined_at = proposal.org.and_then(|org| OrgMembers::<T, I>::get(org, who));
+		let since =
+			joined_at.map_or(voter.registered_at, |joined_at| joined_at.max(voter.registered_at));
+		let bonus = Pallet::<T, I>::tenure_bonus(since, proposal.start_block);
+		if !proposal.settings.nft_bonus {
+			return bonus
+		}
+		bonus.saturating_add(Pallet::<T, I>::nft_pass(who).unwrap_or_default())
+	}
+
+	/// The bonus of the best `NftCollections` collection `who` holds an item of, if any.
+	fn nft_pass(who: &T::AccountId) -> Option<Percent> {
+		T::NftCollections::get()
+			.into_iter()
+			.filter(|(collection, _)| {
+				<T::Nfts as nonfungibles_v2::InspectEnumerable<T::AccountId>>::owned_in_collection(
+					collection, who,
+				)
+				.next()
+				.is_some()
+			})
+			.map(|(_, bonus)| bonus)
+			.max()
+	}
+
+	/// The tenure bonus at block `at` of a voter registered `since`, growing with each full epoch
+	/// of registration.
+	fn tenure_bonus(since: BlockNumberFor<T>, at: BlockNumberFor<T>) -> Percent {
+		let tenure: u32 = at.saturating_sub(since).saturated_into();
+		let epochs = tenure.checked_div(T::EpochDuration::get()).unwrap_or_default();
+		let bonus = u32::from(T::TenureBonusPerEpoch::get().deconstruct()).saturating_mul(epochs);
+		Percent::from_parts(bonus.min(T::MaxTenureBonus::get().deconstruct().into()) as u8)
+	}
+
+	fn snapshot_balance(who: &T::AccountId) {
+		let balance = Pallet::<T, I>::voting_balance(who);
+		BalanceSnapshots::<T, I>::insert(
+			who,
+			(Pallet::<T, I>::get_current_block_number(), balance),
+		);
+		Self::deposit_event(Event::BalanceSnapshotTaken { who: who.clone(), balance });
+	}
+
+	/// Checks that a `held` amount is backed by a balance snapshot of at least `BalanceAge`
+	/// blocks, so funds received right before a vote cannot back it.
+	fn ensure_seasoned_balance(who: &T::AccountId, held: BalanceOf<T, I>) -> DispatchResult {
+		let balance_age = T::BalanceAge::get();
+		if balance_age.is_zero() {
+			return Ok(())
+		}
+		let (taken_at, balance) =
+			BalanceSnapshots::<T, I>::get(who).ok_or(Error::<T, I>::BalanceTooRecent)?;
+		let seasoned_at = taken_at.saturating_add(balance_age.into());
+		ensure!(
+			seasoned_at <= Pallet::<T, I>::get_current_block_number() && held <= balance,
+			Error::<T, I>::BalanceTooRecent
+		);
+		Ok(())
+	}
+
+	fn has_min_balance(who: &T::AccountId) -> bool {
+		Pallet::<T, I>::voting_balance(who) >= T::MinVoterBalance::get()
+	}
+
+	/// The balance of `who`, counting the collateral held for its votes.
+	fn voting_balance(who: &T::AccountId) -> BalanceOf<T, I> {
+		<T::NativeBalance as fungible::Inspect<T::AccountId>>::balance(who)
+			.saturating_add(Pallet::<T, I>::held_for_voting(who))
+	}
+
+	fn current_epoch() -> u32 {
+		Pallet::<T, I>::epoch_at(Pallet::<T, I>::get_current_block_number())
+	}
+
+	fn epoch_at(block: BlockNumberFor<T>) -> u32 {
+		let block: u32 = block.saturated_into();
+		block.checked_div(T::EpochDuration::get()).unwrap_or_default()
+	}
+
+	/// Locks the additional cost of a vote, either in voice credits, by holding funds or by
+	/// paying it, in the asset of the proposal if any.
+	fn lock_power(
+		who: &T::AccountId,
+		proposal_id: ProposalId,
+		settings: &ProposalSettings,
+		prev_power: PowerOf<T, I>,
+		power: PowerOf<T, I>,
+	) -> DispatchResult {
+		if settings.shared_budget {
+			return Pallet::<T, I>::spend_credits(who, prev_power, power)
+		}
+		if let Some(asset) = ProposalAssets::<T, I>::get(proposal_id) {
+			return Pallet::<T, I>::lock_asset(who, proposal_id, asset, settings, prev_power, power)
+		}
+		match settings.vote_cost {
+			VoteCost::Frozen | VoteCost::PartiallyBurned(_) =>
+				Pallet::<T, I>::hold(who, prev_power, power),
+			VoteCost::Burned => Pallet::<T, I>::pay_cost(who, true, prev_power, power),
+			VoteCost::Treasury => Pallet::<T, I>::pay_cost(who, false, prev_power, power),
+		}
+	}
+
+	/// Releases the extra cost of a vote, either in voice credits or by releasing or returning
+	/// the escrowed funds minus the fee of a partially burned cost. A paid cost is not refunded.
+	fn release_power(
+		who: &T::AccountId,
+		proposal_id: ProposalId,
+		settings: &ProposalSettings,
+		prev_power: PowerOf<T, I>,
+		power: PowerOf<T, I>,
+	) -> DispatchResult {
+		if settings.shared_budget {
+			Pallet::<T, I>::refund_credits(who, prev_power, power);
+			return Ok(())
+		}
+		if let Some(asset) = ProposalAssets::<T, I>::get(proposal_id) {
+			if !settings.vote_cost.is_frozen() {
+				return Ok(())
+			}
+			let fee = settings.vote_cost.release_fee();
+			return Pallet::<T, I>::release_escrow(who, proposal_id, asset, fee, prev_power, power)
+				.map(|_| ())
+		}
+		match settings.vote_cost {
+			VoteCost::Frozen => Pallet::<T, I>::release_hold(who, prev_power, power),
+			VoteCost::PartiallyBurned(fee) => {
+				Pallet::<T, I>::release_hold(who, prev_power, power)?;
+				let released = Pallet::<T, I>::calculate_quadratic_amount(prev_power)
+					.saturating_sub(Pallet::<T, I>::calculate_quadratic_amount(power));
+				Pallet::<T, I>::burn_release_fee(who, fee, released);
+				Ok(())
+			},
+			VoteCost::Burned | VoteCost::Treasury => Ok(()),
+		}
+	}
+
+	/// Releases the whole collateral of a vote of `power` on a closed proposal, from the escrow of
+	/// its asset or from the hold, and returns the burned `fee` share.
+	fn release_collateral(
+		who: &T::AccountId,
+		proposal_id: ProposalId,
+		fee: Percent,
+		power: PowerOf<T, I>,
+	) -> Result<BalanceOf<T, I>, DispatchError> {
+		if let Some(asset) = ProposalAssets::<T, I>::get(proposal_id) {
+			return Pallet::<T, I>::release_escrow(who, proposal_id, asset, fee, power, Zero::zero())
+		}
+		Pallet::<T, I>::release_hold(who, power, Zero::zero())?;
+		let released = Pallet::<T, I>::calculate_quadratic_amount(power);
+		Ok(Pallet::<T, I>::burn_release_fee(who, fee, released))
+	}
+
+	/// The account escrowing the collateral of the votes of a proposal voted with an asset.
+	pub fn escrow_account(proposal_id: ProposalId) -> T::AccountId {
+		T::PalletId::get().into_sub_account_truncating((b"escrow", proposal_id))
+	}
+
+	/// The balance `who` can spend on a vote of a proposal, in its asset if any.
+	fn reducible_balance(proposal_id: ProposalId, who: &T::AccountId) -> BalanceOf<T, I> {
+		match ProposalAssets::<T, I>::get(proposal_id) {
+			Some(asset) => <T::Assets as fungibles::Inspect<T::AccountId>>::reducible_balance(
+				asset,
+				who,
+				Preservation::Preserve,
+				Fortitude::Polite,
+			),
+			None => <T::NativeBalance as fungible::Inspect<T::AccountId>>::reducible_balance(
+				who,
+				Preservation::Preserve,
+				Fortitude::Polite,
+			),
+		}
+	}
+
+	/// Takes the additional cost of a vote in the asset of the proposal: escrowed for a frozen
+	/// cost, burned or paid to the treasury account otherwise.
+	fn lock_asset(
+		who: &T::AccountId,
+		proposal_id: ProposalId,
+		asset: AssetIdOf<T, I>,
+		settings: &ProposalSettings,
+		prev_power: PowerOf<T, I>,
+		power: PowerOf<T, I>,
+	) -> DispatchResult {
+		use fungibles::Mutate;
+
+		let additional_amount = Pallet::<T, I>::calculate_quadratic_amount(power)
+			.saturating_sub(Pallet::<T, I>::calculate_quadratic_amount(prev_power));
+		let available_balance = Pallet::<T, I>::reducible_balance(proposal_id, who);
+		ensure!(available_balance.ge(&additional_amount), Error::<T, I>::InsufficientBalance);
+		if additional_amount.is_zero() {
+			return Ok(())
+		}
+
+		match settings.vote_cost {
+			VoteCost::Frozen | VoteCost::PartiallyBurned(_) => T::Assets::transfer(
+				asset,
+				who,
+				&Pallet::<T, I>::escrow_account(proposal_id),
+				additional_amount,
+				Preservation::Preserve,
+			)
+			.map(|_| ()),
+			VoteCost::Burned => T::Assets::burn_from(
+				asset,
+				who,
+				additional_amount,
+				Precision::Exact,
+				Fortitude::Polite,
+			)
+			.map(|_| ()),
+			VoteCost::Treasury => T::Assets::transfer(
+				asset,
+				who,
+				&T::TreasuryAccount::get(),
+				additional_amount,
+				Preservation::Preserve,
+			)
+			.map(|_| ()),
+		}
+	}
+
+	/// Returns the collateral released from a vote of `prev_power` lowered to `power` out of the
+	/// escrow of the proposal, burning its `fee` share, and returns the burned amount.
+	fn release_escrow(
+		who: &T::AccountId,
+		proposal_id: ProposalId,
+		asset: AssetIdOf<T, I>,
+		fee: Percent,
+		prev_power: PowerOf<T, I>,
+		power: PowerOf<T, I>,
+	) -> Result<BalanceOf<T, I>, DispatchError> {
+		use fungibles::Mutate;
+
+		let released = Pallet::<T, I>::calculate_quadratic_amount(prev_power)
+			.saturating_sub(Pallet::<T, I>::calculate_quadratic_amount(power));
+		let burned = fee.mul_floor(released);
+		let escrow = Pallet::<T, I>::escrow_account(proposal_id);
+		let returned = released.saturating_sub(burned);
+		if !returned.is_zero() {
+			T::Assets::transfer(asset, &escrow, who, returned, Preservation::Expendable)?;
+		}
+		if !burned.is_zero() {
+			T::Assets::burn_from(asset, &escrow, burned, Precision::BestEffort, Fortitude::Polite)?;
+		}
+		Ok(burned)
 	}
 
-	fn calculate_quadratic_amount(power: u128) -> BalanceOf<T> {
-		power.checked_mul(power).unwrap_or(u128::MAX).saturated_into()
+	/// Burns the `fee` share of an amount just released from the hold of `who` and returns the
+	/// burned amount.
+	fn burn_release_fee(
+		who: &T::AccountId,
+		fee: Percent,
+		released: BalanceOf<T, I>,
+	) -> BalanceOf<T, I> {
+		use frame_support::traits::fungible::Mutate;
+
+		let amount = fee.mul_floor(released);
+		if amount.is_zero() {
+			return amount
+		}
+		// Defensive: the released amount is still owned by the voter
+		T::NativeBalance::burn_from(who, amount, Precision::BestEffort, Fortitude::Polite)
+			.unwrap_or_default()
+	}
+
+	/// Burns the additional cost of a vote or pays it to the treasury account.
+	fn pay_cost(
+		who: &T::AccountId,
+		burn: bool,
+		prev_power: PowerOf<T, I>,
+		power: PowerOf<T, I>,
+	) -> DispatchResult {
+		use frame_support::traits::fungible::{Inspect, Mutate};
+
+		let prev_amount = Pallet::<T, I>::calculate_quadratic_amount(prev_power);
+		let new_amount = Pallet::<T, I>::calculate_quadratic_amount(power);
+		let additional_amount = new_amount.saturating_sub(prev_amount);
+
+		let available_balance =
+			T::NativeBalance::reducible_balance(who, Preservation::Preserve, Fortitude::Polite);
+		ensure!(available_balance.ge(&additional_amount), Error::<T, I>::InsufficientBalance);
+
+		if burn {
+			T::NativeBalance::burn_from(
+				who,
+				additional_amount,
+				Precision::Exact,
+				Fortitude::Polite,
+			)?;
+		} else {
+			T::NativeBalance::transfer(
+				who,
+				&T::TreasuryAccount::get(),
+				additional_amount,
+				Preservation::Preserve,
+			)?;
+		}
+		Ok(())
+	}
+
+	fn spend_credits(
+		who: &T::AccountId,
+		prev_power: PowerOf<T, I>,
+		power: PowerOf<T, I>,
+	) -> DispatchResult {
+		let epoch = Pallet::<T, I>::current_epoch();
+		let additional_credits = Pallet::<T, I>::calculate_quadratic_amount(power)
+			.saturating_sub(Pallet::<T, I>::calculate_quadratic_amount(prev_power));
+
+		SpentCredits::<T, I>::try_mutate(who, |(spent_epoch, spent)| -> DispatchResult {
+			if *spent_epoch != epoch {
+				*spent_epoch = epoch;
+				*spent = Zero::zero();
+			}
+			let new_spent = spent.saturating_add(additional_credits);
+			ensure!(new_spent <= T::VoiceCredits::get(), Error::<T, I>::InsufficientCredits);
+			*spent = new_spent;
+			Ok(())
+		})
+	}
+
+	/// Credits spent during a previous epoch are not refunded since the budget is renewed.
+	fn refund_credits(who: &T::AccountId, prev_power: PowerOf<T, I>, power: PowerOf<T, I>) {
+		let epoch = Pallet::<T, I>::current_epoch();
+		let extra_credits = Pallet::<T, I>::calculate_quadratic_amount(prev_power)
+			.saturating_sub(Pallet::<T, I>::calculate_quadratic_amount(power));
+
+		SpentCredits::<T, I>::mutate(who, |(spent_epoch, spent)| {
+			if *spent_epoch == epoch {
+				*spent = spent.saturating_sub(extra_credits);
+			}
+		});
 	}
 
-	fn freeze(who: &T::AccountId, prev_power: u128, power: u128) -> DispatchResult {
-		use frame_support::traits::fungible::{Inspect, InspectFreeze, MutateFreeze};
+	fn hold(who: &T::AccountId, prev_power: PowerOf<T, I>, power: PowerOf<T, I>) -> DispatchResult {
+		use frame_support::traits::fungible::{Inspect, MutateHold};
 
-		let current_frozen_balance =
-			T::NativeBalance::balance_frozen(&T::FreezeIdForPallet::get(), who);
-		let prev_amount = Pallet::<T>::calculate_quadratic_amount(prev_power);
-		let new_amount = Pallet::<T>::calculate_quadratic_amount(power);
+		let current_held_balance = Pallet::<T, I>::held_for_voting(who);
+		let prev_amount = Pallet::<T, I>::calculate_quadratic_amount(prev_power);
+		let new_amount = Pallet::<T, I>::calculate_quadratic_amount(power);
 		let additional_amount = new_amount.saturating_sub(prev_amount);
 
 		let available_balance =
 			T::NativeBalance::reducible_balance(who, Preservation::Preserve, Fortitude::Polite);
-		ensure!(available_balance.ge(&additional_amount), Error::<T>::InsufficientBalance);
+		ensure!(available_balance.ge(&additional_amount), Error::<T, I>::InsufficientBalance);
 
-		let new_freeze_amount = current_frozen_balance.saturating_add(additional_amount);
-		T::NativeBalance::set_freeze(&T::FreezeIdForPallet::get(), who, new_freeze_amount)
+		let new_held_amount = current_held_balance.saturating_add(additional_amount);
+		Pallet::<T, I>::ensure_seasoned_balance(who, new_held_amount)?;
+		// The account cannot be reaped, dusting its votes, while funds are held for them
+		if current_held_balance.is_zero() && !new_held_amount.is_zero() {
+			frame_system::Pallet::<T>::inc_consumers(who)?;
+		}
+		T::NativeBalance::hold(&HoldReason::VoteCollateral.into(), who, additional_amount)
 	}
 
-	fn unfreeze(who: &T::AccountId, prev_power: u128, power: u128) -> DispatchResult {
-		use frame_support::traits::fungible::{InspectFreeze, MutateFreeze};
+	fn release_hold(
+		who: &T::AccountId,
+		prev_power: PowerOf<T, I>,
+		power: PowerOf<T, I>,
+	) -> DispatchResult {
+		use frame_support::traits::fungible::MutateHold;
 
-		let current_frozen_balance =
-			T::NativeBalance::balance_frozen(&T::FreezeIdForPallet::get(), who);
-		let prev_amount = Pallet::<T>::calculate_quadratic_amount(prev_power);
-		let new_amount = Pallet::<T>::calculate_quadratic_amount(power);
+		let current_held_balance = Pallet::<T, I>::held_for_voting(who);
+		let prev_amount = Pallet::<T, I>::calculate_quadratic_amount(prev_power);
+		let new_amount = Pallet::<T, I>::calculate_quadratic_amount(power);
 		let extra_amount = prev_amount.saturating_sub(new_amount);
 
-		let new_freeze_amount = current_frozen_balance.saturating_sub(extra_amount);
-		T::NativeBalance::set_freeze(&T::FreezeIdForPallet::get(), who, new_freeze_amount)
+		let released = T::NativeBalance::release(
+			&HoldReason::VoteCollateral.into(),
+			who,
+			extra_amount,
+			Precision::BestEffort,
+		)?;
+		if !current_held_balance.is_zero() && released == current_held_balance {
+			frame_system::Pallet::<T>::dec_consumers(who);
+		}
+		Ok(())
+	}
+}
+
+impl<T: Config<I>, I: 'static> From<CreateError> for Error<T, I> {
+	fn from(error: CreateError) -> Self {
+		match error {
+			CreateError::VoterNotRegistered => Error::<T, I>::VoterNotRegistered,
+			CreateError::MissingCreatorRole => Error::<T, I>::MissingCreatorRole,
+			CreateError::AccountListFull => Error::<T, I>::AccountListFull,
+			CreateError::ProposalCannotStartInThePast =>
+				Error::<T, I>::ProposalCannotStartInThePast,
+			CreateError::ProposalCannotFinishBeforeStarting =>
+				Error::<T, I>::ProposalCannotFinishBeforeStarting,
+			CreateError::ProposalStartIsTooFarAway => Error::<T, I>::ProposalStartIsTooFarAway,
+			CreateError::ProposalDurationIsTooShort => Error::<T, I>::ProposalDurationIsTooShort,
+			CreateError::ProposalDurationIsTooLong => Error::<T, I>::ProposalDurationIsTooLong,
+			CreateError::EmergencyProposalNotAllowed => Error::<T, I>::EmergencyProposalNotAllowed,
+			CreateError::ProposalKindNotAllowed => Error::<T, I>::ProposalKindNotAllowed,
+			CreateError::TooManyProposals => Error::<T, I>::TooManyProposals,
+			CreateError::InsufficientBalance => Error::<T, I>::InsufficientBalance,
+		}
 	}
 }
 
 // Look at `../interface/` to better understand this API.
-impl<T: Config> pba_interface::VotingInterface for Pallet<T> {
+impl<T: Config<I>, I: 'static> pba_interface::VotingInterface for Pallet<T, I> {
 	type AccountId = T::AccountId;
 	type VotingBalance = <T::NativeBalance as fungible::Inspect<Self::AccountId>>::Balance;
-	// You can change this if you need.
-	type ProposalId = u32;
+	type ProposalId = ProposalId;
 
-	fn add_voter(_who: Self::AccountId, _amount: Self::VotingBalance) -> DispatchResult {
-		unimplemented!()
+	// Unlike the calls, these functions are not dispatched in a storage layer, so each runs in
+	// its own to be discarded on failure.
+	fn add_voter(who: Self::AccountId, amount: Self::VotingBalance) -> DispatchResult {
+		with_storage_layer(|| {
+			<T::NativeBalance as fungible::Mutate<T::AccountId>>::set_balance(&who, amount);
+			if Pallet::<T, I>::register(&who)? {
+				Self::deposit_event(Event::<T, I>::NewVoterRegistered { who });
+			}
+			Ok(())
+		})
 	}
 
-	fn create_proposal(_metadata: Vec<u8>) -> Result<Self::ProposalId, DispatchError> {
-		unimplemented!()
+	/// Creates a public proposal with the default settings, starting right away for the maximum
+	/// duration. Its creator is the pallet account, and it opens for voting already seconded by
+	/// `SecondsThreshold` voters since nobody can second it before its start.
+	fn create_proposal(metadata: Vec<u8>) -> Result<Self::ProposalId, DispatchError> {
+		let offchain_data =
+			BoundedVec::try_from(metadata).map_err(|_| Error::<T, I>::OffchainDataTooLong)?;
+		let start_block = Pallet::<T, I>::get_current_block_number();
+		let end_block = start_block.saturating_add(Pallet::<T, I>::maximum_duration().into());
+		let mut proposal = ProposalData::<T, I>::new(
+			offchain_data,
+			ProposalKind::Public,
+			T::PalletId::get().into_account_truncating(),
+			None,
+			start_block,
+			end_block,
+			ProposalSettings::default(),
+		);
+		proposal.seconds = T::SecondsThreshold::get();
+		with_storage_layer(|| Pallet::<T, I>::do_create_proposal(proposal))?;
+		Ok(NextProposalId::<T, I>::get().saturating_sub(1))
 	}
 
 	fn vote(
-		_proposal: Self::ProposalId,
-		_voter: Self::AccountId,
-		_aye: bool,
-		_vote_weight: Self::VotingBalance,
+		proposal: Self::ProposalId,
+		voter: Self::AccountId,
+		aye: bool,
+		vote_weight: Self::VotingBalance,
 	) -> DispatchResult {
-		unimplemented!()
+		with_storage_layer(|| {
+			Pallet::<T, I>::do_vote(voter, proposal, aye, vote_weight, Conviction::None, None)
+				.map(|_| ())
+				.map_err(|error| error.error)
+		})
 	}
 
-	fn close_vote(_proposal: Self::ProposalId) -> Result<bool, DispatchError> {
-		unimplemented!()
+	/// Closes the proposal, ending it at the current block if it is still in progress.
+	fn close_vote(proposal: Self::ProposalId) -> Result<bool, DispatchError> {
+		let current_block = Pallet::<T, I>::get_current_block_number();
+		with_storage_layer(|| {
+			let creator = Proposals::<T, I>::try_mutate(proposal, |maybe_proposal| {
+				let data = maybe_proposal.as_mut().ok_or(Error::<T, I>::ProposalDoesNotExist)?;
+				ensure!(data.has_started(&current_block), Error::<T, I>::ProposalHasNotStartedYet);
+				Pallet::<T, I>::unschedule_end(proposal, data.closes_at());
+				data.end_block = data.end_block.min(current_block);
+				Ok::<_, DispatchError>(data.creator.clone())
+			})?;
+			Pallet::<T, I>::close_proposal(
+				frame_system::RawOrigin::Signed(creator).into(),
+				proposal,
+			)
+			.map(|_| ())
+			.map_err(|error| error.error)
+		})?;
+		// An expired proposal was not approved
+		Ok(matches!(
+			ArchivedProposals::<T, I>::get(proposal).map(|archive| archive.outcome),
+			Some(ProposalOutcome::Completed { approved: true, .. })
+		))
 	}
 }
@@ -0,0 +1,790 @@
+use crate::{
+	Answers, BalanceOf, Commitments, Config, Contributions, Conviction, DelegateCasts,
+	DelegatedVotes, Delegations, Delegators, Disputes, FreezeReason, HoldReason, MigrationCursor,
+	OngoingMigration, OptionChoices, OutcomeOverride, Pallet, PowerOf, ProposalCalls, ProposalId,
+	ProposalOutcome, ProposalStatus, ProposalStatuses, Proposals, QueuedProposals,
+	RegisteredVoters, SpentCredits, VoterIndices, VotesTree, VotesTrees,
+};
+use frame_support::{
+	pallet_prelude::*,
+	sp_io,
+	sp_runtime::{traits::Zero, Perbill, SaturatedConversion, Saturating},
+	sp_std::{collections::btree_set::BTreeSet, vec, vec::Vec},
+	storage::{unhashed, StoragePrefixedMap},
+	traits::{
+		fungible::{InspectFreeze, MutateFreeze, MutateHold},
+		Bounded, BoundedInline, GetStorageVersion, OnRuntimeUpgrade, StorageVersion, StorePreimage,
+	},
+	Hashable,
+};
+use frame_system::pallet_prelude::BlockNumberFor;
+
+/// The migrations of the pallet in order, each run from the storage version preceding its own
+/// only. The stepped migrations run the ones following them once they complete.
+pub type Migrations<T, I = ()> = (
+	v1::MigrateToV1<T, I>,
+	v2::MigrateToV2<T, I>,
+	v3::MigrateToV3<T, I>,
+	v4::MigrateToV4<T, I>,
+	v5::MigrateToV5<T, I>,
+	v6::MigrateToV6<T, I>,
+	v7::MigrateToV7<T, I>,
+	v8::MigrateToV8<T, I>,
+	v9::MigrateToV9<T, I>,
+	v10::MigrateToV10<T, I>,
+	v11::MigrateToV11<T, I>,
+	v12::MigrateToV12<T, I>,
+);
+
+/// Starts the stepped migration to `version` from the first entry of its first map, of prefix
+/// `prefix`, unless a stepped migration is already in progress. The maps are then translated
+/// from `on_idle`, so chains with millions of entries can migrate without exceeding the block
+/// limits, and the storage version is bumped once they all are.
+fn start_stepped<T: Config<I>, I: 'static>(version: u16, prefix: [u8; 32]) -> Weight {
+	if OngoingMigration::<T, I>::exists() {
+		return T::DbWeight::get().reads(2)
+	}
+	let last_key = BoundedVec::truncate_from(prefix.to_vec());
+	OngoingMigration::<T, I>::put((version, MigrationCursor::Translate { map: 0, last_key }));
+	T::DbWeight::get().reads_writes(2, 1)
+}
+
+/// Steps the stepped migration in progress: translates the next entry of its maps, or appends
+/// the next leaf of the votes tree being rebuilt. Once it completes, the storage version is
+/// bumped and the migrations following it are run. Returns the weight of those migrations on top
+/// of the step, `None` if no migration is in progress.
+pub(crate) fn step<T: Config<I>, I: 'static>() -> Option<Weight> {
+	let (version, cursor) = OngoingMigration::<T, I>::get()?;
+	let maps = match version {
+		1 => v1::maps::<T, I>(),
+		9 => v9::maps::<T, I>(),
+		_ => Vec::new(),
+	};
+	let next = match cursor {
+		MigrationCursor::Translate { map, last_key } => {
+			let prefix = maps.get(map as usize).copied().unwrap_or_default();
+			match sp_io::storage::next_key(&last_key).filter(|key| key.starts_with(&prefix)) {
+				Some(key) => {
+					match version {
+						1 => v1::translate::<T, I>(map, &key),
+						_ => v9::translate::<T, I>(map, &key),
+					}
+					// Defensive: a raw key of the maps fits in a cursor
+					let last_key = BoundedVec::try_from(key).unwrap_or_default();
+					Some(MigrationCursor::Translate { map, last_key })
+				},
+				None => match maps.get(map.saturating_add(1) as usize) {
+					Some(prefix) => Some(MigrationCursor::Translate {
+						map: map.saturating_add(1),
+						last_key: BoundedVec::truncate_from(prefix.to_vec()),
+					}),
+					None if version == 9 => v9::next_tree::<T, I>(None),
+					None => None,
+				},
+			}
+		},
+		MigrationCursor::RebuildTree { proposal_id, tree } =>
+			v9::rebuild_next::<T, I>(proposal_id, tree),
+	};
+
+	match next {
+		Some(cursor) => {
+			OngoingMigration::<T, I>::put((version, cursor));
+			Some(Weight::zero())
+		},
+		None => {
+			OngoingMigration::<T, I>::kill();
+			StorageVersion::new(version).put::<Pallet<T, I>>();
+			Some(Migrations::<T, I>::on_runtime_upgrade())
+		},
+	}
+}
+
+/// Translates the value stored at the raw key `key` from `Old` to `New`, removing it if it cannot
+/// be decoded, like `translate_values`.
+fn translate_raw<Old: Decode, New: Encode>(key: &[u8], translate: impl FnOnce(Old) -> New) {
+	match unhashed::get::<Old>(key) {
+		Some(old) => unhashed::put(key, &translate(old)),
+		None => unhashed::kill(key),
+	}
+}
+
+/// The accounts which may have collateral for their votes and the number of entries read to
+/// find them. The votes are keyed with a non-reversible hasher, so the voters are found from the
+/// contributions, answers, option choices and commitments of the proposals not cleaned up yet.
+/// The votes left on closed proposals until they are claimed, with a claim delay, a conviction
+/// or a cost not simply frozen, have none of those anymore: they are looked up for the registered
+/// voters and for every account registered once, which keeps its voter index.
+fn collateral_voters<T: Config<I>, I: 'static>() -> (BTreeSet<T::AccountId>, u64) {
+	let mut reads: u64 = 0;
+	let mut voters: BTreeSet<_> = Contributions::<T, I>::iter_keys()
+		.chain(Answers::<T, I>::iter_keys())
+		.chain(OptionChoices::<T, I>::iter_keys())
+		.chain(Commitments::<T, I>::iter_keys())
+		.inspect(|_| reads.saturating_inc())
+		.map(|(_, who)| who)
+		.collect();
+	for who in RegisteredVoters::<T, I>::iter_keys().chain(VoterIndices::<T, I>::iter_keys()) {
+		reads.saturating_inc();
+		if voters.contains(&who) {
+			continue
+		}
+		reads.saturating_inc();
+		// The votes kept the layout of v1 until v9
+		if v1::Votes::<T, I>::iter_prefix_values(&who).next().is_some() {
+			voters.insert(who);
+		}
+	}
+	(voters, reads)
+}
+
+/// Expresses the power of the votes and the spent voice credits in the balance type rather than
+/// in `u128`.
+pub mod v1 {
+	use super::*;
+
+	#[derive(Decode)]
+	struct OldVoteInfo {
+		proposal_id: ProposalId,
+		aye: bool,
+		power: u128,
+	}
+
+	/// A vote as of this version.
+	#[derive(Encode, Decode)]
+	pub(super) struct VoteInfo<Power> {
+		pub(super) proposal_id: ProposalId,
+		pub(super) aye: bool,
+		pub(super) power: Power,
+	}
+
+	#[frame_support::storage_alias]
+	pub(super) type Votes<T: Config<I>, I: 'static> = StorageDoubleMap<
+		Pallet<T, I>,
+		Blake2_256,
+		<T as frame_system::Config>::AccountId,
+		Blake2_256,
+		ProposalId,
+		VoteInfo<BalanceOf<T, I>>,
+		OptionQuery,
+	>;
+
+	/// The prefixes of the maps translated, the votes then the spent credits.
+	pub(super) fn maps<T: Config<I>, I: 'static>() -> Vec<[u8; 32]> {
+		vec![Votes::<T, I>::final_prefix(), SpentCredits::<T, I>::final_prefix()]
+	}
+
+	/// Translates the entry at the raw key `key` of the map `map`.
+	pub(super) fn translate<T: Config<I>, I: 'static>(map: u8, key: &[u8]) {
+		match map {
+			0 => translate_raw(key, |vote: OldVoteInfo| VoteInfo::<BalanceOf<T, I>> {
+				proposal_id: vote.proposal_id,
+				aye: vote.aye,
+				power: vote.power.saturated_into(),
+			}),
+			_ => translate_raw(key, |(epoch, spent): (u32, u128)| {
+				(epoch, spent.saturated_into::<BalanceOf<T, I>>())
+			}),
+		}
+	}
+
+	/// Translates the votes and the spent credits as a stepped migration.
+	pub struct MigrateToV1<T, I = ()>(PhantomData<(T, I)>);
+
+	impl<T: Config<I>, I: 'static> OnRuntimeUpgrade for MigrateToV1<T, I> {
+		fn on_runtime_upgrade() -> Weight {
+			if Pallet::<T, I>::on_chain_storage_version() >= 1 {
+				return T::DbWeight::get().reads(1)
+			}
+
+			// A 16 bytes balance is encoded like a `u128`, only the version is bumped
+			if BalanceOf::<T, I>::max_encoded_len() == u128::max_encoded_len() {
+				StorageVersion::new(1).put::<Pallet<T, I>>();
+				return T::DbWeight::get().reads_writes(1, 1)
+			}
+			start_stepped::<T, I>(1, Votes::<T, I>::final_prefix())
+		}
+	}
+}
+
+/// Records in the archive of the proposals whether their outcome was overridden.
+pub mod v2 {
+	use super::*;
+
+	#[derive(Decode)]
+	struct OldArchivedProposal<AccountId, BlockNumber> {
+		creator: AccountId,
+		outcome: ProposalOutcome,
+		archived_at: BlockNumber,
+	}
+
+	/// The archive of a proposal as of this version.
+	#[derive(Encode, Decode)]
+	pub(super) struct ArchivedProposal<AccountId, BlockNumber> {
+		pub(super) creator: AccountId,
+		pub(super) outcome: ProposalOutcome,
+		pub(super) archived_at: BlockNumber,
+		pub(super) overridden: Option<(OutcomeOverride, AccountId)>,
+	}
+
+	#[frame_support::storage_alias]
+	pub(super) type ArchivedProposals<T: Config<I>, I: 'static> = StorageMap<
+		Pallet<T, I>,
+		Blake2_128Concat,
+		ProposalId,
+		ArchivedProposal<<T as frame_system::Config>::AccountId, BlockNumberFor<T>>,
+		OptionQuery,
+	>;
+
+	pub struct MigrateToV2<T, I = ()>(PhantomData<(T, I)>);
+
+	impl<T: Config<I>, I: 'static> OnRuntimeUpgrade for MigrateToV2<T, I> {
+		fn on_runtime_upgrade() -> Weight {
+			if Pallet::<T, I>::on_chain_storage_version() != 1 {
+				return T::DbWeight::get().reads(1)
+			}
+
+			let mut translated: u64 = 0;
+			ArchivedProposals::<T, I>::translate::<
+				OldArchivedProposal<T::AccountId, BlockNumberFor<T>>,
+				_,
+			>(|_, old| {
+				translated.saturating_inc();
+				Some(ArchivedProposal {
+					creator: old.creator,
+					outcome: old.outcome,
+					archived_at: old.archived_at,
+					overridden: None,
+				})
+			});
+			StorageVersion::new(2).put::<Pallet<T, I>>();
+
+			T::DbWeight::get()
+				.reads_writes(translated.saturating_add(1), translated.saturating_add(1))
+		}
+	}
+}
+
+/// Adds a consumer reference to the accounts with funds frozen by the pallet, now held until their
+/// freeze is fully released.
+pub mod v3 {
+	use super::*;
+
+	pub struct MigrateToV3<T, I = ()>(PhantomData<(T, I)>);
+
+	impl<T: Config<I>, I: 'static> OnRuntimeUpgrade for MigrateToV3<T, I> {
+		fn on_runtime_upgrade() -> Weight {
+			if Pallet::<T, I>::on_chain_storage_version() != 2 {
+				return T::DbWeight::get().reads(1)
+			}
+
+			let (voters, mut reads) = collateral_voters::<T, I>();
+			let mut writes: u64 = 0;
+			for who in voters {
+				reads.saturating_inc();
+				let frozen = T::NativeBalance::balance_frozen(&FreezeReason::Voting.into(), &who);
+				if !frozen.is_zero() && frame_system::Pallet::<T>::inc_consumers(&who).is_ok() {
+					writes.saturating_inc();
+				}
+			}
+			StorageVersion::new(3).put::<Pallet<T, I>>();
+
+			T::DbWeight::get().reads_writes(reads.saturating_add(1), writes.saturating_add(1))
+		}
+	}
+}
+
+/// Records the delegate who cast each delegated vote, and indexes the delegations and the
+/// delegated votes by delegate so they can be revoked once it is unregistered.
+pub mod v4 {
+	use super::*;
+
+	pub struct MigrateToV4<T, I = ()>(PhantomData<(T, I)>);
+
+	impl<T: Config<I>, I: 'static> OnRuntimeUpgrade for MigrateToV4<T, I> {
+		fn on_runtime_upgrade() -> Weight {
+			if Pallet::<T, I>::on_chain_storage_version() != 3 {
+				return T::DbWeight::get().reads(1)
+			}
+
+			let mut reads: u64 = 0;
+			let mut writes: u64 = 0;
+			for (delegator, delegate) in Delegations::<T, I>::iter() {
+				reads.saturating_inc();
+				writes.saturating_inc();
+				Delegators::<T, I>::insert(delegate, delegator, ());
+			}
+			DelegatedVotes::<T, I>::translate::<u32, _>(|proposal_id, delegator, hops| {
+				// The delegate is found along the current chain, the one it voted through unless
+				// the chain changed since
+				let mut delegate = delegator.clone();
+				for _ in 0..hops {
+					reads.saturating_inc();
+					match Delegations::<T, I>::get(&delegate) {
+						Some(next) => delegate = next,
+						None => break,
+					}
+				}
+				reads.saturating_inc();
+				writes.saturating_accrue(2);
+				DelegateCasts::<T, I>::insert(&delegate, (proposal_id, delegator), ());
+				Some((delegate, hops))
+			});
+			StorageVersion::new(4).put::<Pallet<T, I>>();
+
+			T::DbWeight::get().reads_writes(reads.saturating_add(1), writes.saturating_add(1))
+		}
+	}
+}
+
+/// Records the lifecycle status of the queued, open and archived proposals.
+pub mod v5 {
+	use super::*;
+
+	pub struct MigrateToV5<T, I = ()>(PhantomData<(T, I)>);
+
+	impl<T: Config<I>, I: 'static> OnRuntimeUpgrade for MigrateToV5<T, I> {
+		fn on_runtime_upgrade() -> Weight {
+			if Pallet::<T, I>::on_chain_storage_version() != 4 {
+				return T::DbWeight::get().reads(1)
+			}
+
+			let current_block = frame_system::Pallet::<T>::block_number();
+			let mut migrated: u64 = 0;
+			for proposal_id in QueuedProposals::<T, I>::iter_keys() {
+				migrated.saturating_inc();
+				ProposalStatuses::<T, I>::insert(proposal_id, ProposalStatus::Pending);
+			}
+			for (proposal_id, proposal) in Proposals::<T, I>::iter() {
+				migrated.saturating_inc();
+				let status = if proposal.has_started(&current_block) {
+					ProposalStatus::Active
+				} else {
+					ProposalStatus::Pending
+				};
+				ProposalStatuses::<T, I>::insert(proposal_id, status);
+			}
+			for (proposal_id, archive) in v2::ArchivedProposals::<T, I>::iter() {
+				migrated.saturating_inc();
+				let status = if Disputes::<T, I>::contains_key(proposal_id) {
+					ProposalStatus::Suspended
+				} else {
+					ProposalStatus::from(archive.outcome)
+				};
+				ProposalStatuses::<T, I>::insert(proposal_id, status);
+			}
+			StorageVersion::new(5).put::<Pallet<T, I>>();
+
+			T::DbWeight::get().reads_writes(
+				migrated.saturating_mul(2).saturating_add(1),
+				migrated.saturating_add(1),
+			)
+		}
+	}
+}
+
+/// Records in the archive of the proposals the factor their contributions were scaled by to fit
+/// their turnout cap.
+pub mod v6 {
+	use super::*;
+
+	/// The archive of a proposal as of this version.
+	#[derive(Encode, Decode)]
+	pub(super) struct ArchivedProposal<AccountId, BlockNumber> {
+		pub(super) creator: AccountId,
+		pub(super) outcome: ProposalOutcome,
+		pub(super) archived_at: BlockNumber,
+		pub(super) overridden: Option<(OutcomeOverride, AccountId)>,
+		pub(super) turnout_scale: Option<Perbill>,
+	}
+
+	#[frame_support::storage_alias]
+	type ArchivedProposals<T: Config<I>, I: 'static> = StorageMap<
+		Pallet<T, I>,
+		Blake2_128Concat,
+		ProposalId,
+		ArchivedProposal<<T as frame_system::Config>::AccountId, BlockNumberFor<T>>,
+		OptionQuery,
+	>;
+
+	pub struct MigrateToV6<T, I = ()>(PhantomData<(T, I)>);
+
+	impl<T: Config<I>, I: 'static> OnRuntimeUpgrade for MigrateToV6<T, I> {
+		fn on_runtime_upgrade() -> Weight {
+			if Pallet::<T, I>::on_chain_storage_version() != 5 {
+				return T::DbWeight::get().reads(1)
+			}
+
+			let mut translated: u64 = 0;
+			ArchivedProposals::<T, I>::translate::<
+				v2::ArchivedProposal<T::AccountId, BlockNumberFor<T>>,
+				_,
+			>(|_, old| {
+				translated.saturating_inc();
+				Some(ArchivedProposal {
+					creator: old.creator,
+					outcome: old.outcome,
+					archived_at: old.archived_at,
+					overridden: old.overridden,
+					turnout_scale: None,
+				})
+			});
+			StorageVersion::new(6).put::<Pallet<T, I>>();
+
+			T::DbWeight::get()
+				.reads_writes(translated.saturating_add(1), translated.saturating_add(1))
+		}
+	}
+}
+
+/// Moves the collateral frozen by the pallet on hold under `HoldReason::VoteCollateral`. The
+/// consumer reference of the accounts is kept until their hold is fully released.
+pub mod v7 {
+	use super::*;
+
+	pub struct MigrateToV7<T, I = ()>(PhantomData<(T, I)>);
+
+	impl<T: Config<I>, I: 'static> OnRuntimeUpgrade for MigrateToV7<T, I> {
+		fn on_runtime_upgrade() -> Weight {
+			if Pallet::<T, I>::on_chain_storage_version() != 6 {
+				return T::DbWeight::get().reads(1)
+			}
+
+			let freeze_id: T::RuntimeFreezeReason = FreezeReason::Voting.into();
+			let (voters, mut reads) = collateral_voters::<T, I>();
+			let mut writes: u64 = 0;
+			for who in voters {
+				reads.saturating_inc();
+				let frozen = T::NativeBalance::balance_frozen(&freeze_id, &who);
+				// Defensive: the thawed funds are free to be held
+				if !frozen.is_zero() &&
+					T::NativeBalance::thaw(&freeze_id, &who).is_ok() &&
+					T::NativeBalance::hold(&HoldReason::VoteCollateral.into(), &who, frozen)
+						.is_ok()
+				{
+					writes.saturating_accrue(2);
+				}
+			}
+			StorageVersion::new(7).put::<Pallet<T, I>>();
+
+			T::DbWeight::get().reads_writes(reads.saturating_add(1), writes.saturating_add(1))
+		}
+	}
+}
+
+/// Records in the archive of the proposals the number of pre-registered voters who abstained.
+pub mod v8 {
+	use super::*;
+
+	/// The archive of a proposal as of this version.
+	#[derive(Encode, Decode)]
+	pub(super) struct ArchivedProposal<AccountId, BlockNumber> {
+		pub(super) creator: AccountId,
+		pub(super) outcome: ProposalOutcome,
+		pub(super) archived_at: BlockNumber,
+		pub(super) overridden: Option<(OutcomeOverride, AccountId)>,
+		pub(super) turnout_scale: Option<Perbill>,
+		pub(super) abstentions: Option<u32>,
+	}
+
+	#[frame_support::storage_alias]
+	type ArchivedProposals<T: Config<I>, I: 'static> = StorageMap<
+		Pallet<T, I>,
+		Blake2_128Concat,
+		ProposalId,
+		ArchivedProposal<<T as frame_system::Config>::AccountId, BlockNumberFor<T>>,
+		OptionQuery,
+	>;
+
+	pub struct MigrateToV8<T, I = ()>(PhantomData<(T, I)>);
+
+	impl<T: Config<I>, I: 'static> OnRuntimeUpgrade for MigrateToV8<T, I> {
+		fn on_runtime_upgrade() -> Weight {
+			if Pallet::<T, I>::on_chain_storage_version() != 7 {
+				return T::DbWeight::get().reads(1)
+			}
+
+			let mut translated: u64 = 0;
+			ArchivedProposals::<T, I>::translate::<
+				v6::ArchivedProposal<T::AccountId, BlockNumberFor<T>>,
+				_,
+			>(|_, old| {
+				translated.saturating_inc();
+				Some(ArchivedProposal {
+					creator: old.creator,
+					outcome: old.outcome,
+					archived_at: old.archived_at,
+					overridden: old.overridden,
+					turnout_scale: old.turnout_scale,
+					abstentions: None,
+				})
+			});
+			StorageVersion::new(8).put::<Pallet<T, I>>();
+
+			T::DbWeight::get()
+				.reads_writes(translated.saturating_add(1), translated.saturating_add(1))
+		}
+	}
+}
+
+/// Adds the conviction to the votes, all cast without conviction, and rebuilds the votes trees
+/// whose leaves hash the votes.
+pub mod v9 {
+	use super::*;
+
+	/// A vote as of this version.
+	#[derive(Encode, Decode)]
+	pub(super) struct VoteInfo<Power> {
+		pub(super) proposal_id: ProposalId,
+		pub(super) aye: bool,
+		pub(super) power: Power,
+		pub(super) conviction: Conviction,
+	}
+
+	impl<Power> From<v1::VoteInfo<Power>> for VoteInfo<Power> {
+		fn from(old: v1::VoteInfo<Power>) -> Self {
+			VoteInfo {
+				proposal_id: old.proposal_id,
+				aye: old.aye,
+				power: old.power,
+				conviction: Conviction::None,
+			}
+		}
+	}
+
+	#[frame_support::storage_alias]
+	type Votes<T: Config<I>, I: 'static> = StorageDoubleMap<
+		Pallet<T, I>,
+		Blake2_256,
+		<T as frame_system::Config>::AccountId,
+		Blake2_256,
+		ProposalId,
+		VoteInfo<PowerOf<T, I>>,
+		OptionQuery,
+	>;
+
+	#[frame_support::storage_alias]
+	type VoteLog<T: Config<I>, I: 'static> = StorageDoubleMap<
+		Pallet<T, I>,
+		Twox64Concat,
+		ProposalId,
+		Twox64Concat,
+		u32,
+		(<T as frame_system::Config>::AccountId, VoteInfo<PowerOf<T, I>>),
+		OptionQuery,
+	>;
+
+	/// The prefixes of the maps translated, the votes then the vote log.
+	pub(super) fn maps<T: Config<I>, I: 'static>() -> Vec<[u8; 32]> {
+		vec![Votes::<T, I>::final_prefix(), VoteLog::<T, I>::final_prefix()]
+	}
+
+	/// Translates the entry at the raw key `key` of the map `map`.
+	pub(super) fn translate<T: Config<I>, I: 'static>(map: u8, key: &[u8]) {
+		match map {
+			0 => translate_raw(key, |vote: v1::VoteInfo<PowerOf<T, I>>| VoteInfo::from(vote)),
+			_ => translate_raw(key, |(who, vote): (T::AccountId, v1::VoteInfo<PowerOf<T, I>>)| {
+				(who, VoteInfo::from(vote))
+			}),
+		}
+	}
+
+	/// The cursor rebuilding the votes tree following the one of `after`, or the first one, from
+	/// its first leaf. `None` once all the trees are rebuilt.
+	pub(super) fn next_tree<T: Config<I>, I: 'static>(
+		after: Option<ProposalId>,
+	) -> Option<MigrationCursor> {
+		let mut proposal_ids = VotesTrees::<T, I>::iter_keys();
+		if let Some(proposal_id) = after {
+			proposal_ids.set_last_raw_key(VotesTrees::<T, I>::hashed_key_for(proposal_id));
+		}
+		let proposal_id = proposal_ids.next()?;
+		Some(MigrationCursor::RebuildTree { proposal_id, tree: VotesTree::default() })
+	}
+
+	/// Appends the next leaf of the votes tree of `proposal_id` to `tree`, storing it once it has
+	/// all its leaves. A tree whose log is being pruned is left as is.
+	pub(super) fn rebuild_next<T: Config<I>, I: 'static>(
+		proposal_id: ProposalId,
+		mut tree: VotesTree,
+	) -> Option<MigrationCursor> {
+		if tree.leaves < VotesTrees::<T, I>::get(proposal_id).leaves {
+			if let Some((who, vote)) = VoteLog::<T, I>::get(proposal_id, tree.leaves) {
+				// The leaf of a vote hashes it with its voter, as `vote_leaf` did then
+				tree.append((who, vote).blake2_256());
+				return Some(MigrationCursor::RebuildTree { proposal_id, tree })
+			}
+		} else {
+			VotesTrees::<T, I>::insert(proposal_id, tree);
+		}
+		next_tree::<T, I>(Some(proposal_id))
+	}
+
+	/// Translates the votes and the vote log, then rebuilds the votes trees, as a stepped
+	/// migration.
+	pub struct MigrateToV9<T, I = ()>(PhantomData<(T, I)>);
+
+	impl<T: Config<I>, I: 'static> OnRuntimeUpgrade for MigrateToV9<T, I> {
+		fn on_runtime_upgrade() -> Weight {
+			if Pallet::<T, I>::on_chain_storage_version() != 8 {
+				return T::DbWeight::get().reads(1)
+			}
+
+			start_stepped::<T, I>(9, Votes::<T, I>::final_prefix())
+		}
+	}
+}
+
+/// Stores the calls of the proposals as bounded calls: inlined, or noted as preimages of
+/// `Preimages` when too large to be inlined.
+pub mod v10 {
+	use super::*;
+
+	pub struct MigrateToV10<T, I = ()>(PhantomData<(T, I)>);
+
+	impl<T: Config<I>, I: 'static> OnRuntimeUpgrade for MigrateToV10<T, I> {
+		fn on_runtime_upgrade() -> Weight {
+			if Pallet::<T, I>::on_chain_storage_version() != 9 {
+				return T::DbWeight::get().reads(1)
+			}
+
+			let mut translated: u64 = 0;
+			let mut noted: u64 = 0;
+			ProposalCalls::<T, I>::translate::<Vec<u8>, _>(|_, encoded| {
+				translated.saturating_inc();
+				let len = encoded.len() as u32;
+				match BoundedInline::try_from(encoded) {
+					Ok(inline) => Some(Bounded::Inline(inline)),
+					// A noted preimage is requested, like the preimage of a call being set
+					Err(encoded) => {
+						noted.saturating_inc();
+						let hash = T::Preimages::note(encoded.into()).ok()?;
+						Some(Bounded::Lookup { hash, len })
+					},
+				}
+			});
+			StorageVersion::new(10).put::<Pallet<T, I>>();
+
+			T::DbWeight::get().reads_writes(
+				translated.saturating_add(noted).saturating_add(1),
+				translated.saturating_add(noted.saturating_mul(2)).saturating_add(1),
+			)
+		}
+	}
+}
+
+/// Adds the voter cap to the archived proposals, none of them having one.
+pub mod v11 {
+	use super::*;
+
+	/// The archive of a proposal as of this version.
+	#[derive(Encode, Decode)]
+	pub(super) struct ArchivedProposal<AccountId, BlockNumber> {
+		pub(super) creator: AccountId,
+		pub(super) outcome: ProposalOutcome,
+		pub(super) archived_at: BlockNumber,
+		pub(super) overridden: Option<(OutcomeOverride, AccountId)>,
+		pub(super) turnout_scale: Option<Perbill>,
+		pub(super) abstentions: Option<u32>,
+		pub(super) voter_cap: Option<(u32, u32)>,
+	}
+
+	#[frame_support::storage_alias]
+	type ArchivedProposals<T: Config<I>, I: 'static> = StorageMap<
+		Pallet<T, I>,
+		Blake2_128Concat,
+		ProposalId,
+		ArchivedProposal<<T as frame_system::Config>::AccountId, BlockNumberFor<T>>,
+		OptionQuery,
+	>;
+
+	pub struct MigrateToV11<T, I = ()>(PhantomData<(T, I)>);
+
+	impl<T: Config<I>, I: 'static> OnRuntimeUpgrade for MigrateToV11<T, I> {
+		fn on_runtime_upgrade() -> Weight {
+			if Pallet::<T, I>::on_chain_storage_version() != 10 {
+				return T::DbWeight::get().reads(1)
+			}
+
+			let mut translated: u64 = 0;
+			ArchivedProposals::<T, I>::translate::<
+				v8::ArchivedProposal<T::AccountId, BlockNumberFor<T>>,
+				_,
+			>(|_, old| {
+				translated.saturating_inc();
+				Some(ArchivedProposal {
+					creator: old.creator,
+					outcome: old.outcome,
+					archived_at: old.archived_at,
+					overridden: old.overridden,
+					turnout_scale: old.turnout_scale,
+					abstentions: old.abstentions,
+					voter_cap: None,
+				})
+			});
+			StorageVersion::new(11).put::<Pallet<T, I>>();
+
+			T::DbWeight::get()
+				.reads_writes(translated.saturating_add(1), translated.saturating_add(1))
+		}
+	}
+}
+
+/// Adds the enactment failure to the archived proposals, none of them having one.
+pub mod v12 {
+	use super::*;
+
+	/// The archive of a proposal as of this version.
+	#[derive(Encode, Decode)]
+	pub(super) struct ArchivedProposal<AccountId, BlockNumber> {
+		pub(super) creator: AccountId,
+		pub(super) outcome: ProposalOutcome,
+		pub(super) archived_at: BlockNumber,
+		pub(super) overridden: Option<(OutcomeOverride, AccountId)>,
+		pub(super) turnout_scale: Option<Perbill>,
+		pub(super) abstentions: Option<u32>,
+		pub(super) voter_cap: Option<(u32, u32)>,
+		pub(super) enactment_failure: Option<DispatchError>,
+	}
+
+	#[frame_support::storage_alias]
+	type ArchivedProposals<T: Config<I>, I: 'static> = StorageMap<
+		Pallet<T, I>,
+		Blake2_128Concat,
+		ProposalId,
+		ArchivedProposal<<T as frame_system::Config>::AccountId, BlockNumberFor<T>>,
+		OptionQuery,
+	>;
+
+	pub struct MigrateToV12<T, I = ()>(PhantomData<(T, I)>);
+
+	impl<T: Config<I>, I: 'static> OnRuntimeUpgrade for MigrateToV12<T, I> {
+		fn on_runtime_upgrade() -> Weight {
+			if Pallet::<T, I>::on_chain_storage_version() != 11 {
+				return T::DbWeight::get().reads(1)
+			}
+
+			let mut translated: u64 = 0;
+			ArchivedProposals::<T, I>::translate::<
+				v11::ArchivedProposal<T::AccountId, BlockNumberFor<T>>,
+				_,
+			>(|_, old| {
+				translated.saturating_inc();
+				Some(ArchivedProposal {
+					creator: old.creator,
+					outcome: old.outcome,
+					archived_at: old.archived_at,
+					overridden: old.overridden,
+					turnout_scale: old.turnout_scale,
+					abstentions: old.abstentions,
+					voter_cap: old.voter_cap,
+					enactment_failure: None,
+				})
+			});
+			StorageVersion::new(12).put::<Pallet<T, I>>();
+
+			T::DbWeight::get()
+				.reads_writes(translated.saturating_add(1), translated.saturating_add(1))
+		}
+	}
+}
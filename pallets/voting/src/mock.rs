@@ -1,13 +1,20 @@
 use crate as pallet_voting;
 use frame_support::{
 	parameter_types,
-	traits::{ConstU128, ConstU16, ConstU32, ConstU64},
+	traits::{
+		tokens::nonfungibles_v2, AsEnsureOriginWithArg, ConstU128, ConstU16, ConstU32, ConstU64,
+		Contains, EqualPrivilegeOnly, FetchResult, QueryPreimage, Randomness, StorePreimage,
+	},
+	weights::Weight,
+	PalletId,
 };
+use frame_system::{EnsureRoot, EnsureRootWithSuccess, EnsureSigned};
 use sp_core::H256;
 use sp_runtime::{
-	traits::{BlakeTwo256, IdentityLookup},
-	BuildStorage,
+	traits::{BlakeTwo256, Hash, IdentityLookup},
+	BuildStorage, DispatchError, Perbill, Percent,
 };
+use std::borrow::Cow;
 
 type Block = frame_system::mocking::MockBlock<Test>;
 type Balance = u128;
@@ -15,9 +22,53 @@ pub type BlockNumber = u32;
 
 pub const PROPOSAL_ACCOUNT_SIZE_LIMIT: u32 = 1000;
 pub const PROPOSAL_OFFCHAIN_DATA_LIMIT: u32 = 150;
+pub const MAX_ELIGIBILITY_BITMAP_LEN: u32 = 16;
 pub const PROPOSAL_MAXIMUM_DURATION: BlockNumber = 1000;
 pub const PROPOSAL_MINIMUM_DURATION: BlockNumber = 100;
 pub const PROPOSAL_DELAY_LIMIT: BlockNumber = 100;
+pub const VOICE_CREDITS: u128 = 20;
+pub const EPOCH_DURATION: BlockNumber = 1000;
+pub const ARCHIVE_RETENTION: BlockNumber = 1000;
+pub const CLAIM_GRACE_PERIOD: BlockNumber = 10;
+pub const CONVICTION_PERIOD: BlockNumber = 10;
+pub const MAX_RELEASES_PER_BLOCK: u32 = 2;
+pub const MAX_CLOSES_PER_BLOCK: u32 = 2;
+pub const MAX_PROPOSALS_PER_KIND: u32 = 100;
+pub const MAX_QUESTIONS: u32 = 4;
+pub const MAX_OPTIONS: u32 = 4;
+pub const MAX_DEPUTIES: u32 = 2;
+pub const DEPUTY_PERIOD: u32 = 10;
+pub const MAX_JURY_SIZE: u32 = 3;
+pub const MAX_LOTTERY_WINNERS: u32 = 3;
+pub const MAX_DELEGATION_DEPTH: u32 = 3;
+pub const DELEGATION_ATTENUATION: Percent = Percent::from_percent(10);
+pub const MAX_MIGRATED_ENTRIES: u32 = 3;
+pub const TENURE_BONUS_PER_EPOCH: Percent = Percent::from_percent(10);
+pub const MAX_TENURE_BONUS: Percent = Percent::from_percent(50);
+pub const LAUNCH_PERIOD: BlockNumber = 50;
+pub const MAX_CHECKPOINTS_PER_BLOCK: u32 = 2;
+pub const EMERGENCY_ACCOUNT: u64 = 100;
+pub const EMERGENCY_MAX_DURATION: BlockNumber = 20;
+pub const EMERGENCY_SUPERMAJORITY: Perbill = Perbill::from_percent(66);
+pub const EXTERNAL_ACCOUNT: u64 = 101;
+pub const TREASURY_ACCOUNT: u64 = 102;
+pub const VOTE_REFUND: Balance = 2;
+pub const DISPUTE_PERIOD: BlockNumber = 10;
+pub const DISPUTE_BOND: Balance = 5;
+pub const REGISTRATION_BOND: Balance = 3;
+pub const OVERRIDE_ACCOUNT: u64 = 103;
+pub const OVERRIDE_PERIOD: BlockNumber = 10;
+pub const MAX_POWER: u128 = 1_000_000;
+pub const PASS_COLLECTION: u32 = 7;
+pub const PASS_BONUS: Percent = Percent::from_percent(50);
+pub const MAX_CALL_SIZE: u32 = 256;
+pub const MAX_ENACTMENT_WEIGHT: Weight = Weight::from_parts(1_000_000_000, 100_000);
+pub const MAX_ENACTMENT_RETRIES: u32 = 2;
+pub const ENACTMENT_RETRY_PERIOD: BlockNumber = 5;
+/// An account never attested by `TestPersonhood`.
+pub const UNATTESTED: u64 = 99;
+/// An account whose identity is never verified by `TestIdentities`.
+pub const UNVERIFIED: u64 = 98;
 
 // Configure a mock runtime to test the pallet.
 frame_support::construct_runtime!(
@@ -25,6 +76,8 @@ frame_support::construct_runtime!(
 	{
 		System: frame_system,
 		Balances: pallet_balances,
+		Assets: pallet_assets,
+		Scheduler: pallet_scheduler,
 		Voting: pallet_voting,
 	}
 );
@@ -65,29 +118,353 @@ impl pallet_balances::Config for Test {
 	type MaxLocks = ConstU32<10>;
 	type MaxReserves = ();
 	type ReserveIdentifier = [u8; 8];
-	type RuntimeHoldReason = ();
-	type FreezeIdentifier = ();
+	type RuntimeHoldReason = RuntimeHoldReason;
+	type FreezeIdentifier = RuntimeFreezeReason;
 	type MaxHolds = ConstU32<10>;
 	type MaxFreezes = ConstU32<10>;
 }
 
+impl pallet_assets::Config for Test {
+	type RuntimeEvent = RuntimeEvent;
+	type Balance = Balance;
+	type AssetId = u32;
+	type AssetIdParameter = u32;
+	type Currency = Balances;
+	type CreateOrigin = AsEnsureOriginWithArg<EnsureSigned<u64>>;
+	type ForceOrigin = EnsureRoot<u64>;
+	type AssetDeposit = ConstU128<0>;
+	type AssetAccountDeposit = ConstU128<0>;
+	type MetadataDepositBase = ConstU128<0>;
+	type MetadataDepositPerByte = ConstU128<0>;
+	type ApprovalDeposit = ConstU128<0>;
+	type StringLimit = ConstU32<50>;
+	type Freezer = ();
+	type Extra = ();
+	type WeightInfo = ();
+	type RemoveItemsLimit = ConstU32<1000>;
+	type CallbackHandle = ();
+	#[cfg(feature = "runtime-benchmarks")]
+	type BenchmarkHelper = ();
+}
+
+parameter_types! {
+	pub const MaximumSchedulerWeight: Weight = Weight::MAX;
+}
+
+impl pallet_scheduler::Config for Test {
+	type RuntimeEvent = RuntimeEvent;
+	type RuntimeOrigin = RuntimeOrigin;
+	type PalletsOrigin = OriginCaller;
+	type RuntimeCall = RuntimeCall;
+	type MaximumWeight = MaximumSchedulerWeight;
+	type ScheduleOrigin = EnsureRoot<u64>;
+	type MaxScheduledPerBlock = ConstU32<10>;
+	type WeightInfo = ();
+	type OriginPrivilegeCmp = EqualPrivilegeOnly;
+	type Preimages = TestPreimages;
+}
+
 parameter_types! {
 	pub const AccountSizeLimit: u32 = PROPOSAL_ACCOUNT_SIZE_LIMIT;
 	pub const ProposalOffchainDataLimit: u32 = PROPOSAL_OFFCHAIN_DATA_LIMIT;
+	pub const MaxEligibilityBitmapLen: u32 = MAX_ELIGIBILITY_BITMAP_LEN;
 	pub const ProposalMaximumDuration: u32 = PROPOSAL_MAXIMUM_DURATION;
 	pub const ProposalMinimumDuration: u32 = PROPOSAL_MINIMUM_DURATION;
 	pub const ProposalDelayLimit: u32 = PROPOSAL_DELAY_LIMIT;
+	pub const VoiceCredits: u128 = VOICE_CREDITS;
+	pub const EpochDuration: u32 = EPOCH_DURATION;
+	pub const ArchiveRetention: u32 = ARCHIVE_RETENTION;
+	pub const ClaimGracePeriod: u32 = CLAIM_GRACE_PERIOD;
+	pub const ConvictionPeriod: u32 = CONVICTION_PERIOD;
+	pub const MaxReleasesPerBlock: u32 = MAX_RELEASES_PER_BLOCK;
+	pub const MaxClosesPerBlock: u32 = MAX_CLOSES_PER_BLOCK;
+	pub const MaxPower: u128 = MAX_POWER;
+	pub const TenureBonusPerEpoch: Percent = TENURE_BONUS_PER_EPOCH;
+	pub const MaxTenureBonus: Percent = MAX_TENURE_BONUS;
+	pub const MaxProposalsPerKind: u32 = MAX_PROPOSALS_PER_KIND;
+	pub const MaxQuestions: u32 = MAX_QUESTIONS;
+	pub const MaxOptions: u32 = MAX_OPTIONS;
+	pub const MaxDeputies: u32 = MAX_DEPUTIES;
+	pub const DeputyPeriod: u32 = DEPUTY_PERIOD;
+	pub const MaxJurySize: u32 = MAX_JURY_SIZE;
+	pub const MaxLotteryWinners: u32 = MAX_LOTTERY_WINNERS;
+	pub const MaxDelegationDepth: u32 = MAX_DELEGATION_DEPTH;
+	pub const DelegationAttenuation: Percent = DELEGATION_ATTENUATION;
+	pub const MaxMigratedEntries: u32 = MAX_MIGRATED_ENTRIES;
+	// No expiry by default, set by the tests covering registration expiry
+	pub storage RegistrationTtl: Option<u32> = None;
+	// No minimum by default, set by the tests covering the minimum balance
+	pub storage MinVoterBalance: Balance = 0;
+	// No balance age by default, set by the tests covering it
+	pub storage BalanceAge: u32 = 0;
+	// No sponsorship phase by default, set by the tests covering seconds
+	pub storage SecondsThreshold: u32 = 0;
+	pub const LaunchPeriod: u32 = LAUNCH_PERIOD;
+	// No tally checkpoint by default, set by the tests covering them
+	pub storage TallyCheckpointPeriod: u32 = 0;
+	pub const MaxCheckpointsPerBlock: u32 = MAX_CHECKPOINTS_PER_BLOCK;
+	// No tally commitment by default, set by the tests covering it
+	pub storage PublishTallyCommitment: bool = false;
+	pub const EmergencyAccount: u64 = EMERGENCY_ACCOUNT;
+	pub const EmergencyMaxDuration: u32 = EMERGENCY_MAX_DURATION;
+	pub const EmergencySupermajority: Perbill = EMERGENCY_SUPERMAJORITY;
+	pub const ExternalAccount: u64 = EXTERNAL_ACCOUNT;
+	pub const VotingPalletId: PalletId = PalletId(*b"py/votng");
+	pub const TreasuryAccount: u64 = TREASURY_ACCOUNT;
+	pub const VoteRefund: Balance = VOTE_REFUND;
+	pub const DisputePeriod: u32 = DISPUTE_PERIOD;
+	pub const DisputeBond: Balance = DISPUTE_BOND;
+	// No proposal deposit by default, set by the tests covering it
+	pub storage ProposalDeposit: Balance = 0;
+	pub const RegistrationBond: Balance = REGISTRATION_BOND;
+	pub const OverrideAccount: u64 = OVERRIDE_ACCOUNT;
+	pub const OverridePeriod: u32 = OVERRIDE_PERIOD;
+	// No sample audit by default, set by the tests covering them
+	pub storage AuditSampleSize: u32 = 0;
+	pub NftCollections: Vec<(u32, Percent)> = vec![(PASS_COLLECTION, PASS_BONUS)];
+	// No pass holder by default, set by the tests covering NFT gating
+	pub storage NftHolders: Vec<(u32, u64)> = vec![];
+	// No preimage by default, noted by the tests covering the voter registry syncs
+	pub storage NotedPreimages: Vec<(H256, Vec<u8>)> = vec![];
+	pub storage RequestedPreimages: Vec<H256> = vec![];
+	// The policies repealed by their sunset review
+	pub storage RepealedPolicies: Vec<(u32, u32)> = vec![];
+	pub EnactmentOrigin: RuntimeOrigin = frame_system::RawOrigin::Root.into();
+	pub const MaxCallSize: u32 = MAX_CALL_SIZE;
+	pub const MaxEnactmentWeight: Weight = MAX_ENACTMENT_WEIGHT;
+	pub const MaxEnactmentRetries: u32 = MAX_ENACTMENT_RETRIES;
+	pub const EnactmentRetryPeriod: u32 = ENACTMENT_RETRY_PERIOD;
 }
 
 impl pallet_voting::Config for Test {
 	type RuntimeEvent = RuntimeEvent;
+	type WeightInfo = ();
 	type NativeBalance = Balances;
+	type RuntimeHoldReason = RuntimeHoldReason;
+	type RuntimeFreezeReason = RuntimeFreezeReason;
 	type AccountSizeLimit = AccountSizeLimit;
+	type MaxEligibilityBitmapLen = MaxEligibilityBitmapLen;
 	type ProposalOffchainDataLimit = ProposalOffchainDataLimit;
 	type ProposalMaximumDuration = ProposalMaximumDuration;
 	type ProposalMinimumDuration = ProposalMinimumDuration;
 	type ProposalDelayLimit = ProposalDelayLimit;
-	type FreezeIdForPallet = ();
+	type VoiceCredits = VoiceCredits;
+	type EpochDuration = EpochDuration;
+	type ArchiveRetention = ArchiveRetention;
+	type ClaimGracePeriod = ClaimGracePeriod;
+	type ConvictionPeriod = ConvictionPeriod;
+	type MaxReleasesPerBlock = MaxReleasesPerBlock;
+	type MaxClosesPerBlock = MaxClosesPerBlock;
+	type MaxPower = MaxPower;
+	type TenureBonusPerEpoch = TenureBonusPerEpoch;
+	type MaxTenureBonus = MaxTenureBonus;
+	type RegistrationTtl = RegistrationTtl;
+	type MinVoterBalance = MinVoterBalance;
+	type BalanceAge = BalanceAge;
+	type SecondsThreshold = SecondsThreshold;
+	type LaunchPeriod = LaunchPeriod;
+	type TallyCheckpointPeriod = TallyCheckpointPeriod;
+	type MaxCheckpointsPerBlock = MaxCheckpointsPerBlock;
+	type PublishTallyCommitment = PublishTallyCommitment;
+	type EmergencyOrigin = EnsureRootWithSuccess<u64, EmergencyAccount>;
+	type ExternalOrigin = EnsureRootWithSuccess<u64, ExternalAccount>;
+	type EmergencyMaxDuration = EmergencyMaxDuration;
+	type EmergencySupermajority = EmergencySupermajority;
+	type MaxProposalsPerKind = MaxProposalsPerKind;
+	type MaxQuestions = MaxQuestions;
+	type MaxOptions = MaxOptions;
+	type Randomness = TestRandomness;
+	type MaxJurySize = MaxJurySize;
+	type MaxLotteryWinners = MaxLotteryWinners;
+	type MaxDelegationDepth = MaxDelegationDepth;
+	type DelegationAttenuation = DelegationAttenuation;
+	type MaxMigratedEntries = MaxMigratedEntries;
+	type PalletId = VotingPalletId;
+	type TreasuryAccount = TreasuryAccount;
+	type VoteRefund = VoteRefund;
+	type DisputePeriod = DisputePeriod;
+	type DisputeBond = DisputeBond;
+	type ProposalDeposit = ProposalDeposit;
+	type RegistrationBond = RegistrationBond;
+	type DisputeResolutionOrigin = EnsureRoot<u64>;
+	type OverrideOrigin = EnsureRootWithSuccess<u64, OverrideAccount>;
+	type OverridePeriod = OverridePeriod;
+	type AdminOrigin = EnsureRoot<u64>;
+	type AuditSampleSize = AuditSampleSize;
+	type PersonhoodProvider = TestPersonhood;
+	type IdentityVerifier = TestIdentities;
+	type KindHandler = ();
+	type OnRepeal = TestOnRepeal;
+	type Nfts = TestNfts;
+	type NftCollections = NftCollections;
+	type RegistrarOrigin = EnsureRoot<u64>;
+	type MaxDeputies = MaxDeputies;
+	type DeputyPeriod = DeputyPeriod;
+	type Preimages = TestPreimages;
+	type Assets = Assets;
+	type AssetOrigin = EnsureRoot<u64>;
+	type RoleOrigin = EnsureRoot<u64>;
+	type RuntimeCall = RuntimeCall;
+	type EnactmentOrigin = EnactmentOrigin;
+	type MaxCallSize = MaxCallSize;
+	type MaxEnactmentWeight = MaxEnactmentWeight;
+	type Scheduler = Scheduler;
+	type MaxEnactmentRetries = MaxEnactmentRetries;
+	type EnactmentRetryPeriod = EnactmentRetryPeriod;
+	#[cfg(feature = "runtime-benchmarks")]
+	type BenchmarkHelper = VotingBenchmarkHelper;
+}
+
+/// Votes with the asset 0 and notes the preimages in `TestPreimages`.
+#[cfg(feature = "runtime-benchmarks")]
+pub struct VotingBenchmarkHelper;
+
+#[cfg(feature = "runtime-benchmarks")]
+impl pallet_voting::BenchmarkHelper<u32> for VotingBenchmarkHelper {
+	fn asset_id() -> u32 {
+		0
+	}
+
+	fn note_preimage(data: Vec<u8>) -> H256 {
+		TestPreimages::note(data)
+	}
+}
+
+/// The NFTs of `NftHolders`, each holder owning the item of its own account ID in a collection.
+pub struct TestNfts;
+
+impl nonfungibles_v2::Inspect<u64> for TestNfts {
+	type ItemId = u64;
+	type CollectionId = u32;
+
+	fn owner(collection: &u32, item: &u64) -> Option<u64> {
+		NftHolders::get().contains(&(*collection, *item)).then_some(*item)
+	}
+}
+
+impl nonfungibles_v2::InspectEnumerable<u64> for TestNfts {
+	type CollectionsIterator = std::vec::IntoIter<u32>;
+	type ItemsIterator = std::vec::IntoIter<u64>;
+	type OwnedIterator = std::vec::IntoIter<(u32, u64)>;
+	type OwnedInCollectionIterator = std::vec::IntoIter<u64>;
+
+	fn collections() -> Self::CollectionsIterator {
+		let mut collections: Vec<u32> = NftHolders::get().into_iter().map(|(c, _)| c).collect();
+		collections.sort();
+		collections.dedup();
+		collections.into_iter()
+	}
+
+	fn items(collection: &u32) -> Self::ItemsIterator {
+		let holders = NftHolders::get().into_iter().filter(|(c, _)| c == collection);
+		holders.map(|(_, who)| who).collect::<Vec<_>>().into_iter()
+	}
+
+	fn owned(who: &u64) -> Self::OwnedIterator {
+		NftHolders::get()
+			.into_iter()
+			.filter(|(_, holder)| holder == who)
+			.collect::<Vec<_>>()
+			.into_iter()
+	}
+
+	fn owned_in_collection(collection: &u32, who: &u64) -> Self::OwnedInCollectionIterator {
+		let owned = Self::owned(who).filter(|(c, _)| c == collection);
+		owned.map(|(_, item)| item).collect::<Vec<_>>().into_iter()
+	}
+}
+
+/// Records the repealed policies in `RepealedPolicies`.
+pub struct TestOnRepeal;
+
+impl pallet_voting::OnRepeal for TestOnRepeal {
+	fn on_repeal(policy_id: u32, review_id: u32) {
+		let mut repealed = RepealedPolicies::get();
+		repealed.push((policy_id, review_id));
+		RepealedPolicies::set(&repealed);
+	}
+}
+
+/// The preimages of `NotedPreimages`, the requested ones being kept in `RequestedPreimages`.
+pub struct TestPreimages;
+
+impl TestPreimages {
+	/// Notes `data` as a preimage and returns its hash.
+	pub fn note(data: Vec<u8>) -> H256 {
+		let hash = BlakeTwo256::hash(&data);
+		let mut preimages = NotedPreimages::get();
+		preimages.push((hash, data));
+		NotedPreimages::set(&preimages);
+		hash
+	}
+
+	fn preimage(hash: &H256) -> Option<Vec<u8>> {
+		NotedPreimages::get().into_iter().find(|(h, _)| h == hash).map(|(_, data)| data)
+	}
+}
+
+impl QueryPreimage for TestPreimages {
+	fn len(hash: &H256) -> Option<u32> {
+		Self::preimage(hash).map(|data| data.len() as u32)
+	}
+
+	fn fetch(hash: &H256, _len: Option<u32>) -> FetchResult {
+		Self::preimage(hash).map(Cow::Owned).ok_or(DispatchError::Unavailable)
+	}
+
+	fn is_requested(hash: &H256) -> bool {
+		RequestedPreimages::get().contains(hash)
+	}
+
+	fn request(hash: &H256) {
+		let mut requested = RequestedPreimages::get();
+		requested.push(*hash);
+		RequestedPreimages::set(&requested);
+	}
+
+	fn unrequest(hash: &H256) {
+		let mut requested = RequestedPreimages::get();
+		if let Some(position) = requested.iter().position(|h| h == hash) {
+			requested.remove(position);
+		}
+		RequestedPreimages::set(&requested);
+	}
+}
+
+// Notes a preimage requested, like `pallet_preimage` does for the preimages it is given
+impl StorePreimage for TestPreimages {
+	const MAX_LENGTH: usize = 4 * 1024 * 1024;
+
+	fn note(bytes: Cow<[u8]>) -> Result<H256, DispatchError> {
+		let hash = Self::note(bytes.into_owned());
+		Self::request(&hash);
+		Ok(hash)
+	}
+}
+
+pub struct TestPersonhood;
+
+impl pallet_voting::PersonhoodProvider<u64> for TestPersonhood {
+	fn is_attested(who: &u64) -> bool {
+		*who != UNATTESTED
+	}
+}
+
+pub struct TestIdentities;
+
+impl Contains<u64> for TestIdentities {
+	fn contains(who: &u64) -> bool {
+		*who != UNVERIFIED
+	}
+}
+
+/// Deterministic randomness derived from the subject only.
+pub struct TestRandomness;
+
+impl Randomness<H256, u64> for TestRandomness {
+	fn random(subject: &[u8]) -> (H256, u64) {
+		(BlakeTwo256::hash(subject), System::block_number())
+	}
 }
 
 // Build genesis storage according to the mock runtime.
@@ -1,14 +1,22 @@
 use crate::{
 	mock::{self, *},
 	pallet::{self as pallet_voting},
-	Error, Event, ProposalKind,
+	CancellationReason, Conviction, Error, Event, FeePayout, OrgId, ProposalKind, ProposalLimits,
+	ProposalSettings, RoleId, Threshold, VoteCost, VoterAdmission, VoterInfo, WeightInfo,
 };
-use frame_support::{assert_noop, assert_ok, BoundedVec};
+use frame_support::{assert_noop, assert_ok, traits::Hooks, weights::Weight, BoundedVec};
 use frame_system::RawOrigin;
-use sp_runtime::DispatchResult;
+use sp_runtime::{DispatchResult, Perbill, Percent};
 
 const ALICE: u64 = 0;
 const BOB: u64 = 1;
+const CHARLIE: u64 = 2;
+const DAVE: u64 = 3;
+
+#[test]
+fn integrity_test_passes() {
+	new_test_ext().execute_with(|| Voting::integrity_test());
+}
 
 mod register_voter {
 	use super::*;
@@ -23,21 +31,156 @@ mod register_voter {
 				DispatchError::BadOrigin
 			);
 			assert_ok!(Voting::register_voter(RuntimeOrigin::root(), 1));
-			assert_eq!(Voting::registered_voters(1), Some(()));
+			assert_eq!(
+				Voting::registered_voters(1),
+				Some(VoterInfo { registered_at: 1, expires_at: None })
+			);
 			System::assert_last_event(Event::NewVoterRegistered { who: 1 }.into());
 		});
 	}
+
+	#[test]
+	fn registered_voters_are_counted_once() {
+		new_test_ext().execute_with(|| {
+			System::set_block_number(1);
+			assert_ok!(Voting::register_voter(RuntimeOrigin::root(), ALICE));
+			System::reset_events();
+			assert_ok!(Voting::register_voter(RuntimeOrigin::root(), ALICE));
+			// Registering a voter again emits no event
+			assert!(System::events().is_empty());
+			assert_ok!(Voting::register_voter(RuntimeOrigin::root(), BOB));
+			assert_eq!(Voting::registered_voters_count(), 2);
+
+			assert_ok!(Voting::unregister_voter(RuntimeOrigin::root(), ALICE));
+			assert_ok!(Voting::unregister_voter(RuntimeOrigin::root(), ALICE));
+			assert_eq!(Voting::registered_voters_count(), 1);
+		});
+	}
+
+	#[test]
+	fn requires_personhood_attestation() {
+		new_test_ext().execute_with(|| {
+			System::set_block_number(1);
+			assert_noop!(
+				Voting::register_voter(RuntimeOrigin::root(), UNATTESTED),
+				Error::<Test>::PersonhoodNotAttested
+			);
+			assert_eq!(Voting::registered_voters(UNATTESTED), None);
+		});
+	}
+
+	#[test]
+	fn requires_a_verified_identity() {
+		ExtBuilder::new_build(vec![(UNVERIFIED, 10)]).execute_with(|| {
+			System::set_block_number(1);
+			assert_noop!(
+				Voting::register_voter(RuntimeOrigin::root(), UNVERIFIED),
+				Error::<Test>::IdentityNotVerified
+			);
+			assert_noop!(
+				Voting::self_register(RuntimeOrigin::signed(UNVERIFIED)),
+				Error::<Test>::IdentityNotVerified
+			);
+			assert_eq!(Voting::registered_voters(UNVERIFIED), None);
+		});
+	}
+
+	#[test]
+	fn requires_minimum_balance() {
+		ExtBuilder::new_build(vec![(ALICE, 30), (BOB, 9)]).execute_with(|| {
+			MinVoterBalance::set(&10);
+			assert_noop!(
+				Voting::register_voter(RuntimeOrigin::root(), BOB),
+				Error::<Test>::BalanceBelowMinimum
+			);
+			assert_ok!(Voting::register_voter(RuntimeOrigin::root(), ALICE));
+		});
+	}
+}
+
+mod renew_registration {
+	use super::*;
+
+	#[test]
+	fn expired_voters_cannot_vote_and_are_pruned() {
+		ExtBuilder::new_build(vec![(ALICE, 30)]).execute_with(|| {
+			RegistrationTtl::set(&Some(100));
+			setup();
+			assert_eq!(
+				Voting::registered_voters(ALICE),
+				Some(VoterInfo { registered_at: 1, expires_at: Some(101) })
+			);
+			assert_ok!(ProposalBuilder::new().start(1).end(200).execute());
+			let proposal_id = Voting::next_proposal_id() - 1;
+
+			System::set_block_number(101);
+			assert_noop!(
+				Voting::vote(RuntimeOrigin::signed(ALICE), proposal_id, true, 1, Conviction::None),
+				Error::<Test>::RegistrationExpired
+			);
+
+			Voting::on_idle(101, Weight::MAX);
+			assert_eq!(Voting::registered_voters(ALICE), None);
+			assert_eq!(Voting::registered_voters_count(), 0);
+			System::assert_last_event(Event::VoterExpired { who: ALICE }.into());
+		})
+	}
+
+	#[test]
+	fn renewal_postpones_expiry() {
+		new_test_ext().execute_with(|| {
+			System::set_block_number(1);
+			RegistrationTtl::set(&Some(100));
+			setup();
+
+			System::set_block_number(50);
+			assert_noop!(
+				Voting::renew_registration(RuntimeOrigin::signed(BOB), ALICE),
+				Error::<Test>::OriginNoPermission
+			);
+			assert_ok!(Voting::renew_registration(RuntimeOrigin::signed(ALICE), ALICE));
+			System::assert_last_event(
+				Event::VoterRenewed { who: ALICE, expires_at: Some(150) }.into(),
+			);
+
+			System::set_block_number(101);
+			Voting::on_idle(101, Weight::MAX);
+			assert!(Voting::registered_voters(ALICE).is_some());
+
+			System::set_block_number(150);
+			assert_noop!(
+				Voting::renew_registration(RuntimeOrigin::root(), ALICE),
+				Error::<Test>::RegistrationExpired
+			);
+			Voting::on_idle(150, Weight::MAX);
+			assert_eq!(Voting::registered_voters(ALICE), None);
+		})
+	}
 }
 
 mod unregister_voter {
 	use super::*;
 
+	#[test]
+	fn refunds_drain_weight_without_votes() {
+		new_test_ext().execute_with(|| {
+			System::set_block_number(1);
+			setup();
+			let post_info = Voting::unregister_voter(RuntimeOrigin::signed(ALICE), ALICE).unwrap();
+			assert_eq!(post_info.actual_weight, Some(<() as WeightInfo>::unregister_voter(0, 0)));
+			assert_eq!(Voting::cleanup_queue_bounds(crate::CleanupLane::Immediate), (0, 0));
+		})
+	}
+
 	#[test]
 	fn works_with_root() {
 		new_test_ext().execute_with(|| {
 			System::set_block_number(1);
 			assert_ok!(Voting::register_voter(RuntimeOrigin::root(), 0));
-			assert_eq!(Voting::registered_voters(0), Some(()));
+			assert_eq!(
+				Voting::registered_voters(0),
+				Some(VoterInfo { registered_at: 1, expires_at: None })
+			);
 			assert_ok!(Voting::unregister_voter(RuntimeOrigin::root(), 0));
 			assert_eq!(Voting::registered_voters(0), None);
 			System::assert_last_event(Event::VoterUnregistered { who: 0 }.into());
@@ -49,7 +192,10 @@ mod unregister_voter {
 		new_test_ext().execute_with(|| {
 			System::set_block_number(1);
 			assert_ok!(Voting::register_voter(RuntimeOrigin::root(), 0));
-			assert_eq!(Voting::registered_voters(0), Some(()));
+			assert_eq!(
+				Voting::registered_voters(0),
+				Some(VoterInfo { registered_at: 1, expires_at: None })
+			);
 			assert_noop!(
 				Voting::unregister_voter(RuntimeOrigin::signed(1), 0),
 				Error::<Test>::OriginNoPermission
@@ -61,10 +207,237 @@ mod unregister_voter {
 	}
 }
 
+mod self_register {
+	use super::*;
+	use crate::HoldReason;
+	use frame_support::traits::fungible::InspectHold;
+
+	fn bond_held(who: u64) -> Balance {
+		Balances::balance_on_hold(&RuntimeHoldReason::from(HoldReason::RegistrationBond), &who)
+	}
+
+	#[test]
+	fn holds_the_bond_until_unregistered() {
+		ExtBuilder::new_build(vec![(ALICE, 10)]).execute_with(|| {
+			System::set_block_number(1);
+			assert_ok!(Voting::self_register(RuntimeOrigin::signed(ALICE)));
+			assert!(Voting::is_registered(&ALICE));
+			assert_eq!(Voting::registration_bonds(ALICE), Some(REGISTRATION_BOND));
+			assert_eq!(bond_held(ALICE), REGISTRATION_BOND);
+			System::assert_last_event(
+				Event::VoterSelfRegistered { who: ALICE, bond: REGISTRATION_BOND }.into(),
+			);
+			assert_noop!(
+				Voting::self_register(RuntimeOrigin::signed(ALICE)),
+				Error::<Test>::AlreadyRegistered
+			);
+
+			assert_ok!(Voting::self_unregister(RuntimeOrigin::signed(ALICE)));
+			assert!(!Voting::is_registered(&ALICE));
+			assert_eq!(Voting::registration_bonds(ALICE), None);
+			assert_eq!(Balances::free_balance(ALICE), 10);
+			System::assert_has_event(
+				Event::RegistrationBondReturned { who: ALICE, amount: REGISTRATION_BOND }.into(),
+			);
+		})
+	}
+
+	#[test]
+	fn the_bond_is_returned_when_a_registrar_unregisters() {
+		ExtBuilder::new_build(vec![(ALICE, 10)]).execute_with(|| {
+			System::set_block_number(1);
+			assert_ok!(Voting::self_register(RuntimeOrigin::signed(ALICE)));
+			assert_ok!(Voting::unregister_voter(RuntimeOrigin::root(), ALICE));
+			assert_eq!(bond_held(ALICE), 0);
+			assert_eq!(Balances::free_balance(ALICE), 10);
+		})
+	}
+
+	#[test]
+	fn the_bond_follows_a_migrated_voter() {
+		ExtBuilder::new_build(vec![(ALICE, 10), (DAVE, 1)]).execute_with(|| {
+			System::set_block_number(1);
+			assert_ok!(Voting::self_register(RuntimeOrigin::signed(ALICE)));
+			assert_ok!(Voting::migrate_voter(RuntimeOrigin::signed(ALICE), ALICE, DAVE));
+			assert_eq!(bond_held(ALICE), 0);
+			assert_eq!(bond_held(DAVE), REGISTRATION_BOND);
+			assert_eq!(Voting::registration_bonds(DAVE), Some(REGISTRATION_BOND));
+
+			assert_ok!(Voting::self_unregister(RuntimeOrigin::signed(DAVE)));
+			assert_eq!(Balances::free_balance(DAVE), 1 + REGISTRATION_BOND);
+		})
+	}
+
+	#[test]
+	fn only_self_registered_voters_can_self_unregister() {
+		ExtBuilder::new_build(vec![(ALICE, 10)]).execute_with(|| {
+			System::set_block_number(1);
+			setup();
+			assert_noop!(
+				Voting::self_unregister(RuntimeOrigin::signed(ALICE)),
+				Error::<Test>::NotSelfRegistered
+			);
+		})
+	}
+
+	#[test]
+	fn cannot_register_without_the_bond() {
+		ExtBuilder::new_build(vec![(ALICE, REGISTRATION_BOND - 1)]).execute_with(|| {
+			System::set_block_number(1);
+			assert_noop!(
+				Voting::self_register(RuntimeOrigin::signed(ALICE)),
+				Error::<Test>::InsufficientBalance
+			);
+		})
+	}
+}
+
+mod sync_voters {
+	use super::*;
+	use codec::Encode;
+	use frame_support::traits::QueryPreimage;
+	use sp_core::H256;
+
+	#[test]
+	fn applies_the_preimage_accounts_lazily() {
+		new_test_ext().execute_with(|| {
+			System::set_block_number(1);
+			setup();
+			let accounts =
+				vec![(BOB, true), (CHARLIE, true), (mock::UNATTESTED, true), (ALICE, false)];
+			let preimage_hash = TestPreimages::note(accounts.encode());
+
+			assert_ok!(Voting::sync_voters(RuntimeOrigin::root(), preimage_hash));
+			System::assert_last_event(
+				Event::VoterSyncStarted { preimage_hash, accounts: 4 }.into(),
+			);
+			assert!(TestPreimages::is_requested(&preimage_hash));
+			assert_eq!(Voting::registered_voters(BOB), None);
+
+			Voting::on_idle(1, Weight::MAX);
+
+			assert!(Voting::registered_voters(BOB).is_some());
+			assert!(Voting::registered_voters(CHARLIE).is_some());
+			assert_eq!(Voting::registered_voters(mock::UNATTESTED), None);
+			assert_eq!(Voting::registered_voters(ALICE), None);
+			System::assert_has_event(Event::VoterUnregistered { who: ALICE }.into());
+			System::assert_last_event(
+				Event::VotersSynced { preimage_hash, added: 2, removed: 1, skipped: 1 }.into(),
+			);
+			assert_eq!(Voting::voter_syncs(preimage_hash), None);
+			assert!(!TestPreimages::is_requested(&preimage_hash));
+		})
+	}
+
+	#[test]
+	fn fails_without_a_valid_preimage() {
+		new_test_ext().execute_with(|| {
+			System::set_block_number(1);
+			assert_noop!(
+				Voting::sync_voters(RuntimeOrigin::root(), H256::zero()),
+				Error::<Test>::PreimageNotFound
+			);
+			let preimage_hash = TestPreimages::note(vec![0xff]);
+			assert_noop!(
+				Voting::sync_voters(RuntimeOrigin::root(), preimage_hash),
+				Error::<Test>::InvalidVoterSync
+			);
+		})
+	}
+
+	#[test]
+	fn fails_while_the_same_sync_is_in_progress() {
+		new_test_ext().execute_with(|| {
+			System::set_block_number(1);
+			let preimage_hash = TestPreimages::note(vec![(BOB, true)].encode());
+			assert_noop!(
+				Voting::sync_voters(RuntimeOrigin::signed(ALICE), preimage_hash),
+				sp_runtime::DispatchError::BadOrigin
+			);
+			assert_ok!(Voting::sync_voters(RuntimeOrigin::root(), preimage_hash));
+			assert_noop!(
+				Voting::sync_voters(RuntimeOrigin::root(), preimage_hash),
+				Error::<Test>::VoterSyncInProgress
+			);
+		})
+	}
+}
+
+mod deputies {
+	use super::*;
+
+	#[test]
+	fn deputies_are_appointed_and_revoked() {
+		new_test_ext().execute_with(|| {
+			System::set_block_number(1);
+			assert_noop!(
+				Voting::appoint_deputy(RuntimeOrigin::signed(ALICE), DAVE, 1),
+				sp_runtime::DispatchError::BadOrigin
+			);
+			assert_ok!(Voting::appoint_deputy(RuntimeOrigin::root(), DAVE, 1));
+			System::assert_last_event(Event::DeputyAppointed { who: DAVE, limit: 1 }.into());
+			// Appointing a deputy again updates its limit
+			assert_ok!(Voting::appoint_deputy(RuntimeOrigin::root(), DAVE, 2));
+			assert_eq!(Voting::deputies(DAVE).unwrap().limit, 2);
+			assert_ok!(Voting::appoint_deputy(RuntimeOrigin::root(), CHARLIE, 1));
+			assert_eq!(Voting::deputies_count(), MAX_DEPUTIES);
+			assert_noop!(
+				Voting::appoint_deputy(RuntimeOrigin::root(), BOB, 1),
+				Error::<Test>::TooManyDeputies
+			);
+
+			assert_ok!(Voting::revoke_deputy(RuntimeOrigin::root(), CHARLIE));
+			System::assert_last_event(Event::DeputyRevoked { who: CHARLIE }.into());
+			assert_noop!(
+				Voting::revoke_deputy(RuntimeOrigin::root(), CHARLIE),
+				Error::<Test>::NotDeputy
+			);
+			assert_noop!(
+				Voting::register_voter(RuntimeOrigin::signed(CHARLIE), ALICE),
+				sp_runtime::DispatchError::BadOrigin
+			);
+			assert_ok!(Voting::appoint_deputy(RuntimeOrigin::root(), BOB, 1));
+		})
+	}
+
+	#[test]
+	fn deputies_are_rate_limited() {
+		new_test_ext().execute_with(|| {
+			System::set_block_number(1);
+			assert_ok!(Voting::appoint_deputy(RuntimeOrigin::root(), DAVE, 2));
+
+			assert_ok!(Voting::register_voter(RuntimeOrigin::signed(DAVE), ALICE));
+			assert_ok!(Voting::register_voter(RuntimeOrigin::signed(DAVE), BOB));
+			assert_eq!(Voting::registered_voters_count(), 2);
+			assert_noop!(
+				Voting::unregister_voter(RuntimeOrigin::signed(DAVE), ALICE),
+				Error::<Test>::DeputyLimitReached
+			);
+			// Voters unregistering themselves are not counted
+			assert_ok!(Voting::unregister_voter(RuntimeOrigin::signed(BOB), BOB));
+
+			System::set_block_number(1 + DEPUTY_PERIOD as u64);
+			assert_ok!(Voting::unregister_voter(RuntimeOrigin::signed(DAVE), ALICE));
+			assert_eq!(Voting::registered_voters(ALICE), None);
+			assert_eq!(Voting::deputies(DAVE).unwrap().used, 1);
+		})
+	}
+}
+
 fn setup() {
 	assert_ok!(Voting::register_voter(RuntimeOrigin::root(), ALICE));
 }
 
+/// Creates a proposal approved by the vote of ALICE and closes it at block 200.
+fn close_approved_proposal() -> u32 {
+	assert_ok!(ProposalBuilder::new().start(1).end(200).execute());
+	let proposal_id = Voting::next_proposal_id() - 1;
+	assert_ok!(Voting::vote(RuntimeOrigin::signed(ALICE), proposal_id, true, 2, Conviction::None));
+	System::set_block_number(200);
+	assert_ok!(Voting::close_proposal(RuntimeOrigin::signed(ALICE), proposal_id));
+	proposal_id
+}
+
 mod create_proposal {
 	use super::*;
 	use crate::ProposalData;
@@ -77,18 +450,14 @@ mod create_proposal {
 			let end_block = 120;
 			setup();
 
-			let proposal_data: ProposalData<
-				Test,
-				u64,
-				AccountSizeLimit,
-				ProposalOffchainDataLimit,
-			> = ProposalData::new(
+			let proposal_data: ProposalData<Test> = ProposalData::new(
 				BoundedVec::default(),
 				ProposalKind::default(),
 				ALICE,
 				Some(BoundedVec::default()),
 				start_block,
 				end_block,
+				ProposalSettings::default(),
 			);
 
 			// Execution
@@ -98,7 +467,8 @@ mod create_proposal {
 				proposal_data.kind,
 				proposal_data.clone().account_list,
 				proposal_data.start_block,
-				proposal_data.end_block
+				proposal_data.end_block,
+				proposal_data.clone().settings
 			));
 
 			// Storage
@@ -117,12 +487,124 @@ mod create_proposal {
 					account_list: proposal_data.account_list,
 					start_block,
 					end_block,
+					settings: proposal_data.settings,
 				}
 				.into(),
 			);
 		})
 	}
 
+	#[test]
+	fn cannot_create_proposal_with_zero_power_step() {
+		new_test_ext().execute_with(|| {
+			System::set_block_number(1);
+			setup();
+
+			// Execution
+			assert_noop!(
+				ProposalBuilder::new().power_step(0).execute(),
+				Error::<Test>::InvalidPowerStep
+			);
+		})
+	}
+
+	#[test]
+	fn custom_kinds_need_a_kind_handler() {
+		new_test_ext().execute_with(|| {
+			System::set_block_number(1);
+			setup();
+
+			// Execution
+			assert_noop!(
+				ProposalBuilder::new().kind(ProposalKind::Custom(0)).execute(),
+				Error::<Test>::ProposalKindNotAllowed
+			);
+		})
+	}
+
+	#[test]
+	fn external_proposal_bypasses_registration() {
+		new_test_ext().execute_with(|| {
+			System::set_block_number(1);
+
+			assert_noop!(
+				ProposalBuilder::new().start(10).end(200).execute(),
+				Error::<Test>::VoterNotRegistered
+			);
+			assert_noop!(
+				Voting::create_external_proposal(
+					RuntimeOrigin::signed(ALICE),
+					BoundedVec::default(),
+					ProposalKind::Public,
+					None,
+					10,
+					200,
+					ProposalSettings::default(),
+				),
+				sp_runtime::DispatchError::BadOrigin
+			);
+			assert_noop!(
+				Voting::create_external_proposal(
+					RuntimeOrigin::root(),
+					BoundedVec::default(),
+					ProposalKind::Public,
+					None,
+					10,
+					1200,
+					ProposalSettings::default(),
+				),
+				Error::<Test>::ProposalDurationIsTooLong
+			);
+
+			assert_ok!(Voting::create_external_proposal(
+				RuntimeOrigin::root(),
+				BoundedVec::default(),
+				ProposalKind::Public,
+				None,
+				10,
+				200,
+				ProposalSettings::default(),
+			));
+			let proposal_id = Voting::next_proposal_id() - 1;
+			let proposal = Voting::proposals(proposal_id).unwrap();
+			assert!(proposal.external);
+			assert_eq!(proposal.creator, EXTERNAL_ACCOUNT);
+			assert_eq!(
+				Voting::proposals_by_kind(ProposalKind::Public).into_inner(),
+				vec![proposal_id]
+			);
+
+			setup();
+			assert_ok!(ProposalBuilder::new().start(10).end(200).execute());
+			assert!(!Voting::proposals(proposal_id + 1).unwrap().external);
+		})
+	}
+
+	#[test]
+	fn proposals_are_indexed_by_kind() {
+		new_test_ext().execute_with(|| {
+			System::set_block_number(1);
+			setup();
+
+			assert_ok!(ProposalBuilder::new().start(10).end(200).execute());
+			assert_ok!(ProposalBuilder::new().start(10).end(200).private().execute());
+			assert_ok!(ProposalBuilder::new().start(10).end(200).execute());
+			assert_eq!(Voting::proposals_by_kind(ProposalKind::Public).into_inner(), vec![0, 2]);
+			assert_eq!(Voting::proposals_by_kind(ProposalKind::Private).into_inner(), vec![1]);
+
+			assert_ok!(Voting::cancel_proposal(
+				RuntimeOrigin::signed(ALICE),
+				0,
+				CancellationReason::CreatorWithdrawn
+			));
+			assert_eq!(Voting::proposals_by_kind(ProposalKind::Public).into_inner(), vec![2]);
+
+			System::set_block_number(200);
+			assert_ok!(Voting::close_proposal(RuntimeOrigin::signed(ALICE), 1));
+			assert!(Voting::proposals_by_kind(ProposalKind::Private).is_empty());
+		})
+	}
+
 	#[test]
 	fn cannot_start_proposal_in_the_past() {
 		new_test_ext().execute_with(|| {
@@ -201,166 +683,122 @@ mod create_proposal {
 			);
 		})
 	}
-}
-
-mod cancel_proposal {
-	use super::*;
-
-	#[test]
-	fn cancel_proposal() {
-		new_test_ext().execute_with(|| {
-			System::set_block_number(1);
-			let start_block = 10;
-			let end_block = 200;
-			setup();
-
-			assert_ok!(ProposalBuilder::new().start(start_block).end(end_block).execute());
-
-			let proposal_id = Voting::get_next_proposal_id() - 1;
-			assert_ok!(Voting::cancel_proposal(RuntimeOrigin::signed(ALICE), proposal_id));
-
-			// Storage
-			let proposal = Voting::proposals(proposal_id);
-			assert_eq!(proposal, None);
-
-			// Event
-			System::assert_last_event(Event::ProposalCancelled { proposal_id }.into());
-		})
-	}
 
 	#[test]
-	fn cannot_cancel_proposal_after_start() {
+	fn minimum_duration_can_be_tuned() {
 		new_test_ext().execute_with(|| {
 			System::set_block_number(1);
-			let start_block = 1;
-			let end_block = 200;
 			setup();
 
-			assert_ok!(ProposalBuilder::new().start(start_block).end(end_block).execute());
-
-			let proposal_id = Voting::get_next_proposal_id() - 1;
+			assert_ok!(ProposalBuilder::new().start(1).end(101).execute());
+			assert_ok!(Voting::set_proposal_limits(
+				RuntimeOrigin::root(),
+				ProposalLimits { minimum_duration: Some(200), ..Default::default() }
+			));
 			assert_noop!(
-				Voting::cancel_proposal(RuntimeOrigin::signed(ALICE), proposal_id),
-				Error::<Test>::ProposalHasAlreadyStarted
+				ProposalBuilder::new().start(1).end(101).execute(),
+				Error::<Test>::ProposalDurationIsTooShort
 			);
+			assert_ok!(ProposalBuilder::new().start(1).end(201).execute());
 		})
 	}
+}
 
-	#[test]
-	fn cannot_cancel_proposal_not_existing() {
-		new_test_ext().execute_with(|| {
-			System::set_block_number(1);
-			let start_block = 1;
-			let end_block = 200;
-			setup();
-
-			assert_ok!(ProposalBuilder::new().start(start_block).end(end_block).execute());
-
-			let next_proposal_id = Voting::get_next_proposal_id();
-			assert_noop!(
-				Voting::cancel_proposal(RuntimeOrigin::signed(ALICE), next_proposal_id),
-				Error::<Test>::ProposalDoesNotExist
-			);
-		})
-	}
+mod proposal_limits {
+	use super::*;
+	use sp_runtime::DispatchError;
 
 	#[test]
-	fn works_only_if_root_or_creator() {
+	fn works_only_with_root() {
 		new_test_ext().execute_with(|| {
 			System::set_block_number(1);
-			let start_block = 10;
-			let end_block = 200;
-			setup();
-
-			assert_ok!(ProposalBuilder::new().start(start_block).end(end_block).execute());
-
-			let proposal_id = Voting::get_next_proposal_id() - 1;
+			let limits = ProposalLimits { delay_limit: Some(10), ..Default::default() };
 			assert_noop!(
-				Voting::cancel_proposal(RuntimeOrigin::signed(BOB), proposal_id),
-				Error::<Test>::OriginNoPermission
+				Voting::set_proposal_limits(RuntimeOrigin::signed(ALICE), limits.clone()),
+				DispatchError::BadOrigin
 			);
+
+			assert_ok!(Voting::set_proposal_limits(RuntimeOrigin::root(), limits.clone()));
+			assert_eq!(Voting::proposal_limits(), limits.clone());
+			assert_eq!(Voting::delay_limit(), 10);
+			assert_eq!(Voting::maximum_duration(), PROPOSAL_MAXIMUM_DURATION);
+			System::assert_last_event(Event::ProposalLimitsSet { limits }.into());
 		})
 	}
-}
-
-mod close_proposal {
-	use super::*;
-	use crate::VoteRatio;
 
 	#[test]
-	fn close_proposal() {
+	fn rejects_invalid_limits() {
 		new_test_ext().execute_with(|| {
 			System::set_block_number(1);
-			let start_block = 1;
-			let end_block = 200;
-			setup();
-
-			assert_ok!(ProposalBuilder::new().start(start_block).end(end_block).execute());
-
-			System::set_block_number(200);
-
-			let proposal_id = Voting::get_next_proposal_id() - 1;
-			assert_ok!(Voting::close_proposal(RuntimeOrigin::signed(BOB), proposal_id));
-
-			// Storage
-			let proposal = Voting::proposals(proposal_id);
-			assert_eq!(proposal, None);
-
-			// Event
-			System::assert_last_event(
-				Event::VoteCompleted { proposal_id, ratio: VoteRatio::default() }.into(),
-			);
+			for limits in [
+				ProposalLimits {
+					minimum_duration: Some(PROPOSAL_MAXIMUM_DURATION + 1),
+					..Default::default()
+				},
+				ProposalLimits {
+					maximum_duration: Some(PROPOSAL_MINIMUM_DURATION - 1),
+					..Default::default()
+				},
+				ProposalLimits {
+					minimum_duration: Some(0),
+					maximum_duration: Some(0),
+					..Default::default()
+				},
+				ProposalLimits { emergency_max_duration: Some(0), ..Default::default() },
+			] {
+				assert_noop!(
+					Voting::set_proposal_limits(RuntimeOrigin::root(), limits),
+					Error::<Test>::InvalidProposalLimits
+				);
+			}
 		})
 	}
 
 	#[test]
-	fn cannot_close_proposal_before_end() {
+	fn apply_to_every_limit() {
 		new_test_ext().execute_with(|| {
 			System::set_block_number(1);
-			let start_block = 1;
-			let end_block = 200;
 			setup();
+			assert_ok!(Voting::set_proposal_limits(
+				RuntimeOrigin::root(),
+				ProposalLimits {
+					minimum_duration: Some(10),
+					maximum_duration: Some(20),
+					delay_limit: Some(5),
+					emergency_max_duration: Some(2),
+					claim_grace_period: Some(1),
+				}
+			));
 
-			assert_ok!(ProposalBuilder::new().start(start_block).end(end_block).execute());
-
-			System::set_block_number(199);
-
-			let proposal_id = Voting::get_next_proposal_id() - 1;
 			assert_noop!(
-				Voting::close_proposal(RuntimeOrigin::signed(BOB), proposal_id),
-				Error::<Test>::ProposalHasNotEndedYet
+				ProposalBuilder::new().start(7).end(27).execute(),
+				Error::<Test>::ProposalStartIsTooFarAway
 			);
-		})
-	}
-
-	#[test]
-	fn cannot_close_proposal_not_existing() {
-		new_test_ext().execute_with(|| {
-			System::set_block_number(1);
-			let start_block = 1;
-			let end_block = 200;
-			setup();
-
-			assert_ok!(ProposalBuilder::new().start(start_block).end(end_block).execute());
-
-			System::set_block_number(200);
-
-			let next_proposal_id = Voting::get_next_proposal_id();
 			assert_noop!(
-				Voting::close_proposal(RuntimeOrigin::signed(BOB), next_proposal_id),
-				Error::<Test>::ProposalDoesNotExist
+				ProposalBuilder::new().start(1).end(22).execute(),
+				Error::<Test>::ProposalDurationIsTooLong
+			);
+			assert_ok!(ProposalBuilder::new().start(6).end(16).execute());
+			assert_noop!(
+				Voting::create_emergency_proposal(
+					RuntimeOrigin::root(),
+					BoundedVec::default(),
+					3,
+					ProposalSettings::default(),
+				),
+				Error::<Test>::ProposalDurationIsTooLong
 			);
+			assert_eq!(Voting::claim_grace_period(), 1);
 		})
 	}
 }
 
-mod set_account_list {
-	use crate::ProposalData;
-
+mod cancel_proposal {
 	use super::*;
+	use crate::{ArchivedProposal, ProposalOutcome};
 
 	#[test]
-	fn set_account_list() {
+	fn cancel_proposal() {
 		new_test_ext().execute_with(|| {
 			System::set_block_number(1);
 			let start_block = 10;
@@ -368,36 +806,35 @@ mod set_account_list {
 			setup();
 
 			assert_ok!(ProposalBuilder::new().start(start_block).end(end_block).execute());
-			let proposal_id = Voting::get_next_proposal_id() - 1;
-			let raw_proposal = Voting::proposals(proposal_id);
-			assert!(raw_proposal.is_some());
 
-			let new_account_list = BoundedVec::try_from(vec![BOB]).unwrap();
-			assert_ok!(Voting::set_account_list(
-				RuntimeOrigin::signed(ALICE),
-				proposal_id,
-				Some(new_account_list.clone())
-			));
+			let proposal_id = Voting::get_next_proposal_id() - 1;
+			let reason = CancellationReason::CreatorWithdrawn;
+			assert_ok!(Voting::cancel_proposal(RuntimeOrigin::signed(ALICE), proposal_id, reason));
 
 			// Storage
 			let proposal = Voting::proposals(proposal_id);
+			assert_eq!(proposal, None);
 			assert_eq!(
-				proposal,
-				Some(ProposalData {
-					account_list: Some(new_account_list.clone()),
-					..raw_proposal.unwrap()
+				Voting::archived_proposals(proposal_id),
+				Some(ArchivedProposal {
+					creator: ALICE,
+					outcome: ProposalOutcome::Cancelled { reason },
+					archived_at: 1,
+					overridden: None,
+					turnout_scale: None,
+					abstentions: None,
+					voter_cap: None,
+					enactment_failure: None,
 				})
 			);
 
 			// Event
-			System::assert_last_event(
-				Event::AccountListSet { proposal_id, account_list: Some(new_account_list) }.into(),
-			);
+			System::assert_last_event(Event::ProposalCancelled { proposal_id, reason }.into());
 		})
 	}
 
 	#[test]
-	fn cannot_set_account_list_after_start() {
+	fn cannot_cancel_proposal_after_start() {
 		new_test_ext().execute_with(|| {
 			System::set_block_number(1);
 			let start_block = 1;
@@ -407,12 +844,11 @@ mod set_account_list {
 			assert_ok!(ProposalBuilder::new().start(start_block).end(end_block).execute());
 
 			let proposal_id = Voting::get_next_proposal_id() - 1;
-			let new_account_list = BoundedVec::try_from(vec![BOB]).unwrap();
 			assert_noop!(
-				Voting::set_account_list(
+				Voting::cancel_proposal(
 					RuntimeOrigin::signed(ALICE),
 					proposal_id,
-					Some(new_account_list)
+					CancellationReason::CreatorWithdrawn
 				),
 				Error::<Test>::ProposalHasAlreadyStarted
 			);
@@ -430,12 +866,11 @@ mod set_account_list {
 			assert_ok!(ProposalBuilder::new().start(start_block).end(end_block).execute());
 
 			let next_proposal_id = Voting::get_next_proposal_id();
-			let new_account_list = BoundedVec::try_from(vec![BOB]).unwrap();
 			assert_noop!(
-				Voting::set_account_list(
+				Voting::cancel_proposal(
 					RuntimeOrigin::signed(ALICE),
 					next_proposal_id,
-					Some(new_account_list)
+					CancellationReason::CreatorWithdrawn
 				),
 				Error::<Test>::ProposalDoesNotExist
 			);
@@ -453,12 +888,11 @@ mod set_account_list {
 			assert_ok!(ProposalBuilder::new().start(start_block).end(end_block).execute());
 
 			let proposal_id = Voting::get_next_proposal_id() - 1;
-			let new_account_list = BoundedVec::try_from(vec![BOB]).unwrap();
 			assert_noop!(
-				Voting::set_account_list(
+				Voting::cancel_proposal(
 					RuntimeOrigin::signed(BOB),
 					proposal_id,
-					Some(new_account_list)
+					CancellationReason::Spam
 				),
 				Error::<Test>::OriginNoPermission
 			);
@@ -466,483 +900,6361 @@ mod set_account_list {
 	}
 }
 
-mod vote {
-	use frame_support::traits::fungible::freeze::Inspect;
-	use sp_core::Get;
-
-	use crate::VoteInfo;
-
+mod proposal_deposit {
 	use super::*;
+	use crate::{HoldReason, ProposalOutcome};
+	use frame_support::traits::fungible::InspectHold;
 
-	fn vote_setup() {
-		let start_block = 1;
-		let end_block = 200;
-		assert_ok!(Voting::register_voter(RuntimeOrigin::root(), ALICE));
-		assert_ok!(ProposalBuilder::new().start(start_block).end(end_block).execute());
+	fn held(who: u64) -> Balance {
+		Balances::balance_on_hold(&RuntimeHoldReason::from(HoldReason::ProposalDeposit), &who)
 	}
 
 	#[test]
-	fn works_only_if_registered_voter() {
-		new_test_ext().execute_with(|| {
-			assert_noop!(
-				Voting::vote(RuntimeOrigin::signed(2), 0, true, 1),
-				Error::<Test>::VoterNotRegistered
+	fn is_held_and_returned_at_close() {
+		ExtBuilder::new_build(vec![(ALICE, 100), (BOB, 10)]).execute_with(|| {
+			System::set_block_number(1);
+			ProposalDeposit::set(&50);
+			setup();
+
+			assert_ok!(ProposalBuilder::new().start(1).end(200).execute());
+			let proposal_id = Voting::next_proposal_id() - 1;
+			assert_eq!(held(ALICE), 50);
+			assert_eq!(Voting::proposal_deposits(proposal_id), Some(50));
+
+			System::set_block_number(200);
+			assert_ok!(Voting::close_proposal(RuntimeOrigin::signed(BOB), proposal_id));
+			assert_eq!(held(ALICE), 0);
+			assert_eq!(Balances::free_balance(ALICE), 100);
+			assert_eq!(Voting::proposal_deposits(proposal_id), None);
+			System::assert_has_event(
+				Event::ProposalDepositReturned { proposal_id, amount: 50 }.into(),
 			);
 		})
 	}
 
 	#[test]
-	fn cannot_vote_proposal_not_existing() {
-		new_test_ext().execute_with(|| {
+	fn is_returned_when_the_creator_withdraws() {
+		ExtBuilder::new_build(vec![(ALICE, 100)]).execute_with(|| {
+			System::set_block_number(1);
+			ProposalDeposit::set(&50);
 			setup();
-			assert_noop!(
-				Voting::vote(RuntimeOrigin::signed(ALICE), 1, true, 1),
-				Error::<Test>::ProposalDoesNotExist
-			);
+
+			assert_ok!(ProposalBuilder::new().start(10).end(200).execute());
+			let proposal_id = Voting::next_proposal_id() - 1;
+			assert_ok!(Voting::cancel_proposal(
+				RuntimeOrigin::signed(ALICE),
+				proposal_id,
+				CancellationReason::Spam
+			));
+			assert_eq!(held(ALICE), 0);
+			assert_eq!(Balances::free_balance(ALICE), 100);
 		})
 	}
 
 	#[test]
-	fn cannot_vote_proposal_not_started() {
-		new_test_ext().execute_with(|| {
+	fn is_slashed_when_root_cancels_as_spam() {
+		ExtBuilder::new_build(vec![(ALICE, 100)]).execute_with(|| {
 			System::set_block_number(1);
-			let start_block = 10;
-			let end_block = 200;
+			ProposalDeposit::set(&50);
 			setup();
 
-			assert_ok!(ProposalBuilder::new().start(start_block).end(end_block).execute());
-
-			let proposal_id = Voting::get_next_proposal_id() - 1;
-			assert_noop!(
-				Voting::vote(RuntimeOrigin::signed(ALICE), proposal_id, true, 1),
-				Error::<Test>::ProposalHasNotStartedYet
+			assert_ok!(ProposalBuilder::new().start(10).end(200).execute());
+			let proposal_id = Voting::next_proposal_id() - 1;
+			assert_ok!(Voting::cancel_proposal(
+				RuntimeOrigin::root(),
+				proposal_id,
+				CancellationReason::Spam
+			));
+			assert_eq!(held(ALICE), 0);
+			assert_eq!(Balances::free_balance(ALICE), 50);
+			assert_eq!(Balances::free_balance(TREASURY_ACCOUNT), 50);
+			assert_eq!(
+				Voting::archived_proposals(proposal_id).map(|archived| archived.outcome),
+				Some(ProposalOutcome::Cancelled { reason: CancellationReason::Spam })
+			);
+			System::assert_has_event(
+				Event::ProposalDepositSlashed { proposal_id, amount: 50 }.into(),
 			);
 		})
 	}
 
 	#[test]
-	fn cannot_vote_proposal_finished() {
-		new_test_ext().execute_with(|| {
+	fn is_returned_when_root_cancels_for_another_reason() {
+		ExtBuilder::new_build(vec![(ALICE, 100)]).execute_with(|| {
 			System::set_block_number(1);
-			let start_block = 10;
-			let end_block = 200;
+			ProposalDeposit::set(&50);
 			setup();
 
-			assert_ok!(ProposalBuilder::new().start(start_block).end(end_block).execute());
-
-			System::set_block_number(201);
-
-			let proposal_id = Voting::get_next_proposal_id() - 1;
-			assert_noop!(
-				Voting::vote(RuntimeOrigin::signed(ALICE), proposal_id, true, 1),
-				Error::<Test>::ProposalHasAlreadyEnded
-			);
+			assert_ok!(ProposalBuilder::new().start(10).end(200).execute());
+			let proposal_id = Voting::next_proposal_id() - 1;
+			assert_ok!(Voting::cancel_proposal(
+				RuntimeOrigin::root(),
+				proposal_id,
+				CancellationReason::CreatorWithdrawn
+			));
+			assert_eq!(Balances::free_balance(ALICE), 100);
+			assert_eq!(Balances::free_balance(TREASURY_ACCOUNT), 0);
 		})
 	}
 
 	#[test]
-	fn banned_voter_cannot_vote() {
-		new_test_ext().execute_with(|| {
+	fn cannot_create_without_the_deposit() {
+		ExtBuilder::new_build(vec![(ALICE, 40)]).execute_with(|| {
 			System::set_block_number(1);
-			let start_block = 1;
-			let end_block = 200;
-			let account_list = BoundedVec::try_from(vec![BOB]).unwrap();
-			assert_ok!(Voting::register_voter(RuntimeOrigin::root(), BOB));
+			ProposalDeposit::set(&50);
 			setup();
 
-			assert_ok!(ProposalBuilder::new()
-				.start(start_block)
-				.end(end_block)
-				.set_account_list(Some(account_list))
-				.execute());
-
-			let proposal_id = Voting::get_next_proposal_id() - 1;
 			assert_noop!(
-				Voting::vote(RuntimeOrigin::signed(BOB), proposal_id, true, 1),
-				Error::<Test>::OriginNoPermission
+				ProposalBuilder::new().start(10).end(200).execute(),
+				Error::<Test>::InsufficientBalance
 			);
 		})
 	}
+}
+
+mod emergency {
+	use super::*;
+	use crate::ProposalOutcome;
+	use sp_runtime::DispatchError;
+
+	fn create(duration: u32) -> DispatchResult {
+		Voting::create_emergency_proposal(
+			RuntimeOrigin::root(),
+			BoundedVec::default(),
+			duration,
+			ProposalSettings::default(),
+		)
+	}
 
 	#[test]
-	fn not_allowed_voter_cannot_vote_private_proposal() {
+	fn works_only_with_emergency_origin() {
 		new_test_ext().execute_with(|| {
 			System::set_block_number(1);
-			let start_block = 1;
-			let end_block = 200;
-			let account_list = BoundedVec::try_from(vec![ALICE]).unwrap();
-			assert_ok!(Voting::register_voter(RuntimeOrigin::root(), BOB));
 			setup();
-
-			assert_ok!(ProposalBuilder::new()
-				.start(start_block)
-				.end(end_block)
-				.set_account_list(Some(account_list))
-				.private()
-				.execute());
-
-			let proposal_id = Voting::get_next_proposal_id() - 1;
 			assert_noop!(
-				Voting::vote(RuntimeOrigin::signed(BOB), proposal_id, true, 1),
-				Error::<Test>::OriginNoPermission
+				Voting::create_emergency_proposal(
+					RuntimeOrigin::signed(ALICE),
+					BoundedVec::default(),
+					EMERGENCY_MAX_DURATION,
+					ProposalSettings::default(),
+				),
+				DispatchError::BadOrigin
+			);
+			assert_noop!(
+				ProposalBuilder::new().kind(ProposalKind::Emergency).execute(),
+				Error::<Test>::EmergencyProposalNotAllowed
+			);
+			assert_noop!(create(0), Error::<Test>::ProposalDurationIsTooShort);
+			assert_noop!(
+				create(EMERGENCY_MAX_DURATION + 1),
+				Error::<Test>::ProposalDurationIsTooLong
+			);
+
+			assert_ok!(create(EMERGENCY_MAX_DURATION));
+			let proposal_id = Voting::next_proposal_id() - 1;
+			let proposal = Voting::proposals(proposal_id).unwrap();
+			assert_eq!(proposal.kind, ProposalKind::Emergency);
+			assert_eq!(proposal.creator, EMERGENCY_ACCOUNT);
+			assert_eq!(proposal.start_block, 1);
+			assert_eq!(proposal.end_block, 1 + EMERGENCY_MAX_DURATION as u64);
+			assert_eq!(
+				Voting::proposals_by_kind(ProposalKind::Emergency).into_inner(),
+				vec![proposal_id]
 			);
 		})
 	}
 
 	#[test]
-	fn ext_builder_balance_setup_works() {
-		ExtBuilder::new_build(vec![(ALICE, 10), (BOB, 100)]).execute_with(|| {
-			let alice_balance = Balances::free_balance(ALICE);
-			assert!(alice_balance == 10);
+	fn requires_supermajority() {
+		ExtBuilder::new_build(vec![(ALICE, 100), (BOB, 30)]).execute_with(|| {
+			// Emergency proposals are exempt from seconding
+			SecondsThreshold::set(&1);
+			setup();
+			assert_ok!(Voting::register_voter(RuntimeOrigin::root(), BOB));
 
-			let bob_balance = Balances::free_balance(BOB);
-			assert!(bob_balance == 100);
+			assert_ok!(create(EMERGENCY_MAX_DURATION));
+			let rejected_id = Voting::next_proposal_id() - 1;
+			assert_ok!(create(EMERGENCY_MAX_DURATION));
+			let approved_id = Voting::next_proposal_id() - 1;
 
-			let freeze_id: () =
-				<<Test as pallet_voting::Config>::FreezeIdForPallet as Get<_>>::get();
-			let alice_balance_frozen_balance =
-				<<Test as crate::Config>::NativeBalance as Inspect<
-					<Test as frame_system::Config>::AccountId,
-				>>::balance_frozen(&freeze_id, &ALICE);
-			assert_eq!(alice_balance_frozen_balance, 0);
+			// A simple majority is not enough
+			assert_ok!(Voting::vote(
+				RuntimeOrigin::signed(ALICE),
+				rejected_id,
+				true,
+				4,
+				Conviction::None
+			));
+			assert_ok!(Voting::vote(
+				RuntimeOrigin::signed(BOB),
+				rejected_id,
+				false,
+				3,
+				Conviction::None
+			));
+			assert_ok!(Voting::vote(
+				RuntimeOrigin::signed(ALICE),
+				approved_id,
+				true,
+				5,
+				Conviction::None
+			));
+			assert_ok!(Voting::vote(
+				RuntimeOrigin::signed(BOB),
+				approved_id,
+				false,
+				2,
+				Conviction::None
+			));
 
-			let bob_balance_frozen_balance = <<Test as crate::Config>::NativeBalance as Inspect<
-				<Test as frame_system::Config>::AccountId,
-			>>::balance_frozen(&freeze_id, &BOB);
-			assert_eq!(bob_balance_frozen_balance, 0);
+			System::set_block_number(1 + EMERGENCY_MAX_DURATION as u64);
+			assert_ok!(Voting::close_proposal(RuntimeOrigin::signed(BOB), rejected_id));
+			assert_ok!(Voting::close_proposal(RuntimeOrigin::signed(BOB), approved_id));
+			assert_eq!(
+				Voting::archived_proposals(rejected_id).map(|archive| archive.outcome),
+				Some(ProposalOutcome::Completed { ratio: (16, 25), approved: false })
+			);
+			assert_eq!(
+				Voting::archived_proposals(approved_id).map(|archive| archive.outcome),
+				Some(ProposalOutcome::Completed { ratio: (25, 29), approved: true })
+			);
 		})
 	}
+}
 
-	#[test]
-	fn cannot_vote_with_insufficient_funds() {
-		ExtBuilder::new_build(vec![(ALICE, 10)]).execute_with(|| {
-			let start_block = 1;
-			let end_block = 200;
-			let aye = true;
-			let power = 4; // 16 tokens required
-			setup();
+mod questions {
+	use super::*;
+	use crate::ProposalOutcome;
 
-			assert_ok!(ProposalBuilder::new().start(start_block).end(end_block).execute());
+	fn held_balance(who: u64) -> u128 {
+		Voting::held_for_voting(&who)
+	}
 
-			let proposal_id = Voting::get_next_proposal_id() - 1;
+	fn answers(answers: Vec<(bool, u128)>) -> BoundedVec<(bool, u128), MaxQuestions> {
+		BoundedVec::try_from(answers).unwrap()
+	}
+
+	#[test]
+	fn bundle_settings_are_checked() {
+		new_test_ext().execute_with(|| {
+			System::set_block_number(1);
+			setup();
 			assert_noop!(
-				Voting::vote(RuntimeOrigin::signed(ALICE), proposal_id, aye, power),
-				Error::<Test>::InsufficientBalance
+				ProposalBuilder::new().questions(MAX_QUESTIONS + 1).execute(),
+				Error::<Test>::TooManyQuestions
+			);
+			assert_noop!(
+				ProposalBuilder::new()
+					.questions(2)
+					.whale_cap(Perbill::from_percent(30))
+					.execute(),
+				Error::<Test>::InvalidBundleSettings
+			);
+			assert_noop!(
+				ProposalBuilder::new().questions(2).hidden_tally().execute(),
+				Error::<Test>::InvalidBundleSettings
 			);
 		})
 	}
 
 	#[test]
-	fn single_vote() {
-		ExtBuilder::new_build(vec![(ALICE, 10)]).execute_with(|| {
-			let freeze_id: () =
-				<<Test as pallet_voting::Config>::FreezeIdForPallet as Get<_>>::get();
-
-			let aye = true;
-			let power = 3; // 9 tokens required
-			let quadratic_amount = Voting::calculate_quadratic_amount(power);
-			vote_setup();
-
+	fn questions_share_one_freeze_and_are_tallied_separately() {
+		ExtBuilder::new_build(vec![(ALICE, 100), (BOB, 100)]).execute_with(|| {
+			setup();
+			assert_ok!(Voting::register_voter(RuntimeOrigin::root(), BOB));
+			assert_ok!(ProposalBuilder::new().start(1).end(200).questions(3).execute());
 			let proposal_id = Voting::next_proposal_id() - 1;
 
-			// Execution
-			assert_ok!(Voting::vote(RuntimeOrigin::signed(ALICE), proposal_id, aye, power));
-
-			// Storage
-			let proposal = Voting::proposals(proposal_id);
-			assert_eq!(proposal.unwrap().ratio, (quadratic_amount, quadratic_amount));
+			assert_noop!(
+				Voting::vote(RuntimeOrigin::signed(ALICE), proposal_id, true, 1, Conviction::None),
+				Error::<Test>::QuestionsMismatch
+			);
+			assert_noop!(
+				Voting::vote_questions(
+					RuntimeOrigin::signed(ALICE),
+					proposal_id,
+					answers(vec![(true, 1)]),
+					None
+				),
+				Error::<Test>::QuestionsMismatch
+			);
 
-			let alice_frozen_balance = <<Test as crate::Config>::NativeBalance as Inspect<
-				<Test as frame_system::Config>::AccountId,
-			>>::balance_frozen(&freeze_id, &ALICE);
-			assert_eq!(alice_frozen_balance, quadratic_amount);
+			let alice_answers = answers(vec![(true, 3), (false, 1), (true, 0)]);
+			assert_ok!(Voting::vote_questions(
+				RuntimeOrigin::signed(ALICE),
+				proposal_id,
+				alice_answers.clone(),
+				None
+			));
+			System::assert_last_event(
+				Event::QuestionsVoted { proposal_id, voter: ALICE, answers: alice_answers }.into(),
+			);
+			assert_ok!(Voting::vote_questions(
+				RuntimeOrigin::signed(BOB),
+				proposal_id,
+				answers(vec![(false, 2), (true, 2), (true, 1)]),
+				None
+			));
+			// The most powerful answer sizes the freeze
+			assert_eq!(held_balance(ALICE), 9);
+			assert_eq!(held_balance(BOB), 4);
+			assert_eq!(
+				Voting::question_tallies(proposal_id).into_inner(),
+				vec![(9, 13), (4, 5), (1, 1)]
+			);
 
-			let vote = Voting::votes(ALICE, proposal_id);
-			assert_eq!(vote, Some(VoteInfo { proposal_id, aye, power }));
+			// Previous answers are replaced
+			assert_ok!(Voting::vote_questions(
+				RuntimeOrigin::signed(ALICE),
+				proposal_id,
+				answers(vec![(true, 1), (false, 1), (true, 0)]),
+				None
+			));
+			assert_eq!(held_balance(ALICE), 1);
+			assert_eq!(Voting::proposals(proposal_id).unwrap().voters_count, 2);
 
-			// Event
-			System::assert_last_event(
-				Event::VoteAdded { proposal_id, voter: ALICE, aye, power }.into(),
+			System::set_block_number(200);
+			assert_ok!(Voting::close_proposal(RuntimeOrigin::signed(BOB), proposal_id));
+			let expected = vec![((1, 5), false), ((4, 5), true), ((1, 1), true)];
+			assert_eq!(Voting::question_outcomes(proposal_id).unwrap().into_inner(), expected);
+			// The bundle is approved only if all its questions are
+			assert_eq!(
+				Voting::archived_proposals(proposal_id).map(|archive| archive.outcome),
+				Some(ProposalOutcome::Completed { ratio: (0, 0), approved: false })
 			);
+			System::assert_has_event(
+				Event::QuestionsCompleted {
+					proposal_id,
+					outcomes: BoundedVec::try_from(expected).unwrap(),
+				}
+				.into(),
+			);
+			assert!(Voting::question_tallies(proposal_id).is_empty());
+
+			Voting::on_idle(200, Weight::MAX);
+			assert_eq!(held_balance(ALICE), 0);
+			assert_eq!(held_balance(BOB), 0);
+			assert_eq!(Voting::answers(proposal_id, ALICE), None);
 		})
 	}
+}
 
-	#[test]
-	fn vote_adjustment() {
-		ExtBuilder::new_build(vec![(ALICE, 100)]).execute_with(|| {
-			let freeze_id: () =
-				<<Test as pallet_voting::Config>::FreezeIdForPallet as Get<_>>::get();
+mod options {
+	use super::*;
+	use crate::{plurality_winner, OptionLabel, ProposalOutcome};
+	use sp_runtime::DispatchError;
 
-			let init_aye = true;
-			let init_power = 3; // 9 tokens required
-			vote_setup();
+	fn options(count: u8) -> BoundedVec<OptionLabel, MaxOptions> {
+		let labels = (0..count).map(|option| OptionLabel::truncate_from(vec![b'a' + option]));
+		BoundedVec::try_from(labels.collect::<Vec<_>>()).unwrap()
+	}
 
+	#[test]
+	fn options_are_checked() {
+		new_test_ext().execute_with(|| {
+			System::set_block_number(1);
+			setup();
+			assert_ok!(ProposalBuilder::new().start(5).end(200).execute());
 			let proposal_id = Voting::next_proposal_id() - 1;
+			assert_noop!(
+				Voting::set_options(RuntimeOrigin::signed(BOB), proposal_id, options(2)),
+				Error::<Test>::OriginNoPermission
+			);
+			assert_noop!(
+				Voting::set_options(RuntimeOrigin::signed(ALICE), proposal_id, options(1)),
+				Error::<Test>::InvalidOptions
+			);
+			assert_ok!(ProposalBuilder::new().start(5).end(200).questions(2).execute());
+			let bundle_id = Voting::next_proposal_id() - 1;
+			assert_noop!(
+				Voting::set_options(RuntimeOrigin::root(), bundle_id, options(2)),
+				Error::<Test>::InvalidOptions
+			);
 
-			// Initial vote execution
-			assert_ok!(Voting::vote(
-				RuntimeOrigin::signed(ALICE),
-				proposal_id,
-				init_aye,
-				init_power
-			));
+			assert_ok!(Voting::set_options(RuntimeOrigin::signed(ALICE), proposal_id, options(3)));
+			System::assert_last_event(
+				Event::OptionsSet { proposal_id, options: options(3) }.into(),
+			);
+			assert_eq!(Voting::proposals(proposal_id).unwrap().options, options(3));
 
-			System::set_block_number(2);
+			System::set_block_number(5);
+			assert_noop!(
+				Voting::set_options(RuntimeOrigin::root(), proposal_id, BoundedVec::default()),
+				Error::<Test>::ProposalHasAlreadyStarted
+			);
+			assert_noop!(
+				Voting::vote_option(RuntimeOrigin::signed(BOB), proposal_id, 0, 1, None),
+				Error::<Test>::VoterNotRegistered
+			);
+			assert_noop!(
+				Voting::vote_option(RuntimeOrigin::none(), proposal_id, 0, 1, None),
+				DispatchError::BadOrigin
+			);
 
-			// Vote adjustment
-			let second_aye = true;
-			let second_power = 4; // 16 tokens required - diff = 7
-			let second_quadratic_amount = Voting::calculate_quadratic_amount(second_power);
+			// Only a single leading option wins
+			assert_eq!(plurality_winner(&[4, 9, 1]), Some(1));
+			assert_eq!(plurality_winner(&[4, 4, 1]), None);
+			assert_eq!(plurality_winner(&[0, 0]), None);
+		})
+	}
 
-			assert_ok!(Voting::vote(
-				RuntimeOrigin::signed(ALICE),
+	#[test]
+	fn options_are_tallied_and_the_winner_reported() {
+		ExtBuilder::new_build(vec![(ALICE, 100), (BOB, 100), (CHARLIE, 100)]).execute_with(|| {
+			setup();
+			assert_ok!(Voting::register_voter(RuntimeOrigin::root(), BOB));
+			assert_ok!(Voting::register_voter(RuntimeOrigin::root(), CHARLIE));
+			assert_ok!(ProposalBuilder::new().start(5).end(200).execute());
+			let proposal_id = Voting::next_proposal_id() - 1;
+			assert_ok!(Voting::set_options(RuntimeOrigin::signed(ALICE), proposal_id, options(3)));
+			System::set_block_number(5);
+			Voting::on_initialize(5);
+
+			assert_noop!(
+				Voting::vote(RuntimeOrigin::signed(ALICE), proposal_id, true, 1, Conviction::None),
+				Error::<Test>::OptionsMismatch
+			);
+			assert_noop!(
+				Voting::vote_option(RuntimeOrigin::signed(ALICE), proposal_id, 3, 1, None),
+				Error::<Test>::OptionsMismatch
+			);
+
+			assert_ok!(Voting::vote_option(RuntimeOrigin::signed(ALICE), proposal_id, 0, 3, None));
+			System::assert_last_event(
+				Event::OptionVoted { proposal_id, voter: ALICE, option: 0, power: 3 }.into(),
+			);
+			assert_ok!(Voting::vote_option(RuntimeOrigin::signed(BOB), proposal_id, 2, 2, None));
+			assert_ok!(Voting::vote_option(
+				RuntimeOrigin::signed(CHARLIE),
 				proposal_id,
-				second_aye,
-				second_power
+				1,
+				2,
+				None
 			));
+			assert_eq!(Voting::option_tallies(proposal_id).into_inner(), vec![9, 4, 4]);
 
-			// Storage
-			let proposal = Voting::proposals(proposal_id);
-			assert_eq!(proposal.unwrap().ratio, (second_quadratic_amount, second_quadratic_amount));
+			// A new choice replaces the previous one
+			assert_ok!(Voting::vote_option(RuntimeOrigin::signed(BOB), proposal_id, 1, 3, None));
+			assert_eq!(Voting::option_tallies(proposal_id).into_inner(), vec![9, 13, 0]);
+			assert_eq!(Voting::option_choices(proposal_id, BOB), Some((1, 9)));
+			assert_eq!(Voting::held_for_voting(&BOB), 9);
+			let proposal = Voting::proposals(proposal_id).unwrap();
+			assert_eq!((proposal.ratio, proposal.voters_count), ((0, 22), 3));
+
+			System::set_block_number(200);
+			assert_ok!(Voting::close_proposal(RuntimeOrigin::signed(ALICE), proposal_id));
+			let tallies = BoundedVec::try_from(vec![9, 13, 0]).unwrap();
+			assert_eq!(Voting::option_outcomes(proposal_id), Some((tallies.clone(), Some(1))));
+			System::assert_has_event(
+				Event::OptionsCompleted { proposal_id, tallies, winner: Some(1) }.into(),
+			);
+			assert_eq!(
+				Voting::archived_proposals(proposal_id).map(|archive| archive.outcome),
+				Some(ProposalOutcome::Completed { ratio: (13, 22), approved: true })
+			);
+			assert!(Voting::option_tallies(proposal_id).is_empty());
 
-			let alice_frozen_balance = <<Test as crate::Config>::NativeBalance as Inspect<
-				<Test as frame_system::Config>::AccountId,
-			>>::balance_frozen(&freeze_id, &ALICE);
-			assert_eq!(alice_frozen_balance, second_quadratic_amount);
+			// `MaxReleasesPerBlock` releases two voters per block
+			Voting::on_idle(200, Weight::MAX);
+			System::set_block_number(201);
+			Voting::on_idle(201, Weight::MAX);
+			assert_eq!(Voting::held_for_voting(&BOB), 0);
+			assert_eq!(Voting::option_choices(proposal_id, BOB), None);
+		})
+	}
+}
 
-			let vote = Voting::votes(ALICE, proposal_id);
-			assert_eq!(vote, Some(VoteInfo { proposal_id, aye: second_aye, power: second_power }));
+mod secret_ballot {
+	use super::*;
+	use crate::ProposalOutcome;
 
-			// Event
-			System::assert_last_event(
-				Event::VoteAdded {
-					proposal_id,
-					voter: ALICE,
-					aye: second_aye,
-					power: second_power,
-				}
-				.into(),
+	#[test]
+	fn reveal_period_is_checked() {
+		new_test_ext().execute_with(|| {
+			System::set_block_number(1);
+			setup();
+			assert_noop!(
+				ProposalBuilder::new().reveal_period(0).execute(),
+				Error::<Test>::InvalidRevealPeriod
+			);
+			assert_noop!(
+				ProposalBuilder::new().reveal_period(10).vote_cost(VoteCost::Burned).execute(),
+				Error::<Test>::InvalidVoteCost
+			);
+			assert_noop!(
+				ProposalBuilder::new().reveal_period(10).questions(2).execute(),
+				Error::<Test>::InvalidBundleSettings
 			);
 		})
 	}
 
 	#[test]
-	fn retract_vote() {
-		ExtBuilder::new_build(vec![(ALICE, 10)]).execute_with(|| {
-			let freeze_id: () =
-				<<Test as pallet_voting::Config>::FreezeIdForPallet as Get<_>>::get();
-
-			let init_aye = true;
-			let init_power = 3; // 9 tokens required
-			vote_setup();
-
+	fn votes_are_committed_then_revealed() {
+		ExtBuilder::new_build(vec![(ALICE, 100), (BOB, 100)]).execute_with(|| {
+			setup();
+			assert_ok!(Voting::register_voter(RuntimeOrigin::root(), BOB));
+			assert_ok!(ProposalBuilder::new().start(5).end(200).reveal_period(10).execute());
 			let proposal_id = Voting::next_proposal_id() - 1;
+			System::set_block_number(5);
+			Voting::on_initialize(5);
 
-			// Initial vote execution
-			assert_ok!(Voting::vote(
+			assert_noop!(
+				Voting::vote(RuntimeOrigin::signed(ALICE), proposal_id, true, 3, Conviction::None),
+				Error::<Test>::SecretBallot
+			);
+			let salt = [7; 32];
+			let commitment = Voting::vote_commitment(&ALICE, proposal_id, true, 3, salt);
+			assert_ok!(Voting::commit_vote(
 				RuntimeOrigin::signed(ALICE),
 				proposal_id,
-				init_aye,
-				init_power
+				commitment,
+				5,
+				None
 			));
+			System::assert_last_event(Event::VoteCommitted { proposal_id, voter: ALICE }.into());
+			// The collateral of the maximum power is held, the tally is unchanged
+			assert_eq!(Voting::held_for_voting(&ALICE), 25);
+			assert_eq!(Voting::proposals(proposal_id).unwrap().ratio, (0, 0));
+			let bob_commitment = Voting::vote_commitment(&BOB, proposal_id, false, 2, salt);
+			assert_ok!(Voting::commit_vote(
+				RuntimeOrigin::signed(BOB),
+				proposal_id,
+				bob_commitment,
+				2,
+				None
+			));
+			assert_noop!(
+				Voting::reveal_vote(RuntimeOrigin::signed(ALICE), proposal_id, true, 3, salt),
+				Error::<Test>::ProposalHasNotEndedYet
+			);
 
-			System::set_block_number(2);
-
-			// Vote adjustment
-			let second_aye = true;
-			let second_power = 0; // 16 tokens required - diff = 7
-			let second_quadratic_amount = Voting::calculate_quadratic_amount(second_power);
-
-			assert_ok!(Voting::vote(
+			System::set_block_number(200);
+			Voting::on_initialize(200);
+			assert_noop!(
+				Voting::commit_vote(RuntimeOrigin::signed(ALICE), proposal_id, commitment, 5, None),
+				Error::<Test>::ProposalHasAlreadyEnded
+			);
+			assert_noop!(
+				Voting::reveal_vote(RuntimeOrigin::signed(ALICE), proposal_id, true, 4, salt),
+				Error::<Test>::InvalidReveal
+			);
+			// A commitment cannot be revealed by another voter
+			assert_noop!(
+				Voting::reveal_vote(RuntimeOrigin::signed(BOB), proposal_id, true, 3, salt),
+				Error::<Test>::InvalidReveal
+			);
+			assert_ok!(Voting::reveal_vote(
 				RuntimeOrigin::signed(ALICE),
 				proposal_id,
-				second_aye,
-				second_power
+				true,
+				3,
+				salt
 			));
-
-			// Storage
-			let proposal = Voting::proposals(proposal_id);
-			assert_eq!(proposal.unwrap().ratio, (second_quadratic_amount, second_quadratic_amount));
-
-			let alice_frozen_balance = <<Test as crate::Config>::NativeBalance as Inspect<
-				<Test as frame_system::Config>::AccountId,
-			>>::balance_frozen(&freeze_id, &ALICE);
-			assert_eq!(alice_frozen_balance, second_quadratic_amount);
-
-			let vote = Voting::votes(ALICE, proposal_id);
-			assert_eq!(vote, None);
-
-			// Event
-			System::assert_last_event(Event::VoteDropped { proposal_id, voter: ALICE }.into());
+			System::assert_last_event(
+				Event::VoteRevealed { proposal_id, voter: ALICE, aye: true, power: 3 }.into(),
+			);
+			assert_eq!(Voting::held_for_voting(&ALICE), 9);
+			assert_eq!(Voting::proposals(proposal_id).unwrap().ratio, (9, 9));
+			assert_eq!(Voting::commitments(proposal_id, ALICE), None);
+			assert_eq!(Voting::pending_commitments(ALICE), 0);
+
+			// Closed once the reveal window is over, the unrevealed commitment being released
+			System::set_block_number(210);
+			Voting::on_initialize(210);
+			assert_eq!(
+				Voting::archived_proposals(proposal_id).map(|archive| archive.outcome),
+				Some(ProposalOutcome::Completed { ratio: (9, 9), approved: true })
+			);
+			Voting::on_idle(210, Weight::MAX);
+			assert_eq!(Voting::held_for_voting(&ALICE), 0);
+			assert_eq!(Voting::held_for_voting(&BOB), 0);
+			assert_eq!(Voting::pending_commitments(BOB), 0);
 		})
 	}
+}
+
+mod sortition {
+	use super::*;
 
 	#[test]
-	fn multiple_proposal_votes() {
-		ExtBuilder::new_build(vec![(ALICE, 30)]).execute_with(|| {
-			let freeze_id: () =
-				<<Test as pallet_voting::Config>::FreezeIdForPallet as Get<_>>::get();
+	fn jury_size_is_checked() {
+		new_test_ext().execute_with(|| {
+			System::set_block_number(1);
+			setup();
+			assert_noop!(
+				ProposalBuilder::new().jury_size(0).execute(),
+				Error::<Test>::InvalidJurySize
+			);
+			assert_noop!(
+				ProposalBuilder::new().jury_size(MAX_JURY_SIZE + 1).execute(),
+				Error::<Test>::InvalidJurySize
+			);
+		})
+	}
 
-			let proposal_1_start_block = 1;
-			let proposal_1_end_block = 200;
-			let proposal_2_start_block = 10;
-			let proposal_2_end_block = 210;
-			assert_ok!(Voting::register_voter(RuntimeOrigin::root(), ALICE));
+	#[test]
+	fn only_jurors_can_vote() {
+		let voters = [ALICE, BOB, CHARLIE, DAVE];
+		ExtBuilder::new_build(voters.iter().map(|voter| (*voter, 10)).collect()).execute_with(
+			|| {
+				for voter in voters {
+					assert_ok!(Voting::register_voter(RuntimeOrigin::root(), voter));
+				}
+				assert_ok!(ProposalBuilder::new().start(10).end(200).jury_size(2).execute());
+				let proposal_id = Voting::next_proposal_id() - 1;
+				assert_eq!(Voting::scheduled_starts(10).into_inner(), vec![proposal_id]);
+
+				System::set_block_number(10);
+				assert_noop!(
+					Voting::vote(
+						RuntimeOrigin::signed(ALICE),
+						proposal_id,
+						true,
+						1,
+						Conviction::None
+					),
+					Error::<Test>::JuryNotDrawn
+				);
+
+				Voting::on_initialize(10);
+				let jury = Voting::juries(proposal_id).unwrap();
+				assert_eq!(jury.len(), 2);
+				System::assert_has_event(Event::JuryDrawn { proposal_id, jurors: 2 }.into());
+				assert!(Voting::scheduled_starts(10).is_empty());
+
+				for voter in voters {
+					if jury.contains(&voter) {
+						assert_ok!(Voting::vote(
+							RuntimeOrigin::signed(voter),
+							proposal_id,
+							true,
+							1,
+							Conviction::None
+						));
+					} else {
+						assert_noop!(
+							Voting::vote(
+								RuntimeOrigin::signed(voter),
+								proposal_id,
+								true,
+								1,
+								Conviction::None
+							),
+							Error::<Test>::NotInJury
+						);
+					}
+				}
+
+				System::set_block_number(200);
+				assert_ok!(Voting::close_proposal(RuntimeOrigin::signed(ALICE), proposal_id));
+				assert_eq!(Voting::juries(proposal_id), None);
+			},
+		)
+	}
+
+	#[test]
+	fn proposal_starting_right_away_draws_in_next_block() {
+		new_test_ext().execute_with(|| {
+			System::set_block_number(1);
+			setup();
+			assert_ok!(ProposalBuilder::new().start(1).end(200).jury_size(2).execute());
+			let proposal_id = Voting::next_proposal_id() - 1;
+			assert_eq!(Voting::scheduled_starts(2).into_inner(), vec![proposal_id]);
+
+			// A single registered voter: the jury is smaller than requested
+			System::set_block_number(2);
+			Voting::on_initialize(2);
+			assert_eq!(Voting::juries(proposal_id).unwrap().into_inner(), vec![ALICE]);
+		})
+	}
+}
+
+mod voter_cap {
+	use super::*;
+	use frame_support::dispatch::DispatchResultWithPostInfo;
+
+	fn vote(who: u64, proposal_id: u32, power: u128) -> DispatchResultWithPostInfo {
+		Voting::vote(RuntimeOrigin::signed(who), proposal_id, true, power, Conviction::None)
+	}
+
+	#[test]
+	fn max_voters_is_checked() {
+		new_test_ext().execute_with(|| {
+			System::set_block_number(1);
+			setup();
+			assert_noop!(
+				ProposalBuilder::new().max_voters(0, VoterAdmission::FirstCome).execute(),
+				Error::<Test>::InvalidMaxVoters
+			);
+			assert_noop!(
+				ProposalBuilder::new()
+					.max_voters(2, VoterAdmission::FirstCome)
+					.reveal_period(10)
+					.execute(),
+				Error::<Test>::InvalidMaxVoters
+			);
+		})
+	}
+
+	#[test]
+	fn first_come_voters_fill_the_seats() {
+		let voters = [ALICE, BOB, CHARLIE];
+		ExtBuilder::new_build(voters.iter().map(|voter| (*voter, 10)).collect()).execute_with(
+			|| {
+				for voter in voters {
+					assert_ok!(Voting::register_voter(RuntimeOrigin::root(), voter));
+				}
+				assert_ok!(ProposalBuilder::new()
+					.start(1)
+					.end(200)
+					.max_voters(2, VoterAdmission::FirstCome)
+					.execute());
+				let proposal_id = Voting::next_proposal_id() - 1;
+
+				assert_ok!(vote(ALICE, proposal_id, 1));
+				assert_ok!(vote(BOB, proposal_id, 1));
+				assert_noop!(vote(CHARLIE, proposal_id, 1), Error::<Test>::VoterCapReached);
+				// The seated voters can still change their vote
+				assert_ok!(vote(BOB, proposal_id, 2));
+
+				// A dropped vote frees its seat
+				assert_ok!(vote(BOB, proposal_id, 0));
+				assert_ok!(vote(CHARLIE, proposal_id, 1));
+
+				System::set_block_number(200);
+				assert_ok!(Voting::close_proposal(RuntimeOrigin::signed(ALICE), proposal_id));
+				let archive = Voting::archived_proposals(proposal_id).unwrap();
+				assert_eq!(archive.voter_cap, Some((2, 2)));
+			},
+		)
+	}
+
+	#[test]
+	fn priority_voters_are_admitted_over_the_cap() {
+		let voters = [ALICE, BOB, CHARLIE, DAVE];
+		ExtBuilder::new_build(voters.iter().map(|voter| (*voter, 10)).collect()).execute_with(
+			|| {
+				for voter in voters {
+					assert_ok!(Voting::register_voter(RuntimeOrigin::root(), voter));
+				}
+				let account_list = BoundedVec::try_from(vec![ALICE, BOB, CHARLIE]).unwrap();
+				assert_ok!(ProposalBuilder::new()
+					.private()
+					.start(10)
+					.end(200)
+					.set_account_list(Some(account_list))
+					.max_voters(1, VoterAdmission::Priority)
+					.execute());
+				let proposal_id = Voting::next_proposal_id() - 1;
+				assert_ok!(Voting::register_expected_voters(
+					RuntimeOrigin::signed(ALICE),
+					proposal_id
+				));
+				// DAVE joins the account list without a pre-registered slot
+				assert_ok!(Voting::update_account_list(
+					RuntimeOrigin::signed(ALICE),
+					proposal_id,
+					BoundedVec::try_from(vec![DAVE]).unwrap(),
+					BoundedVec::default(),
+				));
+
+				System::set_block_number(10);
+				assert_ok!(vote(ALICE, proposal_id, 1));
+				assert_ok!(vote(BOB, proposal_id, 1));
+				assert_noop!(vote(DAVE, proposal_id, 1), Error::<Test>::VoterCapReached);
+
+				System::set_block_number(200);
+				assert_ok!(Voting::close_proposal(RuntimeOrigin::signed(ALICE), proposal_id));
+				let archive = Voting::archived_proposals(proposal_id).unwrap();
+				assert_eq!(archive.voter_cap, Some((2, 1)));
+			},
+		)
+	}
+}
+
+mod close_proposal {
+	use super::*;
+	use crate::{HiddenTallies, ProposalOutcome, VoteRatio};
+
+	#[test]
+	fn ended_proposals_are_closed_in_on_initialize() {
+		ExtBuilder::new_build(vec![(ALICE, 10)]).execute_with(|| {
+			setup();
+			for _ in 0..3 {
+				assert_ok!(ProposalBuilder::new().start(1).end(200).execute());
+			}
+			let proposal_id = Voting::next_proposal_id() - 1;
+			assert_ok!(Voting::vote(
+				RuntimeOrigin::signed(ALICE),
+				proposal_id,
+				true,
+				2,
+				Conviction::None
+			));
+			assert_eq!(Voting::scheduled_ends(200).len(), 3);
+
+			// At most 2 proposals are closed per block, the last one is carried over
+			System::set_block_number(200);
+			Voting::on_initialize(200);
+			assert!(Voting::proposals(proposal_id - 2).is_none());
+			assert!(Voting::proposals(proposal_id - 1).is_none());
+			assert!(Voting::proposals(proposal_id).is_some());
+			assert_eq!(Voting::scheduled_ends(201).into_inner(), vec![proposal_id]);
+
+			System::set_block_number(201);
+			Voting::on_initialize(201);
+			assert!(Voting::proposals(proposal_id).is_none());
+			System::assert_has_event(
+				Event::VoteCompleted { proposal_id, ratio: (4, 4), approved: true }.into(),
+			);
+		})
+	}
+
+	#[test]
+	fn proposals_leave_the_end_index_once_cancelled_or_closed() {
+		ExtBuilder::new_build(vec![(ALICE, 10)]).execute_with(|| {
+			setup();
+			assert_ok!(ProposalBuilder::new().start(1).end(200).execute());
+			assert_ok!(ProposalBuilder::new().start(10).end(200).execute());
+			assert_ok!(ProposalBuilder::new().start(1).end(300).execute());
+			let proposal_id = Voting::next_proposal_id() - 1;
+			assert_eq!(Voting::proposals_ending_at(200), vec![proposal_id - 2, proposal_id - 1]);
+
+			assert_ok!(Voting::cancel_proposal(
+				RuntimeOrigin::signed(ALICE),
+				proposal_id - 1,
+				CancellationReason::CreatorWithdrawn
+			));
+			assert_eq!(Voting::proposals_ending_at(200), vec![proposal_id - 2]);
+
+			// A proposal closed before the hook runs is not left behind in the index
+			System::set_block_number(300);
+			assert_ok!(Voting::close_proposal(RuntimeOrigin::signed(BOB), proposal_id));
+			assert!(Voting::proposals_ending_at(300).is_empty());
+			assert!(!pallet_voting::ScheduledEnds::<Test>::contains_key(300));
+		})
+	}
+
+	#[test]
+	fn close_proposal() {
+		new_test_ext().execute_with(|| {
+			System::set_block_number(1);
+			let start_block = 1;
+			let end_block = 200;
+			setup();
+
+			assert_ok!(ProposalBuilder::new().start(start_block).end(end_block).execute());
+
+			System::set_block_number(200);
+
+			let proposal_id = Voting::get_next_proposal_id() - 1;
+			assert_ok!(Voting::close_proposal(RuntimeOrigin::signed(BOB), proposal_id));
+
+			// Storage
+			let proposal = Voting::proposals(proposal_id);
+			assert_eq!(proposal, None);
+			assert_eq!(
+				Voting::archived_proposals(proposal_id).map(|archived| archived.outcome),
+				Some(ProposalOutcome::Completed { ratio: VoteRatio::default(), approved: false })
+			);
+
+			// Event
+			System::assert_last_event(
+				Event::VoteCompleted { proposal_id, ratio: VoteRatio::default(), approved: false }
+					.into(),
+			);
+		})
+	}
+
+	#[test]
+	fn private_proposals_close_once_settled() {
+		ExtBuilder::new_build(vec![(ALICE, 10), (BOB, 10), (CHARLIE, 10)]).execute_with(|| {
+			setup();
+			assert_ok!(Voting::register_voter(RuntimeOrigin::root(), BOB));
+			assert_ok!(Voting::register_voter(RuntimeOrigin::root(), CHARLIE));
 			assert_ok!(ProposalBuilder::new()
-				.start(proposal_1_start_block)
-				.end(proposal_1_end_block)
+				.private()
+				.start(1)
+				.end(200)
+				.set_account_list(Some(BoundedVec::try_from(vec![ALICE, BOB, CHARLIE]).unwrap()))
+				.immutable_ballot()
 				.execute());
-			let proposal_1_id = Voting::next_proposal_id() - 1;
+			let proposal_id = Voting::next_proposal_id() - 1;
+			assert_ok!(Voting::vote(
+				RuntimeOrigin::signed(ALICE),
+				proposal_id,
+				true,
+				2,
+				Conviction::None
+			));
+			assert_ok!(Voting::vote(
+				RuntimeOrigin::signed(BOB),
+				proposal_id,
+				false,
+				1,
+				Conviction::None
+			));
+			// CHARLIE could still turn the outcome with `MaxPower`
+			assert!(Voting::proposals(proposal_id).is_some());
+
+			// No voter is left, the proposal is closed before its end
+			assert_ok!(Voting::vote(
+				RuntimeOrigin::signed(CHARLIE),
+				proposal_id,
+				true,
+				1,
+				Conviction::None
+			));
+			assert!(Voting::proposals(proposal_id).is_none());
+			assert!(Voting::proposals_ending_at(200).is_empty());
+			assert_eq!(
+				Voting::archived_proposals(proposal_id).map(|archived| archived.outcome),
+				Some(ProposalOutcome::Completed { ratio: (5, 6), approved: true })
+			);
+			System::assert_has_event(
+				Event::VoteCompleted { proposal_id, ratio: (5, 6), approved: true }.into(),
+			);
+		})
+	}
+
+	#[test]
+	fn editable_ballots_do_not_settle() {
+		ExtBuilder::new_build(vec![(ALICE, 10), (BOB, 10), (CHARLIE, 10)]).execute_with(|| {
+			setup();
+			assert_ok!(Voting::register_voter(RuntimeOrigin::root(), BOB));
+			assert_ok!(Voting::register_voter(RuntimeOrigin::root(), CHARLIE));
 			assert_ok!(ProposalBuilder::new()
-				.start(proposal_2_start_block)
-				.end(proposal_2_end_block)
+				.private()
+				.start(1)
+				.end(200)
+				.set_account_list(Some(BoundedVec::try_from(vec![ALICE, BOB, CHARLIE]).unwrap()))
 				.execute());
-			let proposal_2_id = Voting::next_proposal_id() - 1;
+			let proposal_id = Voting::next_proposal_id() - 1;
+			for (voter, aye, power) in [(ALICE, true, 2), (BOB, false, 1), (CHARLIE, true, 1)] {
+				assert_ok!(Voting::vote(
+					RuntimeOrigin::signed(voter),
+					proposal_id,
+					aye,
+					power,
+					Conviction::None
+				));
+			}
+			// Everyone voted but ALICE can still change its vote
+			assert!(Voting::proposals(proposal_id).is_some());
+
+			// The flip turns the outcome around
+			assert_ok!(Voting::vote(
+				RuntimeOrigin::signed(ALICE),
+				proposal_id,
+				false,
+				2,
+				Conviction::None
+			));
+			assert!(Voting::proposals(proposal_id).is_some());
+			System::set_block_number(200);
+			assert_ok!(Voting::close_proposal(RuntimeOrigin::signed(ALICE), proposal_id));
+			System::assert_last_event(
+				Event::VoteCompleted { proposal_id, ratio: (1, 6), approved: false }.into(),
+			);
+		})
+	}
+
+	#[test]
+	fn approval_follows_the_threshold() {
+		ExtBuilder::new_build(vec![(ALICE, 100), (BOB, 100)]).execute_with(|| {
+			setup();
+			assert_ok!(Voting::register_voter(RuntimeOrigin::root(), BOB));
+			let thresholds = [
+				(Threshold::SimpleMajority, true),
+				(Threshold::TwoThirds, true),
+				(Threshold::ThreeQuarters, false),
+				(Threshold::Unanimous, false),
+			];
+			for (threshold, _) in thresholds {
+				assert_ok!(ProposalBuilder::new().start(1).end(200).threshold(threshold).execute());
+				let proposal_id = Voting::next_proposal_id() - 1;
+				assert_ok!(Voting::vote(
+					RuntimeOrigin::signed(ALICE),
+					proposal_id,
+					true,
+					3,
+					Conviction::None
+				));
+				assert_ok!(Voting::vote(
+					RuntimeOrigin::signed(BOB),
+					proposal_id,
+					false,
+					2,
+					Conviction::None
+				));
+			}
+
+			// 9 'aye' votes out of 13 are more than two thirds but less than three quarters
+			System::set_block_number(200);
+			let first_id = Voting::next_proposal_id() - thresholds.len() as u32;
+			for (proposal_id, (_, approved)) in (first_id..).zip(thresholds) {
+				assert_ok!(Voting::close_proposal(RuntimeOrigin::signed(ALICE), proposal_id));
+				System::assert_last_event(
+					Event::VoteCompleted { proposal_id, ratio: (9, 13), approved }.into(),
+				);
+			}
+		})
+	}
+
+	#[test]
+	fn issuance_quorum_is_a_share_of_the_total_issuance() {
+		ExtBuilder::new_build(vec![(ALICE, 10), (BOB, 10)]).execute_with(|| {
+			setup();
+
+			// 10% of an issuance of 20: a turnout of 2 is enough, 20% needs 4
+			let mut proposal_ids = vec![];
+			for quorum in [10, 20] {
+				assert_ok!(ProposalBuilder::new()
+					.start(1)
+					.end(200)
+					.issuance_quorum(Perbill::from_percent(quorum))
+					.execute());
+				let proposal_id = Voting::next_proposal_id() - 1;
+				assert_ok!(Voting::vote(
+					RuntimeOrigin::signed(ALICE),
+					proposal_id,
+					true,
+					2,
+					Conviction::None
+				));
+				proposal_ids.push(proposal_id);
+			}
+
+			System::set_block_number(200);
+			for (proposal_id, approved) in proposal_ids.into_iter().zip([true, false]) {
+				assert_ok!(Voting::close_proposal(RuntimeOrigin::signed(ALICE), proposal_id));
+				System::assert_last_event(
+					Event::VoteCompleted { proposal_id, ratio: (2, 2), approved }.into(),
+				);
+			}
+		})
+	}
+
+	#[test]
+	fn headcount_quorum_is_evaluated_at_close() {
+		ExtBuilder::new_build(vec![(ALICE, 10), (BOB, 10)]).execute_with(|| {
+			for voter in [ALICE, BOB, CHARLIE, DAVE] {
+				assert_ok!(Voting::register_voter(RuntimeOrigin::root(), voter));
+			}
+
+			// 50% of 4 registered voters: 2 voters must participate
+			assert_ok!(ProposalBuilder::new()
+				.start(1)
+				.end(200)
+				.headcount_quorum(Percent::from_percent(50))
+				.execute());
+			let first_proposal_id = Voting::next_proposal_id() - 1;
+			assert_ok!(ProposalBuilder::new()
+				.start(1)
+				.end(200)
+				.headcount_quorum(Percent::from_percent(50))
+				.execute());
+			let second_proposal_id = Voting::next_proposal_id() - 1;
+
+			assert_ok!(Voting::vote(
+				RuntimeOrigin::signed(ALICE),
+				first_proposal_id,
+				true,
+				1,
+				Conviction::None
+			));
+			assert_ok!(Voting::vote(
+				RuntimeOrigin::signed(ALICE),
+				second_proposal_id,
+				true,
+				1,
+				Conviction::None
+			));
+			assert_ok!(Voting::vote(
+				RuntimeOrigin::signed(BOB),
+				second_proposal_id,
+				true,
+				1,
+				Conviction::None
+			));
+			assert_eq!(Voting::proposals(second_proposal_id).unwrap().voters_count, 2);
+
+			System::set_block_number(200);
+
+			assert_ok!(Voting::close_proposal(RuntimeOrigin::signed(BOB), first_proposal_id));
+			System::assert_last_event(
+				Event::VoteCompleted {
+					proposal_id: first_proposal_id,
+					ratio: (1, 1),
+					approved: false,
+				}
+				.into(),
+			);
+
+			assert_ok!(Voting::close_proposal(RuntimeOrigin::signed(BOB), second_proposal_id));
+			System::assert_last_event(
+				Event::VoteCompleted {
+					proposal_id: second_proposal_id,
+					ratio: (2, 2),
+					approved: true,
+				}
+				.into(),
+			);
+		})
+	}
+
+	#[test]
+	fn quorum_electorate_is_recorded_at_start() {
+		ExtBuilder::new_build(vec![(ALICE, 10), (BOB, 10)]).execute_with(|| {
+			assert_ok!(Voting::register_voter(RuntimeOrigin::root(), ALICE));
+			assert_ok!(Voting::register_voter(RuntimeOrigin::root(), BOB));
+
+			// 50% of 2 registered voters: a single voter is enough
+			assert_ok!(ProposalBuilder::new()
+				.start(1)
+				.end(200)
+				.headcount_quorum(Percent::from_percent(50))
+				.execute());
+			let proposal_id = Voting::next_proposal_id() - 1;
+			assert_eq!(Voting::proposals(proposal_id).unwrap().electorate, Some(2));
+
+			// Late registrations don't raise the quorum
+			assert_ok!(Voting::register_voter(RuntimeOrigin::root(), CHARLIE));
+			assert_ok!(Voting::register_voter(RuntimeOrigin::root(), DAVE));
+			assert_ok!(Voting::vote(
+				RuntimeOrigin::signed(ALICE),
+				proposal_id,
+				true,
+				1,
+				Conviction::None
+			));
+
+			System::set_block_number(200);
+			assert_ok!(Voting::close_proposal(RuntimeOrigin::signed(BOB), proposal_id));
+			System::assert_last_event(
+				Event::VoteCompleted { proposal_id, ratio: (1, 1), approved: true }.into(),
+			);
+		})
+	}
+
+	#[test]
+	fn private_quorum_counts_the_account_list() {
+		ExtBuilder::new_build(vec![(ALICE, 10), (BOB, 10)]).execute_with(|| {
+			for voter in [ALICE, BOB, CHARLIE, DAVE] {
+				assert_ok!(Voting::register_voter(RuntimeOrigin::root(), voter));
+			}
+			assert_ok!(ProposalBuilder::new()
+				.private()
+				.start(10)
+				.end(200)
+				.headcount_quorum(Percent::from_percent(50))
+				.execute());
+			let proposal_id = Voting::next_proposal_id() - 1;
+			assert_ok!(Voting::set_account_list(
+				RuntimeOrigin::signed(ALICE),
+				proposal_id,
+				Some(BoundedVec::try_from(vec![ALICE, BOB]).unwrap())
+			));
+			assert_eq!(Voting::proposals(proposal_id).unwrap().electorate, None);
+
+			System::set_block_number(10);
+			Voting::on_initialize(10);
+			assert_eq!(Voting::proposals(proposal_id).unwrap().electorate, Some(2));
+
+			// 50% of the 2 allowed voters rather than of the 4 registered ones
+			assert_ok!(Voting::vote(
+				RuntimeOrigin::signed(ALICE),
+				proposal_id,
+				true,
+				1,
+				Conviction::None
+			));
+			System::set_block_number(200);
+			assert_ok!(Voting::close_proposal(RuntimeOrigin::signed(BOB), proposal_id));
+			System::assert_last_event(
+				Event::VoteCompleted { proposal_id, ratio: (1, 1), approved: true }.into(),
+			);
+		})
+	}
+
+	#[test]
+	fn whale_cap_discards_excess_contributions() {
+		ExtBuilder::new_build(vec![(ALICE, 10), (BOB, 10)]).execute_with(|| {
+			assert_ok!(Voting::register_voter(RuntimeOrigin::root(), ALICE));
+			assert_ok!(Voting::register_voter(RuntimeOrigin::root(), BOB));
+			assert_ok!(ProposalBuilder::new()
+				.start(1)
+				.end(200)
+				.whale_cap(Perbill::from_percent(30))
+				.execute());
+			let proposal_id = Voting::next_proposal_id() - 1;
+
+			assert_ok!(Voting::vote(
+				RuntimeOrigin::signed(ALICE),
+				proposal_id,
+				true,
+				3,
+				Conviction::None
+			));
+			assert_ok!(Voting::vote(
+				RuntimeOrigin::signed(BOB),
+				proposal_id,
+				false,
+				2,
+				Conviction::None
+			));
+			assert_eq!(Voting::proposals(proposal_id).unwrap().ratio, (9, 13));
+			assert_eq!(Voting::contributions(proposal_id, ALICE), Some((true, 9)));
+
+			System::set_block_number(200);
+
+			// Each contribution is capped to 30% of 13 = 3
+			assert_ok!(Voting::close_proposal(RuntimeOrigin::signed(BOB), proposal_id));
+			System::assert_last_event(
+				Event::VoteCompleted { proposal_id, ratio: (3, 6), approved: false }.into(),
+			);
+
+			Voting::on_idle(200, Weight::MAX);
+			assert_eq!(Voting::contributions(proposal_id, ALICE), None);
+		})
+	}
+
+	#[test]
+	fn turnout_cap_scales_contributions_pro_rata() {
+		ExtBuilder::new_build(vec![(ALICE, 20), (BOB, 20)]).execute_with(|| {
+			assert_ok!(Voting::register_voter(RuntimeOrigin::root(), ALICE));
+			assert_ok!(Voting::register_voter(RuntimeOrigin::root(), BOB));
+			assert_noop!(
+				ProposalBuilder::new().start(1).end(200).turnout_cap(0).execute(),
+				Error::<Test>::InvalidTurnoutCap
+			);
+			assert_ok!(ProposalBuilder::new().start(1).end(200).turnout_cap(10).execute());
+			let proposal_id = Voting::next_proposal_id() - 1;
+
+			assert_ok!(Voting::vote(
+				RuntimeOrigin::signed(ALICE),
+				proposal_id,
+				true,
+				4,
+				Conviction::None
+			));
+			assert_ok!(Voting::vote(
+				RuntimeOrigin::signed(BOB),
+				proposal_id,
+				false,
+				2,
+				Conviction::None
+			));
+			assert_eq!(Voting::proposals(proposal_id).unwrap().ratio, (16, 20));
+
+			System::set_block_number(200);
+
+			// A turnout of 20 for a cap of 10, each contribution counts for half
+			let scale = Perbill::from_percent(50);
+			assert_ok!(Voting::close_proposal(RuntimeOrigin::signed(BOB), proposal_id));
+			System::assert_has_event(Event::TurnoutScaled { proposal_id, scale }.into());
+			System::assert_last_event(
+				Event::VoteCompleted { proposal_id, ratio: (8, 10), approved: true }.into(),
+			);
+			assert_eq!(
+				Voting::archived_proposals(proposal_id).and_then(|archive| archive.turnout_scale),
+				Some(scale)
+			);
+		})
+	}
+
+	#[test]
+	fn role_quorums_are_evaluated_at_close() {
+		ExtBuilder::new_build(vec![(ALICE, 10), (BOB, 10), (CHARLIE, 10)]).execute_with(|| {
+			for voter in [ALICE, BOB, CHARLIE] {
+				assert_ok!(Voting::register_voter(RuntimeOrigin::root(), voter));
+			}
+			assert_noop!(
+				ProposalBuilder::new().start(1).end(200).role_quorums(vec![(1, 0)]).execute(),
+				Error::<Test>::InvalidRoleQuorum
+			);
+			assert_noop!(
+				ProposalBuilder::new()
+					.start(1)
+					.end(200)
+					.role_quorums(vec![(1, 1), (1, 2)])
+					.execute(),
+				Error::<Test>::InvalidRoleQuorum
+			);
+			assert_ok!(Voting::set_creator_role(RuntimeOrigin::root(), ALICE, 1, true));
+			assert_ok!(Voting::set_creator_role(RuntimeOrigin::root(), BOB, 1, true));
+			assert_ok!(ProposalBuilder::new()
+				.start(1)
+				.end(200)
+				.role_quorums(vec![(1, 2)])
+				.execute());
+			let short_id = Voting::next_proposal_id() - 1;
+			assert_ok!(ProposalBuilder::new()
+				.start(1)
+				.end(200)
+				.role_quorums(vec![(1, 2)])
+				.execute());
+			let reached_id = Voting::next_proposal_id() - 1;
+
+			assert_ok!(Voting::vote(
+				RuntimeOrigin::signed(ALICE),
+				short_id,
+				true,
+				1,
+				Conviction::None
+			));
+			assert_ok!(Voting::vote(
+				RuntimeOrigin::signed(CHARLIE),
+				short_id,
+				true,
+				1,
+				Conviction::None
+			));
+			assert_eq!(Voting::role_turnouts(short_id, 1), 1);
+			// Dropping a vote counts its voter out
+			assert_ok!(Voting::vote(
+				RuntimeOrigin::signed(ALICE),
+				reached_id,
+				true,
+				1,
+				Conviction::None
+			));
+			assert_ok!(Voting::vote(
+				RuntimeOrigin::signed(ALICE),
+				reached_id,
+				true,
+				0,
+				Conviction::None
+			));
+			assert_eq!(Voting::role_turnouts(reached_id, 1), 0);
+			assert_ok!(Voting::vote(
+				RuntimeOrigin::signed(ALICE),
+				reached_id,
+				true,
+				1,
+				Conviction::None
+			));
+			assert_ok!(Voting::vote(
+				RuntimeOrigin::signed(BOB),
+				reached_id,
+				true,
+				1,
+				Conviction::None
+			));
+			assert_eq!(Voting::role_turnouts(reached_id, 1), 2);
+
+			System::set_block_number(200);
+
+			// A single voter holding the role took part
+			assert_ok!(Voting::close_proposal(RuntimeOrigin::signed(BOB), short_id));
+			System::assert_last_event(
+				Event::VoteCompleted { proposal_id: short_id, ratio: (2, 2), approved: false }
+					.into(),
+			);
+			assert_ok!(Voting::close_proposal(RuntimeOrigin::signed(BOB), reached_id));
+			System::assert_last_event(
+				Event::VoteCompleted { proposal_id: reached_id, ratio: (2, 2), approved: true }
+					.into(),
+			);
+			assert_eq!(pallet_voting::RoleTurnouts::<Test>::iter_prefix(reached_id).count(), 0);
+		})
+	}
+
+	#[test]
+	fn sunset_reviews_confirm_or_repeal_the_policy() {
+		ExtBuilder::new_build(vec![(ALICE, 10), (BOB, 10)]).execute_with(|| {
+			for voter in [ALICE, BOB] {
+				assert_ok!(Voting::register_voter(RuntimeOrigin::root(), voter));
+			}
+			assert_noop!(
+				ProposalBuilder::new().start(1).end(200).sunset_after(0).execute(),
+				Error::<Test>::InvalidSunsetPeriod
+			);
+			assert_noop!(
+				ProposalBuilder::new()
+					.start(1)
+					.end(200)
+					.kind(ProposalKind::SunsetReview)
+					.execute(),
+				Error::<Test>::ProposalKindNotAllowed
+			);
+			assert_ok!(ProposalBuilder::new().start(1).end(200).sunset_after(100).execute());
+			let policy_id = Voting::next_proposal_id() - 1;
+			assert_ok!(Voting::vote(
+				RuntimeOrigin::signed(ALICE),
+				policy_id,
+				true,
+				1,
+				Conviction::None
+			));
+
+			System::set_block_number(200);
+			assert_ok!(Voting::close_proposal(RuntimeOrigin::signed(BOB), policy_id));
+			let review_id = Voting::next_proposal_id() - 1;
+			System::assert_last_event(
+				Event::SunsetReviewScheduled { policy_id, review_id, start_block: 300 }.into(),
+			);
+			let review = Voting::proposals(review_id).unwrap();
+			assert_eq!(review.kind, ProposalKind::SunsetReview);
+			assert_eq!((review.start_block, review.end_block), (300, 499));
+			assert_eq!(Voting::sunset_reviews(review_id), Some(policy_id));
+
+			// A confirmed policy is reviewed again
+			System::set_block_number(300);
+			assert_ok!(Voting::vote(
+				RuntimeOrigin::signed(ALICE),
+				review_id,
+				true,
+				1,
+				Conviction::None
+			));
+			System::set_block_number(499);
+			assert_ok!(Voting::close_proposal(RuntimeOrigin::signed(BOB), review_id));
+			let next_review_id = Voting::next_proposal_id() - 1;
+			System::assert_last_event(
+				Event::SunsetReviewScheduled {
+					policy_id,
+					review_id: next_review_id,
+					start_block: 599,
+				}
+				.into(),
+			);
+			assert!(RepealedPolicies::get().is_empty());
+
+			System::set_block_number(599);
+			assert_ok!(Voting::vote(
+				RuntimeOrigin::signed(BOB),
+				next_review_id,
+				false,
+				1,
+				Conviction::None
+			));
+			System::set_block_number(798);
+			assert_ok!(Voting::close_proposal(RuntimeOrigin::signed(BOB), next_review_id));
+			System::assert_last_event(
+				Event::PolicyRepealed { policy_id, review_id: next_review_id }.into(),
+			);
+			assert_eq!(RepealedPolicies::get(), vec![(policy_id, next_review_id)]);
+			assert_eq!(Voting::next_proposal_id() - 1, next_review_id);
+		})
+	}
+
+	#[test]
+	fn hidden_tally_is_revealed_at_close() {
+		ExtBuilder::new_build(vec![(ALICE, 10), (BOB, 10), (CHARLIE, 10)]).execute_with(|| {
+			for voter in [ALICE, BOB, CHARLIE] {
+				assert_ok!(Voting::register_voter(RuntimeOrigin::root(), voter));
+			}
+			assert_ok!(ProposalBuilder::new().start(1).end(200).hidden_tally().execute());
+			let proposal_id = Voting::next_proposal_id() - 1;
+
+			assert_ok!(Voting::vote(
+				RuntimeOrigin::signed(ALICE),
+				proposal_id,
+				true,
+				3,
+				Conviction::None
+			));
+			assert_ok!(Voting::vote(
+				RuntimeOrigin::signed(BOB),
+				proposal_id,
+				false,
+				2,
+				Conviction::None
+			));
+			assert_ok!(Voting::vote(
+				RuntimeOrigin::signed(CHARLIE),
+				proposal_id,
+				false,
+				1,
+				Conviction::None
+			));
+			assert_eq!(Voting::proposals(proposal_id).unwrap().ratio, VoteRatio::default());
+			assert_eq!(HiddenTallies::<Test>::get(proposal_id), (9, 14));
+
+			// Dropped votes are removed from the hidden tally
+			assert_ok!(Voting::unregister_voter(RuntimeOrigin::root(), CHARLIE));
+			Voting::on_idle(1, Weight::MAX);
+			assert_eq!(Voting::proposals(proposal_id).unwrap().ratio, VoteRatio::default());
+			assert_eq!(HiddenTallies::<Test>::get(proposal_id), (9, 13));
+
+			System::set_block_number(200);
+			assert_ok!(Voting::close_proposal(RuntimeOrigin::signed(BOB), proposal_id));
+			System::assert_last_event(
+				Event::VoteCompleted { proposal_id, ratio: (9, 13), approved: true }.into(),
+			);
+			assert!(!HiddenTallies::<Test>::contains_key(proposal_id));
+		})
+	}
+
+	#[test]
+	fn cannot_close_proposal_before_end() {
+		new_test_ext().execute_with(|| {
+			System::set_block_number(1);
+			let start_block = 1;
+			let end_block = 200;
+			setup();
+
+			assert_ok!(ProposalBuilder::new().start(start_block).end(end_block).execute());
+
+			System::set_block_number(199);
+
+			let proposal_id = Voting::get_next_proposal_id() - 1;
+			assert_noop!(
+				Voting::close_proposal(RuntimeOrigin::signed(BOB), proposal_id),
+				Error::<Test>::ProposalHasNotEndedYet
+			);
+		})
+	}
+
+	#[test]
+	fn cannot_close_proposal_not_existing() {
+		new_test_ext().execute_with(|| {
+			System::set_block_number(1);
+			let start_block = 1;
+			let end_block = 200;
+			setup();
+
+			assert_ok!(ProposalBuilder::new().start(start_block).end(end_block).execute());
+
+			System::set_block_number(200);
+
+			let next_proposal_id = Voting::get_next_proposal_id();
+			assert_noop!(
+				Voting::close_proposal(RuntimeOrigin::signed(BOB), next_proposal_id),
+				Error::<Test>::ProposalDoesNotExist
+			);
+		})
+	}
+}
+
+mod set_account_list {
+	use crate::ProposalData;
+
+	use super::*;
+
+	#[test]
+	fn set_account_list() {
+		new_test_ext().execute_with(|| {
+			System::set_block_number(1);
+			let start_block = 10;
+			let end_block = 200;
+			setup();
+
+			assert_ok!(ProposalBuilder::new().start(start_block).end(end_block).execute());
+			let proposal_id = Voting::get_next_proposal_id() - 1;
+			let raw_proposal = Voting::proposals(proposal_id);
+			assert!(raw_proposal.is_some());
+
+			let new_account_list = BoundedVec::try_from(vec![BOB]).unwrap();
+			assert_ok!(Voting::set_account_list(
+				RuntimeOrigin::signed(ALICE),
+				proposal_id,
+				Some(new_account_list.clone())
+			));
+
+			// Storage
+			let proposal = Voting::proposals(proposal_id);
+			assert_eq!(
+				proposal,
+				Some(ProposalData {
+					account_list: Some(new_account_list.clone()),
+					..raw_proposal.unwrap()
+				})
+			);
+
+			// Event
+			System::assert_last_event(
+				Event::AccountListSet { proposal_id, account_list: Some(new_account_list) }.into(),
+			);
+		})
+	}
+
+	#[test]
+	fn cannot_set_account_list_after_start() {
+		new_test_ext().execute_with(|| {
+			System::set_block_number(1);
+			let start_block = 1;
+			let end_block = 200;
+			setup();
+
+			assert_ok!(ProposalBuilder::new().start(start_block).end(end_block).execute());
+
+			let proposal_id = Voting::get_next_proposal_id() - 1;
+			let new_account_list = BoundedVec::try_from(vec![BOB]).unwrap();
+			assert_noop!(
+				Voting::set_account_list(
+					RuntimeOrigin::signed(ALICE),
+					proposal_id,
+					Some(new_account_list)
+				),
+				Error::<Test>::ProposalHasAlreadyStarted
+			);
+		})
+	}
+
+	#[test]
+	fn cannot_cancel_proposal_not_existing() {
+		new_test_ext().execute_with(|| {
+			System::set_block_number(1);
+			let start_block = 1;
+			let end_block = 200;
+			setup();
+
+			assert_ok!(ProposalBuilder::new().start(start_block).end(end_block).execute());
+
+			let next_proposal_id = Voting::get_next_proposal_id();
+			let new_account_list = BoundedVec::try_from(vec![BOB]).unwrap();
+			assert_noop!(
+				Voting::set_account_list(
+					RuntimeOrigin::signed(ALICE),
+					next_proposal_id,
+					Some(new_account_list)
+				),
+				Error::<Test>::ProposalDoesNotExist
+			);
+		})
+	}
+
+	#[test]
+	fn works_only_if_root_or_creator() {
+		new_test_ext().execute_with(|| {
+			System::set_block_number(1);
+			let start_block = 10;
+			let end_block = 200;
+			setup();
+
+			assert_ok!(ProposalBuilder::new().start(start_block).end(end_block).execute());
+
+			let proposal_id = Voting::get_next_proposal_id() - 1;
+			let new_account_list = BoundedVec::try_from(vec![BOB]).unwrap();
+			assert_noop!(
+				Voting::set_account_list(
+					RuntimeOrigin::signed(BOB),
+					proposal_id,
+					Some(new_account_list)
+				),
+				Error::<Test>::OriginNoPermission
+			);
+		})
+	}
+}
+
+mod update_account_list {
+	use super::*;
+
+	#[test]
+	fn update_account_list_reports_only_changes() {
+		new_test_ext().execute_with(|| {
+			System::set_block_number(1);
+			setup();
+
+			assert_ok!(ProposalBuilder::new()
+				.start(10)
+				.end(200)
+				.set_account_list(Some(BoundedVec::try_from(vec![BOB]).unwrap()))
+				.execute());
+			let proposal_id = Voting::get_next_proposal_id() - 1;
+
+			assert_ok!(Voting::update_account_list(
+				RuntimeOrigin::signed(ALICE),
+				proposal_id,
+				BoundedVec::try_from(vec![BOB, CHARLIE]).unwrap(),
+				BoundedVec::try_from(vec![DAVE]).unwrap(),
+			));
+			System::assert_last_event(
+				Event::AccountListUpdated {
+					proposal_id,
+					added: BoundedVec::try_from(vec![CHARLIE]).unwrap(),
+					removed: BoundedVec::default(),
+				}
+				.into(),
+			);
+
+			assert_ok!(Voting::update_account_list(
+				RuntimeOrigin::root(),
+				proposal_id,
+				BoundedVec::default(),
+				BoundedVec::try_from(vec![BOB]).unwrap(),
+			));
+			System::assert_last_event(
+				Event::AccountListUpdated {
+					proposal_id,
+					added: BoundedVec::default(),
+					removed: BoundedVec::try_from(vec![BOB]).unwrap(),
+				}
+				.into(),
+			);
+
+			let proposal = Voting::proposals(proposal_id).unwrap();
+			assert_eq!(proposal.account_list, Some(BoundedVec::try_from(vec![CHARLIE]).unwrap()));
+		})
+	}
+
+	#[test]
+	fn cannot_update_account_list_after_start() {
+		new_test_ext().execute_with(|| {
+			System::set_block_number(1);
+			setup();
+
+			assert_ok!(ProposalBuilder::new().start(1).end(200).execute());
+			let proposal_id = Voting::get_next_proposal_id() - 1;
+
+			assert_noop!(
+				Voting::update_account_list(
+					RuntimeOrigin::signed(ALICE),
+					proposal_id,
+					BoundedVec::try_from(vec![BOB]).unwrap(),
+					BoundedVec::default(),
+				),
+				Error::<Test>::ProposalHasAlreadyStarted
+			);
+		})
+	}
+
+	#[test]
+	fn works_only_if_root_or_creator() {
+		new_test_ext().execute_with(|| {
+			System::set_block_number(1);
+			setup();
+
+			assert_ok!(ProposalBuilder::new().start(10).end(200).execute());
+			let proposal_id = Voting::get_next_proposal_id() - 1;
+
+			assert_noop!(
+				Voting::update_account_list(
+					RuntimeOrigin::signed(BOB),
+					proposal_id,
+					BoundedVec::try_from(vec![BOB]).unwrap(),
+					BoundedVec::default(),
+				),
+				Error::<Test>::OriginNoPermission
+			);
+		})
+	}
+}
+
+mod copy_account_list {
+	use super::*;
+
+	#[test]
+	fn copy_account_list_from_another_proposal() {
+		new_test_ext().execute_with(|| {
+			System::set_block_number(1);
+			setup();
+
+			let members: BoundedVec<u64, AccountSizeLimit> =
+				BoundedVec::try_from(vec![BOB, CHARLIE]).unwrap();
+			assert_ok!(ProposalBuilder::new()
+				.private()
+				.start(1)
+				.end(200)
+				.set_account_list(Some(members.clone()))
+				.execute());
+			let source = Voting::get_next_proposal_id() - 1;
+			assert_ok!(ProposalBuilder::new().private().start(10).end(200).execute());
+			let proposal_id = Voting::get_next_proposal_id() - 1;
+
+			assert_ok!(Voting::copy_account_list(
+				RuntimeOrigin::signed(ALICE),
+				proposal_id,
+				source
+			));
+			assert_eq!(Voting::proposals(proposal_id).unwrap().account_list, Some(members.clone()));
+			System::assert_last_event(
+				Event::AccountListSet { proposal_id, account_list: Some(members) }.into(),
+			);
+		})
+	}
+
+	#[test]
+	fn cannot_copy_account_list_across_kinds() {
+		new_test_ext().execute_with(|| {
+			System::set_block_number(1);
+			setup();
+
+			assert_ok!(ProposalBuilder::new().start(1).end(200).execute());
+			let source = Voting::get_next_proposal_id() - 1;
+			assert_ok!(ProposalBuilder::new().private().start(10).end(200).execute());
+			let proposal_id = Voting::get_next_proposal_id() - 1;
+
+			assert_noop!(
+				Voting::copy_account_list(RuntimeOrigin::signed(ALICE), proposal_id, source),
+				Error::<Test>::AccountListKindMismatch
+			);
+			assert_noop!(
+				Voting::copy_account_list(RuntimeOrigin::signed(ALICE), source, proposal_id),
+				Error::<Test>::ProposalHasAlreadyStarted
+			);
+			assert_noop!(
+				Voting::copy_account_list(RuntimeOrigin::signed(BOB), proposal_id, source),
+				Error::<Test>::OriginNoPermission
+			);
+		})
+	}
+}
+
+mod eligible_proposals {
+	use super::*;
+
+	#[test]
+	fn private_account_lists_are_indexed_per_voter() {
+		new_test_ext().execute_with(|| {
+			System::set_block_number(1);
+			setup();
+
+			assert_ok!(ProposalBuilder::new()
+				.private()
+				.start(10)
+				.end(200)
+				.set_account_list(Some(BoundedVec::try_from(vec![BOB, CHARLIE]).unwrap()))
+				.execute());
+			let proposal_id = Voting::get_next_proposal_id() - 1;
+			// The account list of a public proposal bans voters
+			assert_ok!(ProposalBuilder::new()
+				.start(10)
+				.end(200)
+				.set_account_list(Some(BoundedVec::try_from(vec![BOB]).unwrap()))
+				.execute());
+			assert_eq!(Voting::eligible_proposals(&BOB), vec![proposal_id]);
+			assert_eq!(Voting::eligible_proposals(&CHARLIE), vec![proposal_id]);
+
+			assert_ok!(Voting::update_account_list(
+				RuntimeOrigin::signed(ALICE),
+				proposal_id,
+				BoundedVec::try_from(vec![DAVE]).unwrap(),
+				BoundedVec::try_from(vec![CHARLIE]).unwrap(),
+			));
+			assert!(Voting::eligible_proposals(&CHARLIE).is_empty());
+			assert_eq!(Voting::eligible_proposals(&DAVE), vec![proposal_id]);
+
+			assert_ok!(Voting::set_account_list(
+				RuntimeOrigin::signed(ALICE),
+				proposal_id,
+				Some(BoundedVec::try_from(vec![BOB]).unwrap())
+			));
+			assert!(Voting::eligible_proposals(&DAVE).is_empty());
+
+			assert_ok!(Voting::cancel_proposal(
+				RuntimeOrigin::signed(ALICE),
+				proposal_id,
+				CancellationReason::CreatorWithdrawn
+			));
+			assert!(Voting::eligible_proposals(&BOB).is_empty());
+		})
+	}
+
+	#[test]
+	fn unvoted_expected_voters_are_archived_as_abstentions() {
+		ExtBuilder::new_build(vec![(ALICE, 10), (DAVE, 10)]).execute_with(|| {
+			for voter in [ALICE, BOB, CHARLIE, DAVE] {
+				assert_ok!(Voting::register_voter(RuntimeOrigin::root(), voter));
+			}
+			assert_ok!(ProposalBuilder::new()
+				.private()
+				.start(10)
+				.end(200)
+				.set_account_list(Some(BoundedVec::try_from(vec![ALICE, BOB, CHARLIE]).unwrap()))
+				.execute());
+			let proposal_id = Voting::next_proposal_id() - 1;
+			assert_noop!(
+				Voting::register_expected_voters(RuntimeOrigin::signed(BOB), proposal_id),
+				Error::<Test>::OriginNoPermission
+			);
+			assert_ok!(Voting::register_expected_voters(RuntimeOrigin::signed(ALICE), proposal_id));
+			System::assert_last_event(
+				Event::ExpectedVotersRegistered { proposal_id, count: 3 }.into(),
+			);
+			// Removed members are no longer expected
+			assert_ok!(Voting::update_account_list(
+				RuntimeOrigin::signed(ALICE),
+				proposal_id,
+				BoundedVec::try_from(vec![DAVE]).unwrap(),
+				BoundedVec::try_from(vec![CHARLIE]).unwrap(),
+			));
+
+			System::set_block_number(10);
+			assert_ok!(Voting::vote(
+				RuntimeOrigin::signed(ALICE),
+				proposal_id,
+				true,
+				1,
+				Conviction::None
+			));
+			assert_ok!(Voting::vote(
+				RuntimeOrigin::signed(DAVE),
+				proposal_id,
+				true,
+				1,
+				Conviction::None
+			));
+			System::set_block_number(200);
+			assert_ok!(Voting::close_proposal(RuntimeOrigin::signed(ALICE), proposal_id));
+
+			let archive = Voting::archived_proposals(proposal_id).unwrap();
+			assert_eq!(archive.abstentions, Some(1));
+			assert_eq!(
+				pallet_voting::ExpectedVoters::<Test>::iter_key_prefix(proposal_id).count(),
+				0
+			);
+		})
+	}
+
+	#[test]
+	fn expected_voters_only_for_private_account_lists() {
+		new_test_ext().execute_with(|| {
+			System::set_block_number(1);
+			setup();
+			assert_ok!(ProposalBuilder::new().start(10).end(200).execute());
+			let proposal_id = Voting::next_proposal_id() - 1;
+			assert_noop!(
+				Voting::register_expected_voters(RuntimeOrigin::signed(ALICE), proposal_id),
+				Error::<Test>::ExpectedVotersOnlyForPrivate
+			);
+		})
+	}
+}
+
+mod eligibility_bitmap {
+	use super::*;
+
+	#[test]
+	fn bitmap_allows_the_voters_of_its_set_bits() {
+		ExtBuilder::new_build(vec![(ALICE, 10), (BOB, 10), (CHARLIE, 10)]).execute_with(|| {
+			for voter in [ALICE, BOB, CHARLIE] {
+				assert_ok!(Voting::register_voter(RuntimeOrigin::root(), voter));
+			}
+			// Indices are kept across registrations
+			assert_ok!(Voting::unregister_voter(RuntimeOrigin::root(), BOB));
+			assert_ok!(Voting::register_voter(RuntimeOrigin::root(), BOB));
+			assert_eq!(Voting::voter_index(&BOB), Some(1));
+
+			assert_ok!(ProposalBuilder::new().private().start(10).end(200).execute());
+			let proposal_id = Voting::next_proposal_id() - 1;
+			// ALICE and CHARLIE
+			let bitmap = BoundedVec::try_from(vec![0b101]).unwrap();
+			assert_ok!(Voting::set_eligibility_bitmap(
+				RuntimeOrigin::signed(ALICE),
+				proposal_id,
+				Some(bitmap)
+			));
+			System::assert_last_event(
+				Event::EligibilityBitmapSet { proposal_id, allowed: 2 }.into(),
+			);
+
+			System::set_block_number(10);
+			assert_ok!(Voting::vote(
+				RuntimeOrigin::signed(CHARLIE),
+				proposal_id,
+				true,
+				1,
+				Conviction::None
+			));
+			assert_noop!(
+				Voting::vote(RuntimeOrigin::signed(BOB), proposal_id, true, 1, Conviction::None),
+				Error::<Test>::OriginNoPermission
+			);
+		})
+	}
+
+	#[test]
+	fn bitmap_only_for_private_proposals() {
+		new_test_ext().execute_with(|| {
+			System::set_block_number(1);
+			setup();
+			assert_ok!(ProposalBuilder::new().start(10).end(200).execute());
+			let proposal_id = Voting::next_proposal_id() - 1;
+			assert_noop!(
+				Voting::set_eligibility_bitmap(
+					RuntimeOrigin::signed(ALICE),
+					proposal_id,
+					Some(BoundedVec::default())
+				),
+				Error::<Test>::EligibilityBitmapOnlyForPrivate
+			);
+		})
+	}
+}
+
+mod voting_asset {
+	use super::*;
+
+	const ASSET: u32 = 1;
+
+	fn create_asset(holder: u64, amount: u128) {
+		assert_ok!(Assets::force_create(RuntimeOrigin::root(), ASSET, holder, true, 1));
+		assert_ok!(Assets::mint(RuntimeOrigin::signed(holder), ASSET, holder, amount));
+	}
+
+	#[test]
+	fn only_allowed_assets_can_be_picked() {
+		ExtBuilder::new_build(vec![(ALICE, 10)]).execute_with(|| {
+			setup();
+			assert_ok!(ProposalBuilder::new().start(10).end(200).execute());
+			let proposal_id = Voting::next_proposal_id() - 1;
+			assert_noop!(
+				Voting::set_proposal_asset(RuntimeOrigin::signed(ALICE), proposal_id, Some(ASSET)),
+				Error::<Test>::AssetNotAllowed
+			);
+
+			assert_noop!(
+				Voting::set_voting_asset(RuntimeOrigin::signed(ALICE), ASSET, true),
+				sp_runtime::DispatchError::BadOrigin
+			);
+			assert_ok!(Voting::set_voting_asset(RuntimeOrigin::root(), ASSET, true));
+			System::assert_last_event(
+				Event::VotingAssetUpdated { asset: ASSET, allowed: true }.into(),
+			);
+			assert_ok!(Voting::set_proposal_asset(
+				RuntimeOrigin::signed(ALICE),
+				proposal_id,
+				Some(ASSET)
+			));
+			System::assert_last_event(
+				Event::ProposalAssetSet { proposal_id, asset: Some(ASSET) }.into(),
+			);
+			assert_eq!(Voting::proposal_assets(proposal_id), Some(ASSET));
+
+			assert_ok!(ProposalBuilder::new().start(10).end(200).shared_budget().execute());
+			assert_noop!(
+				Voting::set_proposal_asset(
+					RuntimeOrigin::signed(ALICE),
+					proposal_id + 1,
+					Some(ASSET)
+				),
+				Error::<Test>::AssetWithSharedBudget
+			);
+
+			System::set_block_number(10);
+			assert_noop!(
+				Voting::set_proposal_asset(RuntimeOrigin::signed(ALICE), proposal_id, None),
+				Error::<Test>::ProposalHasAlreadyStarted
+			);
+		})
+	}
+
+	#[test]
+	fn collateral_is_escrowed_in_the_asset() {
+		ExtBuilder::new_build(vec![(ALICE, 10)]).execute_with(|| {
+			setup();
+			create_asset(ALICE, 30);
+			assert_ok!(Voting::set_voting_asset(RuntimeOrigin::root(), ASSET, true));
+			assert_ok!(ProposalBuilder::new().start(2).end(200).execute());
+			let proposal_id = Voting::next_proposal_id() - 1;
+			assert_ok!(Voting::set_proposal_asset(
+				RuntimeOrigin::signed(ALICE),
+				proposal_id,
+				Some(ASSET)
+			));
+			let escrow = Voting::escrow_account(proposal_id);
+
+			System::set_block_number(2);
+			assert_noop!(
+				Voting::vote(RuntimeOrigin::signed(ALICE), proposal_id, true, 6, Conviction::None),
+				Error::<Test>::InsufficientBalance
+			);
+			assert_ok!(Voting::vote(
+				RuntimeOrigin::signed(ALICE),
+				proposal_id,
+				true,
+				4,
+				Conviction::None
+			));
+			assert_eq!(Assets::balance(ASSET, ALICE), 14);
+			assert_eq!(Assets::balance(ASSET, escrow), 16);
+			assert_eq!(Voting::held_for_voting(&ALICE), 0);
+
+			// Lowering the vote returns the difference
+			assert_ok!(Voting::vote(
+				RuntimeOrigin::signed(ALICE),
+				proposal_id,
+				true,
+				2,
+				Conviction::None
+			));
+			assert_eq!(Assets::balance(ASSET, ALICE), 26);
+
+			System::set_block_number(200);
+			assert_ok!(Voting::close_proposal(RuntimeOrigin::signed(ALICE), proposal_id));
+			assert_ok!(Voting::claim(RuntimeOrigin::signed(ALICE), proposal_id));
+			System::assert_last_event(Event::BalanceClaimed { who: ALICE, amount: 4 }.into());
+			assert_eq!(Assets::balance(ASSET, ALICE), 30);
+			assert_eq!(Assets::balance(ASSET, escrow), 0);
+		})
+	}
+}
+
+mod enactment {
+	use super::*;
+	use crate::{BoundedCallOf, DisputeResolution};
+	use codec::Encode;
+	use frame_support::traits::{Bounded, QueryPreimage};
+	use sp_core::H256;
+	use sp_runtime::{
+		traits::{BlakeTwo256, Hash},
+		DispatchError,
+	};
+
+	const KEY: &[u8] = b"enacted";
+	fn set_storage(value: Vec<u8>) -> RuntimeCall {
+		RuntimeCall::System(frame_system::Call::set_storage { items: vec![(KEY.to_vec(), value)] })
+	}
+
+	fn inline(call: RuntimeCall) -> BoundedCallOf<Test> {
+		Bounded::Inline(call.encode().try_into().unwrap())
+	}
+
+	fn lookup(call: RuntimeCall) -> (BoundedCallOf<Test>, H256) {
+		let encoded = call.encode();
+		let hash = BlakeTwo256::hash(&encoded);
+		(Bounded::Lookup { hash, len: encoded.len() as u32 }, hash)
+	}
+
+	#[test]
+	fn approved_proposals_dispatch_their_call() {
+		ExtBuilder::new_build(vec![(ALICE, 10)]).execute_with(|| {
+			setup();
+			assert_ok!(ProposalBuilder::new().start(10).end(200).execute());
+			let proposal_id = Voting::next_proposal_id() - 1;
+			let call = inline(set_storage(vec![1]));
+			assert_ok!(Voting::set_proposal_call(
+				RuntimeOrigin::signed(ALICE),
+				proposal_id,
+				Some(call.clone())
+			));
+			System::assert_last_event(
+				Event::ProposalCallSet { proposal_id, call_hash: Some(call.hash().into()) }.into(),
+			);
+
+			System::set_block_number(10);
+			assert_ok!(Voting::vote(
+				RuntimeOrigin::signed(ALICE),
+				proposal_id,
+				true,
+				2,
+				Conviction::None
+			));
+			System::set_block_number(200);
+			assert_ok!(Voting::close_proposal(RuntimeOrigin::signed(ALICE), proposal_id));
+			// The call waits until the outcome can no longer be disputed or overridden
+			Voting::on_idle(200, Weight::MAX);
+			assert_eq!(sp_io::storage::get(KEY), None);
+
+			System::set_block_number(200 + DISPUTE_PERIOD.max(OVERRIDE_PERIOD));
+			Voting::on_idle(200 + DISPUTE_PERIOD.max(OVERRIDE_PERIOD), Weight::MAX);
+			System::assert_has_event(Event::ProposalEnacted { proposal_id, result: Ok(()) }.into());
+			assert_eq!(sp_io::storage::get(KEY).map(|value| value.to_vec()), Some(vec![1]));
+			assert!(Voting::proposal_calls(proposal_id).is_none());
+		})
+	}
+
+	#[test]
+	fn large_calls_are_enacted_from_a_preimage() {
+		ExtBuilder::new_build(vec![(ALICE, 10)]).execute_with(|| {
+			setup();
+			assert_ok!(ProposalBuilder::new().start(10).end(200).execute());
+			let proposal_id = Voting::next_proposal_id() - 1;
+			let value = vec![1; 150];
+			let (call, hash) = lookup(set_storage(value.clone()));
+			assert_noop!(
+				Voting::set_proposal_call(
+					RuntimeOrigin::signed(ALICE),
+					proposal_id,
+					Some(call.clone())
+				),
+				Error::<Test>::CallUnavailable
+			);
+			TestPreimages::note(set_storage(value.clone()).encode());
+			assert_ok!(Voting::set_proposal_call(
+				RuntimeOrigin::signed(ALICE),
+				proposal_id,
+				Some(call)
+			));
+			assert!(TestPreimages::is_requested(&hash));
+
+			System::set_block_number(10);
+			assert_ok!(Voting::vote(
+				RuntimeOrigin::signed(ALICE),
+				proposal_id,
+				true,
+				2,
+				Conviction::None
+			));
+			System::set_block_number(200);
+			assert_ok!(Voting::close_proposal(RuntimeOrigin::signed(ALICE), proposal_id));
+			System::set_block_number(200 + DISPUTE_PERIOD.max(OVERRIDE_PERIOD));
+			Voting::on_idle(200 + DISPUTE_PERIOD.max(OVERRIDE_PERIOD), Weight::MAX);
+			System::assert_has_event(Event::ProposalEnacted { proposal_id, result: Ok(()) }.into());
+			assert_eq!(sp_io::storage::get(KEY).map(|value| value.to_vec()), Some(value));
+			assert!(!TestPreimages::is_requested(&hash));
+		})
+	}
+
+	#[test]
+	fn rejected_proposals_drop_their_call() {
+		ExtBuilder::new_build(vec![(ALICE, 10), (BOB, 10)]).execute_with(|| {
+			setup();
+			assert_ok!(Voting::register_voter(RuntimeOrigin::root(), BOB));
+			assert_ok!(ProposalBuilder::new().start(10).end(200).execute());
+			let proposal_id = Voting::next_proposal_id() - 1;
+			let call = inline(set_storage(vec![1]));
+			assert_noop!(
+				Voting::set_proposal_call(
+					RuntimeOrigin::signed(BOB),
+					proposal_id,
+					Some(call.clone())
+				),
+				Error::<Test>::OriginNoPermission
+			);
+			let remark = RuntimeCall::System(frame_system::Call::remark {
+				remark: vec![0; MAX_CALL_SIZE as usize],
+			});
+			let (remark, _) = lookup(remark);
+			assert_noop!(
+				Voting::set_proposal_call(RuntimeOrigin::signed(ALICE), proposal_id, Some(remark)),
+				Error::<Test>::CallTooLarge
+			);
+			let (large_call, hash) = lookup(set_storage(vec![1; 150]));
+			TestPreimages::note(set_storage(vec![1; 150]).encode());
+			assert_ok!(Voting::set_proposal_call(
+				RuntimeOrigin::signed(ALICE),
+				proposal_id,
+				Some(large_call)
+			));
+			// The preimage of a replaced call is unrequested
+			assert_ok!(Voting::set_proposal_call(
+				RuntimeOrigin::signed(ALICE),
+				proposal_id,
+				Some(call)
+			));
+			assert!(!TestPreimages::is_requested(&hash));
+
+			System::set_block_number(10);
+			assert_noop!(
+				Voting::set_proposal_call(RuntimeOrigin::signed(ALICE), proposal_id, None),
+				Error::<Test>::ProposalHasAlreadyStarted
+			);
+			assert_ok!(Voting::vote(
+				RuntimeOrigin::signed(BOB),
+				proposal_id,
+				false,
+				2,
+				Conviction::None
+			));
+			System::set_block_number(200);
+			assert_ok!(Voting::close_proposal(RuntimeOrigin::signed(ALICE), proposal_id));
+			System::assert_last_event(
+				Event::VoteCompleted { proposal_id, ratio: (0, 4), approved: false }.into(),
+			);
+			System::set_block_number(200 + DISPUTE_PERIOD.max(OVERRIDE_PERIOD));
+			Voting::on_idle(200 + DISPUTE_PERIOD.max(OVERRIDE_PERIOD), Weight::MAX);
+			assert_eq!(sp_io::storage::get(KEY), None);
+			assert!(Voting::proposal_calls(proposal_id).is_none());
+		})
+	}
+
+	#[test]
+	fn overturned_proposals_never_dispatch_their_call() {
+		ExtBuilder::new_build(vec![(ALICE, 10), (BOB, 10)]).execute_with(|| {
+			setup();
+			assert_ok!(ProposalBuilder::new().start(10).end(200).execute());
+			let proposal_id = Voting::next_proposal_id() - 1;
+			assert_ok!(Voting::set_proposal_call(
+				RuntimeOrigin::signed(ALICE),
+				proposal_id,
+				Some(inline(set_storage(vec![1])))
+			));
+			System::set_block_number(10);
+			assert_ok!(Voting::vote(
+				RuntimeOrigin::signed(ALICE),
+				proposal_id,
+				true,
+				2,
+				Conviction::None
+			));
+			System::set_block_number(200);
+			assert_ok!(Voting::close_proposal(RuntimeOrigin::signed(ALICE), proposal_id));
+			assert_ok!(Voting::dispute(RuntimeOrigin::signed(BOB), proposal_id));
+
+			// The disputed call is withheld past the end of the periods
+			let enactment_at = 200 + DISPUTE_PERIOD.max(OVERRIDE_PERIOD);
+			System::set_block_number(enactment_at);
+			Voting::on_idle(enactment_at, Weight::MAX);
+			assert_eq!(sp_io::storage::get(KEY), None);
+			assert!(Voting::proposal_calls(proposal_id).is_some());
+
+			assert_ok!(Voting::resolve_dispute(
+				RuntimeOrigin::root(),
+				proposal_id,
+				DisputeResolution::Overturn
+			));
+			assert_eq!(sp_io::storage::get(KEY), None);
+			assert!(Voting::proposal_calls(proposal_id).is_none());
+			assert!(!System::events().iter().any(|record| matches!(
+				record.event,
+				RuntimeEvent::Voting(Event::ProposalEnacted { .. })
+			)));
+		})
+	}
+
+	#[test]
+	fn confirmed_disputes_enact_the_call() {
+		ExtBuilder::new_build(vec![(ALICE, 10), (BOB, 10)]).execute_with(|| {
+			setup();
+			assert_ok!(ProposalBuilder::new().start(10).end(200).execute());
+			let proposal_id = Voting::next_proposal_id() - 1;
+			assert_ok!(Voting::set_proposal_call(
+				RuntimeOrigin::signed(ALICE),
+				proposal_id,
+				Some(inline(set_storage(vec![1])))
+			));
+			System::set_block_number(10);
+			assert_ok!(Voting::vote(
+				RuntimeOrigin::signed(ALICE),
+				proposal_id,
+				true,
+				2,
+				Conviction::None
+			));
+			System::set_block_number(200);
+			assert_ok!(Voting::close_proposal(RuntimeOrigin::signed(ALICE), proposal_id));
+			assert_ok!(Voting::dispute(RuntimeOrigin::signed(BOB), proposal_id));
+			let enactment_at = 200 + DISPUTE_PERIOD.max(OVERRIDE_PERIOD);
+			System::set_block_number(enactment_at);
+			Voting::on_idle(enactment_at, Weight::MAX);
+
+			assert_ok!(Voting::resolve_dispute(
+				RuntimeOrigin::root(),
+				proposal_id,
+				DisputeResolution::Confirm
+			));
+			System::assert_has_event(Event::ProposalEnacted { proposal_id, result: Ok(()) }.into());
+			assert_eq!(sp_io::storage::get(KEY).map(|value| value.to_vec()), Some(vec![1]));
+		})
+	}
+
+	/// Closes an approved proposal enacting `call`, whose preimage is lost before its enactment
+	/// fails once the outcome is final.
+	fn fail_enactment(call: RuntimeCall) -> u32 {
+		assert_ok!(ProposalBuilder::new().start(10).end(200).execute());
+		let proposal_id = Voting::next_proposal_id() - 1;
+		TestPreimages::note(call.encode());
+		assert_ok!(Voting::set_proposal_call(
+			RuntimeOrigin::signed(ALICE),
+			proposal_id,
+			Some(lookup(call).0)
+		));
+		System::set_block_number(10);
+		assert_ok!(Voting::vote(
+			RuntimeOrigin::signed(ALICE),
+			proposal_id,
+			true,
+			2,
+			Conviction::None
+		));
+		System::set_block_number(200);
+		assert_ok!(Voting::close_proposal(RuntimeOrigin::signed(ALICE), proposal_id));
+		NotedPreimages::set(&vec![]);
+		let enactment_at = 200 + DISPUTE_PERIOD.max(OVERRIDE_PERIOD);
+		System::set_block_number(enactment_at);
+		Voting::on_idle(enactment_at, Weight::MAX);
+		proposal_id
+	}
+
+	#[test]
+	fn failed_enactments_are_retried_with_the_scheduler() {
+		ExtBuilder::new_build(vec![(ALICE, 10)]).execute_with(|| {
+			setup();
+			let value = vec![1; 150];
+			let (_, hash) = lookup(set_storage(value.clone()));
+			let proposal_id = fail_enactment(set_storage(value.clone()));
+			System::assert_last_event(
+				Event::EnactmentRetryScheduled {
+					proposal_id,
+					retry: 1,
+					error: DispatchError::Unavailable,
+				}
+				.into(),
+			);
+			assert_eq!(Voting::enactment_retries(proposal_id), 1);
+			assert!(TestPreimages::is_requested(&hash));
+
+			// The preimage is noted again before the retry
+			TestPreimages::note(set_storage(value.clone()).encode());
+			let retry_at = 200 + DISPUTE_PERIOD.max(OVERRIDE_PERIOD) + ENACTMENT_RETRY_PERIOD;
+			System::set_block_number(retry_at);
+			Scheduler::on_initialize(retry_at);
+			System::assert_has_event(Event::ProposalEnacted { proposal_id, result: Ok(()) }.into());
+			assert_eq!(sp_io::storage::get(KEY).map(|value| value.to_vec()), Some(value));
+			assert!(Voting::proposal_calls(proposal_id).is_none());
+			assert_eq!(Voting::enactment_retries(proposal_id), 0);
+			assert!(!TestPreimages::is_requested(&hash));
+		})
+	}
+
+	#[test]
+	fn enactments_failing_every_retry_are_archived_as_failed() {
+		ExtBuilder::new_build(vec![(ALICE, 10)]).execute_with(|| {
+			setup();
+			let proposal_id = fail_enactment(set_storage(vec![1; 150]));
+			assert_noop!(
+				Voting::retry_enactment(RuntimeOrigin::signed(ALICE), proposal_id),
+				DispatchError::BadOrigin
+			);
+			assert_noop!(
+				Voting::retry_enactment(RuntimeOrigin::root(), proposal_id + 1),
+				Error::<Test>::NoEnactmentRetry
+			);
+
+			let enactment_at = 200 + DISPUTE_PERIOD.max(OVERRIDE_PERIOD);
+			for retry in 1..=MAX_ENACTMENT_RETRIES {
+				let retry_at = enactment_at + retry * ENACTMENT_RETRY_PERIOD;
+				System::set_block_number(retry_at);
+				Scheduler::on_initialize(retry_at);
+			}
+			System::assert_has_event(
+				Event::ProposalEnacted { proposal_id, result: Err(DispatchError::Unavailable) }
+					.into(),
+			);
+			assert_eq!(
+				Voting::archived_proposals(proposal_id).unwrap().enactment_failure,
+				Some(DispatchError::Unavailable)
+			);
+			assert_eq!(sp_io::storage::get(KEY), None);
+			assert!(Voting::proposal_calls(proposal_id).is_none());
+			assert_eq!(Voting::enactment_retries(proposal_id), 0);
+		})
+	}
+}
+
+mod creator_roles {
+	use super::*;
+	use sp_runtime::DispatchError;
+
+	#[test]
+	fn restricted_kinds_require_the_role() {
+		ExtBuilder::new_build(vec![(ALICE, 10)]).execute_with(|| {
+			setup();
+			assert_noop!(
+				Voting::set_kind_role(RuntimeOrigin::signed(ALICE), ProposalKind::Private, Some(1)),
+				DispatchError::BadOrigin
+			);
+			assert_ok!(Voting::set_kind_role(
+				RuntimeOrigin::root(),
+				ProposalKind::Private,
+				Some(1)
+			));
+			System::assert_last_event(
+				Event::KindRoleSet { kind: ProposalKind::Private, role: Some(1) }.into(),
+			);
+			assert_noop!(
+				ProposalBuilder::new().private().execute(),
+				Error::<Test>::MissingCreatorRole
+			);
+			assert_ok!(ProposalBuilder::new().execute());
+
+			assert_ok!(Voting::set_creator_role(RuntimeOrigin::root(), ALICE, 1, true));
+			System::assert_last_event(Event::CreatorRoleGranted { who: ALICE, role: 1 }.into());
+			assert_ok!(ProposalBuilder::new().private().execute());
+
+			assert_ok!(Voting::set_creator_role(RuntimeOrigin::root(), ALICE, 1, false));
+			System::assert_last_event(Event::CreatorRoleRevoked { who: ALICE, role: 1 }.into());
+			assert_noop!(
+				ProposalBuilder::new().private().execute(),
+				Error::<Test>::MissingCreatorRole
+			);
+
+			assert_ok!(Voting::set_kind_role(RuntimeOrigin::root(), ProposalKind::Private, None));
+			assert_ok!(ProposalBuilder::new().private().execute());
+		})
+	}
+}
+
+mod nft_pass {
+	use super::*;
+
+	#[test]
+	fn gated_proposal_requires_a_pass() {
+		ExtBuilder::new_build(vec![(ALICE, 10), (BOB, 10)]).execute_with(|| {
+			setup();
+			assert_ok!(Voting::register_voter(RuntimeOrigin::root(), BOB));
+			NftHolders::set(&vec![(PASS_COLLECTION, BOB), (PASS_COLLECTION + 1, ALICE)]);
+
+			assert_ok!(ProposalBuilder::new().start(1).end(200).nft_gated().execute());
+			let proposal_id = Voting::next_proposal_id() - 1;
+			assert_noop!(
+				Voting::vote(RuntimeOrigin::signed(ALICE), proposal_id, true, 1, Conviction::None),
+				Error::<Test>::NftPassRequired
+			);
+			assert_ok!(Voting::vote(
+				RuntimeOrigin::signed(BOB),
+				proposal_id,
+				true,
+				1,
+				Conviction::None
+			));
+		})
+	}
+
+	#[test]
+	fn pass_holders_get_the_bonus_of_their_collection() {
+		ExtBuilder::new_build(vec![(ALICE, 10), (BOB, 10)]).execute_with(|| {
+			setup();
+			assert_ok!(Voting::register_voter(RuntimeOrigin::root(), BOB));
+			NftHolders::set(&vec![(PASS_COLLECTION, BOB)]);
+
+			assert_ok!(ProposalBuilder::new().start(1).end(200).nft_bonus().execute());
+			let proposal_id = Voting::next_proposal_id() - 1;
+			assert_ok!(Voting::vote(
+				RuntimeOrigin::signed(ALICE),
+				proposal_id,
+				true,
+				2,
+				Conviction::None
+			));
+			assert_ok!(Voting::vote(
+				RuntimeOrigin::signed(BOB),
+				proposal_id,
+				false,
+				2,
+				Conviction::None
+			));
+			assert_eq!(Voting::proposals(proposal_id).unwrap().ratio, (4, 10));
+
+			// The bonus of an adjusted vote follows the holdings of the voter
+			NftHolders::set(&vec![]);
+			assert_ok!(Voting::vote(
+				RuntimeOrigin::signed(BOB),
+				proposal_id,
+				false,
+				3,
+				Conviction::None
+			));
+			assert_eq!(Voting::proposals(proposal_id).unwrap().ratio, (4, 13));
+		})
+	}
+}
+
+mod org {
+	use super::*;
+	use crate::OrgParameters;
+
+	#[test]
+	fn only_members_vote_on_org_proposals() {
+		ExtBuilder::new_build(vec![(ALICE, 10), (BOB, 10)]).execute_with(|| {
+			setup();
+			assert_ok!(Voting::register_voter(RuntimeOrigin::root(), BOB));
+			assert_ok!(Voting::create_org(RuntimeOrigin::root(), ALICE));
+			let org = Voting::next_org_id() - 1;
+			System::assert_last_event(Event::OrgCreated { org, admin: ALICE }.into());
+			assert_noop!(
+				Voting::add_org_member(RuntimeOrigin::signed(BOB), org, BOB),
+				Error::<Test>::OriginNoPermission
+			);
+			assert_ok!(Voting::add_org_member(RuntimeOrigin::signed(ALICE), org, ALICE));
+			assert_noop!(
+				ProposalBuilder { origin: RawOrigin::Signed(BOB).into(), ..ProposalBuilder::new() }
+					.execute_in(org),
+				Error::<Test>::NotOrgMember
+			);
+
+			assert_ok!(ProposalBuilder::new().start(1).end(200).execute_in(org));
+			let proposal_id = Voting::next_proposal_id() - 1;
+			System::assert_has_event(
+				Event::OrgProposalCreated { org, index: 0, proposal_id }.into(),
+			);
+			assert_eq!(Voting::org_proposals(org, 0), Some(proposal_id));
+			// The electorate is the organisation, not the registry
+			assert_eq!(Voting::proposals(proposal_id).unwrap().electorate, Some(1));
+
+			assert_noop!(
+				Voting::vote(RuntimeOrigin::signed(BOB), proposal_id, true, 1, Conviction::None),
+				Error::<Test>::NotOrgMember
+			);
+			assert_ok!(Voting::vote(
+				RuntimeOrigin::signed(ALICE),
+				proposal_id,
+				true,
+				1,
+				Conviction::None
+			));
+		})
+	}
+
+	#[test]
+	fn org_parameters_bound_durations() {
+		ExtBuilder::new_build(vec![(ALICE, 10)]).execute_with(|| {
+			setup();
+			assert_ok!(Voting::create_org(RuntimeOrigin::root(), ALICE));
+			let org = Voting::next_org_id() - 1;
+			assert_ok!(Voting::add_org_member(RuntimeOrigin::signed(ALICE), org, ALICE));
+
+			let parameters = OrgParameters {
+				minimum_duration: Some(300),
+				maximum_duration: Some(PROPOSAL_MAXIMUM_DURATION + 1),
+			};
+			assert_noop!(
+				Voting::set_org_parameters(RuntimeOrigin::signed(ALICE), org, parameters),
+				Error::<Test>::InvalidOrgParameters
+			);
+			let parameters = OrgParameters { minimum_duration: Some(300), maximum_duration: None };
+			assert_ok!(Voting::set_org_parameters(
+				RuntimeOrigin::signed(ALICE),
+				org,
+				parameters.clone()
+			));
+			System::assert_last_event(Event::OrgParametersSet { org, parameters }.into());
+
+			assert_noop!(
+				ProposalBuilder::new().start(1).end(200).execute_in(org),
+				Error::<Test>::ProposalDurationIsTooShort
+			);
+			assert_ok!(ProposalBuilder::new().start(1).end(301).execute_in(org));
+			// Proposals outside the organisation keep the pallet bounds
+			assert_ok!(ProposalBuilder::new().start(1).end(200).execute());
+		})
+	}
+}
+
+mod voting_interface {
+	use super::*;
+	use pba_interface::VotingInterface;
+
+	#[test]
+	fn drives_a_proposal_to_its_outcome() {
+		ExtBuilder::new_build(vec![(ALICE, 10)]).execute_with(|| {
+			assert_ok!(<Voting as VotingInterface>::add_voter(BOB, 100));
+			assert_ok!(<Voting as VotingInterface>::add_voter(CHARLIE, 50));
+			assert!(Voting::is_registered(&BOB));
+			assert_eq!(Balances::free_balance(BOB), 100);
+
+			assert_noop!(
+				<Voting as VotingInterface>::create_proposal(vec![0; 151]),
+				Error::<Test>::OffchainDataTooLong
+			);
+			let proposal_id =
+				<Voting as VotingInterface>::create_proposal(b"metadata".to_vec()).unwrap();
+			assert_eq!(Voting::proposals(proposal_id).unwrap().offchain_data.to_vec(), b"metadata");
+
+			// 64 tokens required
+			assert_noop!(
+				<Voting as VotingInterface>::vote(proposal_id, CHARLIE, false, 8),
+				Error::<Test>::InsufficientBalance
+			);
+			assert_ok!(<Voting as VotingInterface>::vote(proposal_id, BOB, true, 5));
+			assert_ok!(<Voting as VotingInterface>::vote(proposal_id, CHARLIE, false, 4));
+
+			// Closed before its end block
+			assert_eq!(<Voting as VotingInterface>::close_vote(proposal_id), Ok(true));
+			assert_noop!(
+				<Voting as VotingInterface>::close_vote(proposal_id),
+				Error::<Test>::ProposalDoesNotExist
+			);
+		})
+	}
+
+	#[test]
+	fn created_proposals_are_already_seconded() {
+		ExtBuilder::new_build(vec![(ALICE, 10)]).execute_with(|| {
+			SecondsThreshold::set(&3);
+			assert_ok!(<Voting as VotingInterface>::add_voter(BOB, 100));
+			let proposal_id =
+				<Voting as VotingInterface>::create_proposal(b"metadata".to_vec()).unwrap();
+			assert_eq!(Voting::proposals(proposal_id).unwrap().seconds, 3);
+
+			assert_ok!(<Voting as VotingInterface>::vote(proposal_id, BOB, true, 5));
+			assert_eq!(<Voting as VotingInterface>::close_vote(proposal_id), Ok(true));
+		})
+	}
+}
+
+mod queries {
+	use super::*;
+	use crate::{
+		verify_vote, CancellationReason, CreateError, DisputeResolution, HoldHealth, HoldReason,
+		LockReport, ProposalOutcome, ProposalStatus, VoteInfo, VoteShortfall,
+	};
+	use frame_support::traits::{
+		fungible::MutateHold, tokens::Precision, LockableCurrency, WithdrawReasons,
+	};
+
+	#[test]
+	fn query_tally_outcome_and_collateral() {
+		ExtBuilder::new_build(vec![(ALICE, 10)]).execute_with(|| {
+			setup();
+			assert!(Voting::is_registered(&ALICE));
+			assert!(!Voting::is_registered(&BOB));
+
+			assert_ok!(ProposalBuilder::new().start(1).end(200).execute());
+			let proposal_id = Voting::next_proposal_id() - 1;
+			assert_ok!(Voting::vote(
+				RuntimeOrigin::signed(ALICE),
+				proposal_id,
+				true,
+				2,
+				Conviction::None
+			));
+			assert_eq!(Voting::tally(proposal_id), Some((4, 4)));
+			assert_eq!(Voting::outcome(proposal_id), None);
+			assert_eq!(Voting::held_for_voting(&ALICE), 4);
+
+			System::set_block_number(200);
+			assert_ok!(Voting::close_proposal(RuntimeOrigin::signed(ALICE), proposal_id));
+			assert_eq!(Voting::tally(proposal_id), Some((4, 4)));
+			assert_eq!(
+				Voting::outcome(proposal_id),
+				Some(ProposalOutcome::Completed { ratio: (4, 4), approved: true })
+			);
+			assert_eq!(Voting::tally(proposal_id + 1), None);
+		})
+	}
+
+	#[test]
+	fn check_vote_reports_the_shortfall() {
+		ExtBuilder::new_build(vec![(ALICE, 10)]).execute_with(|| {
+			setup();
+			assert_ok!(ProposalBuilder::new().start(1).end(200).execute());
+			let proposal_id = Voting::next_proposal_id() - 1;
+			assert_eq!(Voting::check_vote(&ALICE, proposal_id, 3), None);
+			// 16 tokens required, the existential deposit is kept
+			let shortfall = VoteShortfall { required: 16, available: 9 };
+			assert_eq!(Voting::check_vote(&ALICE, proposal_id, 4), Some(shortfall));
+
+			// Only the cost over the current vote is required
+			assert_ok!(Voting::vote(
+				RuntimeOrigin::signed(ALICE),
+				proposal_id,
+				true,
+				2,
+				Conviction::None
+			));
+			let shortfall = VoteShortfall { required: 12, available: 5 };
+			assert_eq!(Voting::check_vote(&ALICE, proposal_id, 4), Some(shortfall));
+
+			assert_ok!(ProposalBuilder::new().start(1).end(200).shared_budget().execute());
+			let shared_id = Voting::next_proposal_id() - 1;
+			assert_eq!(Voting::check_vote(&ALICE, shared_id, 4), None);
+			let shortfall = VoteShortfall { required: 25, available: VOICE_CREDITS };
+			assert_eq!(Voting::check_vote(&ALICE, shared_id, 5), Some(shortfall));
+		})
+	}
+
+	#[test]
+	fn hold_health_reports_the_drift() {
+		ExtBuilder::new_build(vec![(ALICE, 100)]).execute_with(|| {
+			setup();
+			assert_ok!(ProposalBuilder::new().start(1).end(200).execute());
+			let proposal_id = Voting::next_proposal_id() - 1;
+			assert_ok!(ProposalBuilder::new()
+				.start(1)
+				.end(200)
+				.vote_cost(VoteCost::Burned)
+				.execute());
+			let burned_id = Voting::next_proposal_id() - 1;
+			assert_ok!(Voting::vote(
+				RuntimeOrigin::signed(ALICE),
+				proposal_id,
+				true,
+				3,
+				Conviction::None
+			));
+			assert_ok!(Voting::vote(
+				RuntimeOrigin::signed(ALICE),
+				burned_id,
+				true,
+				2,
+				Conviction::None
+			));
+			let health = HoldHealth { expected_from_votes: 9, actual_held: 9, drift: 0 };
+			assert_eq!(Voting::hold_health(&ALICE), health);
+
+			// A hold released behind the back of the pallet shows as a drift
+			let reason = RuntimeHoldReason::from(HoldReason::VoteCollateral);
+			assert_ok!(Balances::release(&reason, &ALICE, 4, Precision::Exact));
+			let health = HoldHealth { expected_from_votes: 9, actual_held: 5, drift: 4 };
+			assert_eq!(Voting::hold_health(&ALICE), health);
+
+			// The votes of a closed proposal count until their collateral is released
+			System::set_block_number(200);
+			assert_ok!(Voting::close_proposal(RuntimeOrigin::signed(ALICE), proposal_id));
+			assert_eq!(Voting::hold_health(&ALICE), health);
+			Voting::on_idle(200, Weight::MAX);
+			let health = HoldHealth { expected_from_votes: 0, actual_held: 0, drift: 0 };
+			assert_eq!(Voting::hold_health(&ALICE), health);
+		})
+	}
+
+	#[test]
+	fn lock_report_shows_the_overlap_with_other_locks() {
+		ExtBuilder::new_build(vec![(ALICE, 100)]).execute_with(|| {
+			setup();
+			assert_ok!(ProposalBuilder::new().start(1).end(200).execute());
+			let proposal_id = Voting::next_proposal_id() - 1;
+			assert_ok!(Voting::vote(
+				RuntimeOrigin::signed(ALICE),
+				proposal_id,
+				true,
+				3,
+				Conviction::None
+			));
+			let report = LockReport {
+				total: 100,
+				held_for_voting: 9,
+				held_elsewhere: 0,
+				restricted: 1,
+				spendable: 90,
+				max_additional_power: 9,
+			};
+			assert_eq!(Voting::lock_report(&ALICE), report);
+
+			// The collateral of the votes counts towards a staking lock of 50
+			Balances::set_lock(*b"staking ", &ALICE, 50, WithdrawReasons::all());
+			let report =
+				LockReport { restricted: 41, spendable: 50, max_additional_power: 7, ..report };
+			assert_eq!(Voting::lock_report(&ALICE), report);
+		})
+	}
+
+	#[test]
+	fn vote_proofs_verify_against_the_votes_root() {
+		ExtBuilder::new_build(vec![(ALICE, 100), (BOB, 100), (CHARLIE, 100)]).execute_with(|| {
+			setup();
+			assert_ok!(Voting::register_voter(RuntimeOrigin::root(), BOB));
+			assert_ok!(Voting::register_voter(RuntimeOrigin::root(), CHARLIE));
+			assert_ok!(ProposalBuilder::new().start(1).end(200).execute());
+			let proposal_id = Voting::next_proposal_id() - 1;
+			assert_ok!(Voting::vote(
+				RuntimeOrigin::signed(ALICE),
+				proposal_id,
+				true,
+				2,
+				Conviction::None
+			));
+			assert_ok!(Voting::vote(
+				RuntimeOrigin::signed(BOB),
+				proposal_id,
+				false,
+				1,
+				Conviction::None
+			));
+			assert_ok!(Voting::vote(
+				RuntimeOrigin::signed(CHARLIE),
+				proposal_id,
+				true,
+				1,
+				Conviction::None
+			));
+			// A changed vote appends a leaf, a dropped one too with a zero power
+			assert_ok!(Voting::vote(
+				RuntimeOrigin::signed(ALICE),
+				proposal_id,
+				false,
+				3,
+				Conviction::None
+			));
+			assert_ok!(Voting::vote(
+				RuntimeOrigin::signed(CHARLIE),
+				proposal_id,
+				true,
+				0,
+				Conviction::None
+			));
+			assert_eq!(Voting::votes_tree(proposal_id).leaves, 5);
+
+			System::set_block_number(200);
+			assert_ok!(Voting::close_proposal(RuntimeOrigin::signed(ALICE), proposal_id));
+			Voting::on_idle(200, Weight::MAX);
+			let root = Voting::votes_tree(proposal_id).root;
+
+			let proof = Voting::vote_proof(proposal_id, &ALICE).unwrap();
+			let vote = VoteInfo { proposal_id, aye: false, power: 3, conviction: Conviction::None };
+			assert_eq!((proof.vote.clone(), proof.leaf_index, proof.root), (vote.clone(), 3, root));
+			assert!(verify_vote(&root, &ALICE, &vote, &proof.proof));
+			assert!(!verify_vote(&root, &ALICE, &VoteInfo { power: 2, ..vote }, &proof.proof));
+			let proof = Voting::vote_proof(proposal_id, &BOB).unwrap();
+			assert!(verify_vote(&root, &BOB, &proof.vote, &proof.proof));
+			assert_eq!(Voting::vote_proof(proposal_id, &CHARLIE), None);
+			assert_eq!(Voting::vote_proof(proposal_id, &DAVE), None);
+		})
+	}
+
+	#[test]
+	fn validate_proposal_dry_runs_create_proposal() {
+		new_test_ext().execute_with(|| {
+			System::set_block_number(1);
+			setup();
+			let validate = |creator, kind, account_list_len, start, end| {
+				Voting::validate_proposal(&creator, kind, account_list_len, start, end)
+			};
+			assert_ok!(validate(ALICE, ProposalKind::Public, 0, 10, 120));
+			assert_eq!(
+				validate(BOB, ProposalKind::Public, 0, 10, 120),
+				Err(CreateError::VoterNotRegistered)
+			);
+			assert_eq!(
+				validate(ALICE, ProposalKind::Private, PROPOSAL_ACCOUNT_SIZE_LIMIT + 1, 10, 120),
+				Err(CreateError::AccountListFull)
+			);
+			assert_eq!(
+				validate(ALICE, ProposalKind::Public, 0, 0, 120),
+				Err(CreateError::ProposalCannotStartInThePast)
+			);
+			assert_eq!(
+				validate(ALICE, ProposalKind::Public, 0, 200, 400),
+				Err(CreateError::ProposalStartIsTooFarAway)
+			);
+			assert_eq!(
+				validate(ALICE, ProposalKind::Emergency, 0, 10, 120),
+				Err(CreateError::EmergencyProposalNotAllowed)
+			);
+
+			// The extrinsic fails with the error of the same name
+			assert_eq!(
+				validate(ALICE, ProposalKind::Public, 0, 10, 50),
+				Err(CreateError::ProposalDurationIsTooShort)
+			);
+			assert_noop!(
+				ProposalBuilder::new().start(10).end(50).execute(),
+				Error::<Test>::ProposalDurationIsTooShort
+			);
+		})
+	}
+
+	#[test]
+	fn proposal_status_follows_the_lifecycle() {
+		ExtBuilder::new_build(vec![(ALICE, 10), (BOB, 10)]).execute_with(|| {
+			setup();
+			assert_ok!(ProposalBuilder::new().start(5).end(200).execute());
+			let proposal_id = Voting::next_proposal_id() - 1;
+			assert_ok!(ProposalBuilder::new().start(10).end(200).execute());
+			let cancelled_id = Voting::next_proposal_id() - 1;
+			assert_eq!(Voting::proposal_status(proposal_id), Some(ProposalStatus::Pending));
+
+			let reason = CancellationReason::Other;
+			assert_ok!(Voting::cancel_proposal(RuntimeOrigin::signed(ALICE), cancelled_id, reason));
+			assert_eq!(
+				Voting::proposal_status(cancelled_id),
+				Some(ProposalStatus::Cancelled { reason })
+			);
+
+			System::set_block_number(5);
+			Voting::on_initialize(5);
+			assert_eq!(Voting::proposal_status(proposal_id), Some(ProposalStatus::Active));
+
+			assert_ok!(Voting::vote(
+				RuntimeOrigin::signed(ALICE),
+				proposal_id,
+				true,
+				2,
+				Conviction::None
+			));
+			System::set_block_number(200);
+			assert_ok!(Voting::close_proposal(RuntimeOrigin::signed(ALICE), proposal_id));
+			let outcome = ProposalOutcome::Completed { ratio: (4, 4), approved: true };
+			assert_eq!(
+				Voting::proposal_status(proposal_id),
+				Some(ProposalStatus::Closed { outcome })
+			);
+
+			assert_ok!(Voting::dispute(RuntimeOrigin::signed(BOB), proposal_id));
+			assert_eq!(Voting::proposal_status(proposal_id), Some(ProposalStatus::Suspended));
+			assert_eq!(Voting::outcome(proposal_id), None);
+			assert_ok!(Voting::resolve_dispute(
+				RuntimeOrigin::root(),
+				proposal_id,
+				DisputeResolution::Confirm
+			));
+			assert_eq!(
+				Voting::proposal_status(proposal_id),
+				Some(ProposalStatus::Closed { outcome })
+			);
+		})
+	}
+}
+
+mod delegation {
+	use super::*;
+
+	fn setup_chain() -> u32 {
+		for voter in [ALICE, BOB, CHARLIE, DAVE] {
+			assert_ok!(Voting::register_voter(RuntimeOrigin::root(), voter));
+		}
+		// ALICE -> BOB -> CHARLIE
+		assert_ok!(Voting::delegate(RuntimeOrigin::signed(ALICE), BOB));
+		assert_ok!(Voting::delegate(RuntimeOrigin::signed(BOB), CHARLIE));
+		assert_ok!(ProposalBuilder::new().start(1).end(200).execute());
+		Voting::next_proposal_id() - 1
+	}
+
+	#[test]
+	fn delegation_cannot_loop() {
+		ExtBuilder::new_build(vec![(ALICE, 200)]).execute_with(|| {
+			setup_chain();
+			assert_noop!(
+				Voting::delegate(RuntimeOrigin::signed(CHARLIE), ALICE),
+				Error::<Test>::DelegationCycle
+			);
+			assert_noop!(
+				Voting::delegate(RuntimeOrigin::signed(DAVE), DAVE),
+				Error::<Test>::DelegationCycle
+			);
+			assert_ok!(Voting::undelegate(RuntimeOrigin::signed(BOB)));
+			assert_noop!(
+				Voting::undelegate(RuntimeOrigin::signed(BOB)),
+				Error::<Test>::NotDelegating
+			);
+			assert_ok!(Voting::delegate(RuntimeOrigin::signed(CHARLIE), ALICE));
+		})
+	}
+
+	#[test]
+	fn unregistered_delegate_loses_its_delegations() {
+		ExtBuilder::new_build(vec![(ALICE, 200), (BOB, 200)]).execute_with(|| {
+			let proposal_id = setup_chain();
+			assert_ok!(Voting::vote_as_delegate(
+				RuntimeOrigin::signed(CHARLIE),
+				ALICE,
+				proposal_id,
+				true,
+				10
+			));
+			assert_ok!(Voting::vote_as_delegate(
+				RuntimeOrigin::signed(CHARLIE),
+				BOB,
+				proposal_id,
+				false,
+				10
+			));
+			assert_eq!(Voting::proposals(proposal_id).unwrap().ratio, (81, 171));
+
+			assert_ok!(Voting::unregister_voter(RuntimeOrigin::signed(CHARLIE), CHARLIE));
+			Voting::on_idle(1, Weight::MAX);
+
+			assert_eq!(Voting::proposals(proposal_id).unwrap().ratio, (0, 0));
+			assert_eq!(Voting::held_for_voting(&ALICE), 0);
+			assert_eq!(Voting::held_for_voting(&BOB), 0);
+			System::assert_has_event(Event::VoteDropped { proposal_id, voter: ALICE }.into());
+			System::assert_has_event(Event::VoteDropped { proposal_id, voter: BOB }.into());
+			// Only the delegation received by the unregistered delegate is revoked
+			assert_eq!(Voting::delegations(BOB), None);
+			assert_eq!(Voting::delegations(ALICE), Some(BOB));
+			System::assert_last_event(
+				Event::DelegationRevoked { who: BOB, delegate: CHARLIE }.into(),
+			);
+		})
+	}
+
+	#[test]
+	fn delegates_vote_along_the_chain() {
+		ExtBuilder::new_build(vec![(ALICE, 200)]).execute_with(|| {
+			let proposal_id = setup_chain();
+
+			// Two hops: 100 attenuated twice by 10%
+			assert_ok!(Voting::vote_as_delegate(
+				RuntimeOrigin::signed(CHARLIE),
+				ALICE,
+				proposal_id,
+				true,
+				10
+			));
+			System::assert_last_event(
+				Event::DelegatedVoteCast {
+					proposal_id,
+					delegator: ALICE,
+					delegate: CHARLIE,
+					hops: 2,
+				}
+				.into(),
+			);
+			assert_eq!(Voting::proposals(proposal_id).unwrap().ratio, (81, 81));
+			assert_eq!(Voting::held_for_voting(&ALICE), 100);
+			assert_noop!(
+				Voting::vote_as_delegate(RuntimeOrigin::signed(DAVE), ALICE, proposal_id, true, 1),
+				Error::<Test>::NotDelegate
+			);
+
+			// A closer delegate replaces the vote
+			assert_ok!(Voting::vote_as_delegate(
+				RuntimeOrigin::signed(BOB),
+				ALICE,
+				proposal_id,
+				false,
+				10
+			));
+			let proposal = Voting::proposals(proposal_id).unwrap();
+			assert_eq!((proposal.ratio, proposal.voters_count), ((0, 90), 1));
+
+			// The delegator voting directly overrides its delegates
+			assert_ok!(Voting::vote(
+				RuntimeOrigin::signed(ALICE),
+				proposal_id,
+				true,
+				1,
+				Conviction::None
+			));
+			let proposal = Voting::proposals(proposal_id).unwrap();
+			assert_eq!((proposal.ratio, proposal.voters_count), ((1, 1), 1));
+			assert_eq!(Voting::held_for_voting(&ALICE), 1);
+			assert_noop!(
+				Voting::vote_as_delegate(RuntimeOrigin::signed(BOB), ALICE, proposal_id, true, 2),
+				Error::<Test>::DirectVoteTakesPrecedence
+			);
+		})
+	}
+}
+
+mod migrate_voter {
+	use super::*;
+	use crate::VoteInfo;
+
+	#[test]
+	fn votes_delegations_and_collateral_follow_the_voter() {
+		ExtBuilder::new_build(vec![(ALICE, 20), (BOB, 10), (DAVE, 1)]).execute_with(|| {
+			setup();
+			assert_ok!(Voting::register_voter(RuntimeOrigin::root(), BOB));
+			assert_ok!(Voting::delegate(RuntimeOrigin::signed(BOB), ALICE));
+			for _ in 0..3 {
+				assert_ok!(ProposalBuilder::new().start(1).end(200).execute());
+				let proposal_id = Voting::next_proposal_id() - 1;
+				assert_ok!(Voting::vote(
+					RuntimeOrigin::signed(ALICE),
+					proposal_id,
+					true,
+					2,
+					Conviction::None
+				));
+			}
+			let proposal_id = Voting::next_proposal_id() - 1;
+
+			assert_noop!(
+				Voting::migrate_voter(RuntimeOrigin::signed(BOB), ALICE, DAVE),
+				Error::<Test>::OriginNoPermission
+			);
+			assert_noop!(
+				Voting::migrate_voter(RuntimeOrigin::signed(ALICE), ALICE, BOB),
+				Error::<Test>::AccountInUse
+			);
+			// 3 votes and a delegator
+			assert_noop!(
+				Voting::migrate_voter(RuntimeOrigin::signed(ALICE), ALICE, DAVE),
+				Error::<Test>::TooManyEntriesToMigrate
+			);
+			assert_ok!(Voting::undelegate(RuntimeOrigin::signed(BOB)));
+			assert_ok!(Voting::delegate(RuntimeOrigin::signed(ALICE), BOB));
+
+			assert_ok!(Voting::migrate_voter(RuntimeOrigin::signed(ALICE), ALICE, DAVE));
+			System::assert_last_event(
+				Event::VoterMigrated { old: ALICE, new: DAVE, entries: 3 }.into(),
+			);
+			assert!(!Voting::is_registered(&ALICE));
+			assert!(Voting::is_registered(&DAVE));
+			assert_eq!(Voting::votes(ALICE, proposal_id), None);
+			assert_eq!(
+				Voting::votes(DAVE, proposal_id),
+				Some(VoteInfo { proposal_id, aye: true, power: 2, conviction: Conviction::None })
+			);
+			assert_eq!(Voting::delegations(DAVE), Some(BOB));
+			assert_eq!(Voting::delegations(ALICE), None);
+			assert_eq!(Voting::held_for_voting(&ALICE), 0);
+			assert_eq!(Voting::held_for_voting(&DAVE), 12);
+			assert_eq!(Balances::free_balance(DAVE), 1);
+			assert_eq!((System::consumers(&ALICE), System::consumers(&DAVE)), (0, 1));
+
+			System::set_block_number(200);
+			assert_ok!(Voting::close_proposal(RuntimeOrigin::signed(BOB), proposal_id));
+			assert_ok!(Voting::claim(RuntimeOrigin::signed(DAVE), proposal_id));
+			assert_eq!(Voting::held_for_voting(&DAVE), 8);
+		})
+	}
+}
+
+mod recount {
+	use super::*;
+
+	#[test]
+	fn recount_replaces_a_wrong_tally() {
+		ExtBuilder::new_build(vec![(ALICE, 10), (BOB, 10)]).execute_with(|| {
+			setup();
+			assert_ok!(Voting::register_voter(RuntimeOrigin::root(), BOB));
+			assert_ok!(ProposalBuilder::new().start(1).end(200).execute());
+			let proposal_id = Voting::next_proposal_id() - 1;
+			assert_ok!(Voting::vote(
+				RuntimeOrigin::signed(ALICE),
+				proposal_id,
+				true,
+				2,
+				Conviction::None
+			));
+			assert_ok!(Voting::vote(
+				RuntimeOrigin::signed(BOB),
+				proposal_id,
+				false,
+				1,
+				Conviction::None
+			));
+			pallet_voting::Proposals::<Test>::mutate(proposal_id, |proposal| {
+				proposal.as_mut().unwrap().ratio = (1, 1);
+			});
+
+			// One vote per call, the recount resumes where it stopped
+			assert_ok!(Voting::recount(RuntimeOrigin::signed(CHARLIE), proposal_id, 1));
+			assert!(Voting::recounts(proposal_id).is_some());
+			assert_eq!(Voting::proposals(proposal_id).unwrap().ratio, (1, 1));
+
+			assert_ok!(Voting::recount(RuntimeOrigin::signed(CHARLIE), proposal_id, 1));
+			System::assert_last_event(
+				Event::TallyRecounted { proposal_id, ayes_diff: 3, total_diff: 4 }.into(),
+			);
+			assert_eq!(Voting::proposals(proposal_id).unwrap().ratio, (4, 5));
+			assert_eq!(Voting::recounts(proposal_id), None);
+		})
+	}
+
+	#[test]
+	fn recount_starts_over_when_the_tally_moves() {
+		ExtBuilder::new_build(vec![(ALICE, 10), (BOB, 10)]).execute_with(|| {
+			setup();
+			assert_ok!(Voting::register_voter(RuntimeOrigin::root(), BOB));
+			assert_ok!(ProposalBuilder::new().start(1).end(200).execute());
+			let proposal_id = Voting::next_proposal_id() - 1;
+			assert_ok!(Voting::vote(
+				RuntimeOrigin::signed(ALICE),
+				proposal_id,
+				true,
+				2,
+				Conviction::None
+			));
+			assert_ok!(Voting::vote(
+				RuntimeOrigin::signed(BOB),
+				proposal_id,
+				false,
+				1,
+				Conviction::None
+			));
+			assert_ok!(Voting::recount(RuntimeOrigin::signed(CHARLIE), proposal_id, 1));
+
+			assert_ok!(Voting::vote(
+				RuntimeOrigin::signed(BOB),
+				proposal_id,
+				true,
+				1,
+				Conviction::None
+			));
+			assert_ok!(Voting::recount(RuntimeOrigin::signed(CHARLIE), proposal_id, 1));
+			assert_eq!(Voting::recounts(proposal_id).unwrap().started_from, ((5, 5), 2));
+
+			assert_ok!(Voting::recount(RuntimeOrigin::signed(CHARLIE), proposal_id, 2));
+			System::assert_last_event(
+				Event::TallyRecounted { proposal_id, ayes_diff: 0, total_diff: 0 }.into(),
+			);
+		})
+	}
+}
+
+mod audit {
+	use super::*;
+	use crate::AuditIssue;
+	use sp_runtime::DispatchError;
+
+	#[test]
+	fn audit_reports_each_discrepancy() {
+		ExtBuilder::new_build(vec![(ALICE, 10), (BOB, 10)]).execute_with(|| {
+			setup();
+			assert_ok!(Voting::register_voter(RuntimeOrigin::root(), BOB));
+			assert_ok!(ProposalBuilder::new().start(1).end(200).execute());
+			let proposal_id = Voting::next_proposal_id() - 1;
+			assert_ok!(Voting::vote(
+				RuntimeOrigin::signed(ALICE),
+				proposal_id,
+				true,
+				2,
+				Conviction::None
+			));
+			assert_ok!(Voting::vote(
+				RuntimeOrigin::signed(BOB),
+				proposal_id,
+				false,
+				1,
+				Conviction::None
+			));
+			pallet_voting::Votes::<Test>::remove(ALICE, proposal_id);
+			pallet_voting::Votes::<Test>::mutate(BOB, proposal_id, |vote| {
+				vote.as_mut().unwrap().power = 3;
+			});
+
+			assert_noop!(
+				Voting::audit_proposal(RuntimeOrigin::signed(ALICE), proposal_id, 2),
+				DispatchError::BadOrigin
+			);
+			// One vote per call, the audit resumes where it stopped
+			assert_ok!(Voting::audit_proposal(RuntimeOrigin::root(), proposal_id, 1));
+			assert!(Voting::audit_cursors(proposal_id).is_some());
+			assert_ok!(Voting::audit_proposal(RuntimeOrigin::root(), proposal_id, 1));
+			assert_eq!(Voting::audit_cursors(proposal_id), None);
+
+			System::assert_has_event(
+				Event::AuditFinding { proposal_id, voter: ALICE, issue: AuditIssue::VoteMissing }
+					.into(),
+			);
+			System::assert_has_event(
+				Event::AuditFinding {
+					proposal_id,
+					voter: BOB,
+					issue: AuditIssue::FreezeShortfall { frozen: 1, cost: 9 },
+				}
+				.into(),
+			);
+			System::assert_last_event(Event::ProposalAudited { proposal_id }.into());
+		})
+	}
+
+	#[test]
+	fn closing_audits_a_random_sample() {
+		ExtBuilder::new_build(vec![(ALICE, 10)]).execute_with(|| {
+			setup();
+			AuditSampleSize::set(&3);
+			assert_ok!(ProposalBuilder::new().start(1).end(200).execute());
+			let proposal_id = Voting::next_proposal_id() - 1;
+			assert_ok!(Voting::vote(
+				RuntimeOrigin::signed(ALICE),
+				proposal_id,
+				true,
+				2,
+				Conviction::None
+			));
+			pallet_voting::Votes::<Test>::mutate(ALICE, proposal_id, |vote| {
+				vote.as_mut().unwrap().power = 4;
+			});
+
+			// The only voter is drawn and audited
+			System::set_block_number(200);
+			assert_ok!(Voting::close_proposal(RuntimeOrigin::signed(ALICE), proposal_id));
+			System::assert_has_event(
+				Event::AuditFinding {
+					proposal_id,
+					voter: ALICE,
+					issue: AuditIssue::FreezeShortfall { frozen: 4, cost: 16 },
+				}
+				.into(),
+			);
+			System::assert_has_event(
+				Event::SampleAudited { proposal_id, sampled: 1, findings: 1 }.into(),
+			);
+		})
+	}
+}
+
+mod lottery {
+	use super::*;
+
+	#[test]
+	fn lottery_must_be_valid() {
+		ExtBuilder::new_build(vec![(ALICE, 10)]).execute_with(|| {
+			setup();
+			for winners in [0, MAX_LOTTERY_WINNERS + 1] {
+				assert_noop!(
+					ProposalBuilder::new().start(1).end(200).lottery_winners(winners).execute(),
+					Error::<Test>::InvalidLottery
+				);
+			}
+			assert_noop!(
+				ProposalBuilder::new()
+					.start(1)
+					.end(200)
+					.lottery_winners(1)
+					.questions(2)
+					.execute(),
+				Error::<Test>::InvalidLottery
+			);
+
+			assert_ok!(ProposalBuilder::new().start(1).end(200).execute());
+			let proposal_id = Voting::next_proposal_id() - 1;
+			assert_noop!(
+				Voting::fund_lottery(RuntimeOrigin::signed(ALICE), proposal_id, 5),
+				Error::<Test>::LotteryNotEnabled
+			);
+		})
+	}
+
+	#[test]
+	fn winners_claim_their_prize() {
+		ExtBuilder::new_build(vec![(ALICE, 100), (BOB, 10), (TREASURY_ACCOUNT, 100)]).execute_with(
+			|| {
+				setup();
+				assert_ok!(ProposalBuilder::new().start(1).end(200).lottery_winners(2).execute());
+				let proposal_id = Voting::next_proposal_id() - 1;
+
+				assert_noop!(
+					Voting::fund_lottery(RuntimeOrigin::signed(BOB), proposal_id, 5),
+					Error::<Test>::OriginNoPermission
+				);
+				assert_ok!(Voting::fund_lottery(RuntimeOrigin::root(), proposal_id, 11));
+				assert_ok!(Voting::fund_lottery(RuntimeOrigin::signed(ALICE), proposal_id, 4));
+				assert_eq!(Voting::lottery_pots(proposal_id), Some(15));
+				assert_eq!(Balances::free_balance(TREASURY_ACCOUNT), 89);
+
+				assert_ok!(Voting::vote(
+					RuntimeOrigin::signed(ALICE),
+					proposal_id,
+					true,
+					2,
+					Conviction::None
+				));
+
+				// The only voter is drawn and wins the whole pot
+				System::set_block_number(200);
+				assert_ok!(Voting::close_proposal(RuntimeOrigin::signed(ALICE), proposal_id));
+				System::assert_has_event(
+					Event::LotteryDrawn {
+						proposal_id,
+						winners: BoundedVec::truncate_from(vec![ALICE]),
+						prize: 15,
+					}
+					.into(),
+				);
+				assert_eq!(Voting::lottery_pots(proposal_id), None);
+
+				assert_ok!(Voting::claim(RuntimeOrigin::signed(ALICE), proposal_id));
+				System::assert_has_event(
+					Event::LotteryPrizePaid { proposal_id, who: ALICE, amount: 15 }.into(),
+				);
+				assert_eq!(Voting::lottery_prizes(proposal_id, ALICE), None);
+				assert_eq!(Balances::free_balance(ALICE), 111);
+			},
+		)
+	}
+
+	#[test]
+	fn winners_are_distinct_voters() {
+		ExtBuilder::new_build(vec![(ALICE, 100), (BOB, 10), (CHARLIE, 10)]).execute_with(|| {
+			setup();
+			assert_ok!(ProposalBuilder::new().start(1).end(200).lottery_winners(2).execute());
+			let proposal_id = Voting::next_proposal_id() - 1;
+			assert_ok!(Voting::fund_lottery(RuntimeOrigin::signed(ALICE), proposal_id, 15));
+			for voter in [ALICE, BOB, CHARLIE] {
+				assert_ok!(Voting::vote(
+					RuntimeOrigin::signed(voter),
+					proposal_id,
+					true,
+					1,
+					Conviction::None
+				));
+			}
+
+			// Two of the three voters share the pot, the remainder goes to the treasury
+			System::set_block_number(200);
+			assert_ok!(Voting::close_proposal(RuntimeOrigin::signed(ALICE), proposal_id));
+			let winners: Vec<_> = pallet_voting::LotteryPrizes::<Test>::iter_prefix(proposal_id)
+				.map(|(winner, prize)| {
+					assert_eq!(prize, 7);
+					winner
+				})
+				.collect();
+			assert_eq!(winners.len(), 2);
+			assert_eq!(Balances::free_balance(TREASURY_ACCOUNT), 1);
+		})
+	}
+
+	#[test]
+	fn claim_to_pays_the_prize_to_the_beneficiary() {
+		ExtBuilder::new_build(vec![(ALICE, 100), (BOB, 10)]).execute_with(|| {
+			setup();
+			assert_ok!(ProposalBuilder::new().start(1).end(200).lottery_winners(1).execute());
+			let proposal_id = Voting::next_proposal_id() - 1;
+			assert_ok!(Voting::fund_lottery(RuntimeOrigin::signed(ALICE), proposal_id, 15));
+			assert_ok!(Voting::vote(
+				RuntimeOrigin::signed(ALICE),
+				proposal_id,
+				true,
+				2,
+				Conviction::None
+			));
+
+			System::set_block_number(200);
+			assert_ok!(Voting::close_proposal(RuntimeOrigin::signed(ALICE), proposal_id));
+			assert_ok!(Voting::claim_to(RuntimeOrigin::signed(ALICE), proposal_id, BOB));
+			System::assert_has_event(
+				Event::LotteryPrizePaid { proposal_id, who: BOB, amount: 15 }.into(),
+			);
+			assert_eq!(Voting::lottery_prizes(proposal_id, ALICE), None);
+			assert_eq!(Balances::free_balance(BOB), 25);
+		})
+	}
+}
+
+mod dispute {
+	use super::*;
+	use crate::{DisputeResolution, ProposalOutcome};
+	use sp_runtime::DispatchError;
+
+	#[test]
+	fn confirmed_outcome_forfeits_the_bond() {
+		ExtBuilder::new_build(vec![(ALICE, 10), (BOB, 10)]).execute_with(|| {
+			setup();
+			let proposal_id = close_approved_proposal();
+
+			assert_ok!(Voting::dispute(RuntimeOrigin::signed(BOB), proposal_id));
+			System::assert_last_event(
+				Event::ProposalDisputed { proposal_id, challenger: BOB }.into(),
+			);
+			assert_eq!(Balances::free_balance(BOB), 10 - DISPUTE_BOND);
+			assert_eq!(Voting::outcome(proposal_id), None);
+			assert_noop!(
+				Voting::dispute(RuntimeOrigin::signed(CHARLIE), proposal_id),
+				Error::<Test>::AlreadyDisputed
+			);
+
+			assert_noop!(
+				Voting::resolve_dispute(
+					RuntimeOrigin::signed(ALICE),
+					proposal_id,
+					DisputeResolution::Confirm
+				),
+				DispatchError::BadOrigin
+			);
+			assert_ok!(Voting::resolve_dispute(
+				RuntimeOrigin::root(),
+				proposal_id,
+				DisputeResolution::Confirm
+			));
+			assert_eq!(Balances::free_balance(TREASURY_ACCOUNT), DISPUTE_BOND);
+			assert_eq!(
+				Voting::outcome(proposal_id),
+				Some(ProposalOutcome::Completed { ratio: (4, 4), approved: true })
+			);
+			assert_noop!(
+				Voting::dispute(RuntimeOrigin::signed(BOB), proposal_id),
+				Error::<Test>::DisputePeriodOver
+			);
+		})
+	}
+
+	#[test]
+	fn outcome_can_only_be_disputed_within_the_period() {
+		ExtBuilder::new_build(vec![(ALICE, 10), (BOB, 10)]).execute_with(|| {
+			setup();
+			let proposal_id = close_approved_proposal();
+
+			System::set_block_number(200 + DISPUTE_PERIOD);
+			assert_noop!(
+				Voting::dispute(RuntimeOrigin::signed(BOB), proposal_id),
+				Error::<Test>::DisputePeriodOver
+			);
+			Voting::on_idle(200 + DISPUTE_PERIOD, Weight::MAX);
+			assert!(!pallet_voting::DisputableProposals::<Test>::contains_key(proposal_id));
+		})
+	}
+
+	#[test]
+	fn dispute_can_overturn_the_outcome_or_schedule_a_revote() {
+		ExtBuilder::new_build(vec![(ALICE, 10), (BOB, 20)]).execute_with(|| {
+			setup();
+			assert_ok!(ProposalBuilder::new().start(1).end(200).execute());
+			let proposal_id = close_approved_proposal();
+			let other_id = proposal_id - 1;
+			assert_ok!(Voting::close_proposal(RuntimeOrigin::signed(ALICE), other_id));
+
+			assert_ok!(Voting::dispute(RuntimeOrigin::signed(BOB), proposal_id));
+			assert_ok!(Voting::resolve_dispute(
+				RuntimeOrigin::root(),
+				proposal_id,
+				DisputeResolution::Overturn
+			));
+			assert_eq!(Balances::free_balance(BOB), 20);
+			assert_eq!(
+				Voting::outcome(proposal_id),
+				Some(ProposalOutcome::Completed { ratio: (4, 4), approved: false })
+			);
+
+			assert_ok!(Voting::dispute(RuntimeOrigin::signed(BOB), other_id));
+			assert_ok!(Voting::resolve_dispute(
+				RuntimeOrigin::root(),
+				other_id,
+				DisputeResolution::Revote { start_block: 201, end_block: 400 }
+			));
+			let revote = Voting::next_proposal_id() - 1;
+			assert_eq!(Voting::outcome(other_id), Some(ProposalOutcome::Revoted { revote }));
+			let proposal = Voting::proposals(revote).unwrap();
+			assert_eq!((proposal.start_block, proposal.end_block), (201, 400));
+			assert_eq!((proposal.ratio, proposal.voters_count), ((0, 0), 0));
+			assert_eq!(Balances::free_balance(BOB), 20);
+		})
+	}
+}
+
+mod override_outcome {
+	use super::*;
+	use crate::{OutcomeOverride, ProposalOutcome};
+	use sp_runtime::DispatchError;
+
+	#[test]
+	fn veto_is_recorded_in_the_archive() {
+		ExtBuilder::new_build(vec![(ALICE, 10)]).execute_with(|| {
+			setup();
+			let proposal_id = close_approved_proposal();
+
+			assert_noop!(
+				Voting::override_outcome(
+					RuntimeOrigin::signed(ALICE),
+					proposal_id,
+					OutcomeOverride::Veto
+				),
+				DispatchError::BadOrigin
+			);
+			assert_ok!(Voting::override_outcome(
+				RuntimeOrigin::root(),
+				proposal_id,
+				OutcomeOverride::Veto
+			));
+			System::assert_last_event(
+				Event::OutcomeOverridden {
+					proposal_id,
+					outcome: OutcomeOverride::Veto,
+					by: OVERRIDE_ACCOUNT,
+				}
+				.into(),
+			);
+			assert_eq!(
+				Voting::outcome(proposal_id),
+				Some(ProposalOutcome::Completed { ratio: (4, 4), approved: false })
+			);
+			assert_eq!(
+				Voting::archived_proposals(proposal_id).unwrap().overridden,
+				Some((OutcomeOverride::Veto, OVERRIDE_ACCOUNT))
+			);
+			assert_noop!(
+				Voting::override_outcome(
+					RuntimeOrigin::root(),
+					proposal_id,
+					OutcomeOverride::Ratify
+				),
+				Error::<Test>::AlreadyOverridden
+			);
+		})
+	}
+
+	#[test]
+	fn only_recently_completed_outcomes_can_be_overridden() {
+		ExtBuilder::new_build(vec![(ALICE, 10)]).execute_with(|| {
+			setup();
+			assert_ok!(ProposalBuilder::new().start(10).end(200).execute());
+			let cancelled_id = Voting::next_proposal_id() - 1;
+			assert_ok!(Voting::cancel_proposal(
+				RuntimeOrigin::signed(ALICE),
+				cancelled_id,
+				CancellationReason::CreatorWithdrawn
+			));
+			assert_noop!(
+				Voting::override_outcome(
+					RuntimeOrigin::root(),
+					cancelled_id,
+					OutcomeOverride::Ratify
+				),
+				Error::<Test>::OutcomeNotOverridable
+			);
+
+			let proposal_id = close_approved_proposal();
+			System::set_block_number(200 + OVERRIDE_PERIOD);
+			assert_noop!(
+				Voting::override_outcome(RuntimeOrigin::root(), proposal_id, OutcomeOverride::Veto),
+				Error::<Test>::OverridePeriodOver
+			);
+		})
+	}
+}
+
+mod vote_fee {
+	use super::*;
+
+	#[test]
+	fn vote_fees_reward_the_closer() {
+		ExtBuilder::new_build(vec![(ALICE, 10), (BOB, 10), (CHARLIE, 10)]).execute_with(|| {
+			setup();
+			assert_ok!(Voting::register_voter(RuntimeOrigin::root(), BOB));
+			assert_ok!(ProposalBuilder::new()
+				.start(1)
+				.end(200)
+				.vote_fee(2, FeePayout::Closer)
+				.execute());
+			let proposal_id = Voting::next_proposal_id() - 1;
+			let pot = Voting::pot_account(proposal_id);
+
+			assert_ok!(Voting::vote(
+				RuntimeOrigin::signed(ALICE),
+				proposal_id,
+				true,
+				1,
+				Conviction::None
+			));
+			assert_ok!(Voting::vote(
+				RuntimeOrigin::signed(BOB),
+				proposal_id,
+				false,
+				1,
+				Conviction::None
+			));
+			// Changing a vote is not charged again
+			assert_ok!(Voting::vote(
+				RuntimeOrigin::signed(BOB),
+				proposal_id,
+				false,
+				2,
+				Conviction::None
+			));
+			assert_eq!(Balances::free_balance(pot), 4);
+			assert_eq!(Voting::fee_pots(proposal_id).unwrap().collected, 4);
+
+			System::set_block_number(200);
+			assert_ok!(Voting::close_proposal(RuntimeOrigin::signed(CHARLIE), proposal_id));
+			System::assert_has_event(
+				Event::FeesPaid { proposal_id, to: CHARLIE, amount: 4 }.into(),
+			);
+			assert_eq!(Balances::free_balance(CHARLIE), 14);
+			assert_eq!(Voting::fee_pots(proposal_id), None);
+		})
+	}
+
+	#[test]
+	fn vote_fees_are_shared_by_the_winning_side() {
+		ExtBuilder::new_build(vec![(ALICE, 10), (BOB, 10), (CHARLIE, 10)]).execute_with(|| {
+			for voter in [ALICE, BOB, CHARLIE] {
+				assert_ok!(Voting::register_voter(RuntimeOrigin::root(), voter));
+			}
+			assert_ok!(ProposalBuilder::new()
+				.start(1)
+				.end(200)
+				.vote_fee(2, FeePayout::WinningSide)
+				.execute());
+			let proposal_id = Voting::next_proposal_id() - 1;
+
+			assert_ok!(Voting::vote(
+				RuntimeOrigin::signed(ALICE),
+				proposal_id,
+				true,
+				1,
+				Conviction::None
+			));
+			assert_ok!(Voting::vote(
+				RuntimeOrigin::signed(BOB),
+				proposal_id,
+				true,
+				1,
+				Conviction::None
+			));
+			assert_ok!(Voting::vote(
+				RuntimeOrigin::signed(CHARLIE),
+				proposal_id,
+				false,
+				1,
+				Conviction::None
+			));
+
+			System::set_block_number(200);
+			assert_ok!(Voting::close_proposal(RuntimeOrigin::signed(CHARLIE), proposal_id));
+			assert_eq!(Voting::fee_pots(proposal_id).unwrap().reward, Some((true, 2)));
+
+			// Each 'aye' voter gets back a share of the pot, the rest goes to the treasury
+			Voting::on_idle(200, Weight::MAX);
+			Voting::on_idle(200, Weight::MAX);
+			assert_eq!(Balances::free_balance(ALICE), 10);
+			assert_eq!(Balances::free_balance(BOB), 10);
+			assert_eq!(Balances::free_balance(CHARLIE), 8);
+			assert_eq!(Balances::free_balance(TREASURY_ACCOUNT), 2);
+			assert_eq!(Voting::fee_pots(proposal_id), None);
+		})
+	}
+
+	#[test]
+	fn vote_fee_must_be_valid() {
+		new_test_ext().execute_with(|| {
+			System::set_block_number(1);
+			setup();
+			assert_noop!(
+				ProposalBuilder::new().vote_fee(0, FeePayout::Treasury).execute(),
+				Error::<Test>::InvalidVoteFee
+			);
+			assert_noop!(
+				ProposalBuilder::new()
+					.questions(2)
+					.vote_fee(1, FeePayout::WinningSide)
+					.execute(),
+				Error::<Test>::InvalidVoteFee
+			);
+		})
+	}
+}
+
+mod sponsor_votes {
+	use super::*;
+
+	#[test]
+	fn sponsorship_refunds_new_voters() {
+		ExtBuilder::new_build(vec![(ALICE, 20), (BOB, 10)]).execute_with(|| {
+			setup();
+			assert_ok!(Voting::register_voter(RuntimeOrigin::root(), BOB));
+			assert_ok!(ProposalBuilder::new().start(1).end(200).execute());
+			let proposal_id = Voting::next_proposal_id() - 1;
+
+			assert_noop!(
+				Voting::sponsor_votes(RuntimeOrigin::signed(BOB), proposal_id, 5),
+				Error::<Test>::OriginNoPermission
+			);
+			assert_ok!(Voting::sponsor_votes(RuntimeOrigin::signed(ALICE), proposal_id, 5));
+			assert_eq!(Balances::free_balance(ALICE), 15);
+			System::assert_last_event(Event::VotesSponsored { proposal_id, amount: 5 }.into());
+
+			assert_ok!(Voting::vote(
+				RuntimeOrigin::signed(BOB),
+				proposal_id,
+				true,
+				1,
+				Conviction::None
+			));
+			System::assert_has_event(
+				Event::VoteRefunded { proposal_id, voter: BOB, amount: VOTE_REFUND }.into(),
+			);
+			// Changing a vote is not refunded again
+			assert_ok!(Voting::vote(
+				RuntimeOrigin::signed(BOB),
+				proposal_id,
+				true,
+				2,
+				Conviction::None
+			));
+			assert_eq!(Balances::free_balance(BOB), 8);
+			assert_eq!(Voting::sponsorships(proposal_id), Some(3));
+
+			System::set_block_number(200);
+			assert_ok!(Voting::close_proposal(RuntimeOrigin::signed(BOB), proposal_id));
+			System::assert_has_event(Event::SponsorshipReturned { proposal_id, amount: 3 }.into());
+			assert_eq!(Balances::free_balance(ALICE), 18);
+			assert_eq!(Voting::sponsorships(proposal_id), None);
+		})
+	}
+
+	#[test]
+	fn exhausted_sponsorship_stops_refunding() {
+		ExtBuilder::new_build(vec![(ALICE, 20), (BOB, 10), (CHARLIE, 10), (DAVE, 10)])
+			.execute_with(|| {
+				for voter in [ALICE, BOB, CHARLIE, DAVE] {
+					assert_ok!(Voting::register_voter(RuntimeOrigin::root(), voter));
+				}
+				assert_ok!(ProposalBuilder::new().start(1).end(200).execute());
+				let proposal_id = Voting::next_proposal_id() - 1;
+				assert_ok!(Voting::sponsor_votes(RuntimeOrigin::signed(ALICE), proposal_id, 3));
+
+				for voter in [BOB, CHARLIE, DAVE] {
+					assert_ok!(Voting::vote(
+						RuntimeOrigin::signed(voter),
+						proposal_id,
+						true,
+						1,
+						Conviction::None
+					));
+				}
+				assert_eq!(Balances::free_balance(BOB), 11);
+				assert_eq!(Balances::free_balance(CHARLIE), 10);
+				assert_eq!(Balances::free_balance(DAVE), 9);
+				assert_eq!(Voting::sponsorships(proposal_id), Some(0));
+			});
+	}
+}
+
+mod second {
+	use super::*;
+	use crate::ProposalOutcome;
+
+	#[test]
+	fn seconded_proposal_opens_for_voting() {
+		ExtBuilder::new_build(vec![(ALICE, 30)]).execute_with(|| {
+			SecondsThreshold::set(&2);
+			setup();
+			assert_ok!(Voting::register_voter(RuntimeOrigin::root(), BOB));
+			assert_ok!(ProposalBuilder::new().start(10).end(200).execute());
+			let proposal_id = Voting::next_proposal_id() - 1;
+
+			assert_ok!(Voting::second(RuntimeOrigin::signed(ALICE), proposal_id));
+			System::assert_last_event(
+				Event::ProposalSeconded { proposal_id, who: ALICE, seconds: 1 }.into(),
+			);
+			assert_noop!(
+				Voting::second(RuntimeOrigin::signed(ALICE), proposal_id),
+				Error::<Test>::AlreadySeconded
+			);
+			assert_noop!(
+				Voting::second(RuntimeOrigin::signed(CHARLIE), proposal_id),
+				Error::<Test>::VoterNotRegistered
+			);
+
+			System::set_block_number(10);
+			assert_noop!(
+				Voting::vote(RuntimeOrigin::signed(ALICE), proposal_id, true, 1, Conviction::None),
+				Error::<Test>::ProposalNotSeconded
+			);
+			assert_noop!(
+				Voting::second(RuntimeOrigin::signed(BOB), proposal_id),
+				Error::<Test>::ProposalHasAlreadyStarted
+			);
+
+			System::set_block_number(1);
+			assert_ok!(Voting::second(RuntimeOrigin::signed(BOB), proposal_id));
+			assert_eq!(Voting::proposals(proposal_id).unwrap().seconds, 2);
+
+			System::set_block_number(10);
+			assert_ok!(Voting::vote(
+				RuntimeOrigin::signed(ALICE),
+				proposal_id,
+				true,
+				1,
+				Conviction::None
+			));
+		})
+	}
+
+	#[test]
+	fn unseconded_proposal_expires() {
+		new_test_ext().execute_with(|| {
+			System::set_block_number(1);
+			SecondsThreshold::set(&2);
+			setup();
+			assert_ok!(ProposalBuilder::new().start(10).end(200).execute());
+			let proposal_id = Voting::next_proposal_id() - 1;
+			assert_ok!(Voting::second(RuntimeOrigin::signed(ALICE), proposal_id));
+
+			assert_noop!(
+				Voting::close_proposal(RuntimeOrigin::signed(ALICE), proposal_id),
+				Error::<Test>::ProposalHasNotStartedYet
+			);
+
+			System::set_block_number(10);
+			assert_ok!(Voting::close_proposal(RuntimeOrigin::signed(ALICE), proposal_id));
+			System::assert_last_event(Event::ProposalExpired { proposal_id }.into());
+			assert_eq!(Voting::proposals(proposal_id), None);
+			assert_eq!(
+				Voting::archived_proposals(proposal_id).map(|archive| archive.outcome),
+				Some(ProposalOutcome::Expired)
+			);
+
+			Voting::on_idle(10, Weight::MAX);
+			assert_eq!(Voting::seconds(proposal_id, ALICE), None);
+		})
+	}
+}
+
+mod launch_queue {
+	use super::*;
+
+	fn submit() -> DispatchResult {
+		Voting::submit_public_proposal(
+			RuntimeOrigin::signed(ALICE),
+			BoundedVec::default(),
+			None,
+			PROPOSAL_MINIMUM_DURATION,
+			ProposalSettings::default(),
+		)
+	}
+
+	#[test]
+	fn most_seconded_queued_proposal_is_launched() {
+		new_test_ext().execute_with(|| {
+			System::set_block_number(1);
+			SecondsThreshold::set(&1);
+			setup();
+			assert_ok!(Voting::register_voter(RuntimeOrigin::root(), BOB));
+			assert_ok!(Voting::register_voter(RuntimeOrigin::root(), CHARLIE));
+
+			assert_ok!(submit());
+			assert_ok!(submit());
+			System::assert_last_event(
+				Event::ProposalQueued { proposal_id: 1, creator: ALICE }.into(),
+			);
+			assert_ok!(Voting::second(RuntimeOrigin::signed(ALICE), 0));
+			assert_ok!(Voting::second(RuntimeOrigin::signed(BOB), 1));
+			assert_ok!(Voting::second(RuntimeOrigin::signed(CHARLIE), 1));
+
+			Voting::on_initialize(LAUNCH_PERIOD as u64 - 1);
+			assert_eq!(Voting::launch_queue().into_inner(), vec![0, 1]);
+
+			System::set_block_number(LAUNCH_PERIOD as u64);
+			Voting::on_initialize(LAUNCH_PERIOD as u64);
+			System::assert_last_event(Event::ProposalLaunched { proposal_id: 1 }.into());
+			assert_eq!(Voting::launch_queue().into_inner(), vec![0]);
+			assert_eq!(Voting::queued_proposals(1), None);
+
+			let proposal = Voting::proposals(1).unwrap();
+			assert_eq!(proposal.start_block, LAUNCH_PERIOD as u64);
+			assert_eq!(proposal.end_block, (LAUNCH_PERIOD + PROPOSAL_MINIMUM_DURATION) as u64);
+			assert_eq!(proposal.seconds, 2);
+			assert_eq!(Voting::proposals_by_kind(ProposalKind::Public).into_inner(), vec![1]);
+		})
+	}
+
+	#[test]
+	fn queued_proposal_below_threshold_is_not_launched() {
+		new_test_ext().execute_with(|| {
+			System::set_block_number(1);
+			SecondsThreshold::set(&1);
+			setup();
+			assert_ok!(submit());
+
+			System::set_block_number(LAUNCH_PERIOD as u64);
+			Voting::on_initialize(LAUNCH_PERIOD as u64);
+			assert_eq!(Voting::launch_queue().into_inner(), vec![0]);
+			assert_eq!(Voting::proposals(0), None);
+		})
+	}
+}
+
+mod webhooks {
+	use super::*;
+	use codec::Encode;
+	use sp_core::offchain::{testing, OffchainDbExt, OffchainWorkerExt, StorageKind};
+
+	const ENDPOINT: &str = "http://localhost:8080/votes";
+
+	fn expect_notification(state: &mut testing::OffchainState, body: &str) {
+		state.expect_request(testing::PendingRequest {
+			method: "POST".into(),
+			uri: ENDPOINT.into(),
+			headers: vec![("Content-Type".into(), "application/json".into())],
+			body: body.as_bytes().to_vec(),
+			response: Some(Vec::new()),
+			sent: true,
+			..Default::default()
+		});
+	}
+
+	#[test]
+	fn notifies_the_proposal_lifecycle() {
+		let (offchain, state) = testing::TestOffchainExt::new();
+		let mut ext = ExtBuilder::new_build(vec![(ALICE, 10)]);
+		ext.register_extension(OffchainWorkerExt::new(offchain.clone()));
+		ext.register_extension(OffchainDbExt::new(offchain));
+		ext.execute_with(|| {
+			setup();
+			sp_io::offchain::local_storage_set(
+				StorageKind::PERSISTENT,
+				pallet_voting::WEBHOOKS_STORAGE_KEY,
+				&vec![ENDPOINT.as_bytes().to_vec()].encode(),
+			);
+
+			assert_ok!(ProposalBuilder::new().start(1).end(200).execute());
+			assert_ok!(ProposalBuilder::new().start(10).end(200).execute());
+			expect_notification(
+				&mut state.write(),
+				r#"{"event":"ProposalCreated","proposal_id":0,"block":1}"#,
+			);
+			expect_notification(
+				&mut state.write(),
+				r#"{"event":"ProposalStarted","proposal_id":0,"block":1}"#,
+			);
+			expect_notification(
+				&mut state.write(),
+				r#"{"event":"ProposalCreated","proposal_id":1,"block":1}"#,
+			);
+			Voting::offchain_worker(1);
+
+			System::reset_events();
+			System::set_block_number(10);
+			Voting::on_initialize(10);
+			expect_notification(
+				&mut state.write(),
+				r#"{"event":"ProposalStarted","proposal_id":1,"block":10}"#,
+			);
+			Voting::offchain_worker(10);
+
+			assert_ok!(Voting::vote(RuntimeOrigin::signed(ALICE), 1, true, 1, Conviction::None));
+			System::reset_events();
+			System::set_block_number(200);
+			assert_ok!(Voting::close_proposal(RuntimeOrigin::signed(ALICE), 1));
+			expect_notification(
+				&mut state.write(),
+				r#"{"event":"VoteCompleted","proposal_id":1,"block":200,"approved":true}"#,
+			);
+			Voting::offchain_worker(200);
+		})
+	}
+
+	#[test]
+	fn does_nothing_without_endpoints() {
+		let (offchain, _) = testing::TestOffchainExt::new();
+		let mut ext = new_test_ext();
+		ext.register_extension(OffchainWorkerExt::new(offchain.clone()));
+		ext.register_extension(OffchainDbExt::new(offchain));
+		ext.execute_with(|| {
+			setup();
+			assert_ok!(ProposalBuilder::new().start(1).end(200).execute());
+			// No request is expected by the offchain state
+			Voting::offchain_worker(1);
+		})
+	}
+}
+
+mod tally_checkpoint {
+	use super::*;
+	use crate::TALLY_COMMITMENT_ENGINE_ID;
+	use frame_support::Hashable;
+	use sp_runtime::DigestItem;
+
+	#[test]
+	fn rounds_carry_over_the_following_blocks() {
+		ExtBuilder::new_build(vec![(ALICE, 10)]).execute_with(|| {
+			setup();
+			TallyCheckpointPeriod::set(&10);
+			assert_ok!(ProposalBuilder::new().start(1).end(200).execute());
+			let proposal_id = Voting::next_proposal_id() - 1;
+			assert_ok!(Voting::vote(
+				RuntimeOrigin::signed(ALICE),
+				proposal_id,
+				true,
+				2,
+				Conviction::None
+			));
+			// Skipped: hidden tally, not started yet
+			assert_ok!(ProposalBuilder::new().start(1).end(200).hidden_tally().execute());
+			assert_ok!(ProposalBuilder::new().start(50).end(200).execute());
+
+			let checkpoints = || {
+				System::events()
+					.into_iter()
+					.filter_map(|record| match record.event {
+						RuntimeEvent::Voting(Event::TallyCheckpoint { proposal_id, tally }) =>
+							Some((proposal_id, tally)),
+						_ => None,
+					})
+					.collect::<Vec<_>>()
+			};
+			System::set_block_number(10);
+			Voting::on_initialize(10);
+			assert!(pallet_voting::CheckpointCursor::<Test>::get().is_some());
+			System::set_block_number(11);
+			Voting::on_initialize(11);
+			assert_eq!(pallet_voting::CheckpointCursor::<Test>::get(), None);
+			assert_eq!(checkpoints(), vec![(proposal_id, (4, 4))]);
+		})
+	}
+
+	#[test]
+	fn tally_commitment_is_published_each_epoch() {
+		ExtBuilder::new_build(vec![(ALICE, 10)]).execute_with(|| {
+			setup();
+			PublishTallyCommitment::set(&true);
+			assert_ok!(ProposalBuilder::new().start(1).end(200).execute());
+			let proposal_id = Voting::next_proposal_id() - 1;
+			assert_ok!(Voting::vote(
+				RuntimeOrigin::signed(ALICE),
+				proposal_id,
+				true,
+				2,
+				Conviction::None
+			));
+
+			Voting::on_initialize(EPOCH_DURATION as u64 - 1);
+			assert!(System::digest().logs.is_empty());
+			Voting::on_initialize(EPOCH_DURATION as u64);
+			let commitment = ([0u8; 32], proposal_id, (4u128, 4u128)).blake2_256().to_vec();
+			assert_eq!(
+				System::digest().logs,
+				vec![DigestItem::Consensus(TALLY_COMMITMENT_ENGINE_ID, commitment)]
+			);
+		})
+	}
+
+	#[test]
+	fn tally_commitment_carries_over_and_skips_pending_proposals() {
+		ExtBuilder::new_build(vec![(ALICE, 10)]).execute_with(|| {
+			setup();
+			PublishTallyCommitment::set(&true);
+			System::set_block_number(EPOCH_DURATION as u64 - 50);
+			for _ in 0..MAX_CHECKPOINTS_PER_BLOCK {
+				assert_ok!(ProposalBuilder::new()
+					.start(EPOCH_DURATION as u64 - 50)
+					.end(EPOCH_DURATION as u64 + 100)
+					.execute());
+			}
+			assert_ok!(ProposalBuilder::new()
+				.start(EPOCH_DURATION as u64 + 10)
+				.end(EPOCH_DURATION as u64 + 100)
+				.execute());
+			let pending_id = Voting::next_proposal_id() - 1;
+			assert_ok!(Voting::vote(
+				RuntimeOrigin::signed(ALICE),
+				pending_id - 1,
+				true,
+				2,
+				Conviction::None
+			));
+
+			Voting::on_initialize(EPOCH_DURATION as u64);
+			assert!(System::digest().logs.is_empty());
+			assert!(crate::CommitmentRound::<Test>::get().is_some());
+			Voting::on_initialize(EPOCH_DURATION as u64 + 1);
+			let commitment = pallet_voting::Proposals::<Test>::iter()
+				.filter(|(proposal_id, _)| *proposal_id != pending_id)
+				.fold([0u8; 32], |commitment, (proposal_id, proposal)| {
+					(commitment, proposal_id, proposal.ratio).blake2_256()
+				});
+			assert_eq!(
+				System::digest().logs,
+				vec![DigestItem::Consensus(TALLY_COMMITMENT_ENGINE_ID, commitment.to_vec())]
+			);
+			assert!(crate::CommitmentRound::<Test>::get().is_none());
+		})
+	}
+}
+
+mod epoch_report {
+	use super::*;
+	use crate::EpochReport;
+
+	#[test]
+	fn is_written_at_the_epoch_boundary() {
+		ExtBuilder::new_build(vec![(ALICE, 10)]).execute_with(|| {
+			setup();
+			assert_ok!(ProposalBuilder::new().start(1).end(200).execute());
+			assert_ok!(ProposalBuilder::new().start(1).end(200).execute());
+			let proposal_id = Voting::next_proposal_id() - 1;
+			assert_ok!(Voting::vote(
+				RuntimeOrigin::signed(ALICE),
+				proposal_id - 1,
+				true,
+				2,
+				Conviction::None
+			));
+			assert_ok!(Voting::vote(
+				RuntimeOrigin::signed(ALICE),
+				proposal_id,
+				false,
+				2,
+				Conviction::None
+			));
+
+			System::set_block_number(200);
+			assert_ok!(Voting::close_proposal(RuntimeOrigin::signed(BOB), proposal_id - 1));
+			assert_ok!(Voting::close_proposal(RuntimeOrigin::signed(BOB), proposal_id));
+			assert_eq!(Voting::epoch_reports(0), None);
+
+			System::set_block_number(EPOCH_DURATION as u64);
+			Voting::on_initialize(EPOCH_DURATION as u64);
+			let report = EpochReport { proposals_closed: 2, average_turnout: 4, unique_voters: 1 };
+			assert_eq!(Voting::epoch_reports(0), Some(report.clone()));
+			System::assert_last_event(Event::EpochReported { epoch: 0, report }.into());
+
+			// The next epoch starts from scratch
+			System::set_block_number(2 * EPOCH_DURATION as u64);
+			Voting::on_initialize(2 * EPOCH_DURATION as u64);
+			assert_eq!(Voting::epoch_reports(1), Some(EpochReport::default()));
+		})
+	}
+}
+
+mod client_types {
+	use super::*;
+	use crate::client_types::{self as client, Tally};
+	use codec::{Decode, Encode};
+
+	#[test]
+	fn decode_the_pallet_storage_and_events() {
+		ExtBuilder::new_build(vec![(ALICE, 10)]).execute_with(|| {
+			setup();
+			assert_ok!(ProposalBuilder::new().start(1).end(200).execute());
+			let proposal_id = Voting::next_proposal_id() - 1;
+			assert_ok!(Voting::vote(
+				RuntimeOrigin::signed(ALICE),
+				proposal_id,
+				true,
+				2,
+				Conviction::None
+			));
+
+			let proposal = Voting::proposals(proposal_id).unwrap();
+			let encoded = proposal.encode();
+			let decoded = client::ProposalData::<u64, u64>::decode(&mut &encoded[..]).unwrap();
+			let tally: Tally = (4, 4);
+			assert_eq!(decoded.ratio, tally);
+			assert_eq!(decoded.creator, proposal.creator);
+			assert_eq!(decoded.end_block, 200);
+			assert_eq!(decoded.offchain_data, proposal.offchain_data.to_vec());
+			assert_eq!(decoded.encode(), encoded);
+
+			let events = System::events()
+				.into_iter()
+				.filter_map(|record| match record.event {
+					RuntimeEvent::Voting(event) => Some(event),
+					_ => None,
+				})
+				.collect::<Vec<_>>();
+			assert!(!events.is_empty());
+			for event in events {
+				let encoded = event.encode();
+				let decoded =
+					client::Event::<u64, u128, u64, u32>::decode(&mut &encoded[..]).unwrap();
+				assert_eq!(decoded.encode(), encoded);
+			}
+		})
+	}
+}
+
+mod kind_encoding {
+	use super::*;
+	use crate::{ProposalOutcome, ProposalStatus, ProposalStatuses};
+	use codec::{Decode, Encode};
+
+	/// The layout of `ProposalKind` before its discriminants were frozen.
+	#[derive(Encode, Decode)]
+	enum LegacyKind {
+		Public,
+		Private,
+		Emergency,
+		Custom(u8),
+		SunsetReview,
+	}
+
+	/// The layout of `ProposalStatus` before its discriminants were frozen.
+	#[derive(Encode, Decode)]
+	enum LegacyStatus {
+		Pending,
+		Active,
+		Suspended,
+		Closed { outcome: ProposalOutcome },
+		Cancelled { reason: CancellationReason },
+	}
+
+	#[test]
+	fn stored_kinds_and_statuses_keep_their_encoding() {
+		let kinds = [
+			(LegacyKind::Public, ProposalKind::Public),
+			(LegacyKind::Private, ProposalKind::Private),
+			(LegacyKind::Emergency, ProposalKind::Emergency),
+			(LegacyKind::Custom(7), ProposalKind::Custom(7)),
+			(LegacyKind::SunsetReview, ProposalKind::SunsetReview),
+		];
+		for (legacy, kind) in kinds {
+			assert_eq!(legacy.encode(), kind.encode());
+			assert_eq!(ProposalKind::decode(&mut &legacy.encode()[..]), Ok(kind));
+		}
+
+		let reason = CancellationReason::Spam;
+		let outcome = ProposalOutcome::Completed { ratio: (3, 4), approved: true };
+		let statuses = [
+			(LegacyStatus::Pending, ProposalStatus::Pending),
+			(LegacyStatus::Active, ProposalStatus::Active),
+			(LegacyStatus::Suspended, ProposalStatus::Suspended),
+			(LegacyStatus::Closed { outcome }, ProposalStatus::Closed { outcome }),
+			(LegacyStatus::Cancelled { reason }, ProposalStatus::Cancelled { reason }),
+		];
+		for (legacy, status) in statuses {
+			assert_eq!(legacy.encode(), status.encode());
+			assert_eq!(ProposalStatus::decode(&mut &legacy.encode()[..]), Ok(status));
+		}
+	}
+
+	#[test]
+	fn kinds_and_statuses_of_a_later_version_decode_as_unknown() {
+		let index = ProposalKind::LATEST_INDEX + 1;
+		let kind = ProposalKind::decode(&mut &[index][..]).unwrap();
+		assert_eq!(kind, ProposalKind::Unknown(index));
+		assert_eq!(kind.encode(), vec![index]);
+
+		let index = ProposalStatus::LATEST_INDEX + 1;
+		let status = ProposalStatus::decode(&mut &[index, 1, 2][..]).unwrap();
+		assert_eq!(status, ProposalStatus::Unknown(index));
+	}
+
+	#[test]
+	fn proposals_of_an_unknown_kind_still_decode() {
+		new_test_ext().execute_with(|| {
+			System::set_block_number(1);
+			setup();
+			assert_ok!(ProposalBuilder::new().start(1).end(200).execute());
+			let proposal_id = Voting::next_proposal_id() - 1;
+
+			let mut proposal = Voting::proposals(proposal_id).unwrap();
+			proposal.kind = ProposalKind::Unknown(ProposalKind::LATEST_INDEX + 1);
+			let encoded = proposal.encode();
+			let decoded = crate::ProposalData::<Test>::decode(&mut &encoded[..]).unwrap();
+			assert_eq!(decoded, proposal);
+			assert_eq!(decoded.encode(), encoded);
+
+			// The statuses of a later version are not reported as an outcome
+			ProposalStatuses::<Test>::insert(proposal_id, ProposalStatus::Unknown(9));
+			assert_eq!(Voting::proposal_status(proposal_id), Some(ProposalStatus::Unknown(9)));
+			assert_eq!(Voting::outcome(proposal_id), None);
+		})
+	}
+
+	#[test]
+	fn unknown_kinds_cannot_be_created() {
+		new_test_ext().execute_with(|| {
+			System::set_block_number(1);
+			setup();
+
+			assert_noop!(
+				ProposalBuilder::new().kind(ProposalKind::Unknown(9)).execute(),
+				Error::<Test>::ProposalKindNotAllowed
+			);
+		})
+	}
+}
+
+mod vote {
+	use crate::{
+		types::{membership_leaf, merkle_parent},
+		VoteInfo,
+	};
+
+	use super::*;
+
+	fn vote_setup() {
+		let start_block = 1;
+		let end_block = 200;
+		assert_ok!(Voting::register_voter(RuntimeOrigin::root(), ALICE));
+		assert_ok!(ProposalBuilder::new().start(start_block).end(end_block).execute());
+	}
+
+	#[test]
+	fn refunds_account_list_scan() {
+		ExtBuilder::new_build(vec![(ALICE, 10)]).execute_with(|| {
+			vote_setup();
+			let proposal_id = Voting::next_proposal_id() - 1;
+			let post_info =
+				Voting::vote(RuntimeOrigin::signed(ALICE), proposal_id, true, 1, Conviction::None)
+					.unwrap();
+			assert_eq!(post_info.actual_weight, Some(<() as WeightInfo>::vote(0)));
+
+			let account_list = BoundedVec::try_from(vec![BOB, CHARLIE]).unwrap();
+			assert_ok!(ProposalBuilder::new()
+				.start(1)
+				.end(200)
+				.set_account_list(Some(account_list))
+				.execute());
+			let proposal_id = Voting::next_proposal_id() - 1;
+			let post_info =
+				Voting::vote(RuntimeOrigin::signed(ALICE), proposal_id, true, 1, Conviction::None)
+					.unwrap();
+			assert_eq!(post_info.actual_weight, Some(<() as WeightInfo>::vote(2)));
+		})
+	}
+
+	#[test]
+	fn works_only_if_registered_voter() {
+		new_test_ext().execute_with(|| {
+			assert_noop!(
+				Voting::vote(RuntimeOrigin::signed(2), 0, true, 1, Conviction::None),
+				Error::<Test>::VoterNotRegistered
+			);
+		})
+	}
+
+	#[test]
+	fn cannot_vote_proposal_not_existing() {
+		new_test_ext().execute_with(|| {
+			setup();
+			assert_noop!(
+				Voting::vote(RuntimeOrigin::signed(ALICE), 1, true, 1, Conviction::None),
+				Error::<Test>::ProposalDoesNotExist
+			);
+		})
+	}
+
+	#[test]
+	fn cannot_vote_proposal_not_started() {
+		new_test_ext().execute_with(|| {
+			System::set_block_number(1);
+			let start_block = 10;
+			let end_block = 200;
+			setup();
+
+			assert_ok!(ProposalBuilder::new().start(start_block).end(end_block).execute());
+
+			let proposal_id = Voting::get_next_proposal_id() - 1;
+			assert_noop!(
+				Voting::vote(RuntimeOrigin::signed(ALICE), proposal_id, true, 1, Conviction::None),
+				Error::<Test>::ProposalHasNotStartedYet
+			);
+		})
+	}
+
+	#[test]
+	fn cannot_vote_proposal_finished() {
+		new_test_ext().execute_with(|| {
+			System::set_block_number(1);
+			let start_block = 10;
+			let end_block = 200;
+			setup();
+
+			assert_ok!(ProposalBuilder::new().start(start_block).end(end_block).execute());
+
+			System::set_block_number(201);
+
+			let proposal_id = Voting::get_next_proposal_id() - 1;
+			assert_noop!(
+				Voting::vote(RuntimeOrigin::signed(ALICE), proposal_id, true, 1, Conviction::None),
+				Error::<Test>::ProposalHasAlreadyEnded
+			);
+		})
+	}
+
+	#[test]
+	fn banned_voter_cannot_vote() {
+		new_test_ext().execute_with(|| {
+			System::set_block_number(1);
+			let start_block = 1;
+			let end_block = 200;
+			let account_list = BoundedVec::try_from(vec![BOB]).unwrap();
+			assert_ok!(Voting::register_voter(RuntimeOrigin::root(), BOB));
+			setup();
+
+			assert_ok!(ProposalBuilder::new()
+				.start(start_block)
+				.end(end_block)
+				.set_account_list(Some(account_list))
+				.execute());
+
+			let proposal_id = Voting::get_next_proposal_id() - 1;
+			assert_noop!(
+				Voting::vote(RuntimeOrigin::signed(BOB), proposal_id, true, 1, Conviction::None),
+				Error::<Test>::OriginNoPermission
+			);
+		})
+	}
+
+	#[test]
+	fn not_allowed_voter_cannot_vote_private_proposal() {
+		new_test_ext().execute_with(|| {
+			System::set_block_number(1);
+			let start_block = 1;
+			let end_block = 200;
+			let account_list = BoundedVec::try_from(vec![ALICE]).unwrap();
+			assert_ok!(Voting::register_voter(RuntimeOrigin::root(), BOB));
+			setup();
+
+			assert_ok!(ProposalBuilder::new()
+				.start(start_block)
+				.end(end_block)
+				.set_account_list(Some(account_list))
+				.private()
+				.execute());
+
+			let proposal_id = Voting::get_next_proposal_id() - 1;
+			assert_noop!(
+				Voting::vote(RuntimeOrigin::signed(BOB), proposal_id, true, 1, Conviction::None),
+				Error::<Test>::OriginNoPermission
+			);
+		})
+	}
+
+	#[test]
+	fn members_root_requires_a_valid_membership_proof() {
+		ExtBuilder::new_build(vec![(ALICE, 10), (BOB, 10), (CHARLIE, 10)]).execute_with(|| {
+			for voter in [ALICE, BOB, CHARLIE] {
+				assert_ok!(Voting::register_voter(RuntimeOrigin::root(), voter));
+			}
+			let alice_leaf = membership_leaf(&ALICE);
+			let bob_leaf = membership_leaf(&BOB);
+			let members_root = merkle_parent(&alice_leaf, &bob_leaf);
+
+			assert_ok!(ProposalBuilder::new()
+				.start(1)
+				.end(200)
+				.private()
+				.set_account_list(None)
+				.members_root(members_root)
+				.execute());
+			let proposal_id = Voting::next_proposal_id() - 1;
+
+			assert_noop!(
+				Voting::vote(RuntimeOrigin::signed(ALICE), proposal_id, true, 1, Conviction::None),
+				Error::<Test>::MembershipProofRequired
+			);
+			let bob_proof = BoundedVec::try_from(vec![bob_leaf]).unwrap();
+			assert_noop!(
+				Voting::vote_with_proof(
+					RuntimeOrigin::signed(CHARLIE),
+					proposal_id,
+					true,
+					1,
+					bob_proof.clone()
+				),
+				Error::<Test>::OriginNoPermission
+			);
+			assert_ok!(Voting::vote_with_proof(
+				RuntimeOrigin::signed(ALICE),
+				proposal_id,
+				true,
+				1,
+				bob_proof
+			));
+			let alice_proof = BoundedVec::try_from(vec![alice_leaf]).unwrap();
+			assert_ok!(Voting::vote_with_proof(
+				RuntimeOrigin::signed(BOB),
+				proposal_id,
+				false,
+				1,
+				alice_proof
+			));
+			assert_eq!(Voting::proposals(proposal_id).unwrap().ratio, (1, 2));
+		})
+	}
+
+	#[test]
+	fn members_root_only_for_private_proposals() {
+		new_test_ext().execute_with(|| {
+			System::set_block_number(1);
+			setup();
+
+			assert_noop!(
+				ProposalBuilder::new().members_root([0; 32]).execute(),
+				Error::<Test>::MembersRootOnlyForPrivate
+			);
+		})
+	}
+
+	#[test]
+	fn ext_builder_balance_setup_works() {
+		ExtBuilder::new_build(vec![(ALICE, 10), (BOB, 100)]).execute_with(|| {
+			let alice_balance = Balances::free_balance(ALICE);
+			assert!(alice_balance == 10);
+
+			let bob_balance = Balances::free_balance(BOB);
+			assert!(bob_balance == 100);
+
+			let alice_held_balance = Voting::held_for_voting(&ALICE);
+			assert_eq!(alice_held_balance, 0);
+
+			let bob_held_balance = Voting::held_for_voting(&BOB);
+			assert_eq!(bob_held_balance, 0);
+		})
+	}
+
+	#[test]
+	fn cannot_vote_with_insufficient_funds() {
+		ExtBuilder::new_build(vec![(ALICE, 10)]).execute_with(|| {
+			let start_block = 1;
+			let end_block = 200;
+			let aye = true;
+			let power = 4; // 16 tokens required
+			setup();
+
+			assert_ok!(ProposalBuilder::new().start(start_block).end(end_block).execute());
+
+			let proposal_id = Voting::get_next_proposal_id() - 1;
+			assert_noop!(
+				Voting::vote(
+					RuntimeOrigin::signed(ALICE),
+					proposal_id,
+					aye,
+					power,
+					Conviction::None
+				),
+				Error::<Test>::InsufficientBalance
+			);
+		})
+	}
+
+	#[test]
+	fn single_vote() {
+		ExtBuilder::new_build(vec![(ALICE, 10)]).execute_with(|| {
+			let aye = true;
+			let power = 3; // 9 tokens required
+			let quadratic_amount = Voting::calculate_quadratic_amount(power);
+			vote_setup();
+
+			let proposal_id = Voting::next_proposal_id() - 1;
+
+			// Execution
+			assert_ok!(Voting::vote(
+				RuntimeOrigin::signed(ALICE),
+				proposal_id,
+				aye,
+				power,
+				Conviction::None
+			));
+
+			// Storage
+			let proposal = Voting::proposals(proposal_id);
+			assert_eq!(proposal.unwrap().ratio, (quadratic_amount, quadratic_amount));
+
+			let alice_held_balance = Voting::held_for_voting(&ALICE);
+			assert_eq!(alice_held_balance, quadratic_amount);
+
+			let vote = Voting::votes(ALICE, proposal_id);
+			assert_eq!(
+				vote,
+				Some(VoteInfo { proposal_id, aye, power, conviction: Conviction::None })
+			);
+
+			// Event
+			System::assert_last_event(
+				Event::VoteAdded { proposal_id, voter: ALICE, aye, power }.into(),
+			);
+		})
+	}
+
+	#[test]
+	fn anonymous_events_omit_the_voter() {
+		ExtBuilder::new_build(vec![(ALICE, 10)]).execute_with(|| {
+			assert_ok!(Voting::register_voter(RuntimeOrigin::root(), ALICE));
+			assert_ok!(ProposalBuilder::new().start(1).end(200).anonymous_events().execute());
+			let proposal_id = Voting::next_proposal_id() - 1;
+
+			assert_ok!(Voting::vote(
+				RuntimeOrigin::signed(ALICE),
+				proposal_id,
+				true,
+				2,
+				Conviction::None
+			));
+			System::assert_last_event(
+				Event::AnonymousVoteAdded { proposal_id, aye: true, power: 2 }.into(),
+			);
+			assert!(!System::events().iter().any(|record| matches!(
+				record.event,
+				RuntimeEvent::Voting(Event::VoteAdded { .. })
+			)));
+
+			// The vote is still stored by voter
+			assert_eq!(
+				Voting::votes(ALICE, proposal_id),
+				Some(VoteInfo { proposal_id, aye: true, power: 2, conviction: Conviction::None })
+			);
+		})
+	}
+
+	#[test]
+	fn vote_adjustment() {
+		ExtBuilder::new_build(vec![(ALICE, 100)]).execute_with(|| {
+			let init_aye = true;
+			let init_power = 3; // 9 tokens required
+			vote_setup();
+
+			let proposal_id = Voting::next_proposal_id() - 1;
+
+			// Initial vote execution
+			assert_ok!(Voting::vote(
+				RuntimeOrigin::signed(ALICE),
+				proposal_id,
+				init_aye,
+				init_power,
+				Conviction::None
+			));
+
+			System::set_block_number(2);
+
+			// Vote adjustment
+			let second_aye = true;
+			let second_power = 4; // 16 tokens required - diff = 7
+			let second_quadratic_amount = Voting::calculate_quadratic_amount(second_power);
+
+			assert_ok!(Voting::vote(
+				RuntimeOrigin::signed(ALICE),
+				proposal_id,
+				second_aye,
+				second_power,
+				Conviction::None
+			));
+
+			// Storage
+			let proposal = Voting::proposals(proposal_id);
+			assert_eq!(proposal.unwrap().ratio, (second_quadratic_amount, second_quadratic_amount));
+
+			let alice_held_balance = Voting::held_for_voting(&ALICE);
+			assert_eq!(alice_held_balance, second_quadratic_amount);
+
+			let vote = Voting::votes(ALICE, proposal_id);
+			assert_eq!(
+				vote,
+				Some(VoteInfo {
+					proposal_id,
+					aye: second_aye,
+					power: second_power,
+					conviction: Conviction::None
+				})
+			);
+
+			// Event
+			System::assert_last_event(
+				Event::VoteAdded {
+					proposal_id,
+					voter: ALICE,
+					aye: second_aye,
+					power: second_power,
+				}
+				.into(),
+			);
+		})
+	}
+
+	#[test]
+	fn retract_vote() {
+		ExtBuilder::new_build(vec![(ALICE, 10)]).execute_with(|| {
+			let init_aye = true;
+			let init_power = 3; // 9 tokens required
+			vote_setup();
+
+			let proposal_id = Voting::next_proposal_id() - 1;
+
+			// Initial vote execution
+			assert_ok!(Voting::vote(
+				RuntimeOrigin::signed(ALICE),
+				proposal_id,
+				init_aye,
+				init_power,
+				Conviction::None
+			));
+
+			System::set_block_number(2);
+
+			// Vote adjustment
+			let second_aye = true;
+			let second_power = 0; // 16 tokens required - diff = 7
+			let second_quadratic_amount = Voting::calculate_quadratic_amount(second_power);
+
+			assert_ok!(Voting::vote(
+				RuntimeOrigin::signed(ALICE),
+				proposal_id,
+				second_aye,
+				second_power,
+				Conviction::None
+			));
+
+			// Storage
+			let proposal = Voting::proposals(proposal_id);
+			assert_eq!(proposal.unwrap().ratio, (second_quadratic_amount, second_quadratic_amount));
+
+			let alice_held_balance = Voting::held_for_voting(&ALICE);
+			assert_eq!(alice_held_balance, second_quadratic_amount);
+
+			let vote = Voting::votes(ALICE, proposal_id);
+			assert_eq!(vote, None);
+
+			// Event
+			System::assert_last_event(Event::VoteDropped { proposal_id, voter: ALICE }.into());
+		})
+	}
+
+	#[test]
+	fn vote_power_must_respect_power_step() {
+		ExtBuilder::new_build(vec![(ALICE, 30)]).execute_with(|| {
+			setup();
+			assert_ok!(ProposalBuilder::new().start(1).end(200).power_step(5).execute());
+			let proposal_id = Voting::next_proposal_id() - 1;
+
+			assert_noop!(
+				Voting::vote(RuntimeOrigin::signed(ALICE), proposal_id, true, 3, Conviction::None),
+				Error::<Test>::PowerNotMultipleOfStep
+			);
+			assert_ok!(Voting::vote(
+				RuntimeOrigin::signed(ALICE),
+				proposal_id,
+				true,
+				5,
+				Conviction::None
+			));
+			assert_eq!(Voting::proposals(proposal_id).unwrap().ratio, (25, 25));
+		})
+	}
+
+	#[test]
+	fn tenure_bonus_increases_tallied_amount() {
+		ExtBuilder::new_build(vec![(ALICE, 200)]).execute_with(|| {
+			setup();
+			// Registered for 2 full epochs when the proposal starts: 2 * 10% bonus
+			let start_block = 2 * EPOCH_DURATION + 1;
+			System::set_block_number(start_block as u64);
+			assert_ok!(ProposalBuilder::new().start(start_block).end(start_block + 200).execute());
+			let proposal_id = Voting::next_proposal_id() - 1;
+
+			assert_ok!(Voting::vote(
+				RuntimeOrigin::signed(ALICE),
+				proposal_id,
+				true,
+				10,
+				Conviction::None
+			));
+			assert_eq!(Voting::proposals(proposal_id).unwrap().ratio, (120, 120));
+			assert_eq!(Voting::contributions(proposal_id, ALICE), Some((true, 120)));
+
+			assert_ok!(Voting::vote(
+				RuntimeOrigin::signed(ALICE),
+				proposal_id,
+				true,
+				0,
+				Conviction::None
+			));
+			assert_eq!(Voting::proposals(proposal_id).unwrap().ratio, (0, 0));
+		})
+	}
+
+	#[test]
+	fn minimum_balance_is_checked_when_voting() {
+		ExtBuilder::new_build(vec![(ALICE, 30)]).execute_with(|| {
+			vote_setup();
+			let proposal_id = Voting::next_proposal_id() - 1;
+
+			MinVoterBalance::set(&40);
+			assert_noop!(
+				Voting::vote(RuntimeOrigin::signed(ALICE), proposal_id, true, 1, Conviction::None),
+				Error::<Test>::BalanceBelowMinimum
+			);
+		})
+	}
+
+	#[test]
+	fn held_balance_must_be_seasoned() {
+		use frame_support::traits::fungible::Mutate;
+
+		ExtBuilder::new_build(vec![(ALICE, 100)]).execute_with(|| {
+			BalanceAge::set(&10);
+			vote_setup();
+			let proposal_id = Voting::next_proposal_id() - 1;
+			assert_eq!(Voting::balance_snapshots(ALICE), Some((1, 100)));
+
+			assert_noop!(
+				Voting::vote(RuntimeOrigin::signed(ALICE), proposal_id, true, 4, Conviction::None),
+				Error::<Test>::BalanceTooRecent
+			);
+
+			System::set_block_number(11);
+			assert_ok!(Voting::vote(
+				RuntimeOrigin::signed(ALICE),
+				proposal_id,
+				true,
+				4,
+				Conviction::None
+			));
+
+			// Funds received after the snapshot cannot back a vote
+			assert_ok!(Balances::mint_into(&ALICE, 100));
+			assert_noop!(
+				Voting::vote(RuntimeOrigin::signed(ALICE), proposal_id, true, 11, Conviction::None),
+				Error::<Test>::BalanceTooRecent
+			);
+
+			assert_ok!(Voting::take_balance_snapshot(RuntimeOrigin::signed(ALICE)));
+			System::assert_last_event(
+				Event::BalanceSnapshotTaken { who: ALICE, balance: 200 }.into(),
+			);
+			System::set_block_number(21);
+			assert_ok!(Voting::vote(
+				RuntimeOrigin::signed(ALICE),
+				proposal_id,
+				true,
+				11,
+				Conviction::None
+			));
+		})
+	}
+
+	#[test]
+	fn vote_power_is_bounded() {
+		ExtBuilder::new_build(vec![(ALICE, 30)]).execute_with(|| {
+			vote_setup();
+			let proposal_id = Voting::next_proposal_id() - 1;
+
+			assert_noop!(
+				Voting::vote(
+					RuntimeOrigin::signed(ALICE),
+					proposal_id,
+					true,
+					MAX_POWER + 1,
+					Conviction::None
+				),
+				Error::<Test>::PowerTooHigh
+			);
+		})
+	}
+
+	#[test]
+	fn saturated_tally_is_reported() {
+		ExtBuilder::new_build(vec![(ALICE, 30)]).execute_with(|| {
+			vote_setup();
+			let proposal_id = Voting::next_proposal_id() - 1;
+			pallet_voting::Proposals::<Test>::mutate(proposal_id, |proposal| {
+				proposal.as_mut().unwrap().ratio = (0, u128::MAX - 1);
+			});
+
+			assert_ok!(Voting::vote(
+				RuntimeOrigin::signed(ALICE),
+				proposal_id,
+				true,
+				2,
+				Conviction::None
+			));
+			System::assert_has_event(Event::TallySaturated { proposal_id }.into());
+			assert_eq!(Voting::proposals(proposal_id).unwrap().ratio, (4, u128::MAX));
+		})
+	}
+
+	#[test]
+	fn shared_budget_is_spent_across_proposals() {
+		ExtBuilder::new_build(vec![(ALICE, 100)]).execute_with(|| {
+			setup();
+			assert_ok!(ProposalBuilder::new().start(1).end(200).shared_budget().execute());
+			let proposal_1_id = Voting::next_proposal_id() - 1;
+			assert_ok!(ProposalBuilder::new().start(1).end(200).shared_budget().execute());
+			let proposal_2_id = Voting::next_proposal_id() - 1;
+
+			assert_ok!(Voting::vote(
+				RuntimeOrigin::signed(ALICE),
+				proposal_1_id,
+				true,
+				4,
+				Conviction::None
+			));
+			assert_eq!(Voting::spent_credits(ALICE), (0, 16));
+
+			// 16 + 9 exceeds the 20 voice credits
+			assert_noop!(
+				Voting::vote(
+					RuntimeOrigin::signed(ALICE),
+					proposal_2_id,
+					true,
+					3,
+					Conviction::None
+				),
+				Error::<Test>::InsufficientCredits
+			);
+			assert_ok!(Voting::vote(
+				RuntimeOrigin::signed(ALICE),
+				proposal_2_id,
+				true,
+				2,
+				Conviction::None
+			));
+			assert_eq!(Voting::spent_credits(ALICE), (0, 20));
+
+			// Lowering a vote gives back credits
+			assert_ok!(Voting::vote(
+				RuntimeOrigin::signed(ALICE),
+				proposal_1_id,
+				true,
+				3,
+				Conviction::None
+			));
+			assert_eq!(Voting::spent_credits(ALICE), (0, 13));
+
+			let alice_held_balance = Voting::held_for_voting(&ALICE);
+			assert_eq!(alice_held_balance, 0);
+
+			// Nothing to claim once closed
+			System::set_block_number(200);
+			assert_ok!(Voting::close_proposal(RuntimeOrigin::signed(ALICE), proposal_1_id));
+			Voting::on_idle(200, Weight::MAX);
+			assert_eq!(Voting::votes(ALICE, proposal_1_id), None);
+			assert_eq!(Voting::spent_credits(ALICE), (0, 13));
+		})
+	}
+
+	#[test]
+	fn immutable_ballot_cannot_be_changed() {
+		ExtBuilder::new_build(vec![(ALICE, 100)]).execute_with(|| {
+			setup();
+			assert_ok!(ProposalBuilder::new().start(1).end(200).immutable_ballot().execute());
+			let proposal_id = Voting::next_proposal_id() - 1;
+
+			assert_ok!(Voting::vote(
+				RuntimeOrigin::signed(ALICE),
+				proposal_id,
+				true,
+				2,
+				Conviction::None
+			));
+			for (aye, power) in [(true, 3), (false, 2), (true, 0)] {
+				assert_noop!(
+					Voting::vote(
+						RuntimeOrigin::signed(ALICE),
+						proposal_id,
+						aye,
+						power,
+						Conviction::None
+					),
+					Error::<Test>::BallotImmutable
+				);
+			}
+		})
+	}
+
+	#[test]
+	fn edits_are_closed_after_the_cutoff() {
+		ExtBuilder::new_build(vec![(ALICE, 100), (BOB, 100)]).execute_with(|| {
+			setup();
+			assert_ok!(Voting::register_voter(RuntimeOrigin::root(), BOB));
+			assert_ok!(ProposalBuilder::new()
+				.start(1)
+				.end(201)
+				.edit_cutoff(Percent::from_percent(50))
+				.execute());
+			let proposal_id = Voting::next_proposal_id() - 1;
+			assert_ok!(Voting::vote(
+				RuntimeOrigin::signed(ALICE),
+				proposal_id,
+				true,
+				2,
+				Conviction::None
+			));
+
+			// Half of the 200 blocks window is elapsed at block 101
+			System::set_block_number(100);
+			assert_ok!(Voting::vote(
+				RuntimeOrigin::signed(ALICE),
+				proposal_id,
+				true,
+				3,
+				Conviction::None
+			));
+			System::set_block_number(101);
+			assert_noop!(
+				Voting::vote(RuntimeOrigin::signed(ALICE), proposal_id, true, 4, Conviction::None),
+				Error::<Test>::EditWindowClosed
+			);
+
+			// First votes are still accepted
+			assert_ok!(Voting::vote(
+				RuntimeOrigin::signed(BOB),
+				proposal_id,
+				false,
+				2,
+				Conviction::None
+			));
+		})
+	}
+
+	#[test]
+	fn burned_cost_is_not_refunded() {
+		ExtBuilder::new_build(vec![(ALICE, 100)]).execute_with(|| {
+			setup();
+			let issuance = Balances::total_issuance();
+			assert_ok!(ProposalBuilder::new()
+				.start(1)
+				.end(200)
+				.vote_cost(VoteCost::Burned)
+				.execute());
+			let proposal_id = Voting::next_proposal_id() - 1;
+
+			assert_ok!(Voting::vote(
+				RuntimeOrigin::signed(ALICE),
+				proposal_id,
+				true,
+				4,
+				Conviction::None
+			));
+			assert_eq!(Balances::free_balance(ALICE), 84);
+			assert_eq!(Balances::total_issuance(), issuance - 16);
+
+			// Lowering a vote gives nothing back, raising it again pays the difference again
+			assert_ok!(Voting::vote(
+				RuntimeOrigin::signed(ALICE),
+				proposal_id,
+				true,
+				3,
+				Conviction::None
+			));
+			assert_eq!(Balances::free_balance(ALICE), 84);
+			assert_ok!(Voting::vote(
+				RuntimeOrigin::signed(ALICE),
+				proposal_id,
+				true,
+				5,
+				Conviction::None
+			));
+			assert_eq!(Balances::free_balance(ALICE), 68);
+			assert_eq!(Voting::proposals(proposal_id).unwrap().ratio, (25, 25));
+
+			let alice_held_balance = Voting::held_for_voting(&ALICE);
+			assert_eq!(alice_held_balance, 0);
+
+			System::set_block_number(200);
+			assert_ok!(Voting::close_proposal(RuntimeOrigin::signed(ALICE), proposal_id));
+			assert_noop!(
+				Voting::claim(RuntimeOrigin::signed(ALICE), proposal_id),
+				Error::<Test>::ClaimDisabled
+			);
+			Voting::on_idle(200, Weight::MAX);
+			assert_eq!(Voting::votes(ALICE, proposal_id), None);
+			assert_eq!(Voting::closed_vote_costs(proposal_id), None);
+			assert_eq!(Balances::free_balance(ALICE), 68);
+		})
+	}
+
+	#[test]
+	fn treasury_cost_is_paid_to_the_treasury() {
+		ExtBuilder::new_build(vec![(ALICE, 100)]).execute_with(|| {
+			setup();
+			assert_noop!(
+				ProposalBuilder::new()
+					.start(1)
+					.end(200)
+					.shared_budget()
+					.vote_cost(VoteCost::Treasury)
+					.execute(),
+				Error::<Test>::InvalidVoteCost
+			);
+
+			let treasury_balance = Balances::free_balance(TREASURY_ACCOUNT);
+			assert_ok!(ProposalBuilder::new()
+				.start(1)
+				.end(200)
+				.vote_cost(VoteCost::Treasury)
+				.execute());
+			let proposal_id = Voting::next_proposal_id() - 1;
+			assert_ok!(Voting::vote(
+				RuntimeOrigin::signed(ALICE),
+				proposal_id,
+				true,
+				4,
+				Conviction::None
+			));
+			assert_eq!(Balances::free_balance(ALICE), 84);
+			assert_eq!(Balances::free_balance(TREASURY_ACCOUNT), treasury_balance + 16);
+
+			// The cost must leave the voter account alive
+			assert_noop!(
+				Voting::vote(RuntimeOrigin::signed(ALICE), proposal_id, true, 10, Conviction::None),
+				Error::<Test>::InsufficientBalance
+			);
+		})
+	}
+
+	#[test]
+	fn multiple_proposal_votes() {
+		ExtBuilder::new_build(vec![(ALICE, 30)]).execute_with(|| {
+			let proposal_1_start_block = 1;
+			let proposal_1_end_block = 200;
+			let proposal_2_start_block = 10;
+			let proposal_2_end_block = 210;
+			assert_ok!(Voting::register_voter(RuntimeOrigin::root(), ALICE));
+			assert_ok!(ProposalBuilder::new()
+				.start(proposal_1_start_block)
+				.end(proposal_1_end_block)
+				.execute());
+			let proposal_1_id = Voting::next_proposal_id() - 1;
+			assert_ok!(ProposalBuilder::new()
+				.start(proposal_2_start_block)
+				.end(proposal_2_end_block)
+				.execute());
+			let proposal_2_id = Voting::next_proposal_id() - 1;
+
+			let proposal_1_vote_aye = true;
+			let proposal_1_vote_power = 3; // 9 tokens required
+			let proposal_1_quadratic_amount =
+				Voting::calculate_quadratic_amount(proposal_1_vote_power);
+			let proposal_2_vote_aye = false;
+			let proposal_2_vote_power = 4; // 16 tokens required
+			let proposal_2_quadratic_amount =
+				Voting::calculate_quadratic_amount(proposal_2_vote_power);
+
+			// Vote proposal 1
+			assert_ok!(Voting::vote(
+				RuntimeOrigin::signed(ALICE),
+				proposal_1_id,
+				proposal_1_vote_aye,
+				proposal_1_vote_power,
+				Conviction::None
+			));
+
+			System::set_block_number(20);
+
+			// Vote proposal 2
+			assert_ok!(Voting::vote(
+				RuntimeOrigin::signed(ALICE),
+				proposal_2_id,
+				proposal_2_vote_aye,
+				proposal_2_vote_power,
+				Conviction::None
+			));
+
+			// Storage
+			let proposal_1 = Voting::proposals(proposal_1_id);
+			assert_eq!(
+				proposal_1.unwrap().ratio,
+				(proposal_1_quadratic_amount, proposal_1_quadratic_amount)
+			);
+
+			let proposal_2 = Voting::proposals(proposal_2_id);
+			assert_eq!(proposal_2.unwrap().ratio, (0, proposal_2_quadratic_amount));
+
+			let alice_held_balance = Voting::held_for_voting(&ALICE);
+			assert_eq!(
+				alice_held_balance,
+				proposal_1_quadratic_amount.saturating_add(proposal_2_quadratic_amount)
+			);
+
+			let vote_1 = Voting::votes(ALICE, proposal_1_id);
+			assert_eq!(
+				vote_1,
+				Some(VoteInfo {
+					proposal_id: proposal_1_id,
+					aye: proposal_1_vote_aye,
+					power: proposal_1_vote_power,
+					conviction: Conviction::None,
+				})
+			);
+
+			let vote_2 = Voting::votes(ALICE, proposal_2_id);
+			assert_eq!(
+				vote_2,
+				Some(VoteInfo {
+					proposal_id: proposal_2_id,
+					aye: proposal_2_vote_aye,
+					power: proposal_2_vote_power,
+					conviction: Conviction::None,
+				})
+			);
+		})
+	}
+
+	mod claim {
+		use super::*;
+
+		#[test]
+		fn conviction_multiplies_the_tally_and_locks_the_claim() {
+			ExtBuilder::new_build(vec![(ALICE, 30)]).execute_with(|| {
+				setup();
+				assert_ok!(ProposalBuilder::new().start(1).end(200).execute());
+				let proposal_id = Voting::next_proposal_id() - 1;
+				assert_ok!(Voting::vote(
+					RuntimeOrigin::signed(ALICE),
+					proposal_id,
+					true,
+					2,
+					Conviction::Locked3x
+				));
+				assert_eq!(Voting::proposals(proposal_id).unwrap().ratio, (12, 12));
+
+				System::set_block_number(200);
+				assert_ok!(Voting::close_proposal(RuntimeOrigin::signed(ALICE), proposal_id));
+				// The release of the closed proposal leaves the locked collateral held
+				Voting::on_idle(200, Weight::MAX);
+				assert_eq!(Voting::held_for_voting(&ALICE), 4);
+
+				// Held for 2 conviction periods after the close
+				System::set_block_number(200 + 2 * CONVICTION_PERIOD as u64 - 1);
+				assert_noop!(
+					Voting::claim(RuntimeOrigin::signed(ALICE), proposal_id),
+					Error::<Test>::ConvictionLocked
+				);
+				System::set_block_number(200 + 2 * CONVICTION_PERIOD as u64);
+				assert_ok!(Voting::claim(RuntimeOrigin::signed(ALICE), proposal_id));
+				assert_eq!(Voting::held_for_voting(&ALICE), 0);
+			})
+		}
+
+		#[test]
+		fn conviction_needs_a_frozen_cost() {
+			ExtBuilder::new_build(vec![(ALICE, 30)]).execute_with(|| {
+				setup();
+				assert_ok!(ProposalBuilder::new().start(1).end(200).shared_budget().execute());
+				let proposal_id = Voting::next_proposal_id() - 1;
+				assert_noop!(
+					Voting::vote(
+						RuntimeOrigin::signed(ALICE),
+						proposal_id,
+						true,
+						2,
+						Conviction::Locked2x
+					),
+					Error::<Test>::ConvictionNotSupported
+				);
+			})
+		}
+
+		#[test]
+		fn claim_delay_holds_the_collateral_after_the_close() {
+			ExtBuilder::new_build(vec![(ALICE, 30)]).execute_with(|| {
+				setup();
+				assert_noop!(
+					ProposalBuilder::new().start(1).end(200).claim_delay(0).execute(),
+					Error::<Test>::InvalidClaimDelay
+				);
+				assert_noop!(
+					ProposalBuilder::new()
+						.start(1)
+						.end(200)
+						.claim_delay(ARCHIVE_RETENTION as u32 + 1)
+						.execute(),
+					Error::<Test>::InvalidClaimDelay
+				);
+				assert_noop!(
+					ProposalBuilder::new()
+						.start(1)
+						.end(200)
+						.shared_budget()
+						.claim_delay(50)
+						.execute(),
+					Error::<Test>::InvalidVoteCost
+				);
+				assert_ok!(ProposalBuilder::new().start(1).end(200).claim_delay(50).execute());
+				let proposal_id = Voting::next_proposal_id() - 1;
+				assert_ok!(Voting::vote(
+					RuntimeOrigin::signed(ALICE),
+					proposal_id,
+					true,
+					2,
+					Conviction::None
+				));
+
+				System::set_block_number(200);
+				assert_ok!(Voting::close_proposal(RuntimeOrigin::signed(ALICE), proposal_id));
+				assert_eq!(Voting::claims_open_at(proposal_id), Some(250));
+				// The release of the closed proposal leaves the collateral held
+				Voting::on_idle(200, Weight::MAX);
+				assert_eq!(Voting::held_for_voting(&ALICE), 4);
+
+				System::set_block_number(249);
+				assert_noop!(
+					Voting::claim(RuntimeOrigin::signed(ALICE), proposal_id),
+					Error::<Test>::ClaimNotYetAvailable
+				);
+				System::set_block_number(250);
+				assert_ok!(Voting::claim(RuntimeOrigin::signed(ALICE), proposal_id));
+				assert_eq!(Voting::held_for_voting(&ALICE), 0);
+			})
+		}
+
+		#[test]
+		fn account_is_kept_alive_until_the_last_claim() {
+			ExtBuilder::new_build(vec![(ALICE, 30)]).execute_with(|| {
+				setup();
+				assert_ok!(ProposalBuilder::new().start(1).end(200).execute());
+				assert_ok!(ProposalBuilder::new().start(1).end(200).execute());
+				let proposal_id = Voting::next_proposal_id() - 1;
+				assert_eq!(System::consumers(&ALICE), 0);
+				assert_ok!(Voting::vote(
+					RuntimeOrigin::signed(ALICE),
+					proposal_id - 1,
+					true,
+					2,
+					Conviction::None
+				));
+				assert_ok!(Voting::vote(
+					RuntimeOrigin::signed(ALICE),
+					proposal_id,
+					true,
+					2,
+					Conviction::None
+				));
+				assert_eq!(System::consumers(&ALICE), 1);
+
+				System::set_block_number(200);
+				assert_ok!(Voting::close_proposal(RuntimeOrigin::signed(ALICE), proposal_id - 1));
+				assert_ok!(Voting::close_proposal(RuntimeOrigin::signed(ALICE), proposal_id));
+				assert_ok!(Voting::claim(RuntimeOrigin::signed(ALICE), proposal_id - 1));
+				assert_eq!(System::consumers(&ALICE), 1);
+				assert_ok!(Voting::claim(RuntimeOrigin::signed(ALICE), proposal_id));
+				assert_eq!(System::consumers(&ALICE), 0);
+			})
+		}
+
+		#[test]
+		fn unregistered_voter_cannot_claim() {
+			new_test_ext().execute_with(|| {
+				assert_noop!(
+					Voting::claim(RuntimeOrigin::signed(BOB), 1),
+					Error::<Test>::VoterNotRegistered
+				);
+			})
+		}
+
+		#[test]
+		fn cannot_claim_in_progress_proposal() {
+			new_test_ext().execute_with(|| {
+				System::set_block_number(1);
+				setup();
+
+				let start_block = 1;
+				let end_block = 200;
+				assert_ok!(ProposalBuilder::new().start(start_block).end(end_block).execute());
+
+				let proposal_id = Voting::next_proposal_id() - 1;
+
+				assert_noop!(
+					Voting::claim(RuntimeOrigin::signed(ALICE), proposal_id),
+					Error::<Test>::ProposalNotClosed
+				);
+			})
+		}
+
+		#[test]
+		fn claim_work() {
+			ExtBuilder::new_build(vec![(ALICE, 30)]).execute_with(|| {
+				setup();
+
+				let start_block = 1;
+				let end_block = 200;
+				assert_ok!(ProposalBuilder::new().start(start_block).end(end_block).execute());
+
+				System::set_block_number(2);
+				let proposal_id = Voting::next_proposal_id() - 1;
+				let aye = true;
+				let power = 4; // 16 tokens required
+				let quadratic_amount = Voting::calculate_quadratic_amount(power);
+
+				assert_ok!(Voting::vote(
+					RuntimeOrigin::signed(ALICE),
+					proposal_id,
+					aye,
+					power,
+					Conviction::None
+				));
+
+				System::set_block_number(201);
+
+				assert_ok!(Voting::close_proposal(RuntimeOrigin::signed(ALICE), proposal_id));
+
+				let alice_held_balance = Voting::held_for_voting(&ALICE);
+				assert_eq!(alice_held_balance, quadratic_amount);
+
+				assert_ok!(Voting::claim(RuntimeOrigin::signed(ALICE), proposal_id));
+
+				let alice_held_balance = Voting::held_for_voting(&ALICE);
+				assert_eq!(alice_held_balance, 0);
+
+				// Storage
+				let vote = Voting::votes(ALICE, proposal_id);
+				assert_eq!(vote, None);
+
+				// Event
+				System::assert_last_event(
+					Event::BalanceClaimed { who: ALICE, amount: quadratic_amount }.into(),
+				);
+			})
+		}
+
+		#[test]
+		fn claim_burns_the_release_fee() {
+			ExtBuilder::new_build(vec![(ALICE, 100)]).execute_with(|| {
+				setup();
+				assert_ok!(ProposalBuilder::new()
+					.start(1)
+					.end(200)
+					.vote_cost(VoteCost::PartiallyBurned(Percent::from_percent(25)))
+					.execute());
+				let proposal_id = Voting::next_proposal_id() - 1;
+				assert_ok!(Voting::vote(
+					RuntimeOrigin::signed(ALICE),
+					proposal_id,
+					true,
+					4,
+					Conviction::None
+				));
+				// The collateral is held, it leaves the free balance
+				assert_eq!(Balances::free_balance(ALICE), 84);
+				assert_eq!(Voting::held_for_voting(&ALICE), 16);
+
+				// Lowering the vote from 16 to 4 burns 25% of the 12 released
+				assert_ok!(Voting::vote(
+					RuntimeOrigin::signed(ALICE),
+					proposal_id,
+					true,
+					2,
+					Conviction::None
+				));
+				assert_eq!(Balances::free_balance(ALICE), 93);
+
+				System::set_block_number(200);
+				assert_ok!(Voting::close_proposal(RuntimeOrigin::signed(ALICE), proposal_id));
+				assert_ok!(Voting::claim(RuntimeOrigin::signed(ALICE), proposal_id));
+				assert_eq!(Balances::free_balance(ALICE), 96);
+				System::assert_last_event(Event::BalanceClaimed { who: ALICE, amount: 3 }.into());
+			})
+		}
+
+		#[test]
+		fn claim_to_beneficiary() {
+			ExtBuilder::new_build(vec![(ALICE, 30)]).execute_with(|| {
+				setup();
+				assert_ok!(ProposalBuilder::new().start(1).end(200).execute());
+				let proposal_id = Voting::next_proposal_id() - 1;
+				assert_ok!(Voting::vote(
+					RuntimeOrigin::signed(ALICE),
+					proposal_id,
+					true,
+					4,
+					Conviction::None
+				));
+
+				System::set_block_number(201);
+				assert_ok!(Voting::close_proposal(RuntimeOrigin::signed(ALICE), proposal_id));
+
+				assert_noop!(
+					Voting::claim_to(RuntimeOrigin::signed(BOB), proposal_id, BOB),
+					Error::<Test>::VoterNotRegistered
+				);
+				assert_ok!(Voting::claim_to(RuntimeOrigin::signed(ALICE), proposal_id, BOB));
+				assert_eq!(Voting::votes(ALICE, proposal_id), None);
+				System::assert_last_event(
+					Event::BalanceClaimedTo { who: ALICE, beneficiary: BOB, amount: 16 }.into(),
+				);
+			})
+		}
+
+		#[test]
+		fn claim_for_voter_after_grace_period() {
+			ExtBuilder::new_build(vec![(ALICE, 30)]).execute_with(|| {
+				setup();
+				assert_ok!(ProposalBuilder::new().start(1).end(200).execute());
+				let proposal_id = Voting::next_proposal_id() - 1;
+				assert_ok!(Voting::vote(
+					RuntimeOrigin::signed(ALICE),
+					proposal_id,
+					true,
+					4,
+					Conviction::None
+				));
+
+				assert_noop!(
+					Voting::claim_for(RuntimeOrigin::signed(BOB), ALICE, proposal_id),
+					Error::<Test>::ProposalNotClosed
+				);
+
+				System::set_block_number(201);
+				assert_ok!(Voting::close_proposal(RuntimeOrigin::signed(ALICE), proposal_id));
+
+				System::set_block_number(201 + CLAIM_GRACE_PERIOD as u64 - 1);
+				assert_noop!(
+					Voting::claim_for(RuntimeOrigin::signed(BOB), ALICE, proposal_id),
+					Error::<Test>::ClaimGracePeriodNotElapsed
+				);
+
+				System::set_block_number(201 + CLAIM_GRACE_PERIOD as u64);
+				assert_ok!(Voting::claim_for(RuntimeOrigin::signed(BOB), ALICE, proposal_id));
+				assert_eq!(Voting::votes(ALICE, proposal_id), None);
+				assert_eq!(Balances::free_balance(BOB), 0);
+				System::assert_last_event(
+					Event::BalanceClaimedFor { who: ALICE, caller: BOB, amount: 16 }.into(),
+				);
+			})
+		}
+	}
+}
+
+mod migrations {
+	use super::*;
+	use crate::{
+		migrations::{
+			v1::MigrateToV1, v10::MigrateToV10, v11::MigrateToV11, v12::MigrateToV12,
+			v2::MigrateToV2, v3::MigrateToV3, v4::MigrateToV4, v5::MigrateToV5, v6::MigrateToV6,
+			v7::MigrateToV7, v8::MigrateToV8, v9::MigrateToV9,
+		},
+		ArchivedProposal, DelegateCasts, Delegations, Delegators, FreezeReason, MigrationCursor,
+		OutcomeOverride, ProposalOutcome, ProposalStatus, ProposalStatuses, VoteInfo,
+	};
+	use codec::Encode;
+	use frame_support::{
+		storage::unhashed,
+		traits::{
+			fungible::{InspectFreeze, MutateFreeze},
+			Bounded, GetStorageVersion, OnRuntimeUpgrade, QueryPreimage, StorageVersion,
+		},
+	};
+	use sp_runtime::traits::{BlakeTwo256, Hash};
+
+	#[test]
+	fn v1_keeps_the_votes_of_a_u128_balance() {
+		ExtBuilder::new_build(vec![(ALICE, 30)]).execute_with(|| {
+			setup();
+			assert_ok!(ProposalBuilder::new().start(1).end(200).shared_budget().execute());
+			let proposal_id = Voting::next_proposal_id() - 1;
+			assert_ok!(Voting::vote(
+				RuntimeOrigin::signed(ALICE),
+				proposal_id,
+				true,
+				4,
+				Conviction::None
+			));
+			StorageVersion::new(0).put::<Voting>();
+
+			MigrateToV1::<Test>::on_runtime_upgrade();
+
+			assert_eq!(Voting::on_chain_storage_version(), 1);
+			assert_eq!(
+				Voting::votes(ALICE, proposal_id),
+				Some(VoteInfo { proposal_id, aye: true, power: 4, conviction: Conviction::None })
+			);
+			assert_eq!(Voting::spent_credits(ALICE), (0, 16));
+		})
+	}
+
+	#[test]
+	fn v2_adds_no_override_to_the_archives() {
+		new_test_ext().execute_with(|| {
+			let outcome = ProposalOutcome::Completed { ratio: (4, 4), approved: true };
+			let key = pallet_voting::ArchivedProposals::<Test>::hashed_key_for(0);
+			unhashed::put_raw(&key, &(ALICE, outcome, 7u32).encode());
+			StorageVersion::new(1).put::<Voting>();
+
+			MigrateToV2::<Test>::on_runtime_upgrade();
+
+			assert_eq!(Voting::on_chain_storage_version(), 2);
+			// The archive has the layout of v2, whatever the current one
+			let archive = (ALICE, outcome, 7u32, None::<(OutcomeOverride, u64)>);
+			assert_eq!(unhashed::get_raw(&key), Some(archive.encode()));
+		})
+	}
+
+	#[test]
+	fn v3_adds_a_consumer_reference_to_frozen_accounts() {
+		ExtBuilder::new_build(vec![(ALICE, 30), (BOB, 30)]).execute_with(|| {
+			// Before the upgrade, the collateral was frozen without a reference
+			for proposal_id in [0, 1] {
+				let vote =
+					VoteInfo { proposal_id, aye: true, power: 2, conviction: Conviction::None };
+				pallet_voting::Votes::<Test>::insert(ALICE, proposal_id, vote);
+				pallet_voting::Contributions::<Test>::insert(proposal_id, ALICE, (true, 4));
+			}
+			let voting = RuntimeFreezeReason::from(FreezeReason::Voting);
+			assert_ok!(Balances::set_freeze(&voting, &ALICE, 8));
+			// A shared budget vote froze nothing
+			let vote =
+				VoteInfo { proposal_id: 2, aye: true, power: 2, conviction: Conviction::None };
+			pallet_voting::Votes::<Test>::insert(BOB, 2, vote);
+			pallet_voting::Contributions::<Test>::insert(2, BOB, (true, 4));
+			StorageVersion::new(2).put::<Voting>();
+
+			MigrateToV3::<Test>::on_runtime_upgrade();
+
+			assert_eq!(Voting::on_chain_storage_version(), 3);
+			assert_eq!(System::consumers(&ALICE), 1);
+			assert_eq!(System::consumers(&BOB), 0);
+		})
+	}
+
+	#[test]
+	fn v3_finds_the_voters_without_a_contribution() {
+		ExtBuilder::new_build(vec![(BOB, 30), (CHARLIE, 30), (DAVE, 30)]).execute_with(|| {
+			let vote =
+				VoteInfo { proposal_id: 0, aye: true, power: 2, conviction: Conviction::None };
+			// An option chosen on a multi-option proposal
+			pallet_voting::Votes::<Test>::insert(BOB, 0, vote.clone());
+			pallet_voting::OptionChoices::<Test>::insert(0, BOB, (1, 4));
+			// A commitment not revealed yet
+			pallet_voting::Commitments::<Test>::insert(0, CHARLIE, (Default::default(), 2));
+			// A vote left on a closed proposal until it is claimed, in the layout of v1
+			pallet_voting::VoterIndices::<Test>::insert(DAVE, 0);
+			let key = pallet_voting::Votes::<Test>::hashed_key_for(DAVE, 0);
+			unhashed::put_raw(&key, &(0u32, true, 2u128).encode());
+			let voting = RuntimeFreezeReason::from(FreezeReason::Voting);
+			for voter in [BOB, CHARLIE, DAVE] {
+				assert_ok!(Balances::set_freeze(&voting, &voter, 4));
+			}
+			StorageVersion::new(2).put::<Voting>();
+
+			MigrateToV3::<Test>::on_runtime_upgrade();
+
+			for voter in [BOB, CHARLIE, DAVE] {
+				assert_eq!(System::consumers(&voter), 1);
+			}
+		})
+	}
+
+	#[test]
+	fn v4_records_the_delegates_of_the_delegated_votes() {
+		new_test_ext().execute_with(|| {
+			// ALICE -> BOB -> CHARLIE, CHARLIE voted for ALICE
+			Delegations::<Test>::insert(ALICE, BOB);
+			Delegations::<Test>::insert(BOB, CHARLIE);
+			let key = pallet_voting::DelegatedVotes::<Test>::hashed_key_for(0, ALICE);
+			unhashed::put_raw(&key, &2u32.encode());
+			StorageVersion::new(3).put::<Voting>();
+
+			MigrateToV4::<Test>::on_runtime_upgrade();
+
+			assert_eq!(Voting::on_chain_storage_version(), 4);
+			assert_eq!(Voting::delegated_votes(0, ALICE), Some((CHARLIE, 2)));
+			assert!(DelegateCasts::<Test>::contains_key(CHARLIE, (0, ALICE)));
+			assert!(Delegators::<Test>::contains_key(BOB, ALICE));
+			assert!(Delegators::<Test>::contains_key(CHARLIE, BOB));
+		})
+	}
+
+	#[test]
+	fn v5_records_the_status_of_the_proposals() {
+		ExtBuilder::new_build(vec![(ALICE, 10)]).execute_with(|| {
+			setup();
+			assert_ok!(ProposalBuilder::new().start(1).end(200).execute());
+			assert_ok!(ProposalBuilder::new().start(50).end(200).execute());
+			let pending_id = Voting::next_proposal_id() - 1;
+			let active_id = pending_id - 1;
+			let outcome = ProposalOutcome::Expired;
+			// The archives had the layout of v2
+			let key = pallet_voting::ArchivedProposals::<Test>::hashed_key_for(7);
+			unhashed::put_raw(
+				&key,
+				&(ALICE, outcome, 1u32, None::<(OutcomeOverride, u64)>).encode(),
+			);
+			let _ = ProposalStatuses::<Test>::clear(u32::MAX, None);
+			StorageVersion::new(4).put::<Voting>();
+
+			MigrateToV5::<Test>::on_runtime_upgrade();
+
+			assert_eq!(Voting::on_chain_storage_version(), 5);
+			assert_eq!(Voting::proposal_status(active_id), Some(ProposalStatus::Active));
+			assert_eq!(Voting::proposal_status(pending_id), Some(ProposalStatus::Pending));
+			assert_eq!(Voting::proposal_status(7), Some(ProposalStatus::Closed { outcome }));
+		})
+	}
+
+	#[test]
+	fn v6_adds_no_turnout_scale_to_the_archives() {
+		new_test_ext().execute_with(|| {
+			let outcome = ProposalOutcome::Completed { ratio: (4, 4), approved: true };
+			let overridden = Some((OutcomeOverride::Veto, BOB));
+			let key = pallet_voting::ArchivedProposals::<Test>::hashed_key_for(0);
+			unhashed::put_raw(&key, &(ALICE, outcome, 7u32, overridden.clone()).encode());
+			StorageVersion::new(5).put::<Voting>();
+
+			MigrateToV6::<Test>::on_runtime_upgrade();
+
+			assert_eq!(Voting::on_chain_storage_version(), 6);
+			let archive = (ALICE, outcome, 7u32, overridden, None::<Perbill>);
+			assert_eq!(unhashed::get_raw(&key), Some(archive.encode()));
+		})
+	}
+
+	#[test]
+	fn v7_moves_the_frozen_collateral_on_hold() {
+		ExtBuilder::new_build(vec![(ALICE, 30)]).execute_with(|| {
+			for proposal_id in [0, 1] {
+				let vote =
+					VoteInfo { proposal_id, aye: true, power: 2, conviction: Conviction::None };
+				pallet_voting::Votes::<Test>::insert(ALICE, proposal_id, vote);
+				pallet_voting::Contributions::<Test>::insert(proposal_id, ALICE, (true, 4));
+			}
+			let voting = RuntimeFreezeReason::from(FreezeReason::Voting);
+			assert_ok!(Balances::set_freeze(&voting, &ALICE, 8));
+			assert_ok!(System::inc_consumers(&ALICE));
+			StorageVersion::new(6).put::<Voting>();
+
+			MigrateToV7::<Test>::on_runtime_upgrade();
+
+			assert_eq!(Voting::on_chain_storage_version(), 7);
+			assert_eq!(Balances::balance_frozen(&voting, &ALICE), 0);
+			assert_eq!(Voting::held_for_voting(&ALICE), 8);
+			assert_eq!(Balances::free_balance(ALICE), 22);
+			assert_eq!(System::consumers(&ALICE), 1);
+		})
+	}
+
+	#[test]
+	fn v7_moves_the_collateral_of_the_votes_without_a_contribution() {
+		ExtBuilder::new_build(vec![(CHARLIE, 30), (DAVE, 30)]).execute_with(|| {
+			// A commitment not revealed yet
+			pallet_voting::Commitments::<Test>::insert(0, CHARLIE, (Default::default(), 2));
+			// A vote left on a closed proposal until it is claimed, in the layout of v1
+			pallet_voting::RegisteredVoters::<Test>::insert(
+				DAVE,
+				VoterInfo { registered_at: 0, expires_at: None },
+			);
+			let key = pallet_voting::Votes::<Test>::hashed_key_for(DAVE, 0);
+			unhashed::put_raw(&key, &(0u32, true, 2u128).encode());
+			let voting = RuntimeFreezeReason::from(FreezeReason::Voting);
+			for voter in [CHARLIE, DAVE] {
+				assert_ok!(Balances::set_freeze(&voting, &voter, 4));
+				assert_ok!(System::inc_consumers(&voter));
+			}
+			StorageVersion::new(6).put::<Voting>();
+
+			MigrateToV7::<Test>::on_runtime_upgrade();
+
+			for voter in [CHARLIE, DAVE] {
+				assert_eq!(Balances::balance_frozen(&voting, &voter), 0);
+				assert_eq!(Voting::held_for_voting(&voter), 4);
+			}
+		})
+	}
+
+	#[test]
+	fn v8_adds_no_abstentions_to_the_archives() {
+		new_test_ext().execute_with(|| {
+			let outcome = ProposalOutcome::Completed { ratio: (4, 4), approved: true };
+			let turnout_scale = Some(Perbill::from_percent(50));
+			let key = pallet_voting::ArchivedProposals::<Test>::hashed_key_for(0);
+			let old = (ALICE, outcome, 7u32, None::<(OutcomeOverride, u64)>, turnout_scale);
+			unhashed::put_raw(&key, &old.encode());
+			StorageVersion::new(7).put::<Voting>();
+
+			MigrateToV8::<Test>::on_runtime_upgrade();
+
+			assert_eq!(Voting::on_chain_storage_version(), 8);
+			let archive =
+				(ALICE, outcome, 7u32, None::<(OutcomeOverride, u64)>, turnout_scale, None::<u32>);
+			assert_eq!(unhashed::get_raw(&key), Some(archive.encode()));
+		})
+	}
+
+	#[test]
+	fn v9_adds_no_conviction_to_the_votes() {
+		ExtBuilder::new_build(vec![(ALICE, 30)]).execute_with(|| {
+			setup();
+			assert_ok!(ProposalBuilder::new().start(1).end(200).execute());
+			let proposal_id = Voting::next_proposal_id() - 1;
+			assert_ok!(Voting::vote(
+				RuntimeOrigin::signed(ALICE),
+				proposal_id,
+				true,
+				2,
+				Conviction::None
+			));
+			let root = Voting::votes_tree(proposal_id).root;
+			let old = (proposal_id, true, 2u128).encode();
+			unhashed::put_raw(
+				&pallet_voting::Votes::<Test>::hashed_key_for(ALICE, proposal_id),
+				&old,
+			);
+			let key = pallet_voting::VoteLog::<Test>::hashed_key_for(proposal_id, 0);
+			unhashed::put_raw(&key, &(ALICE, proposal_id, true, 2u128).encode());
+			pallet_voting::VotesTrees::<Test>::mutate(proposal_id, |tree| tree.root = [0; 32]);
+			StorageVersion::new(8).put::<Voting>();
+
+			MigrateToV9::<Test>::on_runtime_upgrade();
+			assert_eq!(Voting::on_chain_storage_version(), 8);
+			assert!(Voting::migrating());
+
+			Voting::on_idle(1, Weight::MAX);
+
+			// The migrations following the stepped one are run once it completes
+			assert!(!Voting::migrating());
+			assert_eq!(Voting::on_chain_storage_version(), Voting::current_storage_version());
+			let vote = VoteInfo { proposal_id, aye: true, power: 2, conviction: Conviction::None };
+			assert_eq!(Voting::votes(ALICE, proposal_id), Some(vote.clone()));
+			assert_eq!(Voting::vote_log(proposal_id, 0), Some((ALICE, vote)));
+			assert_eq!(Voting::votes_tree(proposal_id).root, root);
+		})
+	}
+
+	#[test]
+	fn v9_migrates_one_entry_per_cleanup_step() {
+		ExtBuilder::new_build(vec![(ALICE, 30), (BOB, 30)]).execute_with(|| {
+			setup();
+			assert_ok!(ProposalBuilder::new().start(1).end(101).execute());
+			let proposal_id = Voting::next_proposal_id() - 1;
+			for who in [ALICE, BOB] {
+				assert_ok!(Voting::vote(
+					RuntimeOrigin::signed(who),
+					proposal_id,
+					true,
+					2,
+					Conviction::None
+				));
+			}
+			let root = Voting::votes_tree(proposal_id).root;
+			for (index, who) in [ALICE, BOB].into_iter().enumerate() {
+				unhashed::put_raw(
+					&pallet_voting::Votes::<Test>::hashed_key_for(who, proposal_id),
+					&(proposal_id, true, 2u128).encode(),
+				);
+				unhashed::put_raw(
+					&pallet_voting::VoteLog::<Test>::hashed_key_for(proposal_id, index as u32),
+					&(who, proposal_id, true, 2u128).encode(),
+				);
+			}
+			pallet_voting::VotesTrees::<Test>::mutate(proposal_id, |tree| tree.root = [0; 32]);
+			StorageVersion::new(8).put::<Voting>();
+			MigrateToV9::<Test>::on_runtime_upgrade();
+
+			// The mock database weights are zero, a cleanup step weighs its base weight
+			let step = Weight::from_parts(10_000, 0);
+			let vote = VoteInfo { proposal_id, aye: true, power: 2, conviction: Conviction::None };
+			let migrated = || {
+				[ALICE, BOB]
+					.into_iter()
+					.filter(|who| Voting::votes(who, proposal_id) == Some(vote.clone()))
+					.count()
+			};
+			Voting::on_idle(1, step);
+			assert_eq!(migrated(), 1);
+			Voting::on_idle(2, step);
+			assert_eq!(migrated(), 2);
+			// Moving to the log, two entries, moving to the tree, two leaves
+			for block in 3..9 {
+				Voting::on_idle(block, step);
+				assert!(Voting::migrating());
+				assert_eq!(Voting::on_chain_storage_version(), 8);
+			}
+			// The hooks are suspended meanwhile
+			Voting::on_initialize(101);
+			assert!(Voting::proposals(proposal_id).is_some());
+			// Storing the tree completes the migration
+			Voting::on_idle(9, step);
+
+			assert!(!Voting::migrating());
+			assert_eq!(Voting::on_chain_storage_version(), Voting::current_storage_version());
+			assert_eq!(Voting::vote_log(proposal_id, 1), Some((BOB, vote)));
+			assert_eq!(Voting::votes_tree(proposal_id).root, root);
+		})
+	}
+
+	#[test]
+	fn stepped_migrations_are_not_restarted() {
+		new_test_ext().execute_with(|| {
+			StorageVersion::new(8).put::<Voting>();
+			MigrateToV9::<Test>::on_runtime_upgrade();
+			let cursor = MigrationCursor::Translate {
+				map: 1,
+				last_key: BoundedVec::truncate_from(vec![1, 2, 3]),
+			};
+			pallet_voting::OngoingMigration::<Test>::put((9, cursor.clone()));
+
+			MigrateToV9::<Test>::on_runtime_upgrade();
+
+			assert_eq!(Voting::ongoing_migration(), Some((9, cursor)));
+		})
+	}
+
+	#[test]
+	fn v10_notes_the_calls_too_large_to_be_inlined() {
+		new_test_ext().execute_with(|| {
+			let (short, long) = (vec![1u8; 10], vec![2u8; 200]);
+			let key = pallet_voting::ProposalCalls::<Test>::hashed_key_for(0);
+			unhashed::put_raw(&key, &short.encode());
+			let key = pallet_voting::ProposalCalls::<Test>::hashed_key_for(1);
+			unhashed::put_raw(&key, &long.encode());
+			StorageVersion::new(9).put::<Voting>();
+
+			MigrateToV10::<Test>::on_runtime_upgrade();
+
+			assert_eq!(Voting::on_chain_storage_version(), 10);
+			assert_eq!(Voting::proposal_calls(0), Some(Bounded::Inline(short.try_into().unwrap())));
+			let hash = BlakeTwo256::hash(&long);
+			assert_eq!(Voting::proposal_calls(1), Some(Bounded::Lookup { hash, len: 200 }));
+			assert!(TestPreimages::is_requested(&hash));
+			assert_eq!(TestPreimages::len(&hash), Some(200));
+		})
+	}
+
+	#[test]
+	fn v11_adds_no_voter_cap_to_the_archives() {
+		new_test_ext().execute_with(|| {
+			let outcome = ProposalOutcome::Completed { ratio: (4, 4), approved: true };
+			let key = pallet_voting::ArchivedProposals::<Test>::hashed_key_for(0);
+			let old =
+				(ALICE, outcome, 7u32, None::<(OutcomeOverride, u64)>, None::<Perbill>, Some(2u32));
+			unhashed::put_raw(&key, &old.encode());
+			StorageVersion::new(10).put::<Voting>();
+
+			MigrateToV11::<Test>::on_runtime_upgrade();
+
+			assert_eq!(Voting::on_chain_storage_version(), 11);
+			let archive = (
+				ALICE,
+				outcome,
+				7u32,
+				None::<(OutcomeOverride, u64)>,
+				None::<Perbill>,
+				Some(2u32),
+				None::<(u32, u32)>,
+			);
+			assert_eq!(unhashed::get_raw(&key), Some(archive.encode()));
+		})
+	}
+
+	#[test]
+	fn v12_adds_no_enactment_failure_to_the_archives() {
+		new_test_ext().execute_with(|| {
+			let outcome = ProposalOutcome::Completed { ratio: (4, 4), approved: true };
+			let key = pallet_voting::ArchivedProposals::<Test>::hashed_key_for(0);
+			let old = (
+				ALICE,
+				outcome,
+				7u32,
+				None::<(OutcomeOverride, u64)>,
+				None::<Perbill>,
+				None::<u32>,
+				Some((2u32, 3u32)),
+			);
+			unhashed::put_raw(&key, &old.encode());
+			StorageVersion::new(11).put::<Voting>();
+
+			MigrateToV12::<Test>::on_runtime_upgrade();
+
+			assert_eq!(Voting::on_chain_storage_version(), 12);
+			assert_eq!(
+				Voting::archived_proposals(0),
+				Some(ArchivedProposal {
+					creator: ALICE,
+					outcome,
+					archived_at: 7,
+					overridden: None,
+					turnout_scale: None,
+					abstentions: None,
+					voter_cap: Some((2, 3)),
+					enactment_failure: None,
+				})
+			);
+		})
+	}
+}
 
-			let proposal_1_vote_aye = true;
-			let proposal_1_vote_power = 3; // 9 tokens required
-			let proposal_1_quadratic_amount =
-				Voting::calculate_quadratic_amount(proposal_1_vote_power);
-			let proposal_2_vote_aye = false;
-			let proposal_2_vote_power = 4; // 16 tokens required
-			let proposal_2_quadratic_amount =
-				Voting::calculate_quadratic_amount(proposal_2_vote_power);
+mod cleanup {
+	use super::*;
+	use crate::{
+		verify_archive, verify_vote, CleanupLane, CleanupTask, CompressedArchive, ProposalOutcome,
+	};
 
-			// Vote proposal 1
+	fn held_balance(who: u64) -> u128 {
+		Voting::held_for_voting(&who)
+	}
+
+	#[test]
+	fn releases_freezes_of_closed_proposals() {
+		ExtBuilder::new_build(vec![(ALICE, 30), (BOB, 30)]).execute_with(|| {
+			setup();
+			assert_ok!(Voting::register_voter(RuntimeOrigin::root(), BOB));
+			assert_ok!(ProposalBuilder::new().start(1).end(200).execute());
+			let proposal_id = Voting::next_proposal_id() - 1;
 			assert_ok!(Voting::vote(
 				RuntimeOrigin::signed(ALICE),
-				proposal_1_id,
-				proposal_1_vote_aye,
-				proposal_1_vote_power
+				proposal_id,
+				true,
+				4,
+				Conviction::None
 			));
-
-			System::set_block_number(20);
-
-			// Vote proposal 2
 			assert_ok!(Voting::vote(
-				RuntimeOrigin::signed(ALICE),
-				proposal_2_id,
-				proposal_2_vote_aye,
-				proposal_2_vote_power
+				RuntimeOrigin::signed(BOB),
+				proposal_id,
+				false,
+				3,
+				Conviction::None
 			));
 
-			// Storage
-			let proposal_1 = Voting::proposals(proposal_1_id);
-			assert_eq!(
-				proposal_1.unwrap().ratio,
-				(proposal_1_quadratic_amount, proposal_1_quadratic_amount)
-			);
-
-			let proposal_2 = Voting::proposals(proposal_2_id);
-			assert_eq!(proposal_2.unwrap().ratio, (0, proposal_2_quadratic_amount));
+			System::set_block_number(200);
+			assert_ok!(Voting::close_proposal(RuntimeOrigin::signed(ALICE), proposal_id));
+			// Bob claims before the queue reaches him
+			assert_ok!(Voting::claim(RuntimeOrigin::signed(BOB), proposal_id));
+			assert_eq!(held_balance(ALICE), 16);
 
-			let alice_frozen_balance = <<Test as crate::Config>::NativeBalance as Inspect<
-				<Test as frame_system::Config>::AccountId,
-			>>::balance_frozen(&freeze_id, &ALICE);
-			assert_eq!(
-				alice_frozen_balance,
-				proposal_1_quadratic_amount.saturating_add(proposal_2_quadratic_amount)
-			);
+			Voting::on_idle(200, Weight::MAX);
 
-			let vote_1 = Voting::votes(ALICE, proposal_1_id);
-			assert_eq!(
-				vote_1,
-				Some(VoteInfo {
-					proposal_id: proposal_1_id,
-					aye: proposal_1_vote_aye,
-					power: proposal_1_vote_power
-				})
-			);
+			assert_eq!(held_balance(ALICE), 0);
+			assert_eq!(held_balance(BOB), 0);
+			assert_eq!(Voting::votes(ALICE, proposal_id), None);
+			assert_eq!(Voting::contributions(proposal_id, ALICE), None);
+			assert_eq!(Voting::contributions(proposal_id, BOB), None);
+			System::assert_has_event(Event::BalanceClaimed { who: ALICE, amount: 16 }.into());
 
-			let vote_2 = Voting::votes(ALICE, proposal_2_id);
-			assert_eq!(
-				vote_2,
-				Some(VoteInfo {
-					proposal_id: proposal_2_id,
-					aye: proposal_2_vote_aye,
-					power: proposal_2_vote_power
-				})
-			);
+			System::set_block_number(201);
+			Voting::on_idle(201, Weight::MAX);
+			assert_eq!(Voting::cleanup_queue(CleanupLane::Immediate, 0), None);
 		})
 	}
 
-	mod claim {
-		use super::*;
+	#[test]
+	fn releases_are_limited_per_block() {
+		ExtBuilder::new_build(vec![(ALICE, 30), (BOB, 30), (CHARLIE, 30)]).execute_with(|| {
+			for voter in [ALICE, BOB, CHARLIE] {
+				assert_ok!(Voting::register_voter(RuntimeOrigin::root(), voter));
+			}
+			assert_ok!(ProposalBuilder::new().start(1).end(200).execute());
+			let proposal_id = Voting::next_proposal_id() - 1;
+			for voter in [ALICE, BOB, CHARLIE] {
+				assert_ok!(Voting::vote(
+					RuntimeOrigin::signed(voter),
+					proposal_id,
+					true,
+					2,
+					Conviction::None
+				));
+			}
 
-		#[test]
-		fn unregistered_voter_cannot_claim() {
-			new_test_ext().execute_with(|| {
-				assert_noop!(
-					Voting::claim(RuntimeOrigin::signed(BOB), 1),
-					Error::<Test>::VoterNotRegistered
-				);
-			})
-		}
+			System::set_block_number(200);
+			assert_ok!(Voting::close_proposal(RuntimeOrigin::signed(ALICE), proposal_id));
 
-		#[test]
-		fn cannot_claim_in_progress_proposal() {
-			new_test_ext().execute_with(|| {
-				System::set_block_number(1);
-				setup();
+			// Only `MaxReleasesPerBlock` voters are released per block
+			Voting::on_idle(200, Weight::MAX);
+			System::assert_last_event(Event::FreezesReleased { proposal_id, released: 2 }.into());
+			let still_held = [ALICE, BOB, CHARLIE]
+				.into_iter()
+				.filter(|voter| held_balance(*voter) > 0)
+				.count();
+			assert_eq!(still_held, 1);
 
-				let start_block = 1;
-				let end_block = 200;
-				assert_ok!(ProposalBuilder::new().start(start_block).end(end_block).execute());
+			System::set_block_number(201);
+			Voting::on_idle(201, Weight::MAX);
+			System::assert_has_event(Event::FreezesReleased { proposal_id, released: 1 }.into());
+			System::assert_last_event(Event::FreezesReleaseCompleted { proposal_id }.into());
+			for voter in [ALICE, BOB, CHARLIE] {
+				assert_eq!(held_balance(voter), 0);
+			}
+		})
+	}
 
-				let proposal_id = Voting::next_proposal_id() - 1;
+	#[test]
+	fn drains_votes_of_unregistered_voters() {
+		ExtBuilder::new_build(vec![(ALICE, 30)]).execute_with(|| {
+			setup();
+			assert_ok!(ProposalBuilder::new().start(1).end(200).execute());
+			let proposal_id = Voting::next_proposal_id() - 1;
+			assert_ok!(Voting::vote(
+				RuntimeOrigin::signed(ALICE),
+				proposal_id,
+				true,
+				4,
+				Conviction::None
+			));
 
-				assert_noop!(
-					Voting::claim(RuntimeOrigin::signed(ALICE), proposal_id),
-					Error::<Test>::ProposalNotClosed
-				);
-			})
-		}
+			assert_ok!(Voting::unregister_voter(RuntimeOrigin::signed(ALICE), ALICE));
+			assert_eq!(
+				Voting::cleanup_queue(CleanupLane::Immediate, 0),
+				Some((1, CleanupTask::DrainVoter { who: ALICE }))
+			);
+			assert_eq!(Voting::proposals(proposal_id).unwrap().ratio, (16, 16));
 
-		#[test]
-		fn claim_work() {
-			ExtBuilder::new_build(vec![(ALICE, 30)]).execute_with(|| {
-				let freeze_id: () =
-					<<Test as pallet_voting::Config>::FreezeIdForPallet as Get<_>>::get();
-				setup();
+			Voting::on_idle(1, Weight::MAX);
 
-				let start_block = 1;
-				let end_block = 200;
-				assert_ok!(ProposalBuilder::new().start(start_block).end(end_block).execute());
+			let proposal = Voting::proposals(proposal_id).unwrap();
+			assert_eq!(proposal.ratio, (0, 0));
+			assert_eq!(proposal.voters_count, 0);
+			assert_eq!(held_balance(ALICE), 0);
+			assert_eq!(Voting::votes(ALICE, proposal_id), None);
+			assert_eq!(Voting::cleanup_queue_bounds(CleanupLane::Immediate), (1, 1));
+		})
+	}
 
-				System::set_block_number(2);
+	#[test]
+	fn drains_votes_before_the_voter_registers_again() {
+		ExtBuilder::new_build(vec![(ALICE, 30)]).execute_with(|| {
+			setup();
+			for _ in 0..2 {
+				assert_ok!(ProposalBuilder::new().start(1).end(200).execute());
 				let proposal_id = Voting::next_proposal_id() - 1;
-				let aye = true;
-				let power = 4; // 16 tokens required
-				let quadratic_amount = Voting::calculate_quadratic_amount(power);
+				assert_ok!(Voting::vote(
+					RuntimeOrigin::signed(ALICE),
+					proposal_id,
+					true,
+					2,
+					Conviction::None
+				));
+			}
+
+			assert_ok!(Voting::unregister_voter(RuntimeOrigin::signed(ALICE), ALICE));
+			assert_eq!(pallet_voting::DrainCursors::<Test>::get(ALICE), Some(Default::default()));
+			assert_noop!(
+				Voting::register_voter(RuntimeOrigin::root(), ALICE),
+				Error::<Test>::VotesDraining
+			);
 
-				assert_ok!(Voting::vote(RuntimeOrigin::signed(ALICE), proposal_id, aye, power));
+			Voting::on_idle(1, Weight::MAX);
 
-				System::set_block_number(201);
+			assert_eq!(pallet_voting::DrainCursors::<Test>::get(ALICE), None);
+			assert_eq!(held_balance(ALICE), 0);
+			assert_ok!(Voting::register_voter(RuntimeOrigin::root(), ALICE));
+		})
+	}
 
-				assert_ok!(Voting::close_proposal(RuntimeOrigin::signed(ALICE), proposal_id));
+	#[test]
+	fn prunes_archive_after_retention() {
+		new_test_ext().execute_with(|| {
+			System::set_block_number(1);
+			setup();
+			assert_ok!(ProposalBuilder::new().start(10).end(200).execute());
+			let proposal_id = Voting::next_proposal_id() - 1;
+			assert_ok!(Voting::cancel_proposal(
+				RuntimeOrigin::signed(ALICE),
+				proposal_id,
+				CancellationReason::CreatorWithdrawn
+			));
 
-				let alice_frozen_balance = <<Test as crate::Config>::NativeBalance as Inspect<
-					<Test as frame_system::Config>::AccountId,
-				>>::balance_frozen(&freeze_id, &ALICE);
-				assert_eq!(alice_frozen_balance, quadratic_amount);
+			let retention = <Test as pallet_voting::Config>::ArchiveRetention::get() as u64;
+			System::set_block_number(retention);
+			Voting::on_idle(retention, Weight::MAX);
+			assert!(Voting::archived_proposals(proposal_id).is_some());
 
-				assert_ok!(Voting::claim(RuntimeOrigin::signed(ALICE), proposal_id));
+			System::set_block_number(retention + 1);
+			Voting::on_idle(retention + 1, Weight::MAX);
+			assert_eq!(Voting::archived_proposals(proposal_id), None);
+		})
+	}
 
-				let alice_frozen_balance = <<Test as crate::Config>::NativeBalance as Inspect<
-					<Test as frame_system::Config>::AccountId,
-				>>::balance_frozen(&freeze_id, &ALICE);
-				assert_eq!(alice_frozen_balance, 0);
+	#[test]
+	fn pruned_archives_are_folded_into_the_epoch_tree() {
+		ExtBuilder::new_build(vec![(ALICE, 10), (BOB, 10)]).execute_with(|| {
+			setup();
+			assert_ok!(Voting::register_voter(RuntimeOrigin::root(), BOB));
+			assert_ok!(ProposalBuilder::new().start(1).end(200).execute());
+			let proposal_id = Voting::next_proposal_id() - 1;
+			assert_ok!(ProposalBuilder::new().start(10).end(200).execute());
+			assert_ok!(Voting::cancel_proposal(
+				RuntimeOrigin::signed(ALICE),
+				proposal_id + 1,
+				CancellationReason::CreatorWithdrawn
+			));
+			assert_ok!(Voting::vote(
+				RuntimeOrigin::signed(ALICE),
+				proposal_id,
+				true,
+				2,
+				Conviction::None
+			));
+			assert_ok!(Voting::vote(
+				RuntimeOrigin::signed(BOB),
+				proposal_id,
+				false,
+				1,
+				Conviction::None
+			));
+			System::set_block_number(200);
+			assert_ok!(Voting::close_proposal(RuntimeOrigin::signed(ALICE), proposal_id));
+			let outcome = Voting::archived_proposals(proposal_id).unwrap().outcome;
+			let votes_root = Voting::votes_tree(proposal_id).root;
+			let vote_proof = Voting::vote_proof(proposal_id, &BOB).unwrap();
+			assert_eq!(Voting::archive_proof(0, proposal_id), None);
+
+			let retention = <Test as pallet_voting::Config>::ArchiveRetention::get() as u64;
+			System::set_block_number(200 + retention);
+			Voting::on_idle(200 + retention, Weight::MAX);
+			assert_eq!(Voting::archived_proposals(proposal_id), None);
+			assert_eq!(Voting::archived_proposals(proposal_id + 1), None);
+			assert_eq!(Voting::vote_log(proposal_id, 0), None);
+			let root = Voting::archive_tree(0).root;
+			assert_eq!(Voting::archive_tree(0).leaves, 2);
+			System::assert_has_event(Event::ArchiveCompressed { proposal_id, era: 0, root }.into());
+
+			// The outcome and the votes of the proposal remain provable against the epoch root
+			let proof = Voting::archive_proof(0, proposal_id).unwrap();
+			let archive = CompressedArchive { proposal_id, outcome, votes_root };
+			assert_eq!((proof.archive.clone(), proof.leaf_index, proof.root), (archive, 1, root));
+			assert!(verify_archive(&root, &proof.archive, &proof.proof));
+			let forged = CompressedArchive {
+				outcome: ProposalOutcome::Cancelled {
+					reason: CancellationReason::CreatorWithdrawn,
+				},
+				..proof.archive.clone()
+			};
+			assert!(!verify_archive(&root, &forged, &proof.proof));
+			assert!(verify_vote(
+				&proof.archive.votes_root,
+				&BOB,
+				&vote_proof.vote,
+				&vote_proof.proof
+			));
+			let proof = Voting::archive_proof(0, proposal_id + 1).unwrap();
+			assert!(verify_archive(&root, &proof.archive, &proof.proof));
+			assert_eq!(Voting::archive_proof(1, proposal_id), None);
+		})
+	}
 
-				// Storage
-				let vote = Voting::votes(ALICE, proposal_id);
-				assert_eq!(vote, None);
+	#[test]
+	fn respects_remaining_weight() {
+		ExtBuilder::new_build(vec![(ALICE, 30)]).execute_with(|| {
+			setup();
+			assert_ok!(ProposalBuilder::new().start(1).end(200).execute());
+			let proposal_id = Voting::next_proposal_id() - 1;
+			assert_ok!(Voting::vote(
+				RuntimeOrigin::signed(ALICE),
+				proposal_id,
+				true,
+				4,
+				Conviction::None
+			));
+			assert_ok!(Voting::unregister_voter(RuntimeOrigin::root(), ALICE));
 
-				// Event
-				System::assert_last_event(
-					Event::BalanceClaimed { who: ALICE, amount: quadratic_amount }.into(),
-				);
-			})
-		}
+			assert_eq!(Voting::on_idle(1, Weight::zero()), Weight::zero());
+			assert_eq!(Voting::cleanup_queue_bounds(CleanupLane::Immediate), (0, 1));
+
+			Voting::on_idle(1, Weight::MAX);
+			assert_eq!(Voting::cleanup_queue_bounds(CleanupLane::Immediate), (1, 1));
+		})
 	}
 }
 
@@ -953,6 +7265,7 @@ pub struct ProposalBuilder {
 	pub account_list: Option<BoundedVec<u64, AccountSizeLimit>>,
 	pub start_block: BlockNumber,
 	pub end_block: BlockNumber,
+	pub settings: ProposalSettings,
 }
 
 impl ProposalBuilder {
@@ -965,6 +7278,7 @@ impl ProposalBuilder {
 			account_list: Some(BoundedVec::default()),
 			start_block: u32::try_from(System::block_number()).unwrap_or(0),
 			end_block: u32::try_from(System::block_number()).unwrap_or(0) + max_duration - 1,
+			settings: ProposalSettings::default(),
 		}
 	}
 
@@ -983,6 +7297,133 @@ impl ProposalBuilder {
 		self
 	}
 
+	pub fn kind(mut self, kind: ProposalKind) -> Self {
+		self.kind = kind;
+		self
+	}
+
+	pub fn headcount_quorum(mut self, quorum: Percent) -> Self {
+		self.settings.headcount_quorum = Some(quorum);
+		self
+	}
+
+	pub fn issuance_quorum(mut self, quorum: Perbill) -> Self {
+		self.settings.issuance_quorum = Some(quorum);
+		self
+	}
+
+	pub fn whale_cap(mut self, cap: Perbill) -> Self {
+		self.settings.whale_cap = Some(cap);
+		self
+	}
+
+	pub fn turnout_cap(mut self, cap: u128) -> Self {
+		self.settings.turnout_cap = Some(cap);
+		self
+	}
+
+	pub fn role_quorums(mut self, quorums: Vec<(RoleId, u32)>) -> Self {
+		self.settings.role_quorums = BoundedVec::truncate_from(quorums);
+		self
+	}
+
+	pub fn sunset_after(mut self, period: u32) -> Self {
+		self.settings.sunset_after = Some(period);
+		self
+	}
+
+	pub fn anonymous_events(mut self) -> Self {
+		self.settings.anonymous_events = true;
+		self
+	}
+
+	pub fn power_step(mut self, step: u128) -> Self {
+		self.settings.power_step = Some(step);
+		self
+	}
+
+	pub fn shared_budget(mut self) -> Self {
+		self.settings.shared_budget = true;
+		self
+	}
+
+	pub fn immutable_ballot(mut self) -> Self {
+		self.settings.immutable_ballot = true;
+		self
+	}
+
+	pub fn edit_cutoff(mut self, edit_cutoff: Percent) -> Self {
+		self.settings.edit_cutoff = Some(edit_cutoff);
+		self
+	}
+
+	pub fn lottery_winners(mut self, winners: u32) -> Self {
+		self.settings.lottery_winners = Some(winners);
+		self
+	}
+
+	pub fn vote_cost(mut self, vote_cost: VoteCost) -> Self {
+		self.settings.vote_cost = vote_cost;
+		self
+	}
+
+	pub fn hidden_tally(mut self) -> Self {
+		self.settings.hidden_tally = true;
+		self
+	}
+
+	pub fn reveal_period(mut self, period: u32) -> Self {
+		self.settings.reveal_period = Some(period);
+		self
+	}
+
+	pub fn threshold(mut self, threshold: Threshold) -> Self {
+		self.settings.threshold = threshold;
+		self
+	}
+
+	pub fn claim_delay(mut self, delay: u32) -> Self {
+		self.settings.claim_delay = Some(delay);
+		self
+	}
+
+	pub fn nft_gated(mut self) -> Self {
+		self.settings.nft_gated = true;
+		self
+	}
+
+	pub fn nft_bonus(mut self) -> Self {
+		self.settings.nft_bonus = true;
+		self
+	}
+
+	pub fn questions(mut self, questions: u32) -> Self {
+		self.settings.questions = questions;
+		self
+	}
+
+	pub fn jury_size(mut self, jury_size: u32) -> Self {
+		self.settings.jury_size = Some(jury_size);
+		self
+	}
+
+	pub fn max_voters(mut self, max_voters: u32, admission: VoterAdmission) -> Self {
+		self.settings.max_voters = Some(max_voters);
+		self.settings.voter_admission = admission;
+		self
+	}
+
+	pub fn vote_fee(mut self, vote_fee: u128, fee_payout: FeePayout) -> Self {
+		self.settings.vote_fee = Some(vote_fee);
+		self.settings.fee_payout = fee_payout;
+		self
+	}
+
+	pub fn members_root(mut self, members_root: [u8; 32]) -> Self {
+		self.settings.members_root = Some(members_root);
+		self
+	}
+
 	pub fn set_account_list(
 		mut self,
 		account_list: Option<BoundedVec<u64, AccountSizeLimit>>,
@@ -999,6 +7440,20 @@ impl ProposalBuilder {
 			self.account_list,
 			self.start_block as u64,
 			self.end_block as u64,
+			self.settings,
+		)
+	}
+
+	pub fn execute_in(self, org: OrgId) -> DispatchResult {
+		Voting::create_org_proposal(
+			self.origin,
+			org,
+			self.offchain_data,
+			self.kind,
+			self.account_list,
+			self.start_block as u64,
+			self.end_block as u64,
+			self.settings,
 		)
 	}
 }
@@ -1,38 +1,903 @@
 #![cfg_attr(not(feature = "std"), no_std)]
 
-use codec::{Decode, Encode, MaxEncodedLen};
-use frame_support::{pallet_prelude::*, BoundedVec};
+use crate::{BalanceOf, Config};
+use codec::{Codec, Decode, Encode, MaxEncodedLen};
+use frame_support::{
+	dispatch::Vec,
+	pallet_prelude::*,
+	sp_runtime::{traits::UniqueSaturatedInto, ConsensusEngineId, Perbill, Percent},
+	traits::{tokens::nonfungibles_v2, Hash as PreimageHash},
+	BoundedVec, Hashable,
+};
 use frame_system::pallet_prelude::BlockNumberFor;
+pub use pba_interface::math::tallied_amount;
 use scale_info::{prelude::fmt::Debug, TypeInfo};
 
 pub type ProposalId = u32;
+/// The identifier of an organisation hosted by the pallet.
+pub type OrgId = u32;
+/// The identifier of a role granted to proposal creators by governance.
+pub type RoleId = u32;
 /// The current vote ratio for a open proposal.
 /// The first element represent 'aye' votes and the second the total number of votes.
 pub type VoteRatio = (u128, u128);
+/// The maximum depth of a membership proof, enough for more than 4 billion members.
+pub const MAX_PROOF_DEPTH: u32 = 32;
+/// The sibling hashes from a member leaf up to the members root.
+pub type MembershipProof = BoundedVec<[u8; 32], ConstU32<MAX_PROOF_DEPTH>>;
+/// The engine ID of the digest items committing to the tallies of the open proposals.
+pub const TALLY_COMMITMENT_ENGINE_ID: ConsensusEngineId = *b"qvtc";
+/// The maximum length of the raw storage key a recount, an audit, a round of tally checkpoints or
+/// a stepped migration resumes from.
+pub const MAX_RECOUNT_CURSOR: u32 = 128;
+/// The maximum number of role quorums of a proposal.
+pub const MAX_ROLE_QUORUMS: u32 = 4;
+/// The maximum length of the label of an option of a multi-option proposal.
+pub const MAX_OPTION_LABEL_LEN: u32 = 64;
+/// The label of an option of a multi-option proposal.
+pub type OptionLabel = BoundedVec<u8, ConstU32<MAX_OPTION_LABEL_LEN>>;
+/// The key of the persistent offchain local storage holding the webhook endpoints, a SCALE
+/// encoded `Vec<Vec<u8>>` of URLs.
+pub const WEBHOOKS_STORAGE_KEY: &[u8] = b"voting::webhooks";
+/// How long the offchain worker waits for the webhook endpoints to answer, in milliseconds.
+pub const WEBHOOK_TIMEOUT_MS: u64 = 2_000;
 
+/// Source of proof-of-personhood attestations, e.g. a people chain oracle or an on-chain
+/// attestation pallet. Only attested accounts can be registered as voters.
+pub trait PersonhoodProvider<AccountId> {
+	/// Whether `who` is attested as a unique person.
+	fn is_attested(who: &AccountId) -> bool;
+}
+
+/// No personhood check, every account is considered attested.
+impl<AccountId> PersonhoodProvider<AccountId> for () {
+	fn is_attested(_who: &AccountId) -> bool {
+		true
+	}
+}
+
+/// Repeals the policy adopted by a proposal when its sunset review is not approved, e.g. by
+/// cancelling its enactment.
+pub trait OnRepeal {
+	/// The sunset review `review_id` repealed the policy adopted by `policy_id`.
+	fn on_repeal(policy_id: ProposalId, review_id: ProposalId);
+}
+
+/// Nothing to repeal, the repeal is only reported by its event.
+impl OnRepeal for () {
+	fn on_repeal(_policy_id: ProposalId, _review_id: ProposalId) {}
+}
+
+/// No NFT source, no account holds a membership pass.
+pub struct NoNfts;
+
+impl<AccountId> nonfungibles_v2::Inspect<AccountId> for NoNfts {
+	type ItemId = u32;
+	type CollectionId = u32;
+
+	fn owner(_collection: &u32, _item: &u32) -> Option<AccountId> {
+		None
+	}
+}
+
+impl<AccountId> nonfungibles_v2::InspectEnumerable<AccountId> for NoNfts {
+	type CollectionsIterator = core::iter::Empty<u32>;
+	type ItemsIterator = core::iter::Empty<u32>;
+	type OwnedIterator = core::iter::Empty<(u32, u32)>;
+	type OwnedInCollectionIterator = core::iter::Empty<u32>;
+
+	fn collections() -> Self::CollectionsIterator {
+		core::iter::empty()
+	}
+
+	fn items(_collection: &u32) -> Self::ItemsIterator {
+		core::iter::empty()
+	}
+
+	fn owned(_who: &AccountId) -> Self::OwnedIterator {
+		core::iter::empty()
+	}
+
+	fn owned_in_collection(_collection: &u32, _who: &AccountId) -> Self::OwnedInCollectionIterator {
+		core::iter::empty()
+	}
+}
+
+/// The kind of a proposal, deciding who can vote on it and how it is approved.
+///
+/// The discriminants are frozen: a new kind takes the next free index and an index is never
+/// reused. New built-in kinds are unit variants, kinds carrying data go through `Custom`, so a
+/// runtime not knowing a kind yet still decodes it as `Unknown` without misreading the fields
+/// stored after it.
+#[derive(PartialEq, Eq, Copy, Clone, RuntimeDebug, Default, TypeInfo, MaxEncodedLen)]
+#[non_exhaustive]
+pub enum ProposalKind {
+	#[default]
+	#[codec(index = 0)]
+	Public,
+	#[codec(index = 1)]
+	Private,
+	/// Incident-response proposal created by a privileged origin, with a compressed timeline
+	/// and a mandatory supermajority.
+	#[codec(index = 2)]
+	Emergency,
+	/// A kind whose rules are defined by the runtime `KindHandler`.
+	#[codec(index = 3)]
+	Custom(u8),
+	/// Review of the policy adopted by a proposal, created by the pallet `sunset_after` blocks
+	/// after the proposal passed. The policy is confirmed when the review is approved and
+	/// repealed otherwise.
+	#[codec(index = 4)]
+	SunsetReview,
+	/// A kind added by a later version of the pallet, kept as its index. It is encoded back as
+	/// read and cannot be created.
+	#[codec(skip)]
+	Unknown(u8),
+}
+
+impl ProposalKind {
+	/// The highest index known to this version of the pallet.
+	pub const LATEST_INDEX: u8 = 4;
+}
+
+impl Encode for ProposalKind {
+	fn size_hint(&self) -> usize {
+		match self {
+			Self::Custom(_) => 2,
+			_ => 1,
+		}
+	}
+
+	fn encode_to<O: codec::Output + ?Sized>(&self, dest: &mut O) {
+		match self {
+			Self::Public => dest.push_byte(0),
+			Self::Private => dest.push_byte(1),
+			Self::Emergency => dest.push_byte(2),
+			Self::Custom(id) => {
+				dest.push_byte(3);
+				dest.push_byte(*id);
+			},
+			Self::SunsetReview => dest.push_byte(4),
+			Self::Unknown(index) => dest.push_byte(*index),
+		}
+	}
+}
+
+impl codec::EncodeLike for ProposalKind {}
+
+impl Decode for ProposalKind {
+	fn decode<In: codec::Input>(input: &mut In) -> Result<Self, codec::Error> {
+		Ok(match input.read_byte()? {
+			0 => Self::Public,
+			1 => Self::Private,
+			2 => Self::Emergency,
+			3 => Self::Custom(input.read_byte()?),
+			4 => Self::SunsetReview,
+			index => Self::Unknown(index),
+		})
+	}
+}
+
+/// Which voters a proposal with a voter cap admits once the cap is reached.
 #[derive(
 	PartialEq, Eq, Copy, Clone, RuntimeDebug, Encode, Decode, Default, TypeInfo, MaxEncodedLen,
 )]
-pub enum ProposalKind {
+pub enum VoterAdmission {
+	/// First come, first served: only the voters who already voted can vote again
 	#[default]
-	Public,
-	Private = 1,
+	FirstCome,
+	/// The voters pre-registered with `register_expected_voters` are admitted over the cap
+	Priority,
 }
 
+/// Where the participation fees collected by a proposal go when it is closed.
 #[derive(
-	Encode, Decode, Eq, CloneNoBound, PartialEqNoBound, RuntimeDebugNoBound, TypeInfo, MaxEncodedLen,
+	PartialEq, Eq, Copy, Clone, RuntimeDebug, Encode, Decode, Default, TypeInfo, MaxEncodedLen,
 )]
-#[scale_info(skip_type_params(T, AccountSizeLimit, ProposalOffchainDataLimit))]
-pub struct ProposalData<T, AccountId, AccountSizeLimit, ProposalOffchainDataLimit>
-where
-	T: frame_system::Config,
-	AccountId: Clone + PartialEq + Debug,
-	AccountSizeLimit: Get<u32>,
-	ProposalOffchainDataLimit: Get<u32>,
-{
+pub enum FeePayout {
+	/// The pot is sent to the treasury account
+	#[default]
+	Treasury,
+	/// The pot rewards the account closing the proposal
+	Closer,
+	/// The pot is shared equally among the voters of the winning side when their collateral is
+	/// released, the shares of the other voters go to the treasury account
+	WinningSide,
+}
+
+/// What the quadratic cost of the votes of a proposal becomes.
+#[derive(
+	PartialEq, Eq, Copy, Clone, RuntimeDebug, Encode, Decode, Default, TypeInfo, MaxEncodedLen,
+)]
+pub enum VoteCost {
+	/// The cost is held as collateral while the proposal is open and released once it is closed
+	#[default]
+	Frozen,
+	/// The cost is held like `Frozen`, but a share of it is burned whenever it is released
+	PartiallyBurned(Percent),
+	/// The cost is burned when voting
+	Burned,
+	/// The cost is paid to the treasury account when voting
+	Treasury,
+}
+
+impl VoteCost {
+	/// Whether the cost is held as collateral while the proposal is open.
+	pub fn is_frozen(&self) -> bool {
+		matches!(self, VoteCost::Frozen | VoteCost::PartiallyBurned(_))
+	}
+
+	/// The share of the cost burned when it is released.
+	pub fn release_fee(&self) -> Percent {
+		match self {
+			VoteCost::PartiallyBurned(fee) => *fee,
+			_ => Percent::from_percent(0),
+		}
+	}
+}
+
+/// The share of the total votes the 'aye' votes must exceed for a proposal to be approved.
+#[derive(
+	PartialEq, Eq, Copy, Clone, RuntimeDebug, Encode, Decode, Default, TypeInfo, MaxEncodedLen,
+)]
+pub enum Threshold {
+	/// More 'aye' votes than 'nay' votes
+	#[default]
+	SimpleMajority,
+	/// More than two thirds of the total votes
+	TwoThirds,
+	/// More than three quarters of the total votes
+	ThreeQuarters,
+	/// Only 'aye' votes, and at least one
+	Unanimous,
+}
+
+impl Threshold {
+	/// Whether the 'aye' votes of `ratio` pass the threshold.
+	pub fn is_met(&self, (aye, total): VoteRatio) -> bool {
+		match self {
+			Threshold::SimpleMajority => aye > total.saturating_sub(aye),
+			Threshold::TwoThirds => aye.saturating_mul(3) > total.saturating_mul(2),
+			Threshold::ThreeQuarters => aye.saturating_mul(4) > total.saturating_mul(3),
+			Threshold::Unanimous => aye > 0 && aye == total,
+		}
+	}
+}
+
+/// The participation fees collected by a proposal.
+#[derive(PartialEq, Eq, Clone, RuntimeDebug, Encode, Decode, Default, TypeInfo, MaxEncodedLen)]
+pub struct FeePot<Balance> {
+	/// The amount held by the pot account of the proposal.
+	pub collected: Balance,
+	/// Once a winning side proposal is closed, the winning side ('aye' or not) and the share of
+	/// each of its voters.
+	pub reward: Option<(bool, Balance)>,
+}
+
+/// Why a proposal could not be created, reported by the dry run of `create_proposal`. Each
+/// variant is the error of the same name of the pallet.
+#[derive(PartialEq, Eq, Copy, Clone, RuntimeDebug, Encode, Decode, TypeInfo, MaxEncodedLen)]
+pub enum CreateError {
+	/// The creator is not a registered voter
+	VoterNotRegistered,
+	/// The kind of proposal is restricted to a role the creator was not granted
+	MissingCreatorRole,
+	/// The account list holds more than `AccountSizeLimit` accounts
+	AccountListFull,
+	/// The proposal would start in the past
+	ProposalCannotStartInThePast,
+	/// The proposal would end before starting
+	ProposalCannotFinishBeforeStarting,
+	/// The proposal would start more than `ProposalDelayLimit` blocks from now
+	ProposalStartIsTooFarAway,
+	/// The proposal duration is below the minimum duration
+	ProposalDurationIsTooShort,
+	/// The proposal duration is above the maximum duration
+	ProposalDurationIsTooLong,
+	/// Emergency proposals can only be created with `create_emergency_proposal`
+	EmergencyProposalNotAllowed,
+	/// The kind of proposal cannot be created
+	ProposalKindNotAllowed,
+	/// There are already `MaxProposalsPerKind` proposals of this kind, or starting at its start
+	/// block
+	TooManyProposals,
+	/// The creator cannot afford the `ProposalDeposit`
+	InsufficientBalance,
+}
+
+/// What a vote is short of, in balance or in voice credits for a shared budget.
+#[derive(PartialEq, Eq, Clone, RuntimeDebug, Encode, Decode, TypeInfo, MaxEncodedLen)]
+pub struct VoteShortfall<Balance> {
+	/// The additional cost of the vote over the current vote of the account.
+	pub required: Balance,
+	/// What the account has available to pay it.
+	pub available: Balance,
+}
+
+/// The collateral held for the votes of an account, compared to what its votes account for.
+#[derive(PartialEq, Eq, Clone, RuntimeDebug, Encode, Decode, TypeInfo, MaxEncodedLen)]
+pub struct HoldHealth<Balance> {
+	/// The quadratic cost of the votes of the account whose cost is held.
+	pub expected_from_votes: Balance,
+	/// The balance of the account actually held as collateral of its votes.
+	pub actual_held: Balance,
+	/// The difference between both, in either direction.
+	pub drift: Balance,
+}
+
+/// How the collateral held for the votes of an account overlaps with its other holds and with
+/// the locks and freezes of other pallets, e.g. staking.
+#[derive(PartialEq, Eq, Clone, RuntimeDebug, Encode, Decode, TypeInfo, MaxEncodedLen)]
+pub struct LockReport<Balance> {
+	/// The total balance of the account, free and held.
+	pub total: Balance,
+	/// The balance held as collateral of its votes.
+	pub held_for_voting: Balance,
+	/// The balance held for other reasons, e.g. the deposits of other pallets.
+	pub held_elsewhere: Balance,
+	/// The free balance that cannot be spent: the part of the largest lock or freeze not covered
+	/// by the held balance, or the existential deposit.
+	pub restricted: Balance,
+	/// The balance that can still be spent, or held as collateral of new votes.
+	pub spendable: Balance,
+	/// The highest power a new vote with the native balance can afford from the spendable
+	/// balance, at most `MaxPower`.
+	pub max_additional_power: Balance,
+}
+
+/// The incremental merkle tree of the votes cast on a proposal, of depth `MAX_PROOF_DEPTH`. Only
+/// the left siblings of the path of the next leaf are kept, enough to append a leaf and update the
+/// root.
+#[derive(PartialEq, Eq, Clone, RuntimeDebug, Encode, Decode, Default, TypeInfo, MaxEncodedLen)]
+pub struct VotesTree {
+	/// The number of leaves appended.
+	pub leaves: u32,
+	/// The left sibling at each height of the path of the next leaf.
+	pub branch: [[u8; 32]; MAX_PROOF_DEPTH as usize],
+	/// The root committing to all the leaves, the missing ones being zero.
+	pub root: [u8; 32],
+}
+
+impl VotesTree {
+	/// Appends `leaf` and updates the root.
+	pub fn append(&mut self, leaf: [u8; 32]) {
+		let mut node = leaf;
+		let mut size = self.leaves;
+		for height in 0..MAX_PROOF_DEPTH as usize {
+			if size & 1 == 0 {
+				self.branch[height] = node;
+				break
+			}
+			node = merkle_parent(&self.branch[height], &node);
+			size >>= 1;
+		}
+		self.leaves = self.leaves.saturating_add(1);
+
+		let mut root = [0; 32];
+		let mut zero = [0; 32];
+		let mut size = self.leaves;
+		for sibling in self.branch.iter() {
+			root = if size & 1 == 1 {
+				merkle_parent(sibling, &root)
+			} else {
+				merkle_parent(&root, &zero)
+			};
+			zero = merkle_parent(&zero, &zero);
+			size >>= 1;
+		}
+		self.root = root;
+	}
+}
+
+/// A vote with the proof of its inclusion in the votes tree of its proposal.
+#[derive(PartialEq, Eq, Clone, RuntimeDebug, Encode, Decode, TypeInfo, MaxEncodedLen)]
+pub struct VoteProof<Power> {
+	/// The last vote of the voter, tallied when the proposal was closed.
+	pub vote: VoteInfo<Power>,
+	/// The index of its leaf in the tree.
+	pub leaf_index: u32,
+	/// The sibling hashes from its leaf up to the root.
+	pub proof: MembershipProof,
+	/// The root of the votes tree of the proposal.
+	pub root: [u8; 32],
+}
+
+/// What is kept of an archived proposal once pruned, folded into the archive tree of the epoch
+/// in which it was archived.
+#[derive(PartialEq, Eq, Clone, RuntimeDebug, Encode, Decode, TypeInfo, MaxEncodedLen)]
+pub struct CompressedArchive {
+	/// The ID of the proposal.
+	pub proposal_id: ProposalId,
+	/// How the proposal ended, after any override.
+	pub outcome: ProposalOutcome,
+	/// The root of the votes tree of the proposal, so its votes remain provable.
+	pub votes_root: [u8; 32],
+}
+
+/// A pruned archive with the proof of its inclusion in the archive tree of its epoch.
+#[derive(PartialEq, Eq, Clone, RuntimeDebug, Encode, Decode, TypeInfo, MaxEncodedLen)]
+pub struct ArchiveProof {
+	/// What is kept of the archive.
+	pub archive: CompressedArchive,
+	/// The index of its leaf in the tree.
+	pub leaf_index: u32,
+	/// The sibling hashes from its leaf up to the root.
+	pub proof: MembershipProof,
+	/// The root of the archive tree of the epoch.
+	pub root: [u8; 32],
+}
+
+/// The running statistics of the current epoch, turned into its report once it ends.
+#[derive(PartialEq, Eq, Clone, RuntimeDebug, Encode, Decode, Default, TypeInfo, MaxEncodedLen)]
+pub struct EpochStats {
+	/// The number of proposals closed so far.
+	pub proposals_closed: u32,
+	/// The sum of the turnouts of the proposals closed so far.
+	pub total_turnout: u128,
+	/// The number of distinct accounts which cast a new vote so far.
+	pub unique_voters: u32,
+}
+
+/// The statistics of an ended epoch, for governance dashboards.
+#[derive(PartialEq, Eq, Clone, RuntimeDebug, Encode, Decode, Default, TypeInfo, MaxEncodedLen)]
+pub struct EpochReport {
+	/// The number of proposals closed during the epoch.
+	pub proposals_closed: u32,
+	/// The average turnout, the total of votes, of the proposals closed during the epoch.
+	pub average_turnout: u128,
+	/// The number of distinct accounts which cast a new vote during the epoch.
+	pub unique_voters: u32,
+}
+
+/// A recount of the tally of a proposal in progress, resumed across calls.
+#[derive(PartialEq, Eq, Clone, RuntimeDebug, Encode, Decode, Default, TypeInfo, MaxEncodedLen)]
+pub struct Recount {
+	/// The tally and the voters count of the proposal when the recount started. The recount
+	/// starts over if they move in between two calls.
+	pub started_from: (VoteRatio, u32),
+	/// The tally re-derived from the votes recounted so far.
+	pub tally: VoteRatio,
+	/// The raw storage key of the last contribution recounted.
+	pub cursor: Option<BoundedVec<u8, ConstU32<MAX_RECOUNT_CURSOR>>>,
+}
+
+/// A discrepancy found by an audit of the votes of a proposal.
+#[derive(PartialEq, Eq, Clone, RuntimeDebug, Encode, Decode, TypeInfo, MaxEncodedLen)]
+pub enum AuditIssue<Balance> {
+	/// The hold of the voter does not cover the quadratic cost of its vote
+	FreezeShortfall { frozen: Balance, cost: Balance },
+	/// The voter contributes to the tally without holding a vote
+	VoteMissing,
+}
+
+/// Why a proposal was cancelled before starting.
+#[derive(PartialEq, Eq, Copy, Clone, RuntimeDebug, Encode, Decode, TypeInfo, MaxEncodedLen)]
+pub enum CancellationReason {
+	/// The creator withdrew the proposal
+	CreatorWithdrawn,
+	/// The proposal is spam
+	Spam,
+	/// The proposal duplicates another one
+	Duplicate,
+	/// The proposal raises a legal issue
+	LegalIssue,
+	/// Any other reason
+	Other,
+}
+
+/// How a proposal ended.
+#[derive(PartialEq, Eq, Copy, Clone, RuntimeDebug, Encode, Decode, TypeInfo, MaxEncodedLen)]
+pub enum ProposalOutcome {
+	/// The proposal was cancelled before starting
+	Cancelled { reason: CancellationReason },
+	/// The proposal was closed once finished
+	Completed { ratio: VoteRatio, approved: bool },
+	/// The proposal did not gather enough seconds before its start
+	Expired,
+	/// The outcome was disputed and the proposal is voted again under a new ID
+	Revoted { revote: ProposalId },
+}
+
+/// Where a proposal stands in its lifecycle, updated at each transition.
+///
+/// Like `ProposalKind`, the discriminants are frozen and a status added by a later version of
+/// the pallet decodes as `Unknown`. Its fields are skipped, which is safe as the status is
+/// stored on its own in `ProposalStatuses`.
+#[derive(PartialEq, Eq, Copy, Clone, RuntimeDebug, TypeInfo, MaxEncodedLen)]
+#[non_exhaustive]
+pub enum ProposalStatus {
+	/// The proposal is queued or waiting for its start block
+	#[codec(index = 0)]
+	Pending,
+	/// The proposal is open for voting, until it is closed
+	#[codec(index = 1)]
+	Active,
+	/// The proposal is closed and its outcome is disputed, pending resolution
+	#[codec(index = 2)]
+	Suspended,
+	/// The proposal ended with `outcome`
+	#[codec(index = 3)]
+	Closed { outcome: ProposalOutcome },
+	/// The proposal was cancelled before starting
+	#[codec(index = 4)]
+	Cancelled { reason: CancellationReason },
+	/// A status added by a later version of the pallet, kept as its index
+	#[codec(skip)]
+	Unknown(u8),
+}
+
+impl ProposalStatus {
+	/// The highest index known to this version of the pallet.
+	pub const LATEST_INDEX: u8 = 4;
+}
+
+impl Encode for ProposalStatus {
+	fn size_hint(&self) -> usize {
+		match self {
+			Self::Closed { outcome } => 1 + outcome.size_hint(),
+			Self::Cancelled { reason } => 1 + reason.size_hint(),
+			_ => 1,
+		}
+	}
+
+	fn encode_to<O: codec::Output + ?Sized>(&self, dest: &mut O) {
+		match self {
+			Self::Pending => dest.push_byte(0),
+			Self::Active => dest.push_byte(1),
+			Self::Suspended => dest.push_byte(2),
+			Self::Closed { outcome } => {
+				dest.push_byte(3);
+				outcome.encode_to(dest);
+			},
+			Self::Cancelled { reason } => {
+				dest.push_byte(4);
+				reason.encode_to(dest);
+			},
+			Self::Unknown(index) => dest.push_byte(*index),
+		}
+	}
+}
+
+impl codec::EncodeLike for ProposalStatus {}
+
+impl Decode for ProposalStatus {
+	fn decode<In: codec::Input>(input: &mut In) -> Result<Self, codec::Error> {
+		Ok(match input.read_byte()? {
+			0 => Self::Pending,
+			1 => Self::Active,
+			2 => Self::Suspended,
+			3 => Self::Closed { outcome: ProposalOutcome::decode(input)? },
+			4 => Self::Cancelled { reason: CancellationReason::decode(input)? },
+			index => Self::Unknown(index),
+		})
+	}
+}
+
+impl From<ProposalOutcome> for ProposalStatus {
+	fn from(outcome: ProposalOutcome) -> Self {
+		match outcome {
+			ProposalOutcome::Cancelled { reason } => ProposalStatus::Cancelled { reason },
+			outcome => ProposalStatus::Closed { outcome },
+		}
+	}
+}
+
+/// The challenge of the outcome of a closed proposal, pending resolution.
+#[derive(PartialEq, Eq, Clone, RuntimeDebug, Encode, Decode, TypeInfo, MaxEncodedLen)]
+pub struct Dispute<AccountId, Balance> {
+	/// The account which disputed the outcome.
+	pub challenger: AccountId,
+	/// The amount bonded by the challenger.
+	pub bond: Balance,
+}
+
+/// How the `DisputeResolutionOrigin` settles a dispute.
+#[derive(PartialEq, Eq, Clone, RuntimeDebug, Encode, Decode, TypeInfo, MaxEncodedLen)]
+pub enum DisputeResolution<BlockNumber> {
+	/// The outcome stands, the bond of the challenger goes to the treasury account
+	Confirm,
+	/// The approval of the outcome is reversed, the bond is returned
+	Overturn,
+	/// The proposal is voted again between the given blocks, the bond is returned
+	Revote { start_block: BlockNumber, end_block: BlockNumber },
+}
+
+/// How the `OverrideOrigin` overrides the outcome of a closed proposal.
+#[derive(PartialEq, Eq, Copy, Clone, RuntimeDebug, Encode, Decode, TypeInfo, MaxEncodedLen)]
+pub enum OutcomeOverride {
+	/// The proposal is approved whatever its tally
+	Ratify,
+	/// The proposal is rejected whatever its tally
+	Veto,
+}
+
+/// The limits of the proposals set by Root with `set_proposal_limits`, each overriding the
+/// config type it is named after.
+#[derive(Default, PartialEq, Eq, Clone, RuntimeDebug, Encode, Decode, TypeInfo, MaxEncodedLen)]
+pub struct ProposalLimits {
+	/// The minimum number of blocks a proposal accepts votes, `ProposalMinimumDuration` if unset.
+	pub minimum_duration: Option<u32>,
+	/// The maximum number of blocks a proposal accepts votes, `ProposalMaximumDuration` if unset.
+	pub maximum_duration: Option<u32>,
+	/// The maximum number of blocks before a proposal starts, `ProposalDelayLimit` if unset.
+	pub delay_limit: Option<u32>,
+	/// The maximum duration of an emergency proposal, `EmergencyMaxDuration` if unset.
+	pub emergency_max_duration: Option<u32>,
+	/// The number of blocks after a proposal is closed from which anyone can release the
+	/// collateral of its voters, `ClaimGracePeriod` if unset.
+	pub claim_grace_period: Option<u32>,
+}
+
+/// The rules an organisation sets for its proposals, each overriding a pallet limit within its
+/// bounds.
+#[derive(Default, PartialEq, Eq, Clone, RuntimeDebug, Encode, Decode, TypeInfo, MaxEncodedLen)]
+pub struct OrgParameters {
+	/// The minimum number of blocks a proposal accepts votes, `ProposalMinimumDuration` if unset.
+	pub minimum_duration: Option<u32>,
+	/// The maximum number of blocks a proposal accepts votes, `ProposalMaximumDuration` if unset.
+	pub maximum_duration: Option<u32>,
+}
+
+/// An organisation hosted by the pallet, with its own voters, parameters and proposal IDs.
+#[derive(PartialEq, Eq, Clone, RuntimeDebug, Encode, Decode, TypeInfo, MaxEncodedLen)]
+pub struct OrgInfo<AccountId> {
+	/// The account administering the members and parameters of the organisation.
+	pub admin: AccountId,
+	/// The number of members of the organisation.
+	pub members_count: u32,
+	/// The index within the organisation of its next proposal.
+	pub next_proposal_index: u32,
+	/// The rules of the proposals of the organisation.
+	pub parameters: OrgParameters,
+}
+
+/// The record kept for a proposal once it has been cancelled or closed, for accountability.
+#[derive(PartialEq, Eq, Clone, RuntimeDebug, Encode, Decode, TypeInfo, MaxEncodedLen)]
+pub struct ArchivedProposal<AccountId, BlockNumber> {
+	/// The proposal creator.
+	pub creator: AccountId,
+	/// How the proposal ended.
+	pub outcome: ProposalOutcome,
+	/// `BlockNumber` at which the proposal was archived.
+	pub archived_at: BlockNumber,
+	/// The override of the outcome, if any, and the account of the origin which overrode it.
+	pub overridden: Option<(OutcomeOverride, AccountId)>,
+	/// The factor every contribution was scaled by when the proposal was closed, if its turnout
+	/// exceeded the turnout cap.
+	pub turnout_scale: Option<Perbill>,
+	/// The number of voters pre-registered with `register_expected_voters`, and still allowed
+	/// when the proposal was closed, who did not vote, if voters were pre-registered.
+	pub abstentions: Option<u32>,
+	/// The number of voters when the proposal was closed and its voter cap, if it had one.
+	pub voter_cap: Option<(u32, u32)>,
+	/// The error of the last attempt to enact the call of the proposal, if its retries ran out.
+	pub enactment_failure: Option<DispatchError>,
+}
+
+/// The registration of a voter.
+#[derive(PartialEq, Eq, Clone, RuntimeDebug, Encode, Decode, TypeInfo, MaxEncodedLen)]
+pub struct VoterInfo<BlockNumber> {
+	/// `BlockNumber` since which the voter is continuously registered.
+	pub registered_at: BlockNumber,
+	/// `BlockNumber` from which the registration is expired, if it must be renewed.
+	pub expires_at: Option<BlockNumber>,
+}
+
+impl<BlockNumber: PartialOrd> VoterInfo<BlockNumber> {
+	pub fn is_expired(&self, current_block: &BlockNumber) -> bool {
+		self.expires_at.as_ref().map_or(false, |expires_at| current_block >= expires_at)
+	}
+}
+
+/// A deputy registrar, registering and unregistering voters on behalf of the registrar.
+#[derive(PartialEq, Eq, Clone, RuntimeDebug, Encode, Decode, TypeInfo, MaxEncodedLen)]
+pub struct DeputyInfo<BlockNumber> {
+	/// Maximum number of voters registered or unregistered per `DeputyPeriod`.
+	pub limit: u32,
+	/// `BlockNumber` at which the current period started.
+	pub period_start: BlockNumber,
+	/// Number of voters registered or unregistered in the current period.
+	pub used: u32,
+}
+
+/// The lanes of the cleanup queue. Tasks of a lane are processed in order once they are due, so a
+/// lane only holds tasks whose due blocks are increasing.
+#[derive(PartialEq, Eq, Copy, Clone, RuntimeDebug, Encode, Decode, TypeInfo, MaxEncodedLen)]
+pub enum CleanupLane {
+	/// Tasks due as soon as they are queued
+	Immediate,
+	/// Tasks due after the archive retention period
+	Retention,
+	/// Tasks due once a voter registration expires
+	Expiry,
+	/// Tasks due once the dispute period of a closed proposal is over
+	DisputePeriod,
+	/// Tasks due once the outcome of a closed proposal can no longer be disputed or overridden
+	Enactment,
+}
+
+/// Deferred cleanup work processed in `on_idle`. A task can take several steps to complete.
+#[derive(PartialEq, Eq, Clone, RuntimeDebug, Encode, Decode, TypeInfo, MaxEncodedLen)]
+pub enum CleanupTask<AccountId> {
+	/// Release the collateral of the votes left on a closed proposal, one voter per step. Votes
+	/// paid with voice credits or whose cost is paid have no held collateral.
+	ReleaseFreezes { proposal_id: ProposalId, frozen: bool },
+	/// Remove an archived proposal past the retention period.
+	PruneArchive { proposal_id: ProposalId },
+	/// Drop the votes of an unregistered voter, one vote per step.
+	DrainVoter { who: AccountId },
+	/// Remove the seconds of an archived proposal, one second per step.
+	ClearSeconds { proposal_id: ProposalId },
+	/// Unregister a voter whose registration expired without being renewed.
+	ExpireVoter { who: AccountId },
+	/// Forget a closed proposal kept for a revote once its dispute period is over, unless it is
+	/// disputed.
+	EndDisputePeriod { proposal_id: ProposalId },
+	/// Apply the next account of a voter registry sync, one account per step.
+	SyncVoters { preimage_hash: PreimageHash },
+	/// Drop the votes an unregistered delegate cast on behalf of its delegators on proposals in
+	/// progress, then revoke the delegations it received, one per step.
+	RevokeDelegations { delegate: AccountId },
+	/// Dispatch the call of a closed proposal once its outcome can no longer be disputed or
+	/// overridden, unless it is disputed.
+	EnactCall { proposal_id: ProposalId },
+}
+
+/// The progress of a voter registry sync through the account list of its preimage.
+#[derive(PartialEq, Eq, Clone, RuntimeDebug, Encode, Decode, Default, TypeInfo, MaxEncodedLen)]
+pub struct VoterSync {
+	/// The length of the preimage.
+	pub len: u32,
+	/// The offset in the preimage of the next account to apply.
+	pub cursor: u32,
+	/// The number of accounts left to apply.
+	pub remaining: u32,
+	/// The number of voters registered so far.
+	pub added: u32,
+	/// The number of voters unregistered so far.
+	pub removed: u32,
+	/// The number of accounts which could not be registered so far.
+	pub skipped: u32,
+}
+
+/// The progress of a stepped migration of the storage: its maps are translated one entry per step,
+/// then the votes trees are rebuilt one leaf per step if the migration changes their leaves.
+#[derive(PartialEq, Eq, Clone, RuntimeDebug, Encode, Decode, TypeInfo, MaxEncodedLen)]
+pub enum MigrationCursor {
+	/// Translating the map `map` of the migration from the raw key of the last entry translated,
+	/// the prefix of the map until its first entry is.
+	Translate { map: u8, last_key: BoundedVec<u8, ConstU32<MAX_RECOUNT_CURSOR>> },
+	/// Rebuilding the votes tree of `proposal_id`, `tree` holding the leaves appended so far.
+	RebuildTree { proposal_id: ProposalId, tree: VotesTree },
+}
+
+/// Optional rules chosen by the creator when submitting a proposal.
+#[derive(PartialEq, Eq, Clone, RuntimeDebug, Encode, Decode, Default, TypeInfo, MaxEncodedLen)]
+pub struct ProposalSettings {
+	/// Minimum share of all registered voters that must have participated when the proposal is
+	/// closed. The registered-voter counter is used as the denominator.
+	pub headcount_quorum: Option<Percent>,
+	/// Minimum total of votes (second item of the ratio) required when the proposal is closed.
+	pub turnout_quorum: Option<u128>,
+	/// Minimum total of votes required when the proposal is closed, as a share of the total
+	/// issuance of the native token at that time.
+	pub issuance_quorum: Option<Perbill>,
+	/// Minimum number of voters holding each role, e.g. `(committee, 3)`, that must have
+	/// participated when the proposal is closed. The roles are the ones granted with
+	/// `set_creator_role`.
+	pub role_quorums: BoundedVec<(RoleId, u32), ConstU32<MAX_ROLE_QUORUMS>>,
+	/// Maximum share of the total turnout a single voter can contribute to the tally. The excess
+	/// is discarded when the proposal is closed.
+	pub whale_cap: Option<Perbill>,
+	/// Maximum total of votes counted in the tally, e.g. the budget of a matching pool. When the
+	/// turnout exceeds it, every contribution is scaled down pro-rata when the proposal is closed.
+	pub turnout_cap: Option<u128>,
+	/// Granularity of the vote power: when set, the power of a vote must be a multiple of it.
+	pub power_step: Option<u128>,
+	/// When set, votes are paid with the voice credits of the voter for the current epoch instead
+	/// of holding funds. The credits are shared across all the proposals using this regime.
+	pub shared_budget: bool,
+	/// What the quadratic cost of the votes becomes. A paid cost is not refunded when a vote is
+	/// lowered or dropped, nor claimed once the proposal is closed. A partially burned cost is
+	/// refunded minus its fee.
+	pub vote_cost: VoteCost,
+	/// Merkle root of the allowed voters of a private proposal, kept instead of the full account
+	/// list. Voters prove their membership with a merkle proof when voting. It takes precedence
+	/// over the account list.
+	pub members_root: Option<[u8; 32]>,
+	/// When set, the running tally is not published in the proposal while voting, to avoid
+	/// bandwagon effects. It is only revealed when the proposal is closed.
+	pub hidden_tally: bool,
+	/// Number of independent yes/no questions bundled in the proposal, voted together with
+	/// `vote_questions` and tallied separately. Zero for a single question proposal.
+	pub questions: u32,
+	/// Size of the jury randomly drawn among the registered voters when the proposal starts.
+	/// Only the jurors can vote.
+	pub jury_size: Option<u32>,
+	/// Flat fee charged to each new vote, collected into the proposal pot and paid out when the
+	/// proposal is closed.
+	pub vote_fee: Option<u128>,
+	/// Where the collected fees go when the proposal is closed.
+	pub fee_payout: FeePayout,
+	/// When set, only the holders of an item of one of the `NftCollections` can vote.
+	pub nft_gated: bool,
+	/// When set, the holders of an item of one of the `NftCollections` get the bonus of their
+	/// collection on top of their tenure bonus.
+	pub nft_bonus: bool,
+	/// Number of voters randomly drawn when the proposal is closed to share its lottery pot, as a
+	/// turnout incentive.
+	pub lottery_winners: Option<u32>,
+	/// When set, a vote cannot be changed nor dropped once cast.
+	pub immutable_ballot: bool,
+	/// Share of the voting window after which the votes already cast cannot be changed anymore.
+	/// New votes are still accepted until the end.
+	pub edit_cutoff: Option<Percent>,
+	/// When set, an approved proposal is reviewed by a sunset review starting this number of
+	/// blocks after it is closed, and again after each confirmation.
+	pub sunset_after: Option<u32>,
+	/// When set, the events report the votes without their voter, only their side and power.
+	/// The votes are still stored by voter, for the claims.
+	pub anonymous_events: bool,
+	/// When set, the proposal is a secret ballot: the votes are committed during the voting
+	/// window and revealed during this number of blocks after its end, the proposal being closed
+	/// afterwards.
+	pub reveal_period: Option<u32>,
+	/// The share of the total votes the 'aye' votes must exceed for the proposal to be approved.
+	pub threshold: Threshold,
+	/// When set, the collateral of the votes can only be claimed this number of blocks after the
+	/// proposal is closed, e.g. once a dispute window or an enactment is over.
+	pub claim_delay: Option<u32>,
+	/// Maximum number of voters, e.g. for jury-style votes. A voter dropping their vote frees
+	/// their seat.
+	pub max_voters: Option<u32>,
+	/// Which voters are admitted once `max_voters` is reached.
+	pub voter_admission: VoterAdmission,
+}
+
+impl ProposalSettings {
+	/// Whether the proposal can have options. The options are tallied on their own, without the
+	/// contributions the other features rely on.
+	pub fn supports_options(&self) -> bool {
+		self.questions == 0 &&
+			self.whale_cap.is_none() &&
+			self.turnout_cap.is_none() &&
+			!self.hidden_tally &&
+			self.sunset_after.is_none() &&
+			self.lottery_winners.is_none() &&
+			self.reveal_period.is_none() &&
+			self.threshold == Threshold::SimpleMajority &&
+			self.fee_payout != FeePayout::WinningSide
+	}
+
+	/// Whether the cost of the votes is held funds, released once the proposal is closed.
+	pub fn freezes_cost(&self) -> bool {
+		!self.shared_budget && self.vote_cost.is_frozen()
+	}
+
+	/// Checks that the power of a vote respects the power step.
+	pub fn is_valid_power<Power: UniqueSaturatedInto<u128>>(&self, power: Power) -> bool {
+		let power: u128 = power.unique_saturated_into();
+		self.power_step
+			.map_or(true, |step| power.checked_rem(step).map_or(false, |rem| rem == 0))
+	}
+}
+
+/// A proposal, its bounds and account type being those of the pallet instance `I`.
+#[derive(
+	Encode,
+	Decode,
+	EqNoBound,
+	CloneNoBound,
+	PartialEqNoBound,
+	RuntimeDebugNoBound,
+	TypeInfo,
+	MaxEncodedLen,
+)]
+#[scale_info(skip_type_params(T, I))]
+pub struct ProposalData<T: Config<I>, I: 'static = ()> {
 	/// The data related to this proposal (e.g an CID Hash pointing to a Json file; a static or
 	/// dynamic link; plain text)
-	pub offchain_data: BoundedVec<u8, ProposalOffchainDataLimit>,
+	pub offchain_data: BoundedVec<u8, T::ProposalOffchainDataLimit>,
 	/// The vote ratio for this proposal.
 	/// The first item represents the number of 'aye' votes.
 	/// The second item represents the total number of votes.
@@ -47,33 +912,68 @@ where
 	/// reached when passing the ending block.
 	pub kind: ProposalKind,
 	/// The proposal creator.
-	pub creator: AccountId,
+	pub creator: T::AccountId,
 	/// The accounts interacting with this list.
 	/// For a 'public' proposal: banned accounts to vote.
 	/// For a 'private' proposal: allowed accounts to vote.
-	pub account_list: Option<BoundedVec<AccountId, AccountSizeLimit>>,
+	pub account_list: Option<BoundedVec<T::AccountId, T::AccountSizeLimit>>,
 	/// `BlockNumber` at which the proposal will accept votes.
 	pub start_block: BlockNumberFor<T>,
 	/// `BlockNumber` at which the proposal will no longer accept votes.
 	pub end_block: BlockNumberFor<T>,
+	/// The optional rules set by the creator.
+	pub settings: ProposalSettings,
+	/// The number of distinct accounts holding a vote for this proposal.
+	pub voters_count: u32,
+	/// The number of distinct registered voters who seconded this proposal.
+	pub seconds: u32,
+	/// Whether the proposal was submitted by the external origin rather than a registered voter.
+	pub external: bool,
+	/// The quorum denominator recorded at the start of the proposal: the size of the account
+	/// list of a private proposal, the number of registered voters otherwise.
+	pub electorate: Option<u32>,
+	/// The organisation hosting the proposal, whose members only can vote on it.
+	pub org: Option<OrgId>,
+	/// The options of a multi-option proposal, voted with `vote_option` and tallied separately.
+	/// Empty for an aye/nay proposal.
+	pub options: BoundedVec<OptionLabel, T::MaxOptions>,
 }
 
-impl<T, AccountId, AccountSizeLimit, ProposalOffchainDataLimit>
-	ProposalData<T, AccountId, AccountSizeLimit, ProposalOffchainDataLimit>
+/// A public proposal waiting in the launch queue to be promoted to a voting proposal.
+#[derive(
+	Encode, Decode, Eq, CloneNoBound, PartialEqNoBound, RuntimeDebugNoBound, TypeInfo, MaxEncodedLen,
+)]
+#[scale_info(skip_type_params(AccountSizeLimit, ProposalOffchainDataLimit))]
+pub struct QueuedProposal<AccountId, AccountSizeLimit, ProposalOffchainDataLimit>
 where
-	T: frame_system::Config,
 	AccountId: Clone + PartialEq + Debug,
 	AccountSizeLimit: Get<u32>,
 	ProposalOffchainDataLimit: Get<u32>,
 {
+	/// The data related to this proposal.
+	pub offchain_data: BoundedVec<u8, ProposalOffchainDataLimit>,
+	/// The proposal creator.
+	pub creator: AccountId,
+	/// The banned accounts to vote.
+	pub account_list: Option<BoundedVec<AccountId, AccountSizeLimit>>,
+	/// The number of blocks the proposal accepts votes once promoted.
+	pub duration: u32,
+	/// The optional rules set by the creator.
+	pub settings: ProposalSettings,
+	/// The number of distinct registered voters who seconded this proposal.
+	pub seconds: u32,
+}
+
+impl<T: Config<I>, I: 'static> ProposalData<T, I> {
 	// TODO: document all helpers
 	pub fn new(
-		offchain_data: BoundedVec<u8, ProposalOffchainDataLimit>,
+		offchain_data: BoundedVec<u8, T::ProposalOffchainDataLimit>,
 		kind: ProposalKind,
-		creator: AccountId,
-		account_list: Option<BoundedVec<AccountId, AccountSizeLimit>>,
+		creator: T::AccountId,
+		account_list: Option<BoundedVec<T::AccountId, T::AccountSizeLimit>>,
 		start_block: BlockNumberFor<T>,
 		end_block: BlockNumberFor<T>,
+		settings: ProposalSettings,
 	) -> Self {
 		Self {
 			offchain_data,
@@ -83,10 +983,17 @@ where
 			account_list,
 			start_block,
 			end_block,
+			settings,
+			voters_count: 0,
+			seconds: 0,
+			external: false,
+			electorate: None,
+			org: None,
+			options: BoundedVec::default(),
 		}
 	}
 
-	pub fn is_creator(&self, who: &AccountId) -> bool {
+	pub fn is_creator(&self, who: &T::AccountId) -> bool {
 		self.creator == *who
 	}
 
@@ -98,35 +1005,184 @@ where
 		self.end_block.le(block)
 	}
 
-	// pub fn has_majority(&self) -> bool {
-	// 	if self.kind == ProposalKind::Private {
-	// 		let maybe_account_list = &self.account_list;
-	// 		if let Some(account_list) = maybe_account_list {
-	// 			let account_list_len = account_list.len();
-	// 			let (aye, total) = self.ratio;
-	// 			return account_list_len > 1 && aye > total / 2;
-	// 		}
-	// 	}
+	/// The block the proposal is closed at, after the reveal window of a secret ballot.
+	pub fn closes_at(&self) -> BlockNumberFor<T> {
+		self.end_block
+			.saturating_add(self.settings.reveal_period.unwrap_or_default().into())
+	}
+
+	/// Whether the proposal ended and the reveal window of a secret ballot is over.
+	pub fn can_close(&self, block: &BlockNumberFor<T>) -> bool {
+		self.closes_at().le(block)
+	}
 
-	// 	false
-	// }
+	/// A proposal opens for voting only once seconded by `threshold` registered voters.
+	/// Emergency proposals are exempt from seconding.
+	pub fn is_seconded(&self, threshold: u32) -> bool {
+		matches!(self.kind, ProposalKind::Emergency | ProposalKind::SunsetReview) ||
+			self.seconds >= threshold
+	}
 
-	pub fn add_ratio(&mut self, aye: bool, prev_power: u128, new_power: u128) {
-		let prev_quadratic_amount = prev_power.checked_mul(prev_power).unwrap_or(u128::MAX);
-		let new_quadratic_amount = new_power.checked_mul(new_power).unwrap_or(u128::MAX);
-		let amount_diff = new_quadratic_amount.saturating_sub(prev_quadratic_amount);
+	pub fn add_voter(&mut self) {
+		self.voters_count = self.voters_count.saturating_add(1);
+	}
 
-		let new_ratio = if aye {
-			(self.ratio.0.saturating_add(amount_diff), self.ratio.1.saturating_add(amount_diff))
+	pub fn remove_voter(&mut self) {
+		self.voters_count = self.voters_count.saturating_sub(1);
+	}
+
+	/// Adds a tallied amount to the ratio. Returns `true` if the tally saturated.
+	pub fn add_contribution(&mut self, aye: bool, amount: u128) -> bool {
+		let ayes = if aye { self.ratio.0.checked_add(amount) } else { Some(self.ratio.0) };
+		let total = self.ratio.1.checked_add(amount);
+		let saturated = ayes.is_none() || total.is_none();
+		self.ratio = (ayes.unwrap_or(u128::MAX), total.unwrap_or(u128::MAX));
+		saturated
+	}
+
+	/// Scales the tally down pro-rata so the turnout fits in `cap`. Returns the factor applied to
+	/// every contribution, `None` when the turnout already fits.
+	pub fn apply_turnout_cap(&mut self, cap: u128) -> Option<Perbill> {
+		let (aye, total) = self.ratio;
+		if total <= cap {
+			return None
+		}
+		let scale = Perbill::from_rational(cap, total);
+		self.ratio = (scale.mul_floor(aye), scale.mul_floor(total));
+		Some(scale)
+	}
+
+	/// Discards an `amount` of votes from the tally, used to apply the whale cap.
+	pub fn discard_contribution(&mut self, aye: bool, amount: u128) {
+		if aye {
+			self.ratio.0 = self.ratio.0.saturating_sub(amount);
+		}
+		self.ratio.1 = self.ratio.1.saturating_sub(amount);
+	}
+
+	/// Checks the quorum rules of the proposal, `electorate` being the number of voters allowed
+	/// to vote on it.
+	pub fn has_quorum(&self, electorate: u32) -> bool {
+		let headcount_reached = self
+			.settings
+			.headcount_quorum
+			.map_or(true, |quorum| self.voters_count >= quorum.mul_ceil(electorate));
+		let turnout_reached =
+			self.settings.turnout_quorum.map_or(true, |quorum| self.ratio.1 >= quorum);
+
+		headcount_reached && turnout_reached
+	}
+
+	/// A proposal is approved when the quorum is reached and 'aye' votes pass its threshold, a
+	/// simple majority by default.
+	pub fn is_approved(&self, electorate: u32) -> bool {
+		self.has_quorum(electorate) && self.settings.threshold.is_met(self.ratio)
+	}
+
+	/// Same as `is_approved` for the tally of one question of a bundled proposal.
+	pub fn is_question_approved(&self, ratio: VoteRatio, electorate: u32) -> bool {
+		Self { ratio, ..self.clone() }.is_approved(electorate)
+	}
+
+	/// Checks that 'aye' votes are more than the `supermajority` share of total votes.
+	pub fn has_supermajority(&self, supermajority: Perbill) -> bool {
+		let (aye, total) = self.ratio;
+		aye > supermajority.mul_floor(total)
+	}
+
+	/// Whether the `edit_cutoff` of the proposal is reached at `block`, the votes already cast
+	/// being final from then on.
+	pub fn edit_window_closed(&self, block: &BlockNumberFor<T>) -> bool {
+		self.settings.edit_cutoff.map_or(false, |edit_cutoff| {
+			let window = self.end_block.saturating_sub(self.start_block);
+			let elapsed = block.saturating_sub(self.start_block);
+			elapsed >= edit_cutoff.mul_floor(window)
+		})
+	}
+
+	/// The outcome of a private proposal once it is settled at `block`: whatever the voters of
+	/// its account list who did not vote yet would vote, each adding at most `max_amount` to the
+	/// tally, it would be approved, or rejected. The votes already cast must be final, so only
+	/// immutable ballots or ballots past their edit cutoff settle. Only the proposals whose
+	/// outcome depends on the tally and the quorum alone can be settled before their end.
+	pub fn settled_outcome(
+		&self,
+		electorate: u32,
+		max_amount: u128,
+		block: &BlockNumberFor<T>,
+	) -> Option<bool> {
+		let settings = &self.settings;
+		let settles_early = (settings.immutable_ballot || self.edit_window_closed(block)) &&
+			self.kind == ProposalKind::Private &&
+			self.account_list.is_some() &&
+			settings.members_root.is_none() &&
+			settings.issuance_quorum.is_none() &&
+			settings.role_quorums.is_empty() &&
+			settings.whale_cap.is_none() &&
+			settings.turnout_cap.is_none() &&
+			!settings.hidden_tally &&
+			settings.questions == 0 &&
+			self.options.is_empty() &&
+			settings.reveal_period.is_none();
+		if !settles_early {
+			return None
+		}
+
+		let remaining_voters = electorate.saturating_sub(self.voters_count);
+		let remaining = max_amount.saturating_mul(remaining_voters.into());
+		let (aye, total) = self.ratio;
+		if self.has_quorum(electorate) &&
+			settings.threshold.is_met((aye, total.saturating_add(remaining)))
+		{
+			Some(true)
+		} else if !settings
+			.threshold
+			.is_met((aye.saturating_add(remaining), total.saturating_add(remaining)))
+		{
+			Some(false)
+		} else {
+			None
+		}
+	}
+
+	/// Adds the quadratic difference between both powers, increased by the voter `bonus`, to the
+	/// tally. Returns `true` if the tally overflowed and was saturated, which `MaxPower` is meant
+	/// to rule out.
+	pub fn add_ratio(
+		&mut self,
+		aye: bool,
+		prev_power: BalanceOf<T, I>,
+		new_power: BalanceOf<T, I>,
+		bonus: Percent,
+	) -> bool {
+		let prev_quadratic_amount = tallied_amount(prev_power, bonus);
+		let new_quadratic_amount = tallied_amount(new_power, bonus);
+		let amount_diff = new_quadratic_amount
+			.zip(prev_quadratic_amount)
+			.map(|(new, prev)| new.saturating_sub(prev));
+
+		let total = amount_diff.and_then(|diff| self.ratio.1.checked_add(diff));
+		let ayes = if aye {
+			amount_diff.and_then(|diff| self.ratio.0.checked_add(diff))
 		} else {
-			(self.ratio.0, self.ratio.1.saturating_add(amount_diff))
+			Some(self.ratio.0)
 		};
-		*self = Self { ratio: new_ratio, ..self.clone() }
+		let saturated = total.is_none() || ayes.is_none();
+
+		let new_ratio = (ayes.unwrap_or(u128::MAX), total.unwrap_or(u128::MAX));
+		*self = Self { ratio: new_ratio, ..self.clone() };
+		saturated
 	}
 
-	pub fn remove_ratio(&mut self, aye: bool, prev_power: u128, new_power: u128) {
-		let prev_quadratic_amount = prev_power.checked_mul(prev_power).unwrap_or(u128::MAX);
-		let new_quadratic_amount = new_power.checked_mul(new_power).unwrap_or(u128::MAX);
+	pub fn remove_ratio(
+		&mut self,
+		aye: bool,
+		prev_power: BalanceOf<T, I>,
+		new_power: BalanceOf<T, I>,
+		bonus: Percent,
+	) {
+		let prev_quadratic_amount = tallied_amount(prev_power, bonus).unwrap_or(u128::MAX);
+		let new_quadratic_amount = tallied_amount(new_power, bonus).unwrap_or(u128::MAX);
 		let amount_diff = prev_quadratic_amount.saturating_sub(new_quadratic_amount);
 
 		let new_ratio = if aye {
@@ -138,10 +1194,140 @@ where
 	}
 }
 
-#[derive(
-	Encode, Decode, Eq, CloneNoBound, PartialEqNoBound, RuntimeDebugNoBound, TypeInfo, MaxEncodedLen,
-)]
-pub struct VoteInfo {
+/// Whether the bit of `index` is set in an eligibility `bitmap`.
+pub fn bitmap_contains(bitmap: &[u8], index: u32) -> bool {
+	bitmap
+		.get((index / 8) as usize)
+		.map_or(false, |byte| byte & (1 << (index % 8)) != 0)
+}
+
+/// The number of bits set in an eligibility `bitmap`.
+pub fn bitmap_len(bitmap: &[u8]) -> u32 {
+	bitmap.iter().map(|byte| byte.count_ones()).sum()
+}
+
+/// The index of the option with the highest tally, if a single option leads with a non-zero
+/// tally.
+pub fn plurality_winner(tallies: &[u128]) -> Option<u32> {
+	let (winner, highest) = tallies.iter().enumerate().max_by_key(|(_, tally)| **tally)?;
+	let tied = tallies.iter().filter(|tally| *tally == highest).count() > 1;
+	(*highest > 0 && !tied).then_some(winner as u32)
+}
+
+/// The leaf of a member in the members merkle tree.
+pub fn membership_leaf<AccountId: Codec>(who: &AccountId) -> [u8; 32] {
+	who.blake2_256()
+}
+
+/// The parent node of two merkle nodes. Nodes are sorted before hashing so a proof does not need
+/// to carry the position of each sibling.
+pub fn merkle_parent(a: &[u8; 32], b: &[u8; 32]) -> [u8; 32] {
+	if a <= b {
+		(*a, *b).blake2_256()
+	} else {
+		(*b, *a).blake2_256()
+	}
+}
+
+/// The leaf of a vote of `who` in the votes tree of its proposal.
+pub fn vote_leaf<AccountId: Codec, Power: Codec>(
+	who: &AccountId,
+	vote: &VoteInfo<Power>,
+) -> [u8; 32] {
+	(who, vote).using_encoded(frame_support::sp_io::hashing::blake2_256)
+}
+
+/// Checks that the vote of `who` belongs to the votes tree committed by `root`.
+pub fn verify_vote<AccountId: Codec, Power: Codec>(
+	root: &[u8; 32],
+	who: &AccountId,
+	vote: &VoteInfo<Power>,
+	proof: &[[u8; 32]],
+) -> bool {
+	let computed_root = proof
+		.iter()
+		.fold(vote_leaf(who, vote), |node, sibling| merkle_parent(&node, sibling));
+	computed_root == *root
+}
+
+/// The sibling hashes from the leaf at `index` up to the root of the tree of depth
+/// `MAX_PROOF_DEPTH` whose leaves are `nodes`, the missing ones being zero.
+pub fn merkle_proof(mut nodes: Vec<[u8; 32]>, mut index: usize) -> MembershipProof {
+	let mut zero = [0; 32];
+	let mut proof = Vec::new();
+	for _ in 0..MAX_PROOF_DEPTH {
+		proof.push(nodes.get(index ^ 1).copied().unwrap_or(zero));
+		nodes = nodes
+			.chunks(2)
+			.map(|pair| merkle_parent(&pair[0], pair.get(1).unwrap_or(&zero)))
+			.collect();
+		zero = merkle_parent(&zero, &zero);
+		index >>= 1;
+	}
+	BoundedVec::truncate_from(proof)
+}
+
+/// The leaf of a pruned archive in the archive tree of its epoch.
+pub fn archive_leaf(archive: &CompressedArchive) -> [u8; 32] {
+	archive.blake2_256()
+}
+
+/// Checks that a pruned archive belongs to the archive tree committed by `root`.
+pub fn verify_archive(root: &[u8; 32], archive: &CompressedArchive, proof: &[[u8; 32]]) -> bool {
+	let computed_root = proof
+		.iter()
+		.fold(archive_leaf(archive), |node, sibling| merkle_parent(&node, sibling));
+	computed_root == *root
+}
+
+/// Checks that `who` belongs to the members tree committed by `root`.
+pub fn verify_membership<AccountId: Codec>(
+	root: &[u8; 32],
+	who: &AccountId,
+	proof: &[[u8; 32]],
+) -> bool {
+	let computed_root = proof
+		.iter()
+		.fold(membership_leaf(who), |node, sibling| merkle_parent(&node, sibling));
+	computed_root == *root
+}
+
+/// The JSON body notified to the webhook endpoints, e.g.
+/// `{"event":"VoteCompleted","proposal_id":3,"block":120,"approved":true}`.
+pub fn webhook_payload(
+	event: &[u8],
+	proposal_id: ProposalId,
+	block: u64,
+	approved: Option<bool>,
+) -> Vec<u8> {
+	fn push_number(payload: &mut Vec<u8>, mut number: u64) {
+		let start = payload.len();
+		loop {
+			payload.push(b'0' + (number % 10) as u8);
+			number /= 10;
+			if number == 0 {
+				break
+			}
+		}
+		payload[start..].reverse();
+	}
+
+	let mut payload = Vec::from(&b"{\"event\":\""[..]);
+	payload.extend_from_slice(event);
+	payload.extend_from_slice(b"\",\"proposal_id\":");
+	push_number(&mut payload, proposal_id.into());
+	payload.extend_from_slice(b",\"block\":");
+	push_number(&mut payload, block);
+	if let Some(approved) = approved {
+		let approved: &[u8] = if approved { b",\"approved\":true" } else { b",\"approved\":false" };
+		payload.extend_from_slice(approved);
+	}
+	payload.push(b'}');
+	payload
+}
+
+#[derive(PartialEq, Eq, Clone, RuntimeDebug, Encode, Decode, TypeInfo, MaxEncodedLen)]
+pub struct VoteInfo<Power> {
 	/// The proposal ID
 	pub proposal_id: ProposalId,
 	/// The vote:
@@ -149,5 +1335,63 @@ pub struct VoteInfo {
 	/// - 'nay' -> false,
 	pub aye: bool,
 	/// The power for this vote
-	pub power: u128,
+	pub power: Power,
+	/// The conviction the vote is cast with
+	pub conviction: Conviction,
+}
+
+/// The largest number of `ConvictionPeriod`s the collateral of a vote can stay held after its
+/// proposal is closed.
+pub const MAX_CONVICTION_PERIODS: u32 = 16;
+
+/// The conviction of a vote, from 1x to 6x: a higher conviction multiplies the amount the vote
+/// adds to the tally, but keeps its collateral held for longer once the proposal is closed.
+#[derive(
+	PartialEq, Eq, Copy, Clone, RuntimeDebug, Encode, Decode, Default, TypeInfo, MaxEncodedLen,
+)]
+pub enum Conviction {
+	/// The vote is tallied once and its collateral is released when the proposal is closed
+	#[default]
+	None,
+	/// Tallied twice, held for 1 period
+	Locked2x,
+	/// Tallied 3 times, held for 2 periods
+	Locked3x,
+	/// Tallied 4 times, held for 4 periods
+	Locked4x,
+	/// Tallied 5 times, held for 8 periods
+	Locked5x,
+	/// Tallied 6 times, held for 16 periods
+	Locked6x,
+}
+
+impl Conviction {
+	/// The factor the tallied amount of the vote is multiplied by.
+	pub fn multiplier(&self) -> u128 {
+		match self {
+			Conviction::None => 1,
+			Conviction::Locked2x => 2,
+			Conviction::Locked3x => 3,
+			Conviction::Locked4x => 4,
+			Conviction::Locked5x => 5,
+			Conviction::Locked6x => 6,
+		}
+	}
+
+	/// The number of `ConvictionPeriod`s the collateral stays held after the proposal is closed.
+	pub fn lock_periods(&self) -> u32 {
+		match self {
+			Conviction::None => 0,
+			Conviction::Locked2x => 1,
+			Conviction::Locked3x => 2,
+			Conviction::Locked4x => 4,
+			Conviction::Locked5x => 8,
+			Conviction::Locked6x => MAX_CONVICTION_PERIODS,
+		}
+	}
+
+	/// The tallied `amount` of a vote multiplied by the conviction. Returns `None` on overflow.
+	pub fn apply(&self, amount: u128) -> Option<u128> {
+		amount.checked_mul(self.multiplier())
+	}
 }
@@ -0,0 +1,701 @@
+//! Weights for `pallet_voting`.
+//!
+//! These are estimates, not measurements: the benchmarks of `benchmarking.rs` have not been run
+//! on the reference hardware yet. Each call is charged the storage accesses counted from its
+//! code, plus 15 µs of execution and 2.5 µs per storage item accessed, and a proof size of
+//! 2_500 bytes per storage item read. An account scanned in the account list of a proposal adds
+//! 0.1 µs and its 32 encoded bytes. Replace them with the generated weights by running:
+//!
+//! ```text
+//! ./target/release/node-template benchmark pallet --chain dev --pallet pallet_voting \
+//! 	--extrinsic '*' --steps 50 --repeat 20 --output pallets/voting/src/weights.rs
+//! ```
+
+#![cfg_attr(rustfmt, rustfmt_skip)]
+#![allow(unused_parens)]
+#![allow(unused_imports)]
+#![allow(missing_docs)]
+
+use frame_support::{traits::Get, weights::{Weight, constants::RocksDbWeight}};
+use core::marker::PhantomData;
+
+/// Weight functions needed for `pallet_voting`.
+pub trait WeightInfo {
+	fn register_voter() -> Weight;
+	fn unregister_voter(v: u32, d: u32) -> Weight;
+	fn self_register() -> Weight;
+	fn self_unregister(v: u32, d: u32) -> Weight;
+	fn migrate_voter(e: u32) -> Weight;
+	fn appoint_deputy() -> Weight;
+	fn revoke_deputy() -> Weight;
+	fn sync_voters() -> Weight;
+	fn create_proposal(a: u32) -> Weight;
+	fn cancel_proposal(a: u32) -> Weight;
+	fn close_proposal(s: u32, w: u32) -> Weight;
+	fn set_account_list(a: u32) -> Weight;
+	fn set_eligibility_bitmap() -> Weight;
+	fn register_expected_voters(a: u32) -> Weight;
+	fn set_voting_asset() -> Weight;
+	fn set_proposal_asset() -> Weight;
+	fn set_proposal_call() -> Weight;
+	fn set_options() -> Weight;
+	fn set_kind_role() -> Weight;
+	fn set_creator_role() -> Weight;
+	fn vote(a: u32) -> Weight;
+	fn vote_with_proof() -> Weight;
+	fn delegate(h: u32) -> Weight;
+	fn undelegate() -> Weight;
+	fn vote_as_delegate(a: u32, h: u32) -> Weight;
+	fn recount(l: u32) -> Weight;
+	fn audit_proposal(l: u32) -> Weight;
+	fn dispute() -> Weight;
+	fn resolve_dispute() -> Weight;
+	fn override_outcome() -> Weight;
+	fn vote_questions(a: u32) -> Weight;
+	fn vote_option(a: u32) -> Weight;
+	fn commit_vote(a: u32) -> Weight;
+	fn reveal_vote() -> Weight;
+	fn claim() -> Weight;
+	fn claim_to() -> Weight;
+	fn claim_for() -> Weight;
+	fn update_account_list(a: u32) -> Weight;
+	fn copy_account_list(a: u32) -> Weight;
+	fn sponsor_votes() -> Weight;
+	fn fund_lottery() -> Weight;
+	fn renew_registration() -> Weight;
+	fn take_balance_snapshot() -> Weight;
+	fn second() -> Weight;
+	fn submit_public_proposal() -> Weight;
+	fn create_emergency_proposal() -> Weight;
+	fn create_external_proposal(a: u32) -> Weight;
+	fn create_org() -> Weight;
+	fn set_org_admin() -> Weight;
+	fn add_org_member() -> Weight;
+	fn remove_org_member() -> Weight;
+	fn set_org_parameters() -> Weight;
+	fn create_org_proposal(a: u32) -> Weight;
+	fn set_proposal_limits() -> Weight;
+}
+
+/// Weights for `pallet_voting` using the Substrate node and recommended hardware.
+pub struct SubstrateWeight<T>(PhantomData<T>);
+impl<T: frame_system::Config> WeightInfo for SubstrateWeight<T> {
+	fn register_voter() -> Weight {
+		Weight::from_parts(25_000_000, 5_000)
+			.saturating_add(T::DbWeight::get().reads(2_u64))
+			.saturating_add(T::DbWeight::get().writes(2_u64))
+	}
+	fn unregister_voter(v: u32, d: u32) -> Weight {
+		Weight::from_parts(47_500_000, 17_500)
+			.saturating_add(T::DbWeight::get().reads(7_u64))
+			.saturating_add(T::DbWeight::get().writes(6_u64))
+			.saturating_add(T::DbWeight::get().reads((2_u64).saturating_mul(v.into())))
+			.saturating_add(T::DbWeight::get().writes((3_u64).saturating_mul(v.into())))
+			.saturating_add(T::DbWeight::get().reads((1_u64).saturating_mul(d.into())))
+			.saturating_add(T::DbWeight::get().writes((2_u64).saturating_mul(d.into())))
+			.saturating_add(Weight::from_parts(12_500_000, 5_000).saturating_mul(v.into()))
+			.saturating_add(Weight::from_parts(7_500_000, 2_500).saturating_mul(d.into()))
+	}
+	fn self_register() -> Weight {
+		Weight::from_parts(40_000_000, 12_500)
+			.saturating_add(T::DbWeight::get().reads(5_u64))
+			.saturating_add(T::DbWeight::get().writes(5_u64))
+	}
+	fn self_unregister(v: u32, d: u32) -> Weight {
+		Weight::from_parts(45_000_000, 15_000)
+			.saturating_add(T::DbWeight::get().reads(6_u64))
+			.saturating_add(T::DbWeight::get().writes(6_u64))
+			.saturating_add(T::DbWeight::get().reads((2_u64).saturating_mul(v.into())))
+			.saturating_add(T::DbWeight::get().writes((3_u64).saturating_mul(v.into())))
+			.saturating_add(T::DbWeight::get().reads((1_u64).saturating_mul(d.into())))
+			.saturating_add(T::DbWeight::get().writes((2_u64).saturating_mul(d.into())))
+			.saturating_add(Weight::from_parts(12_500_000, 5_000).saturating_mul(v.into()))
+			.saturating_add(Weight::from_parts(7_500_000, 2_500).saturating_mul(d.into()))
+	}
+	fn migrate_voter(e: u32) -> Weight {
+		Weight::from_parts(87_500_000, 32_500)
+			.saturating_add(T::DbWeight::get().reads(13_u64))
+			.saturating_add(T::DbWeight::get().writes(16_u64))
+			.saturating_add(T::DbWeight::get().reads((5_u64).saturating_mul(e.into())))
+			.saturating_add(T::DbWeight::get().writes((9_u64).saturating_mul(e.into())))
+			.saturating_add(Weight::from_parts(35_000_000, 12_500).saturating_mul(e.into()))
+	}
+	fn appoint_deputy() -> Weight {
+		Weight::from_parts(25_000_000, 5_000)
+			.saturating_add(T::DbWeight::get().reads(2_u64))
+			.saturating_add(T::DbWeight::get().writes(2_u64))
+	}
+	fn revoke_deputy() -> Weight {
+		Weight::from_parts(25_000_000, 5_000)
+			.saturating_add(T::DbWeight::get().reads(2_u64))
+			.saturating_add(T::DbWeight::get().writes(2_u64))
+	}
+	fn sync_voters() -> Weight {
+		Weight::from_parts(30_000_000, 7_500)
+			.saturating_add(T::DbWeight::get().reads(3_u64))
+			.saturating_add(T::DbWeight::get().writes(3_u64))
+	}
+	fn create_proposal(a: u32) -> Weight {
+		Weight::from_parts(22_500_000, 0)
+			.saturating_add(T::DbWeight::get().writes(3_u64))
+			.saturating_add(T::DbWeight::get().writes((1_u64).saturating_mul(a.into())))
+			.saturating_add(Weight::from_parts(2_500_000, 0).saturating_mul(a.into()))
+	}
+	fn cancel_proposal(a: u32) -> Weight {
+		Weight::from_parts(22_500_000, 0)
+			.saturating_add(T::DbWeight::get().writes(3_u64))
+			.saturating_add(T::DbWeight::get().writes((1_u64).saturating_mul(a.into())))
+			.saturating_add(Weight::from_parts(2_500_000, 0).saturating_mul(a.into()))
+	}
+	fn close_proposal(s: u32, w: u32) -> Weight {
+		Weight::from_parts(17_500_000, 0)
+			.saturating_add(T::DbWeight::get().writes(1_u64))
+			.saturating_add(T::DbWeight::get().reads((3_u64).saturating_mul(s.into())))
+			.saturating_add(T::DbWeight::get().reads((2_u64).saturating_mul(w.into())))
+			.saturating_add(T::DbWeight::get().writes((1_u64).saturating_mul(w.into())))
+			.saturating_add(Weight::from_parts(7_500_000, 7_500).saturating_mul(s.into()))
+			.saturating_add(Weight::from_parts(7_500_000, 5_000).saturating_mul(w.into()))
+	}
+	fn set_account_list(a: u32) -> Weight {
+		Weight::from_parts(17_500_000, 0)
+			.saturating_add(T::DbWeight::get().writes(1_u64))
+			.saturating_add(T::DbWeight::get().writes((2_u64).saturating_mul(a.into())))
+			.saturating_add(Weight::from_parts(5_000_000, 0).saturating_mul(a.into()))
+	}
+	fn set_eligibility_bitmap() -> Weight {
+		Weight::from_parts(20_000_000, 2_500)
+			.saturating_add(T::DbWeight::get().reads(1_u64))
+			.saturating_add(T::DbWeight::get().writes(1_u64))
+	}
+	fn register_expected_voters(a: u32) -> Weight {
+		Weight::from_parts(17_500_000, 2_500)
+			.saturating_add(T::DbWeight::get().reads(1_u64))
+			.saturating_add(T::DbWeight::get().writes((2_u64).saturating_mul(a.into())))
+			.saturating_add(Weight::from_parts(5_000_000, 0).saturating_mul(a.into()))
+	}
+	fn set_voting_asset() -> Weight {
+		Weight::from_parts(17_500_000, 0)
+			.saturating_add(T::DbWeight::get().writes(1_u64))
+	}
+	fn set_proposal_asset() -> Weight {
+		Weight::from_parts(22_500_000, 5_000)
+			.saturating_add(T::DbWeight::get().reads(2_u64))
+			.saturating_add(T::DbWeight::get().writes(1_u64))
+	}
+	fn set_proposal_call() -> Weight {
+		Weight::from_parts(32_500_000, 10_000)
+			.saturating_add(T::DbWeight::get().reads(4_u64))
+			.saturating_add(T::DbWeight::get().writes(3_u64))
+	}
+	fn set_options() -> Weight {
+		Weight::from_parts(20_000_000, 2_500)
+			.saturating_add(T::DbWeight::get().reads(1_u64))
+			.saturating_add(T::DbWeight::get().writes(1_u64))
+	}
+	fn set_kind_role() -> Weight {
+		Weight::from_parts(17_500_000, 0)
+			.saturating_add(T::DbWeight::get().writes(1_u64))
+	}
+	fn set_creator_role() -> Weight {
+		Weight::from_parts(17_500_000, 0)
+			.saturating_add(T::DbWeight::get().writes(1_u64))
+	}
+	fn vote(a: u32) -> Weight {
+		Weight::from_parts(67_500_000, 30_000)
+			.saturating_add(Weight::from_parts(100_000, 32).saturating_mul(a.into()))
+			.saturating_add(T::DbWeight::get().reads(12_u64))
+			.saturating_add(T::DbWeight::get().writes(9_u64))
+	}
+	fn vote_with_proof() -> Weight {
+		Weight::from_parts(67_500_000, 30_000)
+			.saturating_add(T::DbWeight::get().reads(12_u64))
+			.saturating_add(T::DbWeight::get().writes(9_u64))
+	}
+	fn delegate(h: u32) -> Weight {
+		Weight::from_parts(17_500_000, 0)
+			.saturating_add(T::DbWeight::get().writes(1_u64))
+			.saturating_add(T::DbWeight::get().reads((1_u64).saturating_mul(h.into())))
+			.saturating_add(Weight::from_parts(2_500_000, 2_500).saturating_mul(h.into()))
+	}
+	fn undelegate() -> Weight {
+		Weight::from_parts(17_500_000, 0)
+			.saturating_add(T::DbWeight::get().writes(1_u64))
+	}
+	fn vote_as_delegate(a: u32, h: u32) -> Weight {
+		Weight::from_parts(72_500_000, 32_500)
+			.saturating_add(Weight::from_parts(100_000, 32).saturating_mul(a.into()))
+			.saturating_add(T::DbWeight::get().reads(13_u64))
+			.saturating_add(T::DbWeight::get().writes(10_u64))
+			.saturating_add(T::DbWeight::get().reads((1_u64).saturating_mul(h.into())))
+			.saturating_add(Weight::from_parts(2_500_000, 2_500).saturating_mul(h.into()))
+	}
+	fn recount(l: u32) -> Weight {
+		Weight::from_parts(30_000_000, 7_500)
+			.saturating_add(T::DbWeight::get().reads(3_u64))
+			.saturating_add(T::DbWeight::get().writes(3_u64))
+			.saturating_add(T::DbWeight::get().reads((3_u64).saturating_mul(l.into())))
+			.saturating_add(Weight::from_parts(7_500_000, 7_500).saturating_mul(l.into()))
+	}
+	fn audit_proposal(l: u32) -> Weight {
+		Weight::from_parts(22_500_000, 5_000)
+			.saturating_add(T::DbWeight::get().reads(2_u64))
+			.saturating_add(T::DbWeight::get().writes(1_u64))
+			.saturating_add(T::DbWeight::get().reads((3_u64).saturating_mul(l.into())))
+			.saturating_add(Weight::from_parts(7_500_000, 7_500).saturating_mul(l.into()))
+	}
+	fn dispute() -> Weight {
+		Weight::from_parts(30_000_000, 7_500)
+			.saturating_add(T::DbWeight::get().reads(3_u64))
+			.saturating_add(T::DbWeight::get().writes(3_u64))
+	}
+	fn resolve_dispute() -> Weight {
+		Weight::from_parts(50_000_000, 15_000)
+			.saturating_add(T::DbWeight::get().reads(6_u64))
+			.saturating_add(T::DbWeight::get().writes(8_u64))
+	}
+	fn override_outcome() -> Weight {
+		Weight::from_parts(22_500_000, 5_000)
+			.saturating_add(T::DbWeight::get().reads(2_u64))
+			.saturating_add(T::DbWeight::get().writes(1_u64))
+	}
+	fn vote_questions(a: u32) -> Weight {
+		Weight::from_parts(70_000_000, 30_000)
+			.saturating_add(Weight::from_parts(100_000, 32).saturating_mul(a.into()))
+			.saturating_add(T::DbWeight::get().reads(12_u64))
+			.saturating_add(T::DbWeight::get().writes(10_u64))
+	}
+	fn vote_option(a: u32) -> Weight {
+		Weight::from_parts(72_500_000, 32_500)
+			.saturating_add(Weight::from_parts(100_000, 32).saturating_mul(a.into()))
+			.saturating_add(T::DbWeight::get().reads(13_u64))
+			.saturating_add(T::DbWeight::get().writes(10_u64))
+	}
+	fn commit_vote(a: u32) -> Weight {
+		Weight::from_parts(37_500_000, 12_500)
+			.saturating_add(Weight::from_parts(100_000, 32).saturating_mul(a.into()))
+			.saturating_add(T::DbWeight::get().reads(5_u64))
+			.saturating_add(T::DbWeight::get().writes(4_u64))
+	}
+	fn reveal_vote() -> Weight {
+		Weight::from_parts(72_500_000, 32_500)
+			.saturating_add(T::DbWeight::get().reads(13_u64))
+			.saturating_add(T::DbWeight::get().writes(10_u64))
+	}
+	fn claim() -> Weight {
+		Weight::from_parts(52_500_000, 22_500)
+			.saturating_add(T::DbWeight::get().reads(9_u64))
+			.saturating_add(T::DbWeight::get().writes(6_u64))
+	}
+	fn claim_to() -> Weight {
+		Weight::from_parts(52_500_000, 22_500)
+			.saturating_add(T::DbWeight::get().reads(9_u64))
+			.saturating_add(T::DbWeight::get().writes(6_u64))
+	}
+	fn claim_for() -> Weight {
+		Weight::from_parts(52_500_000, 22_500)
+			.saturating_add(T::DbWeight::get().reads(9_u64))
+			.saturating_add(T::DbWeight::get().writes(6_u64))
+	}
+	fn update_account_list(a: u32) -> Weight {
+		Weight::from_parts(20_000_000, 2_500)
+			.saturating_add(T::DbWeight::get().reads(1_u64))
+			.saturating_add(T::DbWeight::get().writes(1_u64))
+			.saturating_add(T::DbWeight::get().writes((1_u64).saturating_mul(a.into())))
+			.saturating_add(Weight::from_parts(2_500_000, 0).saturating_mul(a.into()))
+	}
+	fn copy_account_list(a: u32) -> Weight {
+		Weight::from_parts(22_500_000, 5_000)
+			.saturating_add(T::DbWeight::get().reads(2_u64))
+			.saturating_add(T::DbWeight::get().writes(1_u64))
+			.saturating_add(T::DbWeight::get().writes((2_u64).saturating_mul(a.into())))
+			.saturating_add(Weight::from_parts(5_000_000, 0).saturating_mul(a.into()))
+	}
+	fn sponsor_votes() -> Weight {
+		Weight::from_parts(30_000_000, 7_500)
+			.saturating_add(T::DbWeight::get().reads(3_u64))
+			.saturating_add(T::DbWeight::get().writes(3_u64))
+	}
+	fn fund_lottery() -> Weight {
+		Weight::from_parts(30_000_000, 7_500)
+			.saturating_add(T::DbWeight::get().reads(3_u64))
+			.saturating_add(T::DbWeight::get().writes(3_u64))
+	}
+	fn renew_registration() -> Weight {
+		Weight::from_parts(30_000_000, 7_500)
+			.saturating_add(T::DbWeight::get().reads(3_u64))
+			.saturating_add(T::DbWeight::get().writes(3_u64))
+	}
+	fn take_balance_snapshot() -> Weight {
+		Weight::from_parts(22_500_000, 5_000)
+			.saturating_add(T::DbWeight::get().reads(2_u64))
+			.saturating_add(T::DbWeight::get().writes(1_u64))
+	}
+	fn second() -> Weight {
+		Weight::from_parts(27_500_000, 7_500)
+			.saturating_add(T::DbWeight::get().reads(3_u64))
+			.saturating_add(T::DbWeight::get().writes(2_u64))
+	}
+	fn submit_public_proposal() -> Weight {
+		Weight::from_parts(27_500_000, 5_000)
+			.saturating_add(T::DbWeight::get().reads(2_u64))
+			.saturating_add(T::DbWeight::get().writes(3_u64))
+	}
+	fn create_emergency_proposal() -> Weight {
+		Weight::from_parts(27_500_000, 5_000)
+			.saturating_add(T::DbWeight::get().reads(2_u64))
+			.saturating_add(T::DbWeight::get().writes(3_u64))
+	}
+	fn create_external_proposal(a: u32) -> Weight {
+		Weight::from_parts(17_500_000, 0)
+			.saturating_add(T::DbWeight::get().writes(1_u64))
+			.saturating_add(T::DbWeight::get().writes((1_u64).saturating_mul(a.into())))
+			.saturating_add(Weight::from_parts(2_500_000, 0).saturating_mul(a.into()))
+	}
+	fn create_org() -> Weight {
+		Weight::from_parts(22_500_000, 2_500)
+			.saturating_add(T::DbWeight::get().reads(1_u64))
+			.saturating_add(T::DbWeight::get().writes(2_u64))
+	}
+	fn set_org_admin() -> Weight {
+		Weight::from_parts(20_000_000, 2_500)
+			.saturating_add(T::DbWeight::get().reads(1_u64))
+			.saturating_add(T::DbWeight::get().writes(1_u64))
+	}
+	fn add_org_member() -> Weight {
+		Weight::from_parts(27_500_000, 7_500)
+			.saturating_add(T::DbWeight::get().reads(3_u64))
+			.saturating_add(T::DbWeight::get().writes(2_u64))
+	}
+	fn remove_org_member() -> Weight {
+		Weight::from_parts(25_000_000, 5_000)
+			.saturating_add(T::DbWeight::get().reads(2_u64))
+			.saturating_add(T::DbWeight::get().writes(2_u64))
+	}
+	fn set_org_parameters() -> Weight {
+		Weight::from_parts(20_000_000, 2_500)
+			.saturating_add(T::DbWeight::get().reads(1_u64))
+			.saturating_add(T::DbWeight::get().writes(1_u64))
+	}
+	fn create_org_proposal(a: u32) -> Weight {
+		Weight::from_parts(27_500_000, 5_000)
+			.saturating_add(T::DbWeight::get().reads(2_u64))
+			.saturating_add(T::DbWeight::get().writes(3_u64))
+			.saturating_add(T::DbWeight::get().writes((1_u64).saturating_mul(a.into())))
+			.saturating_add(Weight::from_parts(2_500_000, 0).saturating_mul(a.into()))
+	}
+	fn set_proposal_limits() -> Weight {
+		Weight::from_parts(17_500_000, 0)
+			.saturating_add(T::DbWeight::get().writes(1_u64))
+	}
+}
+
+// For backwards compatibility and tests.
+impl WeightInfo for () {
+	fn register_voter() -> Weight {
+		Weight::from_parts(25_000_000, 5_000)
+			.saturating_add(RocksDbWeight::get().reads(2_u64))
+			.saturating_add(RocksDbWeight::get().writes(2_u64))
+	}
+	fn unregister_voter(v: u32, d: u32) -> Weight {
+		Weight::from_parts(47_500_000, 17_500)
+			.saturating_add(RocksDbWeight::get().reads(7_u64))
+			.saturating_add(RocksDbWeight::get().writes(6_u64))
+			.saturating_add(RocksDbWeight::get().reads((2_u64).saturating_mul(v.into())))
+			.saturating_add(RocksDbWeight::get().writes((3_u64).saturating_mul(v.into())))
+			.saturating_add(RocksDbWeight::get().reads((1_u64).saturating_mul(d.into())))
+			.saturating_add(RocksDbWeight::get().writes((2_u64).saturating_mul(d.into())))
+			.saturating_add(Weight::from_parts(12_500_000, 5_000).saturating_mul(v.into()))
+			.saturating_add(Weight::from_parts(7_500_000, 2_500).saturating_mul(d.into()))
+	}
+	fn self_register() -> Weight {
+		Weight::from_parts(40_000_000, 12_500)
+			.saturating_add(RocksDbWeight::get().reads(5_u64))
+			.saturating_add(RocksDbWeight::get().writes(5_u64))
+	}
+	fn self_unregister(v: u32, d: u32) -> Weight {
+		Weight::from_parts(45_000_000, 15_000)
+			.saturating_add(RocksDbWeight::get().reads(6_u64))
+			.saturating_add(RocksDbWeight::get().writes(6_u64))
+			.saturating_add(RocksDbWeight::get().reads((2_u64).saturating_mul(v.into())))
+			.saturating_add(RocksDbWeight::get().writes((3_u64).saturating_mul(v.into())))
+			.saturating_add(RocksDbWeight::get().reads((1_u64).saturating_mul(d.into())))
+			.saturating_add(RocksDbWeight::get().writes((2_u64).saturating_mul(d.into())))
+			.saturating_add(Weight::from_parts(12_500_000, 5_000).saturating_mul(v.into()))
+			.saturating_add(Weight::from_parts(7_500_000, 2_500).saturating_mul(d.into()))
+	}
+	fn migrate_voter(e: u32) -> Weight {
+		Weight::from_parts(87_500_000, 32_500)
+			.saturating_add(RocksDbWeight::get().reads(13_u64))
+			.saturating_add(RocksDbWeight::get().writes(16_u64))
+			.saturating_add(RocksDbWeight::get().reads((5_u64).saturating_mul(e.into())))
+			.saturating_add(RocksDbWeight::get().writes((9_u64).saturating_mul(e.into())))
+			.saturating_add(Weight::from_parts(35_000_000, 12_500).saturating_mul(e.into()))
+	}
+	fn appoint_deputy() -> Weight {
+		Weight::from_parts(25_000_000, 5_000)
+			.saturating_add(RocksDbWeight::get().reads(2_u64))
+			.saturating_add(RocksDbWeight::get().writes(2_u64))
+	}
+	fn revoke_deputy() -> Weight {
+		Weight::from_parts(25_000_000, 5_000)
+			.saturating_add(RocksDbWeight::get().reads(2_u64))
+			.saturating_add(RocksDbWeight::get().writes(2_u64))
+	}
+	fn sync_voters() -> Weight {
+		Weight::from_parts(30_000_000, 7_500)
+			.saturating_add(RocksDbWeight::get().reads(3_u64))
+			.saturating_add(RocksDbWeight::get().writes(3_u64))
+	}
+	fn create_proposal(a: u32) -> Weight {
+		Weight::from_parts(22_500_000, 0)
+			.saturating_add(RocksDbWeight::get().writes(3_u64))
+			.saturating_add(RocksDbWeight::get().writes((1_u64).saturating_mul(a.into())))
+			.saturating_add(Weight::from_parts(2_500_000, 0).saturating_mul(a.into()))
+	}
+	fn cancel_proposal(a: u32) -> Weight {
+		Weight::from_parts(22_500_000, 0)
+			.saturating_add(RocksDbWeight::get().writes(3_u64))
+			.saturating_add(RocksDbWeight::get().writes((1_u64).saturating_mul(a.into())))
+			.saturating_add(Weight::from_parts(2_500_000, 0).saturating_mul(a.into()))
+	}
+	fn close_proposal(s: u32, w: u32) -> Weight {
+		Weight::from_parts(17_500_000, 0)
+			.saturating_add(RocksDbWeight::get().writes(1_u64))
+			.saturating_add(RocksDbWeight::get().reads((3_u64).saturating_mul(s.into())))
+			.saturating_add(RocksDbWeight::get().reads((2_u64).saturating_mul(w.into())))
+			.saturating_add(RocksDbWeight::get().writes((1_u64).saturating_mul(w.into())))
+			.saturating_add(Weight::from_parts(7_500_000, 7_500).saturating_mul(s.into()))
+			.saturating_add(Weight::from_parts(7_500_000, 5_000).saturating_mul(w.into()))
+	}
+	fn set_account_list(a: u32) -> Weight {
+		Weight::from_parts(17_500_000, 0)
+			.saturating_add(RocksDbWeight::get().writes(1_u64))
+			.saturating_add(RocksDbWeight::get().writes((2_u64).saturating_mul(a.into())))
+			.saturating_add(Weight::from_parts(5_000_000, 0).saturating_mul(a.into()))
+	}
+	fn set_eligibility_bitmap() -> Weight {
+		Weight::from_parts(20_000_000, 2_500)
+			.saturating_add(RocksDbWeight::get().reads(1_u64))
+			.saturating_add(RocksDbWeight::get().writes(1_u64))
+	}
+	fn register_expected_voters(a: u32) -> Weight {
+		Weight::from_parts(17_500_000, 2_500)
+			.saturating_add(RocksDbWeight::get().reads(1_u64))
+			.saturating_add(RocksDbWeight::get().writes((2_u64).saturating_mul(a.into())))
+			.saturating_add(Weight::from_parts(5_000_000, 0).saturating_mul(a.into()))
+	}
+	fn set_voting_asset() -> Weight {
+		Weight::from_parts(17_500_000, 0)
+			.saturating_add(RocksDbWeight::get().writes(1_u64))
+	}
+	fn set_proposal_asset() -> Weight {
+		Weight::from_parts(22_500_000, 5_000)
+			.saturating_add(RocksDbWeight::get().reads(2_u64))
+			.saturating_add(RocksDbWeight::get().writes(1_u64))
+	}
+	fn set_proposal_call() -> Weight {
+		Weight::from_parts(32_500_000, 10_000)
+			.saturating_add(RocksDbWeight::get().reads(4_u64))
+			.saturating_add(RocksDbWeight::get().writes(3_u64))
+	}
+	fn set_options() -> Weight {
+		Weight::from_parts(20_000_000, 2_500)
+			.saturating_add(RocksDbWeight::get().reads(1_u64))
+			.saturating_add(RocksDbWeight::get().writes(1_u64))
+	}
+	fn set_kind_role() -> Weight {
+		Weight::from_parts(17_500_000, 0)
+			.saturating_add(RocksDbWeight::get().writes(1_u64))
+	}
+	fn set_creator_role() -> Weight {
+		Weight::from_parts(17_500_000, 0)
+			.saturating_add(RocksDbWeight::get().writes(1_u64))
+	}
+	fn vote(a: u32) -> Weight {
+		Weight::from_parts(67_500_000, 30_000)
+			.saturating_add(Weight::from_parts(100_000, 32).saturating_mul(a.into()))
+			.saturating_add(RocksDbWeight::get().reads(12_u64))
+			.saturating_add(RocksDbWeight::get().writes(9_u64))
+	}
+	fn vote_with_proof() -> Weight {
+		Weight::from_parts(67_500_000, 30_000)
+			.saturating_add(RocksDbWeight::get().reads(12_u64))
+			.saturating_add(RocksDbWeight::get().writes(9_u64))
+	}
+	fn delegate(h: u32) -> Weight {
+		Weight::from_parts(17_500_000, 0)
+			.saturating_add(RocksDbWeight::get().writes(1_u64))
+			.saturating_add(RocksDbWeight::get().reads((1_u64).saturating_mul(h.into())))
+			.saturating_add(Weight::from_parts(2_500_000, 2_500).saturating_mul(h.into()))
+	}
+	fn undelegate() -> Weight {
+		Weight::from_parts(17_500_000, 0)
+			.saturating_add(RocksDbWeight::get().writes(1_u64))
+	}
+	fn vote_as_delegate(a: u32, h: u32) -> Weight {
+		Weight::from_parts(72_500_000, 32_500)
+			.saturating_add(Weight::from_parts(100_000, 32).saturating_mul(a.into()))
+			.saturating_add(RocksDbWeight::get().reads(13_u64))
+			.saturating_add(RocksDbWeight::get().writes(10_u64))
+			.saturating_add(RocksDbWeight::get().reads((1_u64).saturating_mul(h.into())))
+			.saturating_add(Weight::from_parts(2_500_000, 2_500).saturating_mul(h.into()))
+	}
+	fn recount(l: u32) -> Weight {
+		Weight::from_parts(30_000_000, 7_500)
+			.saturating_add(RocksDbWeight::get().reads(3_u64))
+			.saturating_add(RocksDbWeight::get().writes(3_u64))
+			.saturating_add(RocksDbWeight::get().reads((3_u64).saturating_mul(l.into())))
+			.saturating_add(Weight::from_parts(7_500_000, 7_500).saturating_mul(l.into()))
+	}
+	fn audit_proposal(l: u32) -> Weight {
+		Weight::from_parts(22_500_000, 5_000)
+			.saturating_add(RocksDbWeight::get().reads(2_u64))
+			.saturating_add(RocksDbWeight::get().writes(1_u64))
+			.saturating_add(RocksDbWeight::get().reads((3_u64).saturating_mul(l.into())))
+			.saturating_add(Weight::from_parts(7_500_000, 7_500).saturating_mul(l.into()))
+	}
+	fn dispute() -> Weight {
+		Weight::from_parts(30_000_000, 7_500)
+			.saturating_add(RocksDbWeight::get().reads(3_u64))
+			.saturating_add(RocksDbWeight::get().writes(3_u64))
+	}
+	fn resolve_dispute() -> Weight {
+		Weight::from_parts(50_000_000, 15_000)
+			.saturating_add(RocksDbWeight::get().reads(6_u64))
+			.saturating_add(RocksDbWeight::get().writes(8_u64))
+	}
+	fn override_outcome() -> Weight {
+		Weight::from_parts(22_500_000, 5_000)
+			.saturating_add(RocksDbWeight::get().reads(2_u64))
+			.saturating_add(RocksDbWeight::get().writes(1_u64))
+	}
+	fn vote_questions(a: u32) -> Weight {
+		Weight::from_parts(70_000_000, 30_000)
+			.saturating_add(Weight::from_parts(100_000, 32).saturating_mul(a.into()))
+			.saturating_add(RocksDbWeight::get().reads(12_u64))
+			.saturating_add(RocksDbWeight::get().writes(10_u64))
+	}
+	fn vote_option(a: u32) -> Weight {
+		Weight::from_parts(72_500_000, 32_500)
+			.saturating_add(Weight::from_parts(100_000, 32).saturating_mul(a.into()))
+			.saturating_add(RocksDbWeight::get().reads(13_u64))
+			.saturating_add(RocksDbWeight::get().writes(10_u64))
+	}
+	fn commit_vote(a: u32) -> Weight {
+		Weight::from_parts(37_500_000, 12_500)
+			.saturating_add(Weight::from_parts(100_000, 32).saturating_mul(a.into()))
+			.saturating_add(RocksDbWeight::get().reads(5_u64))
+			.saturating_add(RocksDbWeight::get().writes(4_u64))
+	}
+	fn reveal_vote() -> Weight {
+		Weight::from_parts(72_500_000, 32_500)
+			.saturating_add(RocksDbWeight::get().reads(13_u64))
+			.saturating_add(RocksDbWeight::get().writes(10_u64))
+	}
+	fn claim() -> Weight {
+		Weight::from_parts(52_500_000, 22_500)
+			.saturating_add(RocksDbWeight::get().reads(9_u64))
+			.saturating_add(RocksDbWeight::get().writes(6_u64))
+	}
+	fn claim_to() -> Weight {
+		Weight::from_parts(52_500_000, 22_500)
+			.saturating_add(RocksDbWeight::get().reads(9_u64))
+			.saturating_add(RocksDbWeight::get().writes(6_u64))
+	}
+	fn claim_for() -> Weight {
+		Weight::from_parts(52_500_000, 22_500)
+			.saturating_add(RocksDbWeight::get().reads(9_u64))
+			.saturating_add(RocksDbWeight::get().writes(6_u64))
+	}
+	fn update_account_list(a: u32) -> Weight {
+		Weight::from_parts(20_000_000, 2_500)
+			.saturating_add(RocksDbWeight::get().reads(1_u64))
+			.saturating_add(RocksDbWeight::get().writes(1_u64))
+			.saturating_add(RocksDbWeight::get().writes((1_u64).saturating_mul(a.into())))
+			.saturating_add(Weight::from_parts(2_500_000, 0).saturating_mul(a.into()))
+	}
+	fn copy_account_list(a: u32) -> Weight {
+		Weight::from_parts(22_500_000, 5_000)
+			.saturating_add(RocksDbWeight::get().reads(2_u64))
+			.saturating_add(RocksDbWeight::get().writes(1_u64))
+			.saturating_add(RocksDbWeight::get().writes((2_u64).saturating_mul(a.into())))
+			.saturating_add(Weight::from_parts(5_000_000, 0).saturating_mul(a.into()))
+	}
+	fn sponsor_votes() -> Weight {
+		Weight::from_parts(30_000_000, 7_500)
+			.saturating_add(RocksDbWeight::get().reads(3_u64))
+			.saturating_add(RocksDbWeight::get().writes(3_u64))
+	}
+	fn fund_lottery() -> Weight {
+		Weight::from_parts(30_000_000, 7_500)
+			.saturating_add(RocksDbWeight::get().reads(3_u64))
+			.saturating_add(RocksDbWeight::get().writes(3_u64))
+	}
+	fn renew_registration() -> Weight {
+		Weight::from_parts(30_000_000, 7_500)
+			.saturating_add(RocksDbWeight::get().reads(3_u64))
+			.saturating_add(RocksDbWeight::get().writes(3_u64))
+	}
+	fn take_balance_snapshot() -> Weight {
+		Weight::from_parts(22_500_000, 5_000)
+			.saturating_add(RocksDbWeight::get().reads(2_u64))
+			.saturating_add(RocksDbWeight::get().writes(1_u64))
+	}
+	fn second() -> Weight {
+		Weight::from_parts(27_500_000, 7_500)
+			.saturating_add(RocksDbWeight::get().reads(3_u64))
+			.saturating_add(RocksDbWeight::get().writes(2_u64))
+	}
+	fn submit_public_proposal() -> Weight {
+		Weight::from_parts(27_500_000, 5_000)
+			.saturating_add(RocksDbWeight::get().reads(2_u64))
+			.saturating_add(RocksDbWeight::get().writes(3_u64))
+	}
+	fn create_emergency_proposal() -> Weight {
+		Weight::from_parts(27_500_000, 5_000)
+			.saturating_add(RocksDbWeight::get().reads(2_u64))
+			.saturating_add(RocksDbWeight::get().writes(3_u64))
+	}
+	fn create_external_proposal(a: u32) -> Weight {
+		Weight::from_parts(17_500_000, 0)
+			.saturating_add(RocksDbWeight::get().writes(1_u64))
+			.saturating_add(RocksDbWeight::get().writes((1_u64).saturating_mul(a.into())))
+			.saturating_add(Weight::from_parts(2_500_000, 0).saturating_mul(a.into()))
+	}
+	fn create_org() -> Weight {
+		Weight::from_parts(22_500_000, 2_500)
+			.saturating_add(RocksDbWeight::get().reads(1_u64))
+			.saturating_add(RocksDbWeight::get().writes(2_u64))
+	}
+	fn set_org_admin() -> Weight {
+		Weight::from_parts(20_000_000, 2_500)
+			.saturating_add(RocksDbWeight::get().reads(1_u64))
+			.saturating_add(RocksDbWeight::get().writes(1_u64))
+	}
+	fn add_org_member() -> Weight {
+		Weight::from_parts(27_500_000, 7_500)
+			.saturating_add(RocksDbWeight::get().reads(3_u64))
+			.saturating_add(RocksDbWeight::get().writes(2_u64))
+	}
+	fn remove_org_member() -> Weight {
+		Weight::from_parts(25_000_000, 5_000)
+			.saturating_add(RocksDbWeight::get().reads(2_u64))
+			.saturating_add(RocksDbWeight::get().writes(2_u64))
+	}
+	fn set_org_parameters() -> Weight {
+		Weight::from_parts(20_000_000, 2_500)
+			.saturating_add(RocksDbWeight::get().reads(1_u64))
+			.saturating_add(RocksDbWeight::get().writes(1_u64))
+	}
+	fn create_org_proposal(a: u32) -> Weight {
+		Weight::from_parts(27_500_000, 5_000)
+			.saturating_add(RocksDbWeight::get().reads(2_u64))
+			.saturating_add(RocksDbWeight::get().writes(3_u64))
+			.saturating_add(RocksDbWeight::get().writes((1_u64).saturating_mul(a.into())))
+			.saturating_add(Weight::from_parts(2_500_000, 0).saturating_mul(a.into()))
+	}
+	fn set_proposal_limits() -> Weight {
+		Weight::from_parts(17_500_000, 0)
+			.saturating_add(RocksDbWeight::get().writes(1_u64))
+	}
+}
@@ -7,8 +7,8 @@
 include!(concat!(env!("OUT_DIR"), "/wasm_binary.rs"));
 
 use codec::{Decode, Encode};
-use frame_support::traits::AsEnsureOriginWithArg;
-use frame_system::{EnsureRoot, EnsureSigned};
+use frame_support::traits::{AsEnsureOriginWithArg, Contains, EqualPrivilegeOnly};
+use frame_system::{EnsureRoot, EnsureRootWithSuccess, EnsureSigned};
 use pallet_grandpa::AuthorityId as GrandpaId;
 use sp_api::impl_runtime_apis;
 use sp_consensus_aura::sr25519::AuthorityId as AuraId;
@@ -16,8 +16,8 @@ use sp_core::{crypto::KeyTypeId, OpaqueMetadata};
 use sp_runtime::{
 	create_runtime_str, generic, impl_opaque_keys,
 	traits::{
-		AccountIdLookup, BlakeTwo256, Block as BlockT, Convert, IdentifyAccount, NumberFor, One,
-		Verify,
+		AccountIdConversion, AccountIdLookup, BlakeTwo256, Block as BlockT, Convert, Hash as HashT,
+		IdentifyAccount, NumberFor, One, Verify,
 	},
 	transaction_validity::{TransactionSource, TransactionValidity},
 	ApplyExtrinsicResult, MultiSignature,
@@ -40,7 +40,7 @@ pub use frame_support::{
 		},
 		IdentityFee, Weight,
 	},
-	StorageValue,
+	PalletId, StorageValue,
 };
 pub use frame_system::Call as SystemCall;
 pub use pallet_balances::Call as BalancesCall;
@@ -48,7 +48,7 @@ pub use pallet_timestamp::Call as TimestampCall;
 use pallet_transaction_payment::{ConstFeeMultiplier, CurrencyAdapter, Multiplier};
 #[cfg(any(feature = "std", test))]
 pub use sp_runtime::BuildStorage;
-pub use sp_runtime::{Perbill, Permill};
+pub use sp_runtime::{Perbill, Percent, Permill};
 
 /// An index to a block.
 pub type BlockNumber = u32;
@@ -151,11 +151,19 @@ parameter_types! {
 	pub const SS58Prefix: u8 = 42;
 }
 
+/// Suspends the calls of the voting pallet while one of its stepped migrations is in progress.
+pub struct BaseCallFilter;
+impl Contains<RuntimeCall> for BaseCallFilter {
+	fn contains(call: &RuntimeCall) -> bool {
+		!matches!(call, RuntimeCall::Voting(_)) || !Voting::migrating()
+	}
+}
+
 // Configure FRAME pallets to include in runtime.
 
 impl frame_system::Config for Runtime {
 	/// The basic call filter to use in dispatchable.
-	type BaseCallFilter = frame_support::traits::Everything;
+	type BaseCallFilter = BaseCallFilter;
 	/// The block type for the runtime.
 	type Block = Block;
 	/// Block & extrinsics weights: base values and limits.
@@ -244,9 +252,9 @@ impl pallet_balances::Config for Runtime {
 	type ExistentialDeposit = ConstU128<EXISTENTIAL_DEPOSIT>;
 	type AccountStore = System;
 	type WeightInfo = pallet_balances::weights::SubstrateWeight<Runtime>;
-	type FreezeIdentifier = ();
+	type FreezeIdentifier = RuntimeFreezeReason;
 	type MaxFreezes = ConstU32<50>;
-	type RuntimeHoldReason = ();
+	type RuntimeHoldReason = RuntimeHoldReason;
 	type MaxHolds = ConstU32<50>;
 }
 
@@ -300,30 +308,261 @@ impl pallet_assets::Config for Runtime {
 	type BenchmarkHelper = ();
 }
 
+parameter_types! {
+	pub const PreimageBaseDeposit: Balance = 10 * EXISTENTIAL_DEPOSIT;
+	pub const PreimageByteDeposit: Balance = 1;
+}
+
+impl pallet_preimage::Config for Runtime {
+	type RuntimeEvent = RuntimeEvent;
+	type WeightInfo = pallet_preimage::weights::SubstrateWeight<Runtime>;
+	type Currency = Balances;
+	type ManagerOrigin = EnsureRoot<AccountId>;
+	type BaseDeposit = PreimageBaseDeposit;
+	type ByteDeposit = PreimageByteDeposit;
+}
+
+parameter_types! {
+	pub MaximumSchedulerWeight: Weight = Perbill::from_percent(80) * BlockWeights::get().max_block;
+}
+
+impl pallet_scheduler::Config for Runtime {
+	type RuntimeEvent = RuntimeEvent;
+	type RuntimeOrigin = RuntimeOrigin;
+	type PalletsOrigin = OriginCaller;
+	type RuntimeCall = RuntimeCall;
+	type MaximumWeight = MaximumSchedulerWeight;
+	type ScheduleOrigin = EnsureRoot<AccountId>;
+	type MaxScheduledPerBlock = ConstU32<50>;
+	type WeightInfo = pallet_scheduler::weights::SubstrateWeight<Runtime>;
+	type OriginPrivilegeCmp = EqualPrivilegeOnly;
+	type Preimages = Preimage;
+}
+
 pub const PROPOSAL_ACCOUNT_SIZE_LIMIT: u32 = 1000;
 pub const PROPOSAL_OFFCHAIN_DATA_LIMIT: u32 = 150;
+// Enough for 16384 registered voters
+pub const MAX_ELIGIBILITY_BITMAP_LEN: u32 = 2048;
 pub const PROPOSAL_MAXIMUM_DURATION: BlockNumber = 1000;
 pub const PROPOSAL_MINIMUM_DURATION: BlockNumber = 100;
 pub const PROPOSAL_DELAY_LIMIT: BlockNumber = 100;
+pub const VOICE_CREDITS: u128 = 10_000;
+pub const EPOCH_DURATION: BlockNumber = DAYS;
+pub const ARCHIVE_RETENTION: BlockNumber = 30 * DAYS;
+pub const CLAIM_GRACE_PERIOD: BlockNumber = 7 * DAYS;
+pub const CONVICTION_PERIOD: BlockNumber = DAYS;
+pub const MAX_RELEASES_PER_BLOCK: u32 = 500;
+pub const MAX_CLOSES_PER_BLOCK: u32 = 10;
+pub const MAX_PROPOSALS_PER_KIND: u32 = 1_000;
+pub const MAX_QUESTIONS: u32 = 16;
+pub const MAX_OPTIONS: u32 = 16;
+pub const MAX_DEPUTIES: u32 = 32;
+pub const DEPUTY_PERIOD: BlockNumber = DAYS;
+pub const MAX_JURY_SIZE: u32 = 100;
+pub const MAX_LOTTERY_WINNERS: u32 = 10;
+pub const MAX_DELEGATION_DEPTH: u32 = 5;
+pub const DELEGATION_ATTENUATION: Percent = Percent::from_percent(10);
+pub const MAX_MIGRATED_ENTRIES: u32 = 100;
+pub const TENURE_BONUS_PER_EPOCH: Percent = Percent::from_percent(1);
+pub const MAX_TENURE_BONUS: Percent = Percent::from_percent(20);
+pub const REGISTRATION_TTL: Option<BlockNumber> = Some(180 * DAYS);
+pub const MIN_VOTER_BALANCE: Balance = 100 * EXISTENTIAL_DEPOSIT;
+pub const BALANCE_AGE: BlockNumber = DAYS;
+pub const SECONDS_THRESHOLD: u32 = 3;
+pub const LAUNCH_PERIOD: BlockNumber = DAYS;
+pub const TALLY_CHECKPOINT_PERIOD: BlockNumber = HOURS;
+pub const MAX_CHECKPOINTS_PER_BLOCK: u32 = 50;
+pub const EMERGENCY_MAX_DURATION: BlockNumber = 6 * HOURS;
+pub const EMERGENCY_SUPERMAJORITY: Perbill = Perbill::from_percent(66);
+pub const MAX_POWER: u128 = 1_000_000_000;
+pub const MAX_CALL_SIZE: u32 = 64 * 1024;
+// Charged to every close, up to `MAX_CLOSES_PER_BLOCK` of them in a block
+pub const MAX_ENACTMENT_WEIGHT: Weight =
+	Weight::from_parts(WEIGHT_REF_TIME_PER_SECOND / 50, 64 * 1024);
+pub const MAX_ENACTMENT_RETRIES: u32 = 3;
+pub const ENACTMENT_RETRY_PERIOD: BlockNumber = HOURS;
+// Roughly the fee of a vote with the identity weight to fee conversion
+pub const VOTE_REFUND: Balance = 500_000_000;
+pub const DISPUTE_PERIOD: BlockNumber = 3 * DAYS;
+pub const DISPUTE_BOND: Balance = 1_000 * EXISTENTIAL_DEPOSIT;
+pub const PROPOSAL_DEPOSIT: Balance = 100 * EXISTENTIAL_DEPOSIT;
+pub const REGISTRATION_BOND: Balance = 100 * EXISTENTIAL_DEPOSIT;
+pub const OVERRIDE_PERIOD: BlockNumber = 3 * DAYS;
+pub const AUDIT_SAMPLE_SIZE: u32 = 5;
 
 parameter_types! {
 	pub const AccountSizeLimit: u32 = PROPOSAL_ACCOUNT_SIZE_LIMIT;
 	pub const ProposalOffchainDataLimit: u32 = PROPOSAL_OFFCHAIN_DATA_LIMIT;
+	pub const MaxEligibilityBitmapLen: u32 = MAX_ELIGIBILITY_BITMAP_LEN;
+	// Defaults of the limits Root can override with `Voting::set_proposal_limits`
 	pub const ProposalMaximumDuration: BlockNumber = PROPOSAL_MAXIMUM_DURATION;
 	pub const ProposalMinimumDuration: BlockNumber = PROPOSAL_MINIMUM_DURATION;
 	pub const ProposalDelayLimit: BlockNumber = PROPOSAL_DELAY_LIMIT;
+	pub const EmergencyMaxDuration: BlockNumber = EMERGENCY_MAX_DURATION;
+	pub const ClaimGracePeriod: BlockNumber = CLAIM_GRACE_PERIOD;
+	pub const VoiceCredits: u128 = VOICE_CREDITS;
+	pub const EpochDuration: BlockNumber = EPOCH_DURATION;
+	pub const ArchiveRetention: BlockNumber = ARCHIVE_RETENTION;
+	pub const ConvictionPeriod: BlockNumber = CONVICTION_PERIOD;
+	pub const MaxReleasesPerBlock: u32 = MAX_RELEASES_PER_BLOCK;
+	pub const MaxClosesPerBlock: u32 = MAX_CLOSES_PER_BLOCK;
+	pub const MaxPower: u128 = MAX_POWER;
+	pub const TenureBonusPerEpoch: Percent = TENURE_BONUS_PER_EPOCH;
+	pub const MaxTenureBonus: Percent = MAX_TENURE_BONUS;
+	pub const RegistrationTtl: Option<BlockNumber> = REGISTRATION_TTL;
+	pub const MinVoterBalance: Balance = MIN_VOTER_BALANCE;
+	pub const BalanceAge: BlockNumber = BALANCE_AGE;
+	pub const SecondsThreshold: u32 = SECONDS_THRESHOLD;
+	pub const LaunchPeriod: u32 = LAUNCH_PERIOD;
+	pub const TallyCheckpointPeriod: u32 = TALLY_CHECKPOINT_PERIOD;
+	pub const MaxCheckpointsPerBlock: u32 = MAX_CHECKPOINTS_PER_BLOCK;
+	pub const PublishTallyCommitment: bool = true;
+	pub const EmergencySupermajority: Perbill = EMERGENCY_SUPERMAJORITY;
+	// Emergency and external proposals created by Root are recorded with the sudo key as creator
+	pub RootAccount: AccountId = Sudo::key().unwrap_or_else(|| AccountId::new([0u8; 32]));
+	pub const MaxProposalsPerKind: u32 = MAX_PROPOSALS_PER_KIND;
+	pub const MaxQuestions: u32 = MAX_QUESTIONS;
+	pub const MaxOptions: u32 = MAX_OPTIONS;
+	pub const MaxDeputies: u32 = MAX_DEPUTIES;
+	pub const DeputyPeriod: u32 = DEPUTY_PERIOD;
+	pub const MaxJurySize: u32 = MAX_JURY_SIZE;
+	pub const MaxLotteryWinners: u32 = MAX_LOTTERY_WINNERS;
+	pub const MaxDelegationDepth: u32 = MAX_DELEGATION_DEPTH;
+	pub const DelegationAttenuation: Percent = DELEGATION_ATTENUATION;
+	pub const MaxMigratedEntries: u32 = MAX_MIGRATED_ENTRIES;
+	pub const VotingPalletId: PalletId = PalletId(*b"py/votng");
+	// No treasury pallet in this runtime, the fees accumulate in its keyless account
+	pub TreasuryAccount: AccountId = PalletId(*b"py/trsry").into_account_truncating();
+	pub const VoteRefund: Balance = VOTE_REFUND;
+	pub const DisputePeriod: BlockNumber = DISPUTE_PERIOD;
+	pub const DisputeBond: Balance = DISPUTE_BOND;
+	pub const ProposalDeposit: Balance = PROPOSAL_DEPOSIT;
+	pub const RegistrationBond: Balance = REGISTRATION_BOND;
+	pub const OverridePeriod: BlockNumber = OVERRIDE_PERIOD;
+	pub const AuditSampleSize: u32 = AUDIT_SAMPLE_SIZE;
+	pub EnactmentOrigin: RuntimeOrigin = frame_system::RawOrigin::Root.into();
+	pub const MaxCallSize: u32 = MAX_CALL_SIZE;
+	pub const MaxEnactmentWeight: Weight = MAX_ENACTMENT_WEIGHT;
+	pub const MaxEnactmentRetries: u32 = MAX_ENACTMENT_RETRIES;
+	pub const EnactmentRetryPeriod: BlockNumber = ENACTMENT_RETRY_PERIOD;
 }
 
 /// Configure the pallet-voting in pallets/voting.
 impl pallet_voting::Config for Runtime {
 	type RuntimeEvent = RuntimeEvent;
+	type WeightInfo = pallet_voting::weights::SubstrateWeight<Runtime>;
 	type NativeBalance = Balances;
+	type RuntimeHoldReason = RuntimeHoldReason;
+	type RuntimeFreezeReason = RuntimeFreezeReason;
 	type AccountSizeLimit = AccountSizeLimit;
+	type MaxEligibilityBitmapLen = MaxEligibilityBitmapLen;
 	type ProposalOffchainDataLimit = ProposalOffchainDataLimit;
 	type ProposalMaximumDuration = ProposalMaximumDuration;
 	type ProposalMinimumDuration = ProposalMinimumDuration;
 	type ProposalDelayLimit = ProposalDelayLimit;
-	type FreezeIdForPallet = ();
+	type VoiceCredits = VoiceCredits;
+	type EpochDuration = EpochDuration;
+	type ArchiveRetention = ArchiveRetention;
+	type ClaimGracePeriod = ClaimGracePeriod;
+	type ConvictionPeriod = ConvictionPeriod;
+	type MaxReleasesPerBlock = MaxReleasesPerBlock;
+	type MaxClosesPerBlock = MaxClosesPerBlock;
+	type MaxPower = MaxPower;
+	type TenureBonusPerEpoch = TenureBonusPerEpoch;
+	type MaxTenureBonus = MaxTenureBonus;
+	type RegistrationTtl = RegistrationTtl;
+	type MinVoterBalance = MinVoterBalance;
+	type BalanceAge = BalanceAge;
+	type SecondsThreshold = SecondsThreshold;
+	type LaunchPeriod = LaunchPeriod;
+	type TallyCheckpointPeriod = TallyCheckpointPeriod;
+	type MaxCheckpointsPerBlock = MaxCheckpointsPerBlock;
+	type PublishTallyCommitment = PublishTallyCommitment;
+	type EmergencyOrigin = EnsureRootWithSuccess<AccountId, RootAccount>;
+	// No collective in this runtime, Root stands in for the external origin
+	type ExternalOrigin = EnsureRootWithSuccess<AccountId, RootAccount>;
+	type EmergencyMaxDuration = EmergencyMaxDuration;
+	type EmergencySupermajority = EmergencySupermajority;
+	type MaxProposalsPerKind = MaxProposalsPerKind;
+	type MaxQuestions = MaxQuestions;
+	type MaxOptions = MaxOptions;
+	type Randomness = ParentHashRandomness;
+	type MaxJurySize = MaxJurySize;
+	type MaxLotteryWinners = MaxLotteryWinners;
+	type MaxDelegationDepth = MaxDelegationDepth;
+	type DelegationAttenuation = DelegationAttenuation;
+	type MaxMigratedEntries = MaxMigratedEntries;
+	type PalletId = VotingPalletId;
+	type TreasuryAccount = TreasuryAccount;
+	type VoteRefund = VoteRefund;
+	type DisputePeriod = DisputePeriod;
+	type DisputeBond = DisputeBond;
+	type ProposalDeposit = ProposalDeposit;
+	type RegistrationBond = RegistrationBond;
+	// No collective in this runtime, Root resolves the disputes
+	type DisputeResolutionOrigin = EnsureRoot<AccountId>;
+	// No collective in this runtime, Root overrides the outcomes
+	type OverrideOrigin = EnsureRootWithSuccess<AccountId, RootAccount>;
+	type OverridePeriod = OverridePeriod;
+	type AdminOrigin = EnsureRoot<AccountId>;
+	type AuditSampleSize = AuditSampleSize;
+	// No people chain is connected yet, every account is considered attested.
+	type PersonhoodProvider = ();
+	// There is no identity pallet in this runtime, every account is considered verified.
+	type IdentityVerifier = frame_support::traits::Everything;
+	type KindHandler = ();
+	// Repeals are not enacted in this runtime, only reported by their event
+	type OnRepeal = ();
+	// No NFT pallet in this runtime, the NFT gate and bonus cannot be enabled
+	type Nfts = pallet_voting::NoNfts;
+	type NftCollections = ();
+	type RegistrarOrigin = EnsureRoot<AccountId>;
+	type MaxDeputies = MaxDeputies;
+	type DeputyPeriod = DeputyPeriod;
+	type Preimages = Preimage;
+	type Assets = Assets;
+	// No collective in this runtime, Root maintains the voting assets
+	type AssetOrigin = EnsureRoot<AccountId>;
+	// Root grants the creator roles as well
+	type RoleOrigin = EnsureRoot<AccountId>;
+	type RuntimeCall = RuntimeCall;
+	// No collective in this runtime, approved proposals enact their call as Root
+	type EnactmentOrigin = EnactmentOrigin;
+	type MaxCallSize = MaxCallSize;
+	type MaxEnactmentWeight = MaxEnactmentWeight;
+	type Scheduler = Scheduler;
+	type MaxEnactmentRetries = MaxEnactmentRetries;
+	type EnactmentRetryPeriod = EnactmentRetryPeriod;
+	#[cfg(feature = "runtime-benchmarks")]
+	type BenchmarkHelper = VotingBenchmarkHelper;
+}
+
+/// Votes with the asset 0 and notes the preimages in `pallet_preimage`.
+#[cfg(feature = "runtime-benchmarks")]
+pub struct VotingBenchmarkHelper;
+
+#[cfg(feature = "runtime-benchmarks")]
+impl pallet_voting::BenchmarkHelper<u32> for VotingBenchmarkHelper {
+	fn asset_id() -> u32 {
+		0
+	}
+
+	fn note_preimage(data: Vec<u8>) -> Hash {
+		use frame_support::traits::StorePreimage;
+		<Preimage as StorePreimage>::note(data.into()).expect("the preimage is not too large; qed")
+	}
+}
+
+/// Randomness derived from the parent block hash. It is predictable by the block author, a VRF
+/// based source should replace it before relying on sortition juries.
+pub struct ParentHashRandomness;
+
+impl Randomness<Hash, BlockNumber> for ParentHashRandomness {
+	fn random(subject: &[u8]) -> (Hash, BlockNumber) {
+		let parent_hash = System::parent_hash();
+		let block_number = System::block_number().saturating_sub(1);
+		(BlakeTwo256::hash_of(&(parent_hash, subject)), block_number)
+	}
 }
 
 pub struct AuthorityToAccount;
@@ -348,6 +587,8 @@ construct_runtime!(
 		TransactionPayment: pallet_transaction_payment,
 		Sudo: pallet_sudo,
 		Assets: pallet_assets,
+		Preimage: pallet_preimage,
+		Scheduler: pallet_scheduler,
 		Voting: pallet_voting,
 	}
 );
@@ -375,6 +616,9 @@ pub type UncheckedExtrinsic =
 	generic::UncheckedExtrinsic<Address, RuntimeCall, Signature, SignedExtra>;
 /// The payload being signed in transactions.
 pub type SignedPayload = generic::SignedPayload<RuntimeCall, SignedExtra>;
+/// The migrations applied on runtime upgrade.
+type Migrations = pallet_voting::migrations::Migrations<Runtime>;
+
 /// Executive: handles dispatch to the various modules.
 pub type Executive = frame_executive::Executive<
 	Runtime,
@@ -382,6 +626,7 @@ pub type Executive = frame_executive::Executive<
 	frame_system::ChainContext<Runtime>,
 	Runtime,
 	AllPalletsWithSystem,
+	Migrations,
 >;
 
 #[cfg(feature = "runtime-benchmarks")]
@@ -568,6 +813,80 @@ impl_runtime_apis! {
 		}
 	}
 
+	impl pallet_voting_runtime_api::VotingApi<Block, AccountId, Balance, BlockNumber> for Runtime {
+		fn eligible_proposals(who: AccountId) -> Vec<pallet_voting::ProposalId> {
+			Voting::eligible_proposals(&who)
+		}
+
+		fn tally(proposal_id: pallet_voting::ProposalId) -> Option<pallet_voting::VoteRatio> {
+			Voting::tally(proposal_id)
+		}
+
+		fn outcome(
+			proposal_id: pallet_voting::ProposalId,
+		) -> Option<pallet_voting::ProposalOutcome> {
+			Voting::outcome(proposal_id)
+		}
+
+		fn status(
+			proposal_id: pallet_voting::ProposalId,
+		) -> Option<pallet_voting::ProposalStatus> {
+			Voting::proposal_status(proposal_id)
+		}
+
+		fn proposals_ending_at(block: BlockNumber) -> Vec<pallet_voting::ProposalId> {
+			Voting::proposals_ending_at(block)
+		}
+
+		fn is_registered(who: AccountId) -> bool {
+			Voting::is_registered(&who)
+		}
+
+		fn held_for_voting(who: AccountId) -> Balance {
+			Voting::held_for_voting(&who)
+		}
+
+		fn hold_health(who: AccountId) -> pallet_voting::HoldHealth<Balance> {
+			Voting::hold_health(&who)
+		}
+
+		fn lock_report(who: AccountId) -> pallet_voting::LockReport<Balance> {
+			Voting::lock_report(&who)
+		}
+
+		fn check_vote(
+			who: AccountId,
+			proposal_id: pallet_voting::ProposalId,
+			power: Balance,
+		) -> Option<pallet_voting::VoteShortfall<Balance>> {
+			Voting::check_vote(&who, proposal_id, power)
+		}
+
+		fn vote_proof(
+			proposal_id: pallet_voting::ProposalId,
+			who: AccountId,
+		) -> Option<pallet_voting::VoteProof<Balance>> {
+			Voting::vote_proof(proposal_id, &who)
+		}
+
+		fn archive_proof(
+			era: u32,
+			proposal_id: pallet_voting::ProposalId,
+		) -> Option<pallet_voting::ArchiveProof> {
+			Voting::archive_proof(era, proposal_id)
+		}
+
+		fn validate_proposal(
+			creator: AccountId,
+			kind: pallet_voting::ProposalKind,
+			account_list_len: u32,
+			start: BlockNumber,
+			end: BlockNumber,
+		) -> Result<(), pallet_voting::CreateError> {
+			Voting::validate_proposal(&creator, kind, account_list_len, start, end)
+		}
+	}
+
 	#[cfg(feature = "runtime-benchmarks")]
 	impl frame_benchmarking::Benchmark<Block> for Runtime {
 		fn benchmark_metadata(extra: bool) -> (